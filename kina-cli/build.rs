@@ -4,6 +4,8 @@
 /// Git vars are optional: if the build runs outside a `.git` tree (e.g. a bare
 /// source tarball), the script prints a warning and continues.  Source code
 /// uses `option_env!("VERGEN_GIT_*")` so missing vars gracefully become `None`.
+///
+/// It also generates the gRPC client and server for `kina daemon`.
 fn main() {
     // Non-git vars always available.
     emit_non_git_vars();
@@ -11,6 +13,60 @@ fn main() {
     if let Err(e) = emit_git_vars() {
         println!("cargo:warning=kina build.rs: git provenance unavailable: {e}");
     }
+    compile_daemon_service();
+}
+
+/// Generate `kina.v1.Kina.rs` for the service in `proto/kina/v1/kina.proto`.
+///
+/// The messages are hand-written prost structs in `src/core/daemon.rs`, so
+/// only the service stubs are generated here and building kina does not
+/// need `protoc`. Keep the method list in sync with the .proto file.
+fn compile_daemon_service() {
+    use tonic_build::manual::{Builder, Method, Service};
+
+    println!("cargo:rerun-if-changed=proto/kina/v1/kina.proto");
+
+    let method = |name: &str, route: &str, input: &str, output: &str| {
+        Method::builder()
+            .name(name)
+            .route_name(route)
+            .input_type(format!("crate::core::daemon::proto::{input}"))
+            .output_type(format!("crate::core::daemon::proto::{output}"))
+            .codec_path("tonic::codec::ProstCodec")
+            .build()
+    };
+
+    let service = Service::builder()
+        .name("Kina")
+        .package("kina.v1")
+        .method(method("ping", "Ping", "PingRequest", "PingResponse"))
+        .method(method(
+            "list_clusters",
+            "ListClusters",
+            "ListClustersRequest",
+            "ListClustersResponse",
+        ))
+        .method(method(
+            "get_cluster_status",
+            "GetClusterStatus",
+            "ClusterRequest",
+            "Cluster",
+        ))
+        .method(method(
+            "get_kubeconfig",
+            "GetKubeconfig",
+            "ClusterRequest",
+            "KubeconfigResponse",
+        ))
+        .method(method(
+            "delete_cluster",
+            "DeleteCluster",
+            "DeleteClusterRequest",
+            "DeleteClusterResponse",
+        ))
+        .build();
+
+    Builder::new().compile(&[service]);
 }
 
 fn emit_non_git_vars() {
@@ -7,6 +7,8 @@
 use anyhow::Result;
 use clap::{Args, Parser, Subcommand, ValueEnum};
 
+use crate::utils::validate;
+
 // ---------------------------------------------------------------------------
 // Arch enum
 // ---------------------------------------------------------------------------
@@ -34,9 +36,9 @@ pub enum Arch {
 #[derive(Parser, Debug)]
 #[command(name = "node-image")]
 pub struct BuildNodeImageArgs {
-    /// Kubernetes version to embed (e.g. 1.36.1).
+    /// Kubernetes version to embed (e.g. v1.36.1).
     /// Defaults to the pinned constant matching the Dockerfile ARG.
-    #[arg(long)]
+    #[arg(long, value_parser = validate::kubernetes_version)]
     pub kubernetes_version: Option<String>,
 
     /// Tag to apply to the built image (e.g. kina/node:v1.36.1)
@@ -153,7 +155,11 @@ impl BuildNodeImageArgs {
             )
         })?;
 
-        let k8s_version = self.kubernetes_version.as_deref();
+        // The Dockerfile ARG is the bare package version, without the `v`.
+        let k8s_version = self
+            .kubernetes_version
+            .as_deref()
+            .map(|v| v.trim_start_matches('v'));
         let extra: Vec<&str> = self.build_arg.iter().map(|s| s.as_str()).collect();
         let resolved_args = resolve_build_args(k8s_version, &extra);
 
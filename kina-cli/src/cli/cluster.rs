@@ -2,51 +2,306 @@ use anyhow::{Context, Result};
 use clap::{Args, ValueEnum};
 use std::io::{self, Write};
 use std::path::PathBuf;
-use tracing::{info, warn};
+use std::time::Duration;
+use tracing::{debug, info, warn};
 
-use crate::config::{CniPlugin, Config};
+use crate::config::cluster_config::{ClusterConfig, NetworkingConfig, RuntimeConfig};
+use crate::config::{CniPlugin, Config, DEFAULT_NODE_IMAGE};
+use crate::core::apple_container::{
+    merge_kubeconfig, validate_containerd_config, POD_SECURITY_LEVELS,
+};
 use crate::core::cluster::ClusterManager;
+use crate::core::daemon::DaemonClient;
+use crate::core::events::{read_events, record_event, render_events_table, EventType};
+use crate::core::image_registry::{self, VersionSpec};
 use crate::core::kernel_fetch;
-use crate::core::types::{ClusterInfo, CreateClusterOptions, LoadImageOptions, NodeRole};
+use crate::core::kubernetes::{
+    format_event, parse_pods, render_pod_table, set_service_node_ports, KubernetesClient,
+};
+use crate::core::storage::StorageProvisioner;
+use crate::core::types::{
+    AuditPolicy, ClusterInfo, ClusterStatus, ContainerRuntime, CreateClusterOptions,
+    DeleteClusterOptions, LoadImageOptions, NodeRole, DEFAULT_DELETE_GRACE_PERIOD,
+};
 use crate::core::verify::{
     aggregate_verify, classify_ingress_kubectl_result, controller_conflict_message_multi,
     controller_label, demo_route_type, gateway_parent_ref, http_layer_pass, ingress_probe_targets,
     parse_dns_domain, probe_host, probe_passed, probe_url, render_demo_manifest, ActiveController,
     DemoRouteType, IngressReadiness, ProbeResult,
 };
+use crate::errors::ClusterError;
+use crate::utils::kubeconfig::resolve_primary_kubeconfig_path;
+use crate::utils::text::format_duration;
+use crate::utils::validate;
+
+/// Cluster name used when none is given
+const DEFAULT_CLUSTER_NAME: &str = "kina";
 
 /// Create a new Kubernetes cluster
 #[derive(Args)]
 pub struct CreateArgs {
-    /// Name of the cluster
-    #[arg(default_value = "kina")]
+    /// Name of the cluster (defaults to the kind config's name with
+    /// --from-kind-config, otherwise "kina")
+    #[arg(default_value = DEFAULT_CLUSTER_NAME)]
     pub name: String,
 
     /// Container image to use for the cluster
-    #[arg(long, default_value = "kindest/node:v1.36.1")]
+    #[arg(long, default_value = DEFAULT_NODE_IMAGE)]
     pub image: String,
 
+    /// Re-pull the node image before creating the cluster, even if a copy is
+    /// already present (picks up a tag pushed again to a local registry)
+    #[arg(long)]
+    pub node_image_pull_always: bool,
+
+    /// Kubernetes version to run (e.g. v1.31.0, or v1.31 for the newest published
+    /// patch); selects the matching kindest/node image
+    #[arg(long, value_name = "VERSION", conflicts_with = "image")]
+    pub kubernetes_version: Option<String>,
+
     /// Configuration file for cluster creation
     #[arg(long, value_name = "FILE")]
     pub config: Option<String>,
 
+    /// Create from a kind cluster config: its name, nodes, images and
+    /// networking are used; fields kina can't apply are reported as warnings
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["config", "networking_config", "workers", "control_plane_nodes"])]
+    pub from_kind_config: Option<PathBuf>,
+
+    /// Start from the flags saved in a template (`kina template save`);
+    /// flags given here override the template's
+    #[arg(long, value_name = "NAME")]
+    pub from_template: Option<String>,
+
+    /// The arguments given on the command line (see [`explicit_create_args`]),
+    /// which override a `--from-template` template's
+    #[arg(skip)]
+    pub explicit_args: Vec<String>,
+
     /// Wait for cluster to be ready
     #[arg(long)]
     pub wait: Option<u64>,
 
-    /// Retain cluster after failure
+    /// Block until every kube-system pod is Running, printing per-pod progress.
+    /// Implies `--wait 300` when `--wait` is not set.
     #[arg(long)]
+    pub wait_for_ready: bool,
+
+    /// Return as soon as the node containers are up, without waiting for the
+    /// cluster to become ready
+    #[arg(long, conflicts_with_all = ["wait", "wait_for_ready"])]
+    pub no_wait: bool,
+
+    /// Load this image into every node once the cluster is ready, as with
+    /// `kina load` (repeatable; images load in parallel)
+    #[arg(long, value_name = "IMAGE", conflicts_with = "no_wait")]
+    pub image_load: Vec<String>,
+
+    /// Apply the manifests in this directory once the cluster is ready:
+    /// numbered files (01-namespace.yaml) in numeric order, then the rest
+    /// alphabetically; files starting with `_` are skipped
+    #[arg(long, value_name = "DIR", conflicts_with = "no_wait")]
+    pub manifests: Option<PathBuf>,
+
+    /// Install a storage provisioner once the cluster is ready and make its
+    /// StorageClass the default (`nfs` needs --nfs-server and --nfs-path)
+    #[arg(
+        long,
+        value_enum,
+        value_name = "PROVISIONER",
+        conflicts_with = "no_wait"
+    )]
+    pub default_storage_class: Option<StorageClassArg>,
+
+    /// NFS server for --default-storage-class nfs
+    #[arg(
+        long,
+        value_name = "HOST",
+        required_if_eq("default_storage_class", "nfs")
+    )]
+    pub nfs_server: Option<String>,
+
+    /// Exported directory on --nfs-server; volumes are created beneath it
+    #[arg(
+        long,
+        value_name = "PATH",
+        required_if_eq("default_storage_class", "nfs")
+    )]
+    pub nfs_path: Option<String>,
+
+    /// Retain cluster after failure and save node diagnostics (container inspect,
+    /// kubeadm log, systemd journal) under <data_dir>/diagnostics/<cluster>
+    /// instead of deleting it
+    #[arg(long, alias = "retain-on-failure")]
     pub retain: bool,
 
     /// Skip automatic kubelet CSR approval (may cause TLS errors)
     #[arg(long)]
     pub skip_csr_approval: bool,
 
+    /// Skip writing the cluster kubeconfig to ~/.kube and merging it into the
+    /// primary kubeconfig (retrieve it later with `kina get kubeconfig`)
+    #[arg(long)]
+    pub no_kubeconfig: bool,
+
+    /// Enable API server audit logging; the log is written to
+    /// <data_dir>/<cluster>/audit/audit.log on the host
+    #[arg(long)]
+    pub audit_log: bool,
+
+    /// Audit policy for --audit-log: a level (none, metadata, request,
+    /// requestresponse) picks a bundled policy, anything else is read as a
+    /// policy file (default: metadata, i.e. log metadata of every request)
+    #[arg(long, value_name = "LEVEL|FILE", requires = "audit_log", value_parser = validate::audit_policy)]
+    pub audit_policy: Option<AuditPolicy>,
+
+    /// Create a kubernetes.io/dockerconfigjson Secret with this name in the
+    /// default and kube-system namespaces from --docker-config-json
+    #[arg(long, value_name = "NAME", requires = "docker_config_json", value_parser = validate::object_name)]
+    pub image_pull_secret: Option<String>,
+
+    /// Docker config.json with private registry credentials; copied to every
+    /// node so the kubelet can pull from those registries
+    #[arg(long, value_name = "FILE", requires = "image_pull_secret")]
+    pub docker_config_json: Option<PathBuf>,
+
+    /// Registry (`host[:port]`) that containerd on every node pulls from over
+    /// plain HTTP without TLS verification, e.g. a local `registry:2` (repeatable)
+    #[arg(long = "insecure-registry", value_name = "HOST:PORT", value_parser = validate::registry_host)]
+    pub insecure_registries: Vec<String>,
+
+    /// Use this kubeadm bootstrap token (`<6 chars>.<16 chars>`, lowercase
+    /// alphanumerics) instead of a random one, valid for 24h. It grants node
+    /// join rights, so treat it as a secret
+    #[arg(long, value_name = "TOKEN", value_parser = validate::bootstrap_token)]
+    pub node_bootstrap_token: Option<String>,
+
+    /// KubeSchedulerConfiguration file (e.g. custom profiles or plugin
+    /// weights) kube-scheduler runs with, via its --config flag;
+    /// clientConnection.kubeconfig defaults to kubeadm's scheduler.conf
+    #[arg(long, value_name = "FILE")]
+    pub scheduler_config: Option<PathBuf>,
+
+    /// containerd config.toml snippet (e.g. sandbox image, snapshotter, CNI
+    /// bin dir) merged into every node's /etc/containerd/config.toml before
+    /// kubeadm runs; tables merge key by key, other values replace the node's
+    #[arg(long, value_name = "FILE")]
+    pub containerd_config: Option<PathBuf>,
+
+    /// KubeletConfiguration file (e.g. systemReserved, kubeReserved,
+    /// evictionHard) merged over kina's kubelet defaults for every node;
+    /// cgroupDriver, failSwapOn and serverTLSBootstrap keep kina's values
+    #[arg(long, value_name = "FILE")]
+    pub kubelet_config: Option<PathBuf>,
+
+    /// Extra API server certificate SAN, e.g. a hostname mapped to the
+    /// control plane in /etc/hosts or DNS (repeatable)
+    #[arg(long = "apiserver-san", value_name = "HOSTNAME", value_parser = validate::cert_san)]
+    pub api_server_sans: Vec<String>,
+
+    /// Run CRI-O instead of containerd as the node container runtime. The
+    /// node image must ship CRI-O (stock kindest/node images do not), and
+    /// `kina load` only supports containerd nodes
+    #[arg(
+        long = "cri-o",
+        conflicts_with_all = ["containerd_config", "insecure_registries", "image_load"]
+    )]
+    pub cri_o: bool,
+
+    /// Pass --ignore-preflight-errors=Swap to kubeadm init/join, for VMs
+    /// that report swap to kubeadm (the kubelet already runs with
+    /// failSwapOn: false)
+    #[arg(long)]
+    pub disable_swap_check: bool,
+
+    /// Pass --ignore-preflight-errors=all to kubeadm init/join, for VMs that
+    /// fail preflight checks (swap, kernel modules) Kubernetes runs fine without
+    #[arg(long)]
+    pub skip_kubeadm_preflight: bool,
+
+    /// Port the API server listens on, instead of 6443 (1024-65535). Defaults
+    /// to networking.apiServerPort from --networking-config or a kind config
+    #[arg(long, value_name = "PORT", value_parser = validate::api_server_port)]
+    pub api_server_port: Option<u16>,
+
+    /// Kubernetes node name for a single-node cluster, instead of
+    /// <cluster>-control-plane (the node container keeps that name)
+    #[arg(long, value_name = "NAME", value_parser = validate::node_name)]
+    pub node_name: Option<String>,
+
+    /// Install MetalLB after creation so `type: LoadBalancer` services get an
+    /// external IP on the node network
+    #[arg(long, conflicts_with = "skip_cni")]
+    pub service_type_loadbalancer: bool,
+
+    /// MetalLB address pool as a CIDR on the node network (default:
+    /// .240-.249 of the control plane's /24, below the kube-vip VIP)
+    #[arg(long, value_name = "CIDR", requires = "service_type_loadbalancer", value_parser = validate::subnet)]
+    pub lb_address_range: Option<String>,
+
+    /// Enable Pod Security admission and enforce this Pod Security Standard
+    /// on the `default` namespace (kube-system stays privileged)
+    #[arg(long, value_name = "LEVEL", value_parser = clap::builder::PossibleValuesParser::new(POD_SECURITY_LEVELS))]
+    pub psp: Option<String>,
+
+    /// Skip CNI installation (install one later with `kina install cni`).
+    /// Also set by `networking.disableDefaultCNI: true` in --config or
+    /// --from-kind-config. Nodes stay NotReady until a CNI is installed; no
+    /// kubelet flag is needed since containerd reports the network as not ready
+    /// until a CNI config appears (--network-plugin was removed in Kubernetes 1.24)
+    #[arg(
+        long,
+        visible_alias = "no-default-cni",
+        conflicts_with = "wait_for_ready"
+    )]
+    pub skip_cni: bool,
+
+    /// YAML file overriding the kubeadm networking section (podSubnet,
+    /// serviceSubnet, dnsDomain, apiServerAddress, apiServerPort)
+    #[arg(long, value_name = "FILE")]
+    pub networking_config: Option<PathBuf>,
+
+    /// Extra kube-apiserver flag as <key>=<value>, e.g. feature-gates=Foo=true (repeatable)
+    #[arg(long = "api-server-extra-arg", value_name = "KEY=VALUE", value_parser = validate::extra_arg)]
+    pub api_server_extra_args: Vec<(String, String)>,
+
+    /// Extra kubelet flag as <key>=<value>, e.g. max-pods=250 (repeatable).
+    /// Applied to every node and passed through unchecked: a bad flag can stop
+    /// the kubelet from starting, and overriding node-ip breaks node networking
+    #[arg(long = "kubelet-extra-arg", value_name = "KEY=VALUE", value_parser = validate::extra_arg)]
+    pub kubelet_extra_args: Vec<(String, String)>,
+
+    /// Extra kube-controller-manager flag as <key>=<value>, e.g.
+    /// allocate-node-cidrs=false (repeatable)
+    #[arg(long = "controller-manager-extra-arg", value_name = "KEY=VALUE", value_parser = validate::extra_arg)]
+    pub controller_manager_extra_args: Vec<(String, String)>,
+
+    /// Extra etcd flag as <key>=<value>, e.g. quota-backend-bytes=8589934592
+    /// (repeatable)
+    #[arg(long = "etcd-extra-arg", value_name = "KEY=VALUE", value_parser = validate::extra_arg)]
+    pub etcd_extra_args: Vec<(String, String)>,
+
+    /// Label applied to every node once the cluster is up, e.g.
+    /// example.com/tier=frontend (repeatable)
+    #[arg(long = "node-extra-label", value_name = "KEY=VALUE", value_parser = validate::node_label)]
+    pub node_extra_labels: Vec<(String, String)>,
+
     /// Number of worker nodes (0 = single-node cluster with combined roles)
     #[arg(long, default_value = "0")]
     pub workers: u32,
 
-    /// CNI plugin to use (ptp or cilium)
+    /// Number of control-plane nodes; more than 1 creates an HA control plane
+    /// behind a kube-vip virtual IP (requires macOS 26+)
+    #[arg(long, value_name = "N", default_value = "1", value_parser = clap::value_parser!(u32).range(1..))]
+    pub control_plane_nodes: u32,
+
+    /// kube-vip virtual IP of an HA control plane (default: the highest free
+    /// address of .250-.254 in the control plane's /24). Must not fall inside
+    /// the MetalLB pool
+    #[arg(long, value_name = "IP")]
+    pub control_plane_vip: Option<std::net::Ipv4Addr>,
+
+    /// CNI plugin to use (ptp, cilium, flannel or calico)
     #[arg(long, value_enum, default_value = "ptp")]
     pub cni: CniPluginArg,
 
@@ -60,14 +315,24 @@ pub struct CreateArgs {
 
     /// CPU count for all nodes (overrides per-role config defaults and the built-in default of 4).
     /// Applies to both control-plane and worker nodes.
-    #[arg(long, value_name = "N")]
+    #[arg(long, visible_alias = "cpu", value_name = "N")]
     pub cpus: Option<u32>,
 
-    /// Memory for all nodes (overrides per-role config defaults and the built-in default of 4g).
-    /// Format: <positive-int><m|g> (e.g. "512m", "2g", "8g").
+    /// Memory for all nodes (overrides the cluster config file's memoryLimit, per-role
+    /// config defaults and the built-in default of 4g).
+    /// Format: <number>[K|M|G] with an optional i (e.g. "512m", "2G", "8Gi").
     /// Applies to both control-plane and worker nodes.
     #[arg(long, value_name = "SIZE")]
     pub memory: Option<String>,
+
+    /// Storage limit for all nodes (overrides the cluster config file's storageLimit).
+    /// Validated only: Apple Container has no per-container storage limit yet.
+    #[arg(long, value_name = "SIZE")]
+    pub storage: Option<String>,
+
+    /// Exit successfully without changes if the cluster already exists
+    #[arg(long)]
+    pub if_not_exists: bool,
 }
 
 /// Delete a Kubernetes cluster
@@ -80,6 +345,61 @@ pub struct DeleteArgs {
     /// Delete all clusters
     #[arg(long, conflicts_with = "name")]
     pub all: bool,
+
+    /// Seconds each node gets to shut down cleanly before it is killed
+    #[arg(long, value_name = "SECS", default_value_t = DEFAULT_DELETE_GRACE_PERIOD)]
+    pub grace_period: u64,
+
+    /// Skip the graceful stop and destroy the node containers immediately,
+    /// ignoring cleanup errors; for nodes that no longer respond
+    #[arg(long, conflicts_with = "grace_period")]
+    pub force: bool,
+}
+
+/// Rename a cluster
+#[derive(Args)]
+pub struct RenameArgs {
+    /// Current name of the cluster
+    pub old_name: String,
+
+    /// New name for the cluster
+    pub new_name: String,
+}
+
+/// Create a new cluster from a running cluster's configuration
+#[derive(Args)]
+pub struct DuplicateArgs {
+    /// Name of the running cluster to copy
+    pub source: String,
+
+    /// Name of the new cluster
+    pub dest: String,
+
+    /// CNI plugin for the new cluster (ptp, cilium, flannel or calico);
+    /// defaults to the source cluster's
+    #[arg(long, value_enum)]
+    pub cni: Option<CniPluginArg>,
+
+    /// Path to a custom Linux kernel for the new cluster's node containers
+    #[arg(long = "kernel-path", value_name = "PATH")]
+    pub node_kernel_path: Option<PathBuf>,
+
+    /// Wait for the new cluster to be ready
+    #[arg(long)]
+    pub wait: Option<u64>,
+
+    /// Block until every kube-system pod is Running (implies `--wait 300`)
+    #[arg(long)]
+    pub wait_for_ready: bool,
+
+    /// Retain the new cluster after failure and save node diagnostics under
+    /// <data_dir>/diagnostics/<cluster>
+    #[arg(long, alias = "retain-on-failure")]
+    pub retain: bool,
+
+    /// Skip automatic kubelet CSR approval (may cause TLS errors)
+    #[arg(long)]
+    pub skip_csr_approval: bool,
 }
 
 /// List existing clusters
@@ -88,6 +408,18 @@ pub struct ListArgs {
     /// Show additional details
     #[arg(short, long)]
     pub verbose: bool,
+
+    /// Only show clusters in this state (running, stopped, paused, error)
+    #[arg(long, value_enum, ignore_case = true, value_name = "STATUS")]
+    pub filter: Option<ClusterStatus>,
+
+    /// Sort order for the output
+    #[arg(long, value_enum, default_value = "name")]
+    pub sort: ListSortField,
+
+    /// Output format (`--verbose` implies `table`)
+    #[arg(short, long, value_enum, default_value = "plain")]
+    pub output: ListOutputFormat,
 }
 
 /// Get information about clusters or resources
@@ -99,6 +431,49 @@ pub struct GetArgs {
 
     /// Name of the specific resource (optional)
     pub name: Option<String>,
+
+    /// Write the kubeconfig to this file instead of stdout (kubeconfig only)
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+
+    /// Output format (pods only)
+    #[arg(long, value_enum, default_value = "table")]
+    pub format: PodOutput,
+
+    /// Stream new events as they happen (events only)
+    #[arg(short, long)]
+    pub watch: bool,
+
+    /// Namespace to read from (events default to all namespaces, pods to "default")
+    #[arg(short, long)]
+    pub namespace: Option<String>,
+
+    /// Read pods from all namespaces (pods only)
+    #[arg(short = 'A', long, conflicts_with = "namespace")]
+    pub all_namespaces: bool,
+
+    /// Only show pods matching this label selector, e.g. app=web (pods only)
+    #[arg(short = 'l', long, value_name = "SELECTOR")]
+    pub label_selector: Option<String>,
+
+    /// Merge the kubeconfig into ~/.kube/config (or the first KUBECONFIG
+    /// entry) instead of printing it (kubeconfig only)
+    #[arg(long, conflicts_with = "output")]
+    pub merge: bool,
+
+    /// After merging, switch kubectl's current context to the cluster
+    /// (kubeconfig only)
+    #[arg(long, requires = "merge")]
+    pub set_context: bool,
+}
+
+/// Output formats for `kina get pods`
+#[derive(ValueEnum, Clone, Debug, PartialEq)]
+pub enum PodOutput {
+    Json,
+    Yaml,
+    Wide,
+    Table,
 }
 
 /// Load container images into clusters
@@ -110,6 +485,10 @@ pub struct LoadArgs {
     /// Target cluster name
     #[arg(long, default_value = "kina")]
     pub cluster: String,
+
+    /// Load the image into every running cluster
+    #[arg(long, conflicts_with = "cluster")]
+    pub all_clusters: bool,
 }
 
 /// Install addons to cluster
@@ -130,6 +509,50 @@ pub struct InstallArgs {
     /// Use custom configuration file
     #[arg(long, value_name = "FILE")]
     pub config: Option<String>,
+
+    /// Skip the install if the addon's workload already exists
+    #[arg(long)]
+    pub if_not_exists: bool,
+
+    /// CNI plugin for `kina install cni` (default: the config's default CNI)
+    #[arg(long, value_enum)]
+    pub plugin: Option<CniPluginArg>,
+
+    /// Helm values file for `kina install prometheus-stack`
+    #[arg(long, value_name = "FILE")]
+    pub values: Option<PathBuf>,
+
+    /// Install the high-availability variant of `kina install argocd`
+    /// (needs at least three nodes)
+    #[arg(long)]
+    pub ha: bool,
+
+    /// Istio configuration profile for `kina install istio`
+    #[arg(long, value_enum)]
+    pub profile: Option<IstioProfile>,
+
+    /// Label the `default` namespace for Istio sidecar injection
+    #[arg(long)]
+    pub inject_default: bool,
+
+    /// Node port nginx-ingress HTTP is exposed on (30000-32767; default 30080)
+    #[arg(long, value_name = "PORT", value_parser = validate::node_port)]
+    pub node_port_http: Option<u16>,
+
+    /// Node port nginx-ingress HTTPS is exposed on (30000-32767; default 30443)
+    #[arg(long, value_name = "PORT", value_parser = validate::node_port)]
+    pub node_port_https: Option<u16>,
+}
+
+/// Istio installation profiles offered by `kina install istio`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum IstioProfile {
+    /// istiod only, no gateways
+    Minimal,
+    /// istiod plus an ingress gateway
+    Default,
+    /// Everything, with high trace sampling; for trying out features
+    Demo,
 }
 
 /// Export cluster configuration
@@ -161,6 +584,16 @@ pub struct StatusArgs {
     /// Output format
     #[arg(long, value_enum, default_value = "table")]
     pub output: StatusOutputFormat,
+
+    /// Show the cluster's lifecycle events (create, delete, pause, resume,
+    /// image loads, addon installs), newest first
+    #[arg(long, requires = "name")]
+    pub history: bool,
+
+    /// Only report the number of pending kubelet-serving CSRs (with
+    /// --verbose, the full status is shown as well)
+    #[arg(long, conflicts_with = "history")]
+    pub check_csrs: bool,
 }
 
 /// Approve pending kubelet Certificate Signing Requests
@@ -169,6 +602,14 @@ pub struct ApproveCSRArgs {
     /// Name of the cluster to approve CSRs for
     #[arg(default_value = "kina")]
     pub name: String,
+
+    /// Approve every pending CSR, not just kubelet-serving ones (e.g.
+    /// kube-apiserver-client-kubelet CSRs from TLS bootstrapping). This signs
+    /// whatever any client with CSR create rights asked for, including client
+    /// certificates for arbitrary identities: only use it on local clusters
+    /// whose workloads you trust.
+    #[arg(long)]
+    pub all_types: bool,
 }
 
 /// Re-resolve the control-plane VM IP, rewrite the saved kubeconfig, and
@@ -183,6 +624,95 @@ pub struct KubeconfigArgs {
     pub name: String,
 }
 
+/// Stop a cluster's node VMs, keeping them for a later `kina start`
+#[derive(Args)]
+pub struct PauseArgs {
+    /// Name of the cluster to stop
+    #[arg(default_value = "kina")]
+    pub name: String,
+}
+
+/// Print raw Apple Container inspection data for a cluster's nodes
+#[derive(Args)]
+pub struct InspectArgs {
+    /// Name of the cluster to inspect
+    #[arg(default_value = "kina")]
+    pub name: String,
+
+    /// Only inspect this node (full name, or e.g. `control-plane`, `worker-2`)
+    #[arg(long)]
+    pub node: Option<String>,
+}
+
+/// Open a shell in (or run a command on) a cluster node VM
+#[derive(Args)]
+pub struct SshArgs {
+    /// Name of the cluster
+    pub cluster: String,
+
+    /// Node to connect to (full name, or e.g. `worker-2`; default: the control plane)
+    pub node: Option<String>,
+
+    /// Command to run instead of an interactive bash, after `--`; kina exits
+    /// with its exit code
+    #[arg(last = true, value_name = "COMMAND")]
+    pub command: Vec<String>,
+
+    /// Run through sudo, for node images that do not run as root
+    #[arg(long)]
+    pub root: bool,
+}
+
+/// Start a paused or stopped cluster's node VMs
+#[derive(Args)]
+pub struct StartArgs {
+    /// Name of the cluster to start
+    #[arg(default_value = "kina")]
+    pub name: String,
+}
+
+/// Block until every node is Ready and every kube-system pod is Running.
+///
+/// Exits non-zero on timeout, so it composes in scripts:
+/// `kina create --no-wait dev && kina wait-ready dev && kubectl apply -f app.yaml`.
+#[derive(Args)]
+pub struct WaitReadyArgs {
+    /// Name of the cluster to wait for
+    #[arg(default_value = "kina")]
+    pub name: String,
+
+    /// Seconds to wait before giving up
+    #[arg(long, default_value = "300")]
+    pub timeout: u64,
+}
+
+/// Measure a cluster's performance: pod startup, DNS, volume provisioning and
+/// API server latency, reported as percentiles.
+///
+/// Unlike `kina verify`, which checks that things work, this measures how fast.
+#[derive(Args)]
+pub struct BenchmarkArgs {
+    /// Name of the cluster to benchmark
+    #[arg(default_value = "kina")]
+    pub name: String,
+
+    /// Samples per test (the API test fires a 50-request burst per iteration)
+    #[arg(long, default_value = "5", value_parser = clap::value_parser!(u32).range(1..))]
+    pub iterations: u32,
+
+    /// Output format
+    #[arg(long, value_enum, default_value = "table")]
+    pub output: BenchmarkOutputFormat,
+}
+
+#[derive(clap::ValueEnum, Clone, Debug, PartialEq)]
+pub enum BenchmarkOutputFormat {
+    /// Aligned table (default)
+    Table,
+    /// JSON array of results
+    Json,
+}
+
 #[derive(clap::ValueEnum, Clone)]
 pub enum GetResource {
     /// List clusters
@@ -191,6 +721,31 @@ pub enum GetResource {
     Kubeconfig,
     /// Get cluster nodes
     Nodes,
+    /// Get Kubernetes events, oldest first
+    Events,
+    /// Get pods
+    Pods,
+    /// Get the configuration a cluster was created with (labels, rebuilt
+    /// cluster config and the node's kubeadm config) as YAML
+    Config,
+}
+
+#[derive(clap::ValueEnum, Clone, Debug, PartialEq)]
+pub enum ListSortField {
+    /// Sort by cluster name
+    Name,
+    /// Sort by creation time (oldest first)
+    Created,
+    /// Sort by status, then name
+    Status,
+}
+
+#[derive(clap::ValueEnum, Clone, Debug, PartialEq)]
+pub enum ListOutputFormat {
+    /// One cluster name per line (default)
+    Plain,
+    /// Column-aligned table with NAME, STATUS, NODES, IMAGE and CREATED
+    Table,
 }
 
 #[derive(clap::ValueEnum, Clone)]
@@ -199,6 +754,12 @@ pub enum ExportFormat {
     Kubeconfig,
     /// Export cluster configuration
     Config,
+    /// kind cluster config (`kind create cluster --config <file>`)
+    Kind,
+    /// Terraform `null_resource` running `kina create`/`kina delete`
+    Terraform,
+    /// GitHub Actions steps that install kina and create this cluster
+    GithubActions,
 }
 
 #[derive(clap::ValueEnum, Clone)]
@@ -229,6 +790,42 @@ pub enum AddonType {
     /// Kubernetes Metrics Server (enables `kubectl top` and HPA)
     #[value(name = "metrics-server")]
     MetricsServer,
+    /// CNI plugin (installs or replaces the cluster network plugin)
+    #[value(name = "cni")]
+    Cni,
+    /// kube-prometheus-stack Helm chart (Prometheus, Alertmanager, Grafana);
+    /// requires helm on PATH
+    #[value(name = "prometheus-stack")]
+    PrometheusStack,
+    /// Argo CD GitOps controller, served over plain HTTP on a node port
+    #[value(name = "argocd")]
+    ArgoCD,
+    /// Istio service mesh, installed with istioctl (downloaded if missing)
+    #[value(name = "istio")]
+    Istio,
+}
+
+impl AddonType {
+    /// The workload (`kind`, `name`, `namespace`) an install of this addon creates.
+    /// `kina install --if-not-exists` skips the install when it is already present.
+    /// `None` for the CNI, whose workload depends on the chosen plugin.
+    pub fn target_workload(&self) -> Option<(&'static str, &'static str, &'static str)> {
+        match self {
+            AddonType::NginxIngress => Some(("daemonset", "nginx-ingress", "nginx-ingress")),
+            AddonType::Traefik => Some(("daemonset", "traefik", "traefik")),
+            AddonType::NginxGatewayFabric => Some(("deployment", "nginx-gateway", "nginx-gateway")),
+            AddonType::DemoApp => Some(("deployment", "kina-demo-app", "default")),
+            AddonType::MetricsServer => Some(("deployment", "metrics-server", "kube-system")),
+            AddonType::Cni => None,
+            AddonType::PrometheusStack => Some((
+                "deployment",
+                "kube-prometheus-stack-operator",
+                PROMETHEUS_STACK_NAMESPACE,
+            )),
+            AddonType::ArgoCD => Some(("deployment", "argocd-server", ARGOCD_NAMESPACE)),
+            AddonType::Istio => Some(("deployment", "istiod", "istio-system")),
+        }
+    }
 }
 
 /// Verify a cluster's health end-to-end
@@ -238,142 +835,197 @@ pub struct VerifyArgs {
     pub cluster: Option<String>,
 }
 
-impl CreateArgs {
-    pub async fn execute(&self, config: &Config) -> Result<()> {
-        info!("Creating cluster '{}'", self.name);
-
-        let cluster_manager = ClusterManager::new(config)?;
+/// Parse `kina create` flags into [`CreateArgs`]. A flag given more than once
+/// keeps its last value, so flags appended after a template's override them.
+pub fn parse_create_args(args: &[String]) -> Result<(CreateArgs, clap::ArgMatches)> {
+    use clap::{Args as _, FromArgMatches as _};
 
-        // Resolve the kernel path, applying the zero-step default for --cni cilium.
-        //
-        // Precedence:
-        //   1. --kernel-path flag (explicit, always wins)
-        //   2. Cached pinned kernel in ~/.kina/kernels/<tag>/vmlinux (silent reuse)
-        //   3. Auto-download the pinned kernel (first-run notice printed)
-        //   4. Hard error with --kernel-path escape hatch (offline / unreachable)
-        //
-        // PTP and other CNI plugins use the stock kernel; kernel_fetch is never invoked.
-        let cni_plugin: CniPlugin = self.cni.clone().into();
-        let node_kernel_path = if kernel_fetch::requires_kernel(&cni_plugin) {
-            // Check whether the pinned kernel is already cached.
-            let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
-            let pinned_cache = kernel_fetch::kernel_cache_file(&home, &config.kernel.tag);
-            let cached_verified = if pinned_cache.exists() {
-                // Verify sha256 of the cached file before trusting it.
-                let file_sha = std::fs::read(&pinned_cache).ok().map(|bytes| {
-                    use sha2::{Digest, Sha256};
-                    hex::encode(Sha256::digest(&bytes))
-                });
-                file_sha
-                    .map(|sha| kernel_fetch::verify_sha256(&sha, &config.kernel.sha256))
-                    .unwrap_or(false)
-            } else {
-                false
-            };
+    let matches = CreateArgs::augment_args(clap::Command::new("create"))
+        .args_override_self(true)
+        .try_get_matches_from(std::iter::once("create".to_string()).chain(args.iter().cloned()))?;
+    Ok((CreateArgs::from_arg_matches(&matches)?, matches))
+}
 
-            let cached_pinned = if cached_verified {
-                Some(pinned_cache.clone())
-            } else {
-                None
-            };
+/// The `kina create` arguments given explicitly in `matches` (the `create`
+/// subcommand's), rebuilt as a command line. Values that came from defaults
+/// are left out, as are global flags such as `--verbose`.
+pub fn explicit_create_args(matches: &clap::ArgMatches) -> Vec<String> {
+    use clap::{parser::ValueSource, Args as _};
+
+    let command = CreateArgs::augment_args(clap::Command::new("create"));
+    let mut args = Vec::new();
+    for arg in command.get_arguments() {
+        let id = arg.get_id().as_str();
+        if matches.value_source(id) != Some(ValueSource::CommandLine) {
+            continue;
+        }
+        let raw: Vec<String> = matches
+            .get_raw(id)
+            .into_iter()
+            .flatten()
+            .map(|value| value.to_string_lossy().into_owned())
+            .collect();
+        let flag = match (arg.get_long(), arg.get_short()) {
+            (Some(long), _) => format!("--{}", long),
+            (None, Some(short)) => format!("-{}", short),
+            (None, None) => {
+                args.extend(raw);
+                continue;
+            }
+        };
+        if arg.get_action().takes_values() {
+            args.extend(raw.iter().map(|value| format!("{}={}", flag, value)));
+        } else {
+            args.push(flag);
+        }
+    }
+    args
+}
 
-            match kernel_fetch::resolve_kernel_for_cilium(
-                self.node_kernel_path.clone(),
-                cached_pinned,
-                true, // fetch_ok: assume online; download errors surface via install_kernel
-            ) {
-                Ok(kernel_fetch::KernelChoice::ExplicitPath(p)) => Some(p),
-                Ok(kernel_fetch::KernelChoice::CachedPinned(p)) => {
-                    info!("Using cached pinned kernel: {}", p.display());
-                    Some(p)
-                }
-                Ok(kernel_fetch::KernelChoice::FetchPinned) => {
-                    // First run: print notice and download the pinned kernel.
-                    let notice = kernel_fetch::first_run_notice(
-                        &config.kernel.tag,
-                        kernel_fetch::KERNEL_SIZE_BYTES,
-                    );
-                    println!("{}", notice);
-
-                    let cache_dir = kernel_fetch::kernel_cache_dir(&home, &config.kernel.tag);
-                    // Use pinned_download_url() — the GitHub release asset is named "Image",
-                    // not "vmlinux".  The downloaded bytes are stored locally as "vmlinux"
-                    // by install_kernel via kernel_cache_file.
-                    let url = kernel_fetch::pinned_download_url();
-
-                    struct HttpFetcher;
-                    impl kernel_fetch::KernelFetcher for HttpFetcher {
-                        fn fetch(
-                            &self,
-                            url: &str,
-                            dest_tmp: &std::path::Path,
-                        ) -> Result<u64, String> {
-                            // Synchronous download via reqwest blocking client.
-                            let mut response = reqwest::blocking::get(url)
-                                .map_err(|e| format!("HTTP request failed: {}", e))?;
-                            if !response.status().is_success() {
-                                return Err(format!(
-                                    "HTTP {} downloading {}",
-                                    response.status(),
-                                    url
-                                ));
-                            }
-                            let mut file = std::fs::File::create(dest_tmp)
-                                .map_err(|e| format!("cannot create temp file: {}", e))?;
-                            let bytes = std::io::copy(&mut response, &mut file)
-                                .map_err(|e| format!("write error: {}", e))?;
-                            Ok(bytes)
-                        }
-                    }
+/// Template flags followed by the command line's own `kina create` arguments
+/// (minus `--from-template`), so the command line wins.
+pub fn merge_template_args(template: &[String], cli: &[String]) -> Vec<String> {
+    let mut merged = template.to_vec();
+    let mut cli = cli.iter();
+    while let Some(arg) = cli.next() {
+        if arg == "--from-template" {
+            cli.next();
+        } else if !arg.starts_with("--from-template=") {
+            merged.push(arg.clone());
+        }
+    }
+    merged
+}
 
-                    let fetcher = HttpFetcher;
-                    match kernel_fetch::install_kernel(
-                        &fetcher,
-                        &url,
-                        &config.kernel.sha256,
-                        &cache_dir,
-                    ) {
-                        Ok(path) => {
-                            info!("Kernel installed to: {}", path.display());
-                            Some(path)
-                        }
-                        Err(e) => {
-                            return Err(anyhow::anyhow!(e));
-                        }
-                    }
+impl CreateArgs {
+    /// The `--default-storage-class` selection, with its NFS flags checked
+    fn storage_provisioner(&self) -> Result<Option<StorageProvisioner>> {
+        let nfs = self.default_storage_class == Some(StorageClassArg::Nfs);
+        if !nfs && (self.nfs_server.is_some() || self.nfs_path.is_some()) {
+            return Err(anyhow::anyhow!(
+                "--nfs-server and --nfs-path are only valid with --default-storage-class nfs"
+            ));
+        }
+        Ok(match self.default_storage_class {
+            None | Some(StorageClassArg::None) => None,
+            Some(StorageClassArg::LocalPath) => Some(StorageProvisioner::LocalPath),
+            Some(StorageClassArg::Nfs) => {
+                let server = self.nfs_server.clone().unwrap_or_default();
+                let path = self.nfs_path.clone().unwrap_or_default();
+                if server.is_empty() || server.contains(|c: char| c.is_whitespace() || c == '/') {
+                    return Err(anyhow::anyhow!("Invalid --nfs-server '{}'", server));
                 }
-                Err(e) => {
-                    return Err(anyhow::anyhow!(e));
+                if !path.starts_with('/') || path.contains(char::is_whitespace) {
+                    return Err(anyhow::anyhow!(
+                        "--nfs-path must be an absolute path, got '{}'",
+                        path
+                    ));
                 }
+                Some(StorageProvisioner::Nfs { server, path })
             }
-        } else {
-            // PTP and other CNI plugins: use the explicit flag or config default (stock kernel).
-            crate::core::apple_container::select_kernel_path(
-                self.node_kernel_path.clone(),
-                config.cluster.node_kernel_path.clone(),
-            )
+        })
+    }
+
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        if let Some(template) = &self.from_template {
+            let saved = crate::core::template::load_template(&config.cluster.data_dir, template)?;
+            let (merged, _) =
+                parse_create_args(&merge_template_args(&saved.args, &self.explicit_args))
+                    .with_context(|| {
+                        format!("Template '{}' does not combine with these flags", template)
+                    })?;
+            info!("Creating cluster from template '{}'", template);
+            return Box::pin(merged.execute(config)).await;
+        }
+
+        let kind_config = match &self.from_kind_config {
+            Some(path) => Some(load_kind_config(path).await?),
+            None => None,
+        };
+        // A positional name wins over the kind config's, as with `kind --name`.
+        let name = match &kind_config {
+            Some(kind_config) if self.name == DEFAULT_CLUSTER_NAME => kind_config.name.clone(),
+            _ => self.name.clone(),
         };
+        let name = name.as_str();
+
+        if let Some(version) = &self.kubernetes_version {
+            match image_registry::parse_version_spec(version)? {
+                VersionSpec::Exact(version) => {
+                    validate::kubernetes_version(&version)?;
+                }
+                VersionSpec::Minor(major, minor) => validate::kubernetes_release(major, minor)?,
+            }
+        }
+
+        info!("Creating cluster '{}'", name);
+
+        let cluster_manager = ClusterManager::new(config)?;
+
+        if self.if_not_exists && cluster_manager.cluster_exists(name).await? {
+            println!("Cluster already exists, skipping: '{}'", name);
+            return Ok(());
+        }
+
+        // Resolve the kernel path, applying the zero-step default for --cni cilium.
+        let cni_plugin: CniPlugin = self.cni.clone().into();
+        let node_kernel_path =
+            resolve_node_kernel_path(&cni_plugin, self.node_kernel_path.clone(), config)?;
 
-        // Resolve per-role resource values: CLI flag > per-role config default > built-in default.
+        // Resolve per-role resource values:
+        // CLI flag > cluster config file runtimeConfig > per-role config default > built-in default.
         // The --cpus/--memory flags apply to ALL nodes (both control-plane and worker).
         use crate::core::apple_container::{
-            resolve_cpus, resolve_memory, validate_resources, DEFAULT_NODE_CPUS,
-            DEFAULT_NODE_MEMORY,
+            audit_log_dir, container_memory_arg, macos_major_version, parse_cpu_limit,
+            resolve_cpus, resolve_memory, resolve_wait_timeout, validate_control_plane_count,
+            validate_resources, DEFAULT_NODE_CPUS, DEFAULT_NODE_MEMORY,
         };
 
-        let control_plane_cpus = resolve_cpus(
-            self.cpus,
-            config.cluster.control_plane_cpus,
-            DEFAULT_NODE_CPUS,
-        );
+        // Only limits written in the file count; RuntimeConfig::default() values do not.
+        let cluster_config = match kind_config {
+            Some(kind_config) => Some(kind_config),
+            None => load_cluster_config(self.config.as_deref()).await?,
+        };
+        let runtime_limits = cluster_config
+            .as_ref()
+            .and_then(|c| c.runtime_config.clone());
+        let file_limit = |field: fn(&RuntimeConfig) -> &Option<String>| {
+            runtime_limits.as_ref().and_then(|r| field(r).clone())
+        };
+
+        let cpus = match self.cpus {
+            Some(cpus) => Some(cpus),
+            None => file_limit(|r| &r.cpu_limit)
+                .as_deref()
+                .map(parse_cpu_limit)
+                .transpose()?,
+        };
+        let memory = match self.memory.clone().or(file_limit(|r| &r.memory_limit)) {
+            Some(memory) => {
+                validate::memory_limit(&memory)?;
+                Some(container_memory_arg(&memory))
+            }
+            None => None,
+        };
+        if let Some(storage) = self.storage.clone().or(file_limit(|r| &r.storage_limit)) {
+            validate::storage_limit(&storage)?;
+            warn!(
+                "Apple Container does not support per-container storage limits; \
+                 ignoring storage limit '{}'",
+                storage
+            );
+        }
+
+        let control_plane_cpus =
+            resolve_cpus(cpus, config.cluster.control_plane_cpus, DEFAULT_NODE_CPUS);
         let control_plane_memory = resolve_memory(
-            self.memory.as_deref(),
+            memory.as_deref(),
             config.cluster.control_plane_memory.as_deref(),
             DEFAULT_NODE_MEMORY,
         );
-        let worker_cpus = resolve_cpus(self.cpus, config.cluster.worker_cpus, DEFAULT_NODE_CPUS);
+        let worker_cpus = resolve_cpus(cpus, config.cluster.worker_cpus, DEFAULT_NODE_CPUS);
         let worker_memory = resolve_memory(
-            self.memory.as_deref(),
+            memory.as_deref(),
             config.cluster.worker_memory.as_deref(),
             DEFAULT_NODE_MEMORY,
         );
@@ -384,20 +1036,159 @@ impl CreateArgs {
         validate_resources(worker_cpus, &worker_memory)
             .context("Invalid worker resource specification")?;
 
+        let networking = match &self.networking_config {
+            Some(path) => Some(load_networking_config(path).await?),
+            None => cluster_config
+                .as_ref()
+                .filter(|c| self.from_kind_config.is_some() && c.networking != Default::default())
+                .map(|c| c.networking.clone()),
+        };
+        // networking.apiServerPort is the default for --api-server-port
+        let api_server_port = match (
+            self.api_server_port,
+            networking.as_ref().and_then(|n| n.api_server_port),
+        ) {
+            (Some(port), _) => Some(port),
+            (None, Some(port)) => Some(
+                validate::api_server_port(&port.to_string())
+                    .context("Invalid networking.apiServerPort")?,
+            ),
+            (None, None) => None,
+        };
+        let skip_cni = self.skip_cni
+            || networking.as_ref().is_some_and(|n| n.disable_default_cni)
+            || cluster_config
+                .as_ref()
+                .is_some_and(|c| c.networking.disable_default_cni);
+
+        // Node images in a kind config win over --image/--kubernetes-version.
+        let kind_image = cluster_config
+            .as_ref()
+            .filter(|_| self.from_kind_config.is_some())
+            .and_then(|c| c.nodes.iter().find_map(|n| n.image.clone()));
+        let image = match (kind_image, &self.kubernetes_version) {
+            (Some(image), _) => image,
+            (None, Some(version)) => image_registry::resolve_node_image(version).await?,
+            (None, None) => self.image.clone(),
+        };
+        let workers = match (&self.from_kind_config, &cluster_config) {
+            (Some(_), Some(c)) => c.worker_nodes().len() as u32,
+            _ => self.workers,
+        };
+        let control_plane_nodes = match (&self.from_kind_config, &cluster_config) {
+            (Some(_), Some(c)) => c.control_plane_nodes().len() as u32,
+            _ => self.control_plane_nodes,
+        };
+        validate_control_plane_count(control_plane_nodes, macos_major_version())?;
+        if self.skip_kubeadm_preflight {
+            warn!(
+                "--skip-kubeadm-preflight ignores every kubeadm preflight error; \
+                 a node that really cannot run Kubernetes will fail later and less clearly"
+            );
+        }
+        if let Some(vip) = self.control_plane_vip {
+            if control_plane_nodes < 2 {
+                return Err(anyhow::anyhow!(
+                    "--control-plane-vip needs an HA control plane (--control-plane-nodes 2 or more)"
+                ));
+            }
+            if self.service_type_loadbalancer
+                && in_lb_address_pool(vip, self.lb_address_range.as_deref())?
+            {
+                return Err(anyhow::anyhow!(
+                    "--control-plane-vip {} is inside the MetalLB address pool",
+                    vip
+                ));
+            }
+        }
+
+        if self.node_name.is_some() && (workers > 0 || control_plane_nodes > 1) {
+            return Err(anyhow::anyhow!(
+                "--node-name only applies to single-node clusters (no --workers and one control-plane node)"
+            ));
+        }
+
+        // Labels every kind node shares become --node-extra-label defaults.
+        let mut node_labels = std::collections::BTreeMap::new();
+        if let Some(kind_config) = cluster_config
+            .as_ref()
+            .filter(|_| self.from_kind_config.is_some())
+        {
+            for (key, value) in kind_config.common_node_labels() {
+                validate::node_label(&format!("{}={}", key, value))?;
+                node_labels.insert(key, value);
+            }
+        }
+        node_labels.extend(self.node_extra_labels.iter().cloned());
+
+        let etcd = cluster_config.as_ref().and_then(|c| c.etcd.clone());
+        let pod_subnet = networking
+            .as_ref()
+            .and_then(|n| n.pod_subnet.clone())
+            .unwrap_or_else(|| crate::core::apple_container::DEFAULT_POD_SUBNET.to_string());
+
+        let containerd_config = self
+            .containerd_config
+            .as_deref()
+            .map(|path| -> Result<String> {
+                let contents = std::fs::read_to_string(path).with_context(|| {
+                    format!("Failed to read containerd config file {}", path.display())
+                })?;
+                validate_containerd_config(&contents).with_context(|| {
+                    format!("Invalid containerd config file {}", path.display())
+                })?;
+                Ok(contents)
+            })
+            .transpose()?;
+
         let options = CreateClusterOptions {
-            name: self.name.clone(),
-            image: self.image.clone(),
+            name: name.to_string(),
+            image,
             config_file: self.config.as_ref().map(PathBuf::from),
-            kubernetes_version: None, // Use default
-            workers: if self.workers > 0 {
-                Some(self.workers)
+            kubernetes_version: self.kubernetes_version.clone(),
+            workers: if workers > 0 { Some(workers) } else { None },
+            control_plane_nodes: Some(control_plane_nodes),
+            wait_timeout: resolve_wait_timeout(self.wait, self.wait_for_ready),
+            wait_for_ready: self.wait_for_ready,
+            no_wait: self.no_wait,
+            retain_on_failure: self.retain || config.cluster.retain_on_failure,
+            skip_csr_approval: self.skip_csr_approval,
+            skip_kubeconfig: self.no_kubeconfig,
+            oidc: cluster_config.and_then(|c| c.oidc),
+            audit_log: self.audit_log,
+            audit_policy: self.audit_policy.clone(),
+            pod_security_standard: self.psp.clone(),
+            node_image_pull_always: self.node_image_pull_always,
+            skip_cni,
+            networking,
+            api_server_extra_args: self.api_server_extra_args.iter().cloned().collect(),
+            kubelet_extra_args: self.kubelet_extra_args.iter().cloned().collect(),
+            controller_manager_extra_args: self
+                .controller_manager_extra_args
+                .iter()
+                .cloned()
+                .collect(),
+            control_plane_vip: self.control_plane_vip.map(|vip| vip.to_string()),
+            etcd_extra_args: self.etcd_extra_args.iter().cloned().collect(),
+            node_labels,
+            image_pull_secret: self.image_pull_secret.clone(),
+            pull_secret_config: self.docker_config_json.clone(),
+            insecure_registries: self.insecure_registries.clone(),
+            bootstrap_token: self.node_bootstrap_token.clone(),
+            scheduler_config_file: self.scheduler_config.clone(),
+            containerd_config,
+            kubelet_config_file: self.kubelet_config.clone(),
+            api_server_sans: self.api_server_sans.clone(),
+            container_runtime: if self.cri_o {
+                ContainerRuntime::CriO
             } else {
-                None
+                ContainerRuntime::Containerd
             },
-            control_plane_nodes: None, // Use default
-            wait_timeout: self.wait,
-            retain_on_failure: self.retain,
-            skip_csr_approval: self.skip_csr_approval,
+            disable_swap_check: self.disable_swap_check,
+            skip_preflight: self.skip_kubeadm_preflight,
+            api_server_port,
+            node_name: self.node_name.clone(),
+            etcd,
             cni_plugin,
             node_kernel_path,
             control_plane_cpus,
@@ -406,24 +1197,498 @@ impl CreateArgs {
             worker_memory,
         };
 
-        cluster_manager.create_cluster(options).await?;
+        let storage_provisioner = self.storage_provisioner()?;
+        // Listed up front so a missing directory fails before anything is created.
+        let startup_manifests = self
+            .manifests
+            .as_deref()
+            .map(crate::cli::kubectl::startup_manifest_files)
+            .transpose()?;
+
+        let data_dir = &config.cluster.data_dir;
+        record_event(data_dir, name, EventType::CreateStart, "kina create", None);
+        if let Err(e) = cluster_manager.create_cluster(options).await {
+            record_event(
+                data_dir,
+                name,
+                EventType::CreateFailure,
+                "kina create",
+                Some(format!("{:#}", e)),
+            );
+            return Err(e);
+        }
+        record_event(
+            data_dir,
+            name,
+            EventType::CreateSuccess,
+            "kina create",
+            None,
+        );
 
-        println!("✅ Cluster '{}' created successfully", self.name);
+        println!("✅ Cluster '{}' created successfully", name);
+        if self.no_wait {
+            println!(
+                "Cluster created. Run 'kina status {0}' to monitor progress, or \
+                 'kina wait-ready {0}' to block until ready.",
+                name
+            );
+        }
+        if self.no_kubeconfig {
+            println!("Kubeconfig not merged. Use 'kina get kubeconfig' to access the cluster.");
+        }
+        if self.audit_log {
+            let log_dir = audit_log_dir(&config.cluster.data_dir, name);
+            println!("Audit log: {}", log_dir.join("audit.log").display());
+        }
+        if skip_cni {
+            println!("{}", no_cni_advisory(name, &pod_subnet));
+        }
+        if !self.image_load.is_empty() {
+            info!(
+                "Loading {} image(s) into cluster '{}'",
+                self.image_load.len(),
+                name
+            );
+            futures::future::try_join_all(self.image_load.iter().map(|image| {
+                cluster_manager.load_image(LoadImageOptions {
+                    cluster: name.to_string(),
+                    image: image.clone(),
+                    archive: None,
+                })
+            }))
+            .await?;
+            for image in &self.image_load {
+                record_event(
+                    data_dir,
+                    name,
+                    EventType::ImageLoad,
+                    "kina create",
+                    Some(image.clone()),
+                );
+                println!("✅ Image '{}' loaded into cluster '{}'", image, name);
+            }
+        }
+        if let Some(provisioner) = &storage_provisioner {
+            cluster_manager
+                .setup_storage_class(name, provisioner)
+                .await?;
+            println!(
+                "✅ Default StorageClass '{}' ready",
+                provisioner.storage_class()
+            );
+        }
+        if let Some(files) = &startup_manifests {
+            let kubeconfig = cluster_manager.kubeconfig_path(name).await?;
+            let kubeconfig = kubeconfig.to_string_lossy();
+            let kubectl = KubernetesClient::new(config)?;
+            for file in files {
+                println!("Applying {}", file.display());
+                let manifest = crate::cli::kubectl::read_manifest(file)?;
+                kubectl
+                    .apply_manifest(&kubeconfig, &manifest)
+                    .await
+                    .with_context(|| format!("Failed to apply {}", file.display()))?;
+            }
+            println!("✅ Applied {} manifest(s)", files.len());
+        }
+        if self.service_type_loadbalancer {
+            let range = match &self.lb_address_range {
+                Some(range) => range.clone(),
+                None => {
+                    let cluster = cluster_manager.get_cluster_status(name).await?;
+                    let cp_ip = cluster
+                        .nodes
+                        .iter()
+                        .find(|n| n.role == NodeRole::ControlPlane)
+                        .and_then(|n| n.ip_address.as_deref())
+                        .ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "Cannot derive a LoadBalancer address range: control plane of '{}' has no IP; pass --lb-address-range",
+                                name
+                            )
+                        })?;
+                    default_lb_address_range(cp_ip)?
+                }
+            };
+            let kubeconfig = cluster_manager.kubeconfig_path(name).await?;
+            let kubectl = KubernetesClient::new(config)?;
+            install_metallb(&kubectl, &kubeconfig.to_string_lossy(), &range).await?;
+            println!(
+                "✅ MetalLB {} installed; LoadBalancer address pool '{}': {}",
+                METALLB_VERSION, METALLB_POOL_NAME, range
+            );
+        }
         Ok(())
     }
 }
 
+/// MetalLB release installed by `kina create --service-type-loadbalancer`
+const METALLB_VERSION: &str = "v0.14.9";
+
+/// Name of the MetalLB `IPAddressPool` and `L2Advertisement` kina creates
+const METALLB_POOL_NAME: &str = "kina-pool";
+
+fn metallb_manifest_url() -> String {
+    format!(
+        "https://raw.githubusercontent.com/metallb/metallb/{}/config/manifests/metallb-native.yaml",
+        METALLB_VERSION
+    )
+}
+
+/// Default MetalLB pool: `.240-.249` of `node_ip`'s /24. LoadBalancer IPs must
+/// sit on the node network to be reachable from the host (pod CIDRs are not
+/// routed there). vmnet hands out VM addresses from the bottom of the subnet
+/// upwards, and `.250-.254` is left for the HA control plane's kube-vip VIP.
+fn default_lb_address_range(node_ip: &str) -> Result<String> {
+    let ip: std::net::Ipv4Addr = node_ip
+        .parse()
+        .with_context(|| format!("Invalid node IP '{}'", node_ip))?;
+    let [a, b, c, _] = ip.octets();
+    Ok(format!("{a}.{b}.{c}.240-{a}.{b}.{c}.249"))
+}
+
+/// Whether `ip` falls in the MetalLB pool: the `lb_address_range` CIDR when
+/// given, otherwise the default `.240-.249` of `ip`'s /24.
+fn in_lb_address_pool(ip: std::net::Ipv4Addr, lb_address_range: Option<&str>) -> Result<bool> {
+    let Some(range) = lb_address_range else {
+        return Ok((240..=249).contains(&ip.octets()[3]));
+    };
+    let (network, prefix) = range
+        .split_once('/')
+        .and_then(|(network, prefix)| {
+            Some((
+                network.parse::<std::net::Ipv4Addr>().ok()?,
+                prefix.parse::<u32>().ok().filter(|p| *p <= 32)?,
+            ))
+        })
+        .ok_or_else(|| anyhow::anyhow!("Invalid LoadBalancer address range '{}'", range))?;
+    let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+    Ok(u32::from(ip) & mask == u32::from(network) & mask)
+}
+
+/// `IPAddressPool` handing out `range` plus an `L2Advertisement` announcing it
+fn metallb_pool_manifest(range: &str) -> String {
+    format!(
+        "apiVersion: metallb.io/v1beta1
+kind: IPAddressPool
+metadata:
+  name: {pool}
+  namespace: metallb-system
+spec:
+  addresses:
+    - {range}
+---
+apiVersion: metallb.io/v1beta1
+kind: L2Advertisement
+metadata:
+  name: {pool}
+  namespace: metallb-system
+spec:
+  ipAddressPools:
+    - {pool}
+",
+        pool = METALLB_POOL_NAME,
+        range = range
+    )
+}
+
+/// Apply the MetalLB release manifest, wait for its controller (which serves the
+/// validating webhook), then create the address pool. The pool apply is retried
+/// because the webhook can refuse requests for a few seconds after the
+/// controller reports Available.
+async fn install_metallb(kubectl: &KubernetesClient, kubeconfig: &str, range: &str) -> Result<()> {
+    info!("Installing MetalLB {}", METALLB_VERSION);
+    let url = metallb_manifest_url();
+    kubectl
+        .ensure_namespace(kubeconfig, "metallb-system")
+        .await?;
+    kubectl
+        .apply_manifest_url(kubeconfig, &url)
+        .await
+        .with_context(|| format!("Failed to apply MetalLB manifest {}", url))?;
+    kubectl
+        .wait_for_deployment(
+            kubeconfig,
+            "metallb-system",
+            "controller",
+            Duration::from_secs(180),
+        )
+        .await
+        .context("MetalLB controller did not become Available")?;
+
+    let manifest = metallb_pool_manifest(range);
+    crate::utils::retry::retry_with_backoff(6, Duration::from_secs(2), 2.0, || async {
+        kubectl
+            .apply_manifest(kubeconfig, &manifest)
+            .await
+            .context("Failed to apply the MetalLB address pool")
+    })
+    .await
+}
+
+/// Post-create advice for a cluster created without a CNI: what is broken and
+/// which CNIs kina can install.
+fn no_cni_advisory(name: &str, pod_subnet: &str) -> String {
+    let plugins: Vec<String> = CniPluginArg::value_variants()
+        .iter()
+        .filter_map(|v| v.to_possible_value())
+        .map(|v| v.get_name().to_string())
+        .collect();
+    format!(
+        "⚠️  No CNI installed. Nodes stay NotReady and pods stay Pending until a CNI plugin is installed.\n   \
+         Run 'kina install cni --cluster {name} --plugin <{}>', or apply any CNI's manifests with kubectl \
+         using pod subnet {}.",
+        plugins.join("|"),
+        pod_subnet,
+    )
+}
+
+/// Load a `--from-kind-config` file, logging a warning for every kind field
+/// that is dropped or that `kina create` does not apply yet.
+async fn load_kind_config(path: &std::path::Path) -> Result<ClusterConfig> {
+    let (kind_config, warnings) = ClusterConfig::from_kind_config(path).await?;
+    for warning in warnings {
+        warn!("{}: {}", path.display(), warning);
+    }
+
+    let node_settings = [
+        (
+            "extraMounts",
+            kind_config.nodes.iter().any(|n| !n.extra_mounts.is_empty()),
+        ),
+        (
+            "extraPortMappings",
+            kind_config
+                .nodes
+                .iter()
+                .any(|n| !n.extra_port_mappings.is_empty()),
+        ),
+        (
+            "labels set on only some nodes",
+            kind_config
+                .nodes
+                .iter()
+                .map(|n| n.labels.len())
+                .sum::<usize>()
+                > kind_config.common_node_labels().len() * kind_config.nodes.len(),
+        ),
+        (
+            "kubeadmConfigPatches",
+            !kind_config.kubeadm_config_patches.is_empty()
+                || kind_config
+                    .nodes
+                    .iter()
+                    .any(|n| !n.kubeadm_config_patches.is_empty()),
+        ),
+        (
+            "kubeadmConfigPatchesJSON6902",
+            !kind_config.kubeadm_config_patches_json6902.is_empty(),
+        ),
+        ("featureGates", !kind_config.feature_gates.is_empty()),
+        (
+            "networking.kubeProxyMode",
+            kind_config.networking.kube_proxy_mode.is_some(),
+        ),
+    ];
+    for (setting, _) in node_settings.iter().filter(|(_, present)| *present) {
+        warn!(
+            "{}: {} is not applied by kina create yet and is ignored",
+            path.display(),
+            setting
+        );
+    }
+    Ok(kind_config)
+}
+
+/// Load and validate a `--networking-config` file.
+async fn load_networking_config(path: &std::path::Path) -> Result<NetworkingConfig> {
+    let content = tokio::fs::read_to_string(path)
+        .await
+        .with_context(|| format!("Failed to read networking config {}", path.display()))?;
+    NetworkingConfig::from_patch_yaml(&content)
+        .with_context(|| format!("Invalid networking config {}", path.display()))
+}
+
+/// Load the cluster config named by `--config`, if it is one.
+///
+/// `--config` is shared with the global kina config flag, so a file that doesn't
+/// parse as a cluster config is skipped. One that parses but fails validation
+/// (e.g. a non-HTTPS OIDC issuer) is an error.
+async fn load_cluster_config(path: Option<&str>) -> Result<Option<ClusterConfig>> {
+    let Some(path) = path else {
+        return Ok(None);
+    };
+    let content = tokio::fs::read_to_string(path)
+        .await
+        .with_context(|| format!("Failed to read config file '{}'", path))?;
+    // `--config` also names the global kina config, which is TOML.
+    if toml::from_str::<toml::Table>(&content).is_ok() {
+        debug!("'{}' is a kina config, not a cluster config", path);
+        return Ok(None);
+    }
+    let mut cluster_config: ClusterConfig = serde_yaml::from_str(&content)
+        .with_context(|| format!("Invalid cluster config '{}'", path))?;
+    cluster_config.assign_node_names();
+    cluster_config
+        .validate()
+        .with_context(|| format!("Invalid cluster config '{}'", path))?;
+    Ok(Some(cluster_config))
+}
+
+/// Resolve the kernel that node containers boot, applying the zero-step default for
+/// `--cni cilium`.
+///
+/// Precedence:
+///   1. `explicit` (the `--kernel-path` flag, always wins)
+///   2. Cached pinned kernel in ~/.kina/kernels/<tag>/vmlinux (silent reuse)
+///   3. Auto-download the pinned kernel (first-run notice printed)
+///   4. Hard error with --kernel-path escape hatch (offline / unreachable)
+///
+/// PTP and other CNI plugins use the stock kernel; kernel_fetch is never invoked.
+fn resolve_node_kernel_path(
+    cni_plugin: &CniPlugin,
+    explicit: Option<PathBuf>,
+    config: &Config,
+) -> Result<Option<PathBuf>> {
+    let node_kernel_path = if kernel_fetch::requires_kernel(cni_plugin) {
+        // Check whether the pinned kernel is already cached.
+        let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+        let pinned_cache = kernel_fetch::kernel_cache_file(&home, &config.kernel.tag);
+        let cached_verified = if pinned_cache.exists() {
+            // Verify sha256 of the cached file before trusting it.
+            let file_sha = std::fs::read(&pinned_cache).ok().map(|bytes| {
+                use sha2::{Digest, Sha256};
+                hex::encode(Sha256::digest(&bytes))
+            });
+            file_sha
+                .map(|sha| kernel_fetch::verify_sha256(&sha, &config.kernel.sha256))
+                .unwrap_or(false)
+        } else {
+            false
+        };
+
+        let cached_pinned = if cached_verified {
+            Some(pinned_cache.clone())
+        } else {
+            None
+        };
+
+        match kernel_fetch::resolve_kernel_for_cilium(
+            explicit,
+            cached_pinned,
+            true, // fetch_ok: assume online; download errors surface via install_kernel
+        ) {
+            Ok(kernel_fetch::KernelChoice::ExplicitPath(p)) => Some(p),
+            Ok(kernel_fetch::KernelChoice::CachedPinned(p)) => {
+                info!("Using cached pinned kernel: {}", p.display());
+                Some(p)
+            }
+            Ok(kernel_fetch::KernelChoice::FetchPinned) => {
+                // First run: print notice and download the pinned kernel.
+                let notice = kernel_fetch::first_run_notice(
+                    &config.kernel.tag,
+                    kernel_fetch::KERNEL_SIZE_BYTES,
+                );
+                println!("{}", notice);
+
+                let cache_dir = kernel_fetch::kernel_cache_dir(&home, &config.kernel.tag);
+                // Use pinned_download_url() — the GitHub release asset is named "Image",
+                // not "vmlinux".  The downloaded bytes are stored locally as "vmlinux"
+                // by install_kernel via kernel_cache_file.
+                let url = kernel_fetch::pinned_download_url();
+
+                struct HttpFetcher;
+                impl kernel_fetch::KernelFetcher for HttpFetcher {
+                    fn fetch(&self, url: &str, dest_tmp: &std::path::Path) -> Result<u64, String> {
+                        // Synchronous download via reqwest blocking client.
+                        let mut response = reqwest::blocking::get(url)
+                            .map_err(|e| format!("HTTP request failed: {}", e))?;
+                        if !response.status().is_success() {
+                            return Err(format!("HTTP {} downloading {}", response.status(), url));
+                        }
+                        let mut file = std::fs::File::create(dest_tmp)
+                            .map_err(|e| format!("cannot create temp file: {}", e))?;
+                        let bytes = std::io::copy(&mut response, &mut file)
+                            .map_err(|e| format!("write error: {}", e))?;
+                        Ok(bytes)
+                    }
+                }
+
+                let fetcher = HttpFetcher;
+                match kernel_fetch::install_kernel(
+                    &fetcher,
+                    &url,
+                    &config.kernel.sha256,
+                    &cache_dir,
+                ) {
+                    Ok(path) => {
+                        info!("Kernel installed to: {}", path.display());
+                        Some(path)
+                    }
+                    Err(e) => {
+                        return Err(anyhow::anyhow!(e));
+                    }
+                }
+            }
+            Err(e) => {
+                return Err(anyhow::anyhow!(e));
+            }
+        }
+    } else {
+        // PTP and other CNI plugins: use the explicit flag or config default (stock kernel).
+        crate::core::apple_container::select_kernel_path(
+            explicit,
+            config.cluster.node_kernel_path.clone(),
+        )
+    };
+
+    Ok(node_kernel_path)
+}
+
 impl DeleteArgs {
     pub async fn execute(&self, config: &Config) -> Result<()> {
-        let cluster_manager = ClusterManager::new(config)?;
+        let options = DeleteClusterOptions {
+            grace_period: if self.force { 0 } else { self.grace_period },
+            force: self.force,
+        };
+        if self.force {
+            warn!(
+                "Force deletion skips graceful shutdown and ignores cleanup errors; \
+                 kubeconfig entries or other Kubernetes state may be left behind"
+            );
+        }
 
         if self.all {
             info!("Deleting all clusters");
-            cluster_manager.delete_all_clusters().await?;
+            ClusterManager::new(config)?
+                .delete_all_clusters(&options, |name| {
+                    record_event(
+                        &config.cluster.data_dir,
+                        name,
+                        EventType::Delete,
+                        "kina delete --all",
+                        None,
+                    )
+                })
+                .await?;
             println!("✅ All clusters deleted successfully");
         } else {
             info!("Deleting cluster '{}'", self.name);
-            cluster_manager.delete_cluster(&self.name).await?;
+            match DaemonClient::connect_if_running(config).await {
+                Some(mut daemon) => daemon.delete_cluster(&self.name, &options).await?,
+                None => {
+                    ClusterManager::new(config)?
+                        .delete_cluster(&self.name, &options)
+                        .await?
+                }
+            }
+            record_event(
+                &config.cluster.data_dir,
+                &self.name,
+                EventType::Delete,
+                "kina delete",
+                None,
+            );
             println!("✅ Cluster '{}' deleted successfully", self.name);
         }
 
@@ -431,28 +1696,105 @@ impl DeleteArgs {
     }
 }
 
-impl ListArgs {
+impl RenameArgs {
     pub async fn execute(&self, config: &Config) -> Result<()> {
         let cluster_manager = ClusterManager::new(config)?;
-        let clusters = cluster_manager.list_clusters().await?;
+
+        info!(
+            "Renaming cluster '{}' to '{}'",
+            self.old_name, self.new_name
+        );
+        cluster_manager
+            .rename_cluster(&self.old_name, &self.new_name)
+            .await?;
+        println!(
+            "✅ Cluster '{}' renamed to '{}'",
+            self.old_name, self.new_name
+        );
+
+        Ok(())
+    }
+}
+
+impl DuplicateArgs {
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        use crate::core::apple_container::resolve_wait_timeout;
+
+        info!("Duplicating cluster '{}' as '{}'", self.source, self.dest);
+
+        let cluster_manager = ClusterManager::new(config)?;
+        let data_dir = &config.cluster.data_dir;
+        record_event(
+            data_dir,
+            &self.dest,
+            EventType::CreateStart,
+            "kina duplicate",
+            Some(format!("from '{}'", self.source)),
+        );
+        // Everything else is rebuilt from the source by duplicate_cluster.
+        let created = cluster_manager
+            .duplicate_cluster(&self.source, &self.dest, |options| {
+                if let Some(cni) = &self.cni {
+                    options.cni_plugin = cni.clone().into();
+                    options.skip_cni = false;
+                }
+                options.node_kernel_path = resolve_node_kernel_path(
+                    &options.cni_plugin,
+                    self.node_kernel_path.clone(),
+                    config,
+                )?;
+                options.wait_timeout = resolve_wait_timeout(self.wait, self.wait_for_ready);
+                options.wait_for_ready = self.wait_for_ready;
+                options.retain_on_failure = self.retain || config.cluster.retain_on_failure;
+                options.skip_csr_approval = self.skip_csr_approval;
+                Ok(())
+            })
+            .await;
+        if let Err(e) = created {
+            record_event(
+                data_dir,
+                &self.dest,
+                EventType::CreateFailure,
+                "kina duplicate",
+                Some(format!("{:#}", e)),
+            );
+            return Err(e);
+        }
+        record_event(
+            data_dir,
+            &self.dest,
+            EventType::CreateSuccess,
+            "kina duplicate",
+            None,
+        );
+
+        println!("✅ Cluster '{}' created from '{}'", self.dest, self.source);
+        Ok(())
+    }
+}
+
+/// Every kina cluster, from the daemon when one is running
+async fn list_clusters(config: &Config) -> Result<Vec<ClusterInfo>> {
+    match DaemonClient::connect_if_running(config).await {
+        Some(mut daemon) => daemon.list_clusters().await,
+        None => ClusterManager::new(config)?.list_clusters().await,
+    }
+}
+
+impl ListArgs {
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        let clusters = self.select(list_clusters(config).await?);
 
         if clusters.is_empty() {
-            println!("No clusters found");
+            match &self.filter {
+                Some(status) => println!("No clusters found with status '{}'", status),
+                None => println!("No clusters found"),
+            }
             return Ok(());
         }
 
-        if self.verbose {
-            println!(
-                "{:<15} {:<20} {:<10} {:<25}",
-                "NAME", "IMAGE", "STATUS", "CREATED"
-            );
-            println!("{}", "-".repeat(70));
-            for cluster in clusters {
-                println!(
-                    "{:<15} {:<20} {:<10} {:<25}",
-                    cluster.name, cluster.image, cluster.status, cluster.created
-                );
-            }
+        if self.verbose || self.output == ListOutputFormat::Table {
+            println!("{}", render_cluster_table(&clusters));
         } else {
             for cluster in clusters {
                 println!("{}", cluster.name);
@@ -461,14 +1803,53 @@ impl ListArgs {
 
         Ok(())
     }
+
+    /// Apply `--filter` and `--sort` to the cluster list.
+    pub fn select(&self, clusters: Vec<ClusterInfo>) -> Vec<ClusterInfo> {
+        let mut clusters: Vec<ClusterInfo> = clusters
+            .into_iter()
+            .filter(|c| match &self.filter {
+                Some(status) => c.status == *status,
+                None => true,
+            })
+            .collect();
+
+        match self.sort {
+            ListSortField::Name => clusters.sort_by(|a, b| a.name.cmp(&b.name)),
+            // Clusters with a creation timestamp come first, oldest first. The rest
+            // fall back to `created`, formatted "YYYY-MM-DD HH:MM UTC" so lexical
+            // order is chronological; "unknown" sorts last.
+            ListSortField::Created => clusters.sort_by(|a, b| {
+                (a.creation_timestamp.is_none(), a.creation_timestamp)
+                    .cmp(&(b.creation_timestamp.is_none(), b.creation_timestamp))
+                    .then(a.created.cmp(&b.created))
+                    .then(a.name.cmp(&b.name))
+            }),
+            ListSortField::Status => clusters.sort_by(|a, b| {
+                a.status
+                    .to_string()
+                    .cmp(&b.status.to_string())
+                    .then(a.name.cmp(&b.name))
+            }),
+        }
+
+        clusters
+    }
+}
+
+/// Render clusters as a column-aligned table (the `Tabled` derive on `ClusterInfo`
+/// defines the columns).
+pub fn render_cluster_table(clusters: &[ClusterInfo]) -> String {
+    tabled::Table::new(clusters)
+        .with(tabled::settings::Style::blank())
+        .to_string()
 }
 
 impl GetArgs {
     pub async fn execute(&self, config: &Config) -> Result<()> {
-        let cluster_manager = ClusterManager::new(config)?;
-
         match self.resource {
             GetResource::Clusters => {
+                let cluster_manager = ClusterManager::new(config)?;
                 let clusters = cluster_manager.list_clusters().await?;
                 if clusters.is_empty() {
                     println!("No clusters found.");
@@ -499,33 +1880,64 @@ impl GetArgs {
             GetResource::Kubeconfig => {
                 let cluster_name = self.name.as_deref().unwrap_or("kina");
 
-                // Check if clusters exist and if the specific cluster exists
-                let clusters = cluster_manager.list_clusters().await?;
-                if clusters.is_empty() {
-                    println!("No clusters found.");
-                    println!();
-                    println!("To create a new cluster, run:");
-                    println!("  kina create [cluster-name]");
-                    return Ok(());
-                }
+                // A running daemon answers from its own manager, so the
+                // container CLI is only probed here when there is none
+                let kubeconfig = match DaemonClient::connect_if_running(config).await {
+                    Some(mut daemon) => daemon.get_kubeconfig(cluster_name).await,
+                    None => {
+                        ClusterManager::new(config)?
+                            .get_kubeconfig(cluster_name)
+                            .await
+                    }
+                };
+                let kubeconfig = match kubeconfig {
+                    Ok(kubeconfig) => kubeconfig,
+                    Err(e) if matches!(e.downcast_ref(), Some(ClusterError::NotFound { .. })) => {
+                        let clusters = list_clusters(config).await?;
+                        if clusters.is_empty() {
+                            println!("No clusters found.");
+                            println!();
+                            println!("To create a new cluster, run:");
+                            println!("  kina create [cluster-name]");
+                            return Ok(());
+                        }
 
-                let cluster_exists = clusters.iter().any(|c| c.name == cluster_name);
-                if !cluster_exists {
-                    let cluster_names: Vec<&str> =
-                        clusters.iter().map(|c| c.name.as_str()).collect();
-                    println!("Cluster '{}' does not exist.", cluster_name);
-                    println!();
-                    println!("Available clusters: {}", cluster_names.join(", "));
-                    println!();
-                    println!("To get kubeconfig for a specific cluster, run:");
-                    println!("  kina get kubeconfig <cluster-name>");
+                        let cluster_names: Vec<&str> =
+                            clusters.iter().map(|c| c.name.as_str()).collect();
+                        println!("Cluster '{}' does not exist.", cluster_name);
+                        println!();
+                        println!("Available clusters: {}", cluster_names.join(", "));
+                        println!();
+                        println!("To get kubeconfig for a specific cluster, run:");
+                        println!("  kina get kubeconfig <cluster-name>");
+                        return Ok(());
+                    }
+                    Err(e) => return Err(e),
+                };
+                if self.merge {
+                    merge_kubeconfig(cluster_name, &kubeconfig, self.set_context).await?;
+                    println!(
+                        "✅ Kubeconfig for '{}' merged into {}",
+                        cluster_name,
+                        resolve_primary_kubeconfig_path().display()
+                    );
+                    if self.set_context {
+                        println!("✅ Current context set to '{}'", cluster_name);
+                    }
                     return Ok(());
                 }
-
-                let kubeconfig = cluster_manager.get_kubeconfig(cluster_name).await?;
-                println!("{}", kubeconfig);
+                match &self.output {
+                    Some(path) => {
+                        std::fs::write(path, &kubeconfig).with_context(|| {
+                            format!("Failed to write kubeconfig to {}", path.display())
+                        })?;
+                        println!("✅ Kubeconfig written to {}", path.display());
+                    }
+                    None => println!("{}", kubeconfig),
+                }
             }
             GetResource::Nodes => {
+                let cluster_manager = ClusterManager::new(config)?;
                 let cluster_name = self.name.as_deref().unwrap_or("kina");
 
                 // Check if clusters exist and if the specific cluster exists
@@ -556,15 +1968,125 @@ impl GetArgs {
                     println!("{}", node);
                 }
             }
+            GetResource::Events => {
+                let cluster_manager = ClusterManager::new(config)?;
+                let cluster_name = self.name.as_deref().unwrap_or("kina");
+                if !cluster_exists_or_explain(&cluster_manager, cluster_name, "events").await? {
+                    return Ok(());
+                }
+
+                let kubeconfig = cluster_manager.kubeconfig_path(cluster_name).await?;
+                let kubeconfig = kubeconfig.to_string_lossy();
+                let kubectl = KubernetesClient::new(config)?;
+                let namespace = self.namespace.as_deref();
+
+                if self.watch {
+                    kubectl.watch_events(&kubeconfig, namespace).await?;
+                } else {
+                    let events = kubectl.get_events(&kubeconfig, namespace).await?;
+                    if events.is_empty() {
+                        println!("No events found.");
+                    }
+                    for event in &events {
+                        println!("{}", format_event(event));
+                    }
+                }
+            }
+            GetResource::Pods => {
+                let cluster_manager = ClusterManager::new(config)?;
+                let cluster_name = self.name.as_deref().unwrap_or("kina");
+                let output = self.format.clone();
+                if !cluster_exists_or_explain(&cluster_manager, cluster_name, "pods").await? {
+                    return Ok(());
+                }
+
+                let kubeconfig = cluster_manager.kubeconfig_path(cluster_name).await?;
+                let kubeconfig = kubeconfig.to_string_lossy();
+                let kubectl = KubernetesClient::new(config)?;
+                let namespace = if self.all_namespaces {
+                    None
+                } else {
+                    Some(self.namespace.as_deref().unwrap_or("default"))
+                };
+                let selector = self.label_selector.as_deref();
+
+                match output {
+                    PodOutput::Table => {
+                        let json = kubectl
+                            .get_pods(&kubeconfig, namespace, selector, "json")
+                            .await?;
+                        let pods = parse_pods(&json)?;
+                        if pods.is_empty() {
+                            println!("No pods found.");
+                        } else {
+                            println!("{}", render_pod_table(&pods));
+                        }
+                    }
+                    PodOutput::Json | PodOutput::Yaml | PodOutput::Wide => {
+                        let format = match output {
+                            PodOutput::Json => "json",
+                            PodOutput::Yaml => "yaml",
+                            _ => "wide",
+                        };
+                        let out = kubectl
+                            .get_pods(&kubeconfig, namespace, selector, format)
+                            .await?;
+                        print!("{}", out);
+                    }
+                }
+            }
+            GetResource::Config => {
+                let cluster_manager = ClusterManager::new(config)?;
+                let cluster_name = self.name.as_deref().unwrap_or("kina");
+                if !cluster_exists_or_explain(&cluster_manager, cluster_name, "config").await? {
+                    return Ok(());
+                }
+
+                let stored = cluster_manager.stored_cluster_config(cluster_name).await?;
+                print!("{}", stored.to_yaml()?);
+            }
         }
 
         Ok(())
     }
 }
 
+/// Check that `cluster_name` exists for a `kina get <resource>` command, printing
+/// the same guidance as the other `get` resources when it doesn't.
+async fn cluster_exists_or_explain(
+    cluster_manager: &ClusterManager,
+    cluster_name: &str,
+    resource: &str,
+) -> Result<bool> {
+    let clusters = cluster_manager.list_clusters().await?;
+    if clusters.is_empty() {
+        println!("No clusters found.");
+        println!();
+        println!("To create a new cluster, run:");
+        println!("  kina create [cluster-name]");
+        return Ok(false);
+    }
+
+    if !clusters.iter().any(|c| c.name == cluster_name) {
+        let cluster_names: Vec<&str> = clusters.iter().map(|c| c.name.as_str()).collect();
+        println!("Cluster '{}' does not exist.", cluster_name);
+        println!();
+        println!("Available clusters: {}", cluster_names.join(", "));
+        println!();
+        println!("To get {} for a specific cluster, run:", resource);
+        println!("  kina get {} <cluster-name>", resource);
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
 impl LoadArgs {
     pub async fn execute(&self, config: &Config) -> Result<()> {
         let cluster_manager = ClusterManager::new(config)?;
+        if self.all_clusters {
+            return self.load_all_clusters(config, &cluster_manager).await;
+        }
 
         // Check if clusters exist and if the specific cluster exists
         let clusters = cluster_manager.list_clusters().await?;
@@ -599,11 +2121,78 @@ impl LoadArgs {
             archive: None, // Not using archive file
         };
 
-        cluster_manager.load_image(options).await?;
+        cluster_manager.load_image(options).await?;
+        record_event(
+            &config.cluster.data_dir,
+            &self.cluster,
+            EventType::ImageLoad,
+            "kina load",
+            Some(self.image.clone()),
+        );
+
+        println!(
+            "✅ Image '{}' loaded successfully into cluster '{}'",
+            self.image, self.cluster
+        );
+        Ok(())
+    }
+
+    async fn load_all_clusters(
+        &self,
+        config: &Config,
+        cluster_manager: &ClusterManager,
+    ) -> Result<()> {
+        info!("Loading image '{}' into all running clusters", self.image);
+        let results = cluster_manager.load_image_all_clusters(&self.image).await?;
+        if results.is_empty() {
+            println!("No running clusters found.");
+            return Ok(());
+        }
+
+        println!("{:<20} {:<10} {:>8}", "CLUSTER", "STATUS", "TIME");
+        for load in &results {
+            let status = if load.result.is_ok() {
+                "success"
+            } else {
+                "failure"
+            };
+            println!(
+                "{:<20} {:<10} {:>7.1}s",
+                load.cluster,
+                status,
+                load.elapsed.as_secs_f64()
+            );
+        }
+
+        let mut failed = 0;
+        for load in &results {
+            match &load.result {
+                Ok(()) => record_event(
+                    &config.cluster.data_dir,
+                    &load.cluster,
+                    EventType::ImageLoad,
+                    "kina load",
+                    Some(self.image.clone()),
+                ),
+                Err(e) => {
+                    failed += 1;
+                    warn!("Failed to load image into '{}': {:#}", load.cluster, e);
+                }
+            }
+        }
+        if failed > 0 {
+            return Err(anyhow::anyhow!(
+                "Failed to load image '{}' into {} of {} clusters",
+                self.image,
+                failed,
+                results.len()
+            ));
+        }
 
         println!(
-            "✅ Image '{}' loaded successfully into cluster '{}'",
-            self.image, self.cluster
+            "✅ Image '{}' loaded successfully into {} clusters",
+            self.image,
+            results.len()
         );
         Ok(())
     }
@@ -635,29 +2224,109 @@ impl InstallArgs {
             return Ok(());
         }
 
+        if self.plugin.is_some() && !matches!(self.addon, AddonType::Cni) {
+            return Err(anyhow::anyhow!(
+                "--plugin is only valid for 'kina install cni'"
+            ));
+        }
+        if self.values.is_some() && !matches!(self.addon, AddonType::PrometheusStack) {
+            return Err(anyhow::anyhow!(
+                "--values is only valid for 'kina install prometheus-stack'"
+            ));
+        }
+        if self.ha && !matches!(self.addon, AddonType::ArgoCD) {
+            return Err(anyhow::anyhow!(
+                "--ha is only valid for 'kina install argocd'"
+            ));
+        }
+        if (self.profile.is_some() || self.inject_default)
+            && !matches!(self.addon, AddonType::Istio)
+        {
+            return Err(anyhow::anyhow!(
+                "--profile and --inject-default are only valid for 'kina install istio'"
+            ));
+        }
+        if (self.node_port_http.is_some() || self.node_port_https.is_some())
+            && !matches!(self.addon, AddonType::NginxIngress)
+        {
+            return Err(anyhow::anyhow!(
+                "--node-port-http and --node-port-https are only valid for 'kina install nginx-ingress'"
+            ));
+        }
+        if matches!(self.addon, AddonType::NginxIngress) {
+            let (http, https) = nginx_node_ports(self.node_port_http, self.node_port_https);
+            if http == https {
+                return Err(anyhow::anyhow!(
+                    "nginx-ingress HTTP and HTTPS node ports must differ (both {}); \
+                     set --node-port-http and --node-port-https to different ports",
+                    http
+                ));
+            }
+        }
+
+        if self.if_not_exists {
+            let Some((kind, name, namespace)) = self.addon.target_workload() else {
+                return Err(anyhow::anyhow!(
+                    "--if-not-exists is not supported for {:?}",
+                    self.addon
+                ));
+            };
+            if workload_exists(&kubeconfig_for(&self.cluster)?, kind, name, namespace)? {
+                println!(
+                    "{:?} addon already installed in cluster '{}' ({} {}/{}), skipping",
+                    self.addon, self.cluster, kind, namespace, name
+                );
+                return Ok(());
+            }
+        }
+
         info!(
             "Installing {:?} addon to cluster '{}'",
             self.addon, self.cluster
         );
 
+        let kubectl = KubernetesClient::new(config)?;
         match &self.addon {
             AddonType::NginxIngress => {
-                self.install_nginx_ingress(&cluster_manager).await?;
+                self.install_nginx_ingress(&kubectl).await?;
             }
             AddonType::Traefik => {
-                self.install_traefik(&cluster_manager).await?;
+                self.install_traefik(&kubectl).await?;
             }
             AddonType::NginxGatewayFabric => {
-                self.install_nginx_gateway_fabric(&cluster_manager).await?;
+                self.install_nginx_gateway_fabric(&kubectl).await?;
             }
             AddonType::DemoApp => {
-                self.install_demo_app(&cluster_manager).await?;
+                self.install_demo_app(&kubectl).await?;
             }
             AddonType::MetricsServer => {
-                self.install_metrics_server(&cluster_manager).await?;
+                self.install_metrics_server(&kubectl).await?;
+            }
+            AddonType::Cni => {
+                self.install_cni(&cluster_manager, config).await?;
+            }
+            AddonType::PrometheusStack => {
+                self.install_prometheus_stack(&kubectl).await?;
+            }
+            AddonType::ArgoCD => {
+                self.install_argocd(&cluster_manager, &kubectl).await?;
+            }
+            AddonType::Istio => {
+                self.install_istio(&kubectl).await?;
             }
         }
 
+        let addon = self
+            .addon
+            .to_possible_value()
+            .map(|v| v.get_name().to_string());
+        record_event(
+            &config.cluster.data_dir,
+            &self.cluster,
+            EventType::AddonInstall,
+            "kina install",
+            addon,
+        );
         println!(
             "✅ {:?} addon installed successfully to cluster '{}'",
             self.addon, self.cluster
@@ -665,7 +2334,7 @@ impl InstallArgs {
         Ok(())
     }
 
-    async fn install_nginx_ingress(&self, _cluster_manager: &ClusterManager) -> Result<()> {
+    async fn install_nginx_ingress(&self, kubectl: &KubernetesClient) -> Result<()> {
         let kubeconfig_str = kubeconfig_for(&self.cluster)?;
 
         // Hard error if any conflicting controller is already installed
@@ -677,6 +2346,9 @@ impl InstallArgs {
         }
 
         info!("Installing NGINX Ingress Controller (nginx.org) with complete deployment");
+        kubectl
+            .ensure_namespace(&kubeconfig_str, "nginx-ingress")
+            .await?;
 
         // Nginx-ingress manifests embedded in the binary — works from any directory (AC2).
         let manifests: &[(&str, &str)] = &[
@@ -726,42 +2398,43 @@ impl InstallArgs {
             tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
         }
 
+        let (http, https) = nginx_node_ports(self.node_port_http, self.node_port_https);
+        let service = set_service_node_ports(
+            include_str!("../../manifests/nginx-ingress/nodeport-service.yaml"),
+            "nginx-ingress",
+            &[("http", http), ("https", https)],
+        )?;
+        kubectl
+            .apply_manifest(&kubeconfig_str, &service)
+            .await
+            .context("Failed to apply NodePort Service")?;
+        info!(
+            "nginx-ingress exposed on node ports {} (HTTP) and {} (HTTPS)",
+            http, https
+        );
+
         info!("NGINX Ingress Controller (DaemonSet) installed successfully");
         info!("Waiting for nginx-ingress DaemonSet rollout (bounded, timeout=120s)...");
 
         // Block until the DaemonSet is fully rolled out — no fixed sleep.
-        let rollout = std::process::Command::new("kubectl")
-            .args([
-                "--kubeconfig",
+        kubectl
+            .wait_for_daemonset(
                 &kubeconfig_str,
-                "rollout",
-                "status",
-                "daemonset/nginx-ingress",
-                "-n",
                 "nginx-ingress",
-                "--timeout=120s",
-            ])
-            .status();
-        match rollout {
-            Ok(s) if s.success() => info!("nginx-ingress DaemonSet rollout complete"),
-            Ok(_) => {
-                return Err(anyhow::anyhow!(
-                    "nginx-ingress DaemonSet did not roll out within 120s; the controller is not ready. \
-                     Inspect with: kubectl -n nginx-ingress get pods"
-                ))
-            }
-            Err(e) => {
-                return Err(anyhow::anyhow!(
-                    "Failed to check nginx-ingress DaemonSet rollout status: {}",
-                    e
-                ))
-            }
-        }
+                "nginx-ingress",
+                ADDON_ROLLOUT_TIMEOUT,
+            )
+            .await
+            .context(
+                "The nginx-ingress controller is not ready. \
+                 Inspect with: kubectl -n nginx-ingress get pods",
+            )?;
+        info!("nginx-ingress DaemonSet rollout complete");
 
         Ok(())
     }
 
-    async fn install_traefik(&self, _cluster_manager: &ClusterManager) -> Result<()> {
+    async fn install_traefik(&self, kubectl: &KubernetesClient) -> Result<()> {
         let kubeconfig_str = kubeconfig_for(&self.cluster)?;
 
         // Hard error if any conflicting controller is already installed
@@ -773,6 +2446,7 @@ impl InstallArgs {
         }
 
         info!("Installing Traefik gateway controller (Gateway API) with complete deployment");
+        kubectl.ensure_namespace(&kubeconfig_str, "traefik").await?;
 
         // Apply the Gateway API CRDs first, then BLOCK until they are
         // Established before applying any custom resource. `kubectl apply` does
@@ -834,33 +2508,13 @@ impl InstallArgs {
         info!("Waiting for traefik DaemonSet rollout (bounded, timeout=120s)...");
 
         // Block until the DaemonSet is fully rolled out — no fixed sleep.
-        let rollout = std::process::Command::new("kubectl")
-            .args([
-                "--kubeconfig",
-                &kubeconfig_str,
-                "rollout",
-                "status",
-                "daemonset/traefik",
-                "-n",
-                "traefik",
-                "--timeout=120s",
-            ])
-            .status();
-        match rollout {
-            Ok(s) if s.success() => info!("traefik DaemonSet rollout complete"),
-            Ok(_) => {
-                return Err(anyhow::anyhow!(
-                    "traefik DaemonSet did not roll out within 120s; the controller is not ready. \
-                     Inspect with: kubectl -n traefik get pods"
-                ))
-            }
-            Err(e) => {
-                return Err(anyhow::anyhow!(
-                    "Failed to check traefik DaemonSet rollout status: {}",
-                    e
-                ))
-            }
-        }
+        kubectl
+            .wait_for_daemonset(&kubeconfig_str, "traefik", "traefik", ADDON_ROLLOUT_TIMEOUT)
+            .await
+            .context(
+                "The traefik controller is not ready. Inspect with: kubectl -n traefik get pods",
+            )?;
+        info!("traefik DaemonSet rollout complete");
 
         info!("Waiting for Traefik Gateway to reach Programmed condition (timeout=60s)...");
 
@@ -896,7 +2550,7 @@ impl InstallArgs {
         Ok(())
     }
 
-    async fn install_nginx_gateway_fabric(&self, _cluster_manager: &ClusterManager) -> Result<()> {
+    async fn install_nginx_gateway_fabric(&self, kubectl: &KubernetesClient) -> Result<()> {
         let kubeconfig_str = kubeconfig_for(&self.cluster)?;
 
         // Hard error if any conflicting controller is already installed.
@@ -906,6 +2560,9 @@ impl InstallArgs {
         }
 
         info!("Installing NGINX Gateway Fabric (Gateway API) with complete deployment");
+        kubectl
+            .ensure_namespace(&kubeconfig_str, "nginx-gateway")
+            .await?;
 
         // 1. Shared Gateway API CRDs (reused from traefik manifests — v1.5.1, compatible).
         info!("Applying shared Gateway API CRDs (v1.5.1)");
@@ -982,33 +2639,19 @@ impl InstallArgs {
 
         // 5. Wait for control-plane Deployment rollout.
         info!("Waiting for nginx-gateway control-plane Deployment rollout (timeout=120s)...");
-        let rollout = std::process::Command::new("kubectl")
-            .args([
-                "--kubeconfig",
+        kubectl
+            .wait_for_deployment(
                 &kubeconfig_str,
-                "rollout",
-                "status",
-                "deployment/nginx-gateway",
-                "-n",
                 "nginx-gateway",
-                "--timeout=120s",
-            ])
-            .status();
-        match rollout {
-            Ok(s) if s.success() => info!("nginx-gateway Deployment rollout complete"),
-            Ok(_) => {
-                return Err(anyhow::anyhow!(
-                    "nginx-gateway Deployment did not roll out within 120s; the control-plane \
-                     is not ready. Inspect with: kubectl -n nginx-gateway get pods"
-                ))
-            }
-            Err(e) => {
-                return Err(anyhow::anyhow!(
-                    "Failed to check nginx-gateway Deployment rollout status: {}",
-                    e
-                ))
-            }
-        }
+                "nginx-gateway",
+                ADDON_ROLLOUT_TIMEOUT,
+            )
+            .await
+            .context(
+                "The nginx-gateway control-plane is not ready. \
+                 Inspect with: kubectl -n nginx-gateway get pods",
+            )?;
+        info!("nginx-gateway Deployment rollout complete");
 
         // 6. Apply NginxProxy CRD resource (DaemonSet config) + Gateway object.
         info!("Applying NginxProxy config and Gateway object");
@@ -1056,38 +2699,24 @@ impl InstallArgs {
         // 8. Wait for the provisioned data-plane DaemonSet to roll out.
         //    The NGF controller names the DaemonSet "nginx-<gateway-name>" = "nginx-nginx".
         info!("Waiting for nginx-nginx data-plane DaemonSet rollout (timeout=120s)...");
-        let ds_rollout = std::process::Command::new("kubectl")
-            .args([
-                "--kubeconfig",
+        kubectl
+            .wait_for_daemonset(
                 &kubeconfig_str,
-                "rollout",
-                "status",
-                "daemonset/nginx-nginx",
-                "-n",
                 "nginx-gateway",
-                "--timeout=120s",
-            ])
-            .status();
-        match ds_rollout {
-            Ok(s) if s.success() => info!("nginx-nginx DaemonSet rollout complete"),
-            Ok(_) => {
-                return Err(anyhow::anyhow!(
-                    "nginx-nginx DaemonSet did not roll out within 120s; the data-plane \
-                     is not ready. Inspect with: kubectl -n nginx-gateway get pods"
-                ))
-            }
-            Err(e) => {
-                return Err(anyhow::anyhow!(
-                    "Failed to check nginx-nginx DaemonSet rollout status: {}",
-                    e
-                ))
-            }
-        }
+                "nginx-nginx",
+                ADDON_ROLLOUT_TIMEOUT,
+            )
+            .await
+            .context(
+                "The nginx-nginx data-plane is not ready. \
+                 Inspect with: kubectl -n nginx-gateway get pods",
+            )?;
+        info!("nginx-nginx DaemonSet rollout complete");
 
         Ok(())
     }
 
-    async fn install_demo_app(&self, _cluster_manager: &ClusterManager) -> Result<()> {
+    async fn install_demo_app(&self, kubectl: &KubernetesClient) -> Result<()> {
         info!("Installing demo application to cluster '{}'", self.cluster);
 
         let kubeconfig_str = kubeconfig_for(&self.cluster)?;
@@ -1179,59 +2808,576 @@ impl InstallArgs {
             }
         }
 
-        info!("Demo app applied; waiting for pods to be Ready...");
+        info!("Demo app applied; waiting for pods to be Ready...");
+
+        match kubectl
+            .wait_for_deployment(
+                &kubeconfig_str,
+                "default",
+                "kina-demo-app",
+                ADDON_ROLLOUT_TIMEOUT,
+            )
+            .await
+        {
+            Ok(()) => info!("Demo app pods are Ready"),
+            Err(e) => warn!("Demo app pods not Ready within timeout: {:#}", e),
+        }
+
+        Ok(())
+    }
+
+    async fn install_metrics_server(&self, kubectl: &KubernetesClient) -> Result<()> {
+        // metrics-server release bundled with this manifest.
+        const VERSION: &str = "v0.8.1";
+        info!("Installing metrics-server {}", VERSION);
+
+        let kubeconfig_str = kubeconfig_for(&self.cluster)?;
+
+        // metrics-server manifest embedded in the binary — works from any directory.
+        // The bundled manifest sets --kubelet-insecure-tls so it works against
+        // kina/kind-style nodes whose kubelet serving certs are self-signed.
+        apply_manifest_via_kubectl(
+            &kubeconfig_str,
+            include_str!("../../manifests/metrics-server/components.yaml"),
+            "metrics-server components",
+        )?;
+        kubectl
+            .wait_for_deployment(
+                &kubeconfig_str,
+                "kube-system",
+                "metrics-server",
+                ADDON_ROLLOUT_TIMEOUT,
+            )
+            .await?;
+
+        info!("metrics-server {} installed successfully", VERSION);
+        Ok(())
+    }
+
+    async fn install_prometheus_stack(&self, kubectl: &KubernetesClient) -> Result<()> {
+        if !crate::utils::process::command_exists("helm") {
+            return Err(anyhow::anyhow!(
+                "helm is required for prometheus-stack; install it from https://helm.sh/docs/intro/install/"
+            ));
+        }
+        let kubeconfig_str = kubeconfig_for(&self.cluster)?;
+        kubectl
+            .ensure_namespace(&kubeconfig_str, PROMETHEUS_STACK_NAMESPACE)
+            .await?;
+
+        info!("Adding the prometheus-community Helm repository");
+        run_helm(&[
+            "repo",
+            "add",
+            "prometheus-community",
+            PROMETHEUS_COMMUNITY_REPO,
+            "--force-update",
+        ])
+        .await?;
+        run_helm(&["repo", "update", "prometheus-community"]).await?;
+
+        info!(
+            "Installing kube-prometheus-stack into '{}'",
+            PROMETHEUS_STACK_NAMESPACE
+        );
+        let args = prometheus_stack_helm_args(
+            &kubeconfig_str,
+            self.version.as_deref(),
+            self.values.as_deref(),
+        );
+        run_helm(&args.iter().map(String::as_str).collect::<Vec<_>>()).await?;
+
+        match secret_value(
+            &kubeconfig_str,
+            PROMETHEUS_STACK_NAMESPACE,
+            "kube-prometheus-stack-grafana",
+            "admin-password",
+        ) {
+            Ok(password) => println!("Grafana admin password (user 'admin'): {}", password),
+            Err(e) => warn!("Could not read the Grafana admin password: {:#}", e),
+        }
+        println!("Open Grafana at http://localhost:3000 after running:");
+        println!(
+            "  kina port-forward {} svc/kube-prometheus-stack-grafana 3000:80 -n {}",
+            self.cluster, PROMETHEUS_STACK_NAMESPACE
+        );
+        Ok(())
+    }
+
+    async fn install_argocd(
+        &self,
+        cluster_manager: &ClusterManager,
+        kubectl: &KubernetesClient,
+    ) -> Result<()> {
+        let cluster = cluster_manager.get_cluster_status(&self.cluster).await?;
+        if self.ha && cluster.nodes.len() < ARGOCD_HA_MIN_NODES {
+            return Err(anyhow::anyhow!(
+                "--ha spreads Argo CD replicas across nodes and needs at least {} nodes; \
+                 cluster '{}' has {}",
+                ARGOCD_HA_MIN_NODES,
+                self.cluster,
+                cluster.nodes.len()
+            ));
+        }
+        let kubeconfig_str = kubeconfig_for(&self.cluster)?;
+        let version = self.version.as_deref().unwrap_or(ARGOCD_VERSION);
+        info!(
+            "Installing Argo CD {}{}",
+            version,
+            if self.ha { " (HA)" } else { "" }
+        );
+
+        kubectl
+            .ensure_namespace(&kubeconfig_str, ARGOCD_NAMESPACE)
+            .await?;
+        // The CRDs exceed the client-side apply annotation limit.
+        let url = argocd_manifest_url(version, self.ha);
+        run_kubectl(
+            &kubeconfig_str,
+            &[
+                "apply",
+                "--server-side",
+                "--force-conflicts",
+                "-n",
+                ARGOCD_NAMESPACE,
+                "-f",
+                &url,
+            ],
+        )
+        .with_context(|| format!("Failed to apply Argo CD manifest {}", url))?;
+
+        // kina clusters have no trusted certificate for the server, so serve
+        // plain HTTP instead of a self-signed TLS endpoint.
+        let args = run_kubectl(
+            &kubeconfig_str,
+            &[
+                "get",
+                "deployment",
+                "argocd-server",
+                "-n",
+                ARGOCD_NAMESPACE,
+                "-o",
+                "jsonpath={.spec.template.spec.containers[0].args}",
+            ],
+        )?;
+        if !args.contains("--insecure") {
+            run_kubectl(
+                &kubeconfig_str,
+                &[
+                    "patch",
+                    "deployment",
+                    "argocd-server",
+                    "-n",
+                    ARGOCD_NAMESPACE,
+                    "--type",
+                    "json",
+                    "-p",
+                    r#"[{"op":"add","path":"/spec/template/spec/containers/0/args/-","value":"--insecure"}]"#,
+                ],
+            )?;
+        }
+        let service_patch = format!(
+            r#"{{"spec":{{"type":"NodePort","ports":[{{"name":"http","port":80,"nodePort":{}}}]}}}}"#,
+            ARGOCD_NODE_PORT
+        );
+        run_kubectl(
+            &kubeconfig_str,
+            &[
+                "patch",
+                "service",
+                "argocd-server",
+                "-n",
+                ARGOCD_NAMESPACE,
+                "-p",
+                &service_patch,
+            ],
+        )?;
+
+        kubectl
+            .wait_for_deployment(
+                &kubeconfig_str,
+                ARGOCD_NAMESPACE,
+                "argocd-server",
+                Duration::from_secs(300),
+            )
+            .await?;
+        run_kubectl(
+            &kubeconfig_str,
+            &[
+                "wait",
+                "--for=condition=Ready",
+                "pods",
+                "--all",
+                "-n",
+                ARGOCD_NAMESPACE,
+                "--timeout=300s",
+            ],
+        )
+        .context("Argo CD pods did not become Ready within 300s")?;
+
+        apply_manifest_via_kubectl(
+            &kubeconfig_str,
+            include_str!("../../manifests/argocd/default-project.yaml"),
+            "Argo CD default AppProject",
+        )?;
+
+        match secret_value(
+            &kubeconfig_str,
+            ARGOCD_NAMESPACE,
+            "argocd-initial-admin-secret",
+            "password",
+        ) {
+            Ok(password) => println!("Argo CD admin password (user 'admin'): {}", password),
+            Err(e) => warn!("Could not read the Argo CD admin password: {:#}", e),
+        }
+        if let Some(ip) = cluster
+            .nodes
+            .iter()
+            .find(|n| n.role == NodeRole::ControlPlane)
+            .and_then(|n| n.ip_address.as_deref())
+        {
+            println!("Argo CD server: http://{}:{}", ip, ARGOCD_NODE_PORT);
+        }
+        Ok(())
+    }
+
+    async fn install_istio(&self, kubectl: &KubernetesClient) -> Result<()> {
+        let kubeconfig_str = kubeconfig_for(&self.cluster)?;
+        // A pinned --version needs that istioctl, since istioctl installs its
+        // own release; otherwise any istioctl on PATH will do.
+        let istioctl = match &self.version {
+            None if crate::utils::process::command_exists("istioctl") => PathBuf::from("istioctl"),
+            version => download_istioctl(version.as_deref().unwrap_or(ISTIO_VERSION)).await?,
+        };
+        let profile = self.profile.unwrap_or(IstioProfile::Minimal);
+        info!(
+            "Installing Istio ({:?} profile) with {}",
+            profile,
+            istioctl.display()
+        );
+
+        kubectl
+            .ensure_namespace(&kubeconfig_str, "istio-system")
+            .await?;
+        let args = istioctl_install_args(profile, &kubeconfig_str);
+        let output = std::process::Command::new(&istioctl)
+            .args(&args)
+            .output()
+            .with_context(|| format!("Failed to run {}", istioctl.display()))?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "istioctl install failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        kubectl
+            .wait_for_deployment(
+                &kubeconfig_str,
+                "istio-system",
+                "istiod",
+                Duration::from_secs(300),
+            )
+            .await
+            .context("istiod did not become ready")?;
+
+        if self.inject_default {
+            run_kubectl(
+                &kubeconfig_str,
+                &[
+                    "label",
+                    "namespace",
+                    "default",
+                    "istio-injection=enabled",
+                    "--overwrite",
+                ],
+            )?;
+            println!("Sidecar injection enabled for namespace 'default'");
+        }
+
+        println!("Verify the installation with:");
+        println!(
+            "  {} version --kubeconfig {}",
+            istioctl.display(),
+            kubeconfig_str
+        );
+        println!(
+            "  kubectl --kubeconfig {} get pods -n istio-system",
+            kubeconfig_str
+        );
+        Ok(())
+    }
+
+    async fn install_cni(&self, cluster_manager: &ClusterManager, config: &Config) -> Result<()> {
+        let cni = crate::core::apple_container::select_cni(
+            self.plugin.clone().map(Into::into),
+            config.cluster.default_cni.clone(),
+        );
+        info!("Installing {:?} CNI on cluster '{}'", cni, self.cluster);
+
+        // Cilium picks its full-eBPF profile when the cluster runs a custom kernel.
+        cluster_manager
+            .install_cni(
+                &self.cluster,
+                cni,
+                config.cluster.node_kernel_path.as_deref(),
+            )
+            .await
+    }
+}
+
+/// Namespace `kina install prometheus-stack` installs into
+const PROMETHEUS_STACK_NAMESPACE: &str = "monitoring";
+
+const PROMETHEUS_COMMUNITY_REPO: &str = "https://prometheus-community.github.io/helm-charts";
+
+/// `helm` arguments installing or upgrading the kube-prometheus-stack
+/// release, optionally pinned to a chart `version` and with a user `values`
+/// file. `upgrade --install` lets a re-run update an existing release.
+fn prometheus_stack_helm_args(
+    kubeconfig: &str,
+    version: Option<&str>,
+    values: Option<&std::path::Path>,
+) -> Vec<String> {
+    let mut args: Vec<String> = [
+        "upgrade",
+        "--install",
+        "kube-prometheus-stack",
+        "prometheus-community/kube-prometheus-stack",
+        "--create-namespace",
+        "-n",
+        PROMETHEUS_STACK_NAMESPACE,
+        "--kubeconfig",
+        kubeconfig,
+        "--wait",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    if let Some(version) = version {
+        args.extend(["--version".to_string(), version.to_string()]);
+    }
+    if let Some(values) = values {
+        args.extend(["--values".to_string(), values.display().to_string()]);
+    }
+    args
+}
+
+/// How long `kina install` waits for an addon's Deployment or DaemonSet to
+/// roll out
+const ADDON_ROLLOUT_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Node ports nginx-ingress is exposed on unless `--node-port-http` /
+/// `--node-port-https` are given
+const NGINX_INGRESS_NODE_PORT_HTTP: u16 = 30080;
+const NGINX_INGRESS_NODE_PORT_HTTPS: u16 = 30443;
+
+/// The nginx-ingress (HTTP, HTTPS) node ports for `--node-port-http` and
+/// `--node-port-https`, with the defaults filled in
+fn nginx_node_ports(http: Option<u16>, https: Option<u16>) -> (u16, u16) {
+    (
+        http.unwrap_or(NGINX_INGRESS_NODE_PORT_HTTP),
+        https.unwrap_or(NGINX_INGRESS_NODE_PORT_HTTPS),
+    )
+}
 
-        // Wait for pods to be Ready
-        let wait = std::process::Command::new("kubectl")
-            .args([
-                "--kubeconfig",
-                &kubeconfig_str,
-                "wait",
-                "--for=condition=Ready",
-                "pods",
-                "-l",
-                "app=kina-demo-app",
-                "--timeout=120s",
-            ])
-            .output();
+/// Argo CD release installed when `kina install argocd` has no `--version`
+const ARGOCD_VERSION: &str = "v2.13.3";
 
-        match wait {
-            Ok(out) if out.status.success() => {
-                info!("Demo app pods are Ready");
-            }
-            Ok(out) => {
-                warn!(
-                    "Demo app pods not Ready within timeout: {}",
-                    String::from_utf8_lossy(&out.stderr)
-                );
-            }
-            Err(e) => {
-                warn!("Failed to wait for demo app pods: {}", e);
-            }
+const ARGOCD_NAMESPACE: &str = "argocd";
+
+/// Node port the Argo CD server's HTTP port is published on, clear of the
+/// nginx-ingress defaults so both addons fit on one cluster
+const ARGOCD_NODE_PORT: u16 = 30880;
+
+/// Nodes needed to schedule the HA manifest's anti-affine replicas
+const ARGOCD_HA_MIN_NODES: usize = 3;
+
+/// Upstream install manifest for Argo CD `version` (`ha/install.yaml` for HA)
+fn argocd_manifest_url(version: &str, ha: bool) -> String {
+    format!(
+        "https://raw.githubusercontent.com/argoproj/argo-cd/{}/manifests/{}install.yaml",
+        version,
+        if ha { "ha/" } else { "" }
+    )
+}
+
+/// Istio release downloaded when `kina install istio` has no `--version` and
+/// no istioctl is on PATH
+const ISTIO_VERSION: &str = "1.24.2";
+
+/// `istioctl` arguments installing the control plane with `profile`
+fn istioctl_install_args(profile: IstioProfile, kubeconfig: &str) -> Vec<String> {
+    let profile = profile
+        .to_possible_value()
+        .map(|v| v.get_name().to_string())
+        .unwrap_or_default();
+    vec![
+        "install".to_string(),
+        "--set".to_string(),
+        format!("profile={}", profile),
+        "-y".to_string(),
+        "--kubeconfig".to_string(),
+        kubeconfig.to_string(),
+    ]
+}
+
+/// Release download URL of istioctl `version` for a host `os`/`arch` as
+/// reported by `std::env::consts` (e.g. `macos`/`aarch64`).
+fn istioctl_download_url(version: &str, os: &str, arch: &str) -> Result<String> {
+    let platform = match (os, arch) {
+        ("macos", "aarch64") => "osx-arm64",
+        ("macos", "x86_64") => "osx",
+        ("linux", "aarch64") => "linux-arm64",
+        ("linux", "x86_64") => "linux-amd64",
+        _ => {
+            return Err(anyhow::anyhow!(
+                "No istioctl release for {}/{}; install istioctl and put it on PATH",
+                os,
+                arch
+            ))
         }
+    };
+    Ok(format!(
+        "https://github.com/istio/istio/releases/download/{0}/istioctl-{0}-{1}.tar.gz",
+        version, platform
+    ))
+}
 
-        Ok(())
+/// The digest in an Istio release `.sha256` file (`<hex>  <file name>`)
+fn parse_sha256_file(contents: &str) -> Option<&str> {
+    contents
+        .split_whitespace()
+        .next()
+        .filter(|digest| digest.len() == 64 && digest.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// GET `url` into memory
+async fn download_bytes(url: &str) -> Result<Vec<u8>> {
+    let response = reqwest::get(url)
+        .await
+        .and_then(|r| r.error_for_status())
+        .with_context(|| format!("Failed to download {}", url))?;
+    let bytes = response
+        .bytes()
+        .await
+        .with_context(|| format!("Failed to download {}", url))?;
+    Ok(bytes.to_vec())
+}
+
+/// Download istioctl `version` into `~/.kina/bin/istioctl-<version>/`, reusing
+/// an earlier download, and return the binary's path.
+///
+/// Like the kernel download, the archive is checked against the release's
+/// `.sha256` and extracted into a temporary directory; the binary is renamed
+/// into place only once complete, so an interrupted download is never reused.
+async fn download_istioctl(version: &str) -> Result<PathBuf> {
+    use sha2::{Digest, Sha256};
+
+    let home = std::env::var("HOME").context("HOME environment variable not set")?;
+    let dir = PathBuf::from(home)
+        .join(".kina")
+        .join("bin")
+        .join(format!("istioctl-{}", version));
+    let binary = dir.join("istioctl");
+    if binary.exists() {
+        debug!("Using cached {}", binary.display());
+        return Ok(binary);
     }
 
-    async fn install_metrics_server(&self, _cluster_manager: &ClusterManager) -> Result<()> {
-        // metrics-server release bundled with this manifest.
-        const VERSION: &str = "v0.8.1";
-        info!("Installing metrics-server {}", VERSION);
+    let url = istioctl_download_url(version, std::env::consts::OS, std::env::consts::ARCH)?;
+    println!("Downloading istioctl {} from {}", version, url);
+    let bytes = download_bytes(&url).await?;
+    let checksum_url = format!("{}.sha256", url);
+    let checksum = String::from_utf8(download_bytes(&checksum_url).await?)
+        .with_context(|| format!("{} is not text", checksum_url))?;
+    let expected = parse_sha256_file(&checksum)
+        .ok_or_else(|| anyhow::anyhow!("No sha256 digest in {}", checksum_url))?;
+    let observed = hex::encode(Sha256::digest(&bytes));
+    if !kernel_fetch::verify_sha256(&observed, expected) {
+        return Err(anyhow::anyhow!(
+            "sha256 mismatch for {}:\n  expected: {}\n  observed: {}",
+            url,
+            expected,
+            observed
+        ));
+    }
 
-        let kubeconfig_str = kubeconfig_for(&self.cluster)?;
+    std::fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    let staging = tempfile::tempdir_in(&dir).with_context(|| {
+        format!(
+            "Failed to create a temporary directory in {}",
+            dir.display()
+        )
+    })?;
+    let archive = staging.path().join("istioctl.tar.gz");
+    std::fs::write(&archive, &bytes)
+        .with_context(|| format!("Failed to write {}", archive.display()))?;
+    let status = tokio::process::Command::new("tar")
+        .arg("-xzf")
+        .arg(&archive)
+        .arg("-C")
+        .arg(staging.path())
+        .status()
+        .await
+        .context("Failed to run tar")?;
+    let extracted = staging.path().join("istioctl");
+    if !status.success() || !extracted.exists() {
+        return Err(anyhow::anyhow!("Failed to extract istioctl from {}", url));
+    }
+    std::fs::rename(&extracted, &binary)
+        .with_context(|| format!("Failed to move istioctl into {}", binary.display()))?;
+    Ok(binary)
+}
 
-        // metrics-server manifest embedded in the binary — works from any directory.
-        // The bundled manifest sets --kubelet-insecure-tls so it works against
-        // kina/kind-style nodes whose kubelet serving certs are self-signed.
-        apply_manifest_via_kubectl(
-            &kubeconfig_str,
-            include_str!("../../manifests/metrics-server/components.yaml"),
-            "metrics-server components",
-        )?;
+/// Run `helm` with `args`, failing with its stderr.
+async fn run_helm(args: &[&str]) -> Result<()> {
+    debug!("Running helm {}", args.join(" "));
+    let output = tokio::process::Command::new("helm")
+        .args(args)
+        .output()
+        .await
+        .context("Failed to run helm")?;
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "helm {} failed: {}",
+            args.first().copied().unwrap_or_default(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
 
-        info!("metrics-server {} installed successfully", VERSION);
-        Ok(())
+/// Run `kubectl --kubeconfig <kubeconfig> <args>`, returning its stdout or
+/// failing with its stderr.
+fn run_kubectl(kubeconfig: &str, args: &[&str]) -> Result<String> {
+    debug!("Running kubectl {}", args.join(" "));
+    let output = std::process::Command::new("kubectl")
+        .args(["--kubeconfig", kubeconfig])
+        .args(args)
+        .output()
+        .context("Failed to run kubectl")?;
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "kubectl {} failed: {}",
+            args.first().copied().unwrap_or_default(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
     }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// The decoded value of `key` in Secret `namespace/name`.
+fn secret_value(kubeconfig: &str, namespace: &str, name: &str, key: &str) -> Result<String> {
+    let template = format!(
+        r#"go-template={{{{index .data "{}" | base64decode}}}}"#,
+        key
+    );
+    let value = run_kubectl(
+        kubeconfig,
+        &["get", "secret", name, "-n", namespace, "-o", &template],
+    )?;
+    Ok(value.trim().to_string())
 }
 
 /// Resolve the kubeconfig file path for an addon's target cluster.
@@ -1284,6 +3430,25 @@ fn controller_namespace_present(kubeconfig: &str, namespace: &str) -> Result<boo
     Ok(output.status.success())
 }
 
+/// True if a workload (`kind` is e.g. `deployment` or `daemonset`) named `name`
+/// exists in `namespace`.
+fn workload_exists(kubeconfig: &str, kind: &str, name: &str, namespace: &str) -> Result<bool> {
+    let output = std::process::Command::new("kubectl")
+        .args([
+            "--kubeconfig",
+            kubeconfig,
+            "get",
+            kind,
+            name,
+            "-n",
+            namespace,
+            "--no-headers",
+        ])
+        .output()
+        .context("Failed to run kubectl get")?;
+    Ok(output.status.success())
+}
+
 /// True if a Gateway API `Gateway` named `name` exists in `namespace`.
 ///
 /// Used to decide the demo-app's routing object. This deliberately checks the
@@ -1687,6 +3852,18 @@ impl ExportArgs {
                 warn!("Config export format not yet implemented");
                 return Ok(());
             }
+            ExportFormat::Kind => cluster_manager
+                .export_cluster_config(&self.name)
+                .await?
+                .to_kind_yaml()?,
+            ExportFormat::Terraform => cluster_manager
+                .export_cluster_config(&self.name)
+                .await?
+                .to_terraform(),
+            ExportFormat::GithubActions => cluster_manager
+                .export_cluster_config(&self.name)
+                .await?
+                .to_github_actions(env!("CARGO_PKG_VERSION")),
         };
 
         if let Some(output_file) = &self.output {
@@ -1700,47 +3877,85 @@ impl ExportArgs {
     }
 }
 
+/// How long `kina status` waits for the CSR listing before reporting it as
+/// unknown.
+const CSR_STATUS_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Pending kubelet-serving CSRs in `cluster`, listed on its control-plane
+/// node, or `None` when the cluster is not running or they cannot be listed
+/// within [`CSR_STATUS_TIMEOUT`] (API server down).
+async fn pending_csr_count(cluster: &ClusterInfo, config: &Config) -> Option<usize> {
+    if cluster.status != ClusterStatus::Running {
+        return None;
+    }
+    let manager = ClusterManager::new(config).ok()?;
+    match tokio::time::timeout(
+        CSR_STATUS_TIMEOUT,
+        manager.pending_csrs(&cluster.name, false),
+    )
+    .await
+    {
+        Ok(Ok(csrs)) => Some(csrs.len()),
+        Ok(Err(e)) => {
+            debug!("Could not list CSRs for '{}': {:#}", cluster.name, e);
+            None
+        }
+        Err(_) => {
+            debug!("Listing CSRs for '{}' timed out", cluster.name);
+            None
+        }
+    }
+}
+
+/// The `kina status` CSR row
+fn csr_status_line(cluster: &str, pending: Option<usize>) -> String {
+    match pending {
+        Some(0) => "CSRs pending: 0".to_string(),
+        Some(n) => format!(
+            "CSRs pending: {} (run 'kina approve-csr {}' to fix)",
+            n, cluster
+        ),
+        None => "CSRs pending: unknown (API server unreachable)".to_string(),
+    }
+}
+
 impl StatusArgs {
     pub async fn execute(&self, config: &Config) -> Result<()> {
-        let cluster_manager = ClusterManager::new(config)?;
-        let container_version = cluster_manager.container_version().to_string();
-
         // Handle the case where a specific cluster name is provided
         if let Some(cluster_name) = &self.name {
-            // Get detailed cluster status for the specified cluster
-            let cluster_info = cluster_manager.get_cluster_status(cluster_name).await?;
-
-            match self.output {
-                StatusOutputFormat::Table => {
-                    self.print_table_format(&cluster_info, config, &container_version)
-                        .await?
-                }
-                StatusOutputFormat::Yaml => {
-                    let mut map = serde_json::to_value(&cluster_info)?;
-                    if let Some(obj) = map.as_object_mut() {
-                        obj.insert(
-                            "apple_container_version".to_string(),
-                            serde_json::Value::String(container_version.clone()),
-                        );
-                    }
-                    println!("{}", serde_yaml::to_string(&map)?);
-                }
-                StatusOutputFormat::Json => {
-                    let mut map = serde_json::to_value(&cluster_info)?;
-                    if let Some(obj) = map.as_object_mut() {
-                        obj.insert(
-                            "apple_container_version".to_string(),
-                            serde_json::Value::String(container_version.clone()),
-                        );
-                    }
-                    println!("{}", serde_json::to_string_pretty(&map)?);
+            if self.history {
+                let events = read_events(&config.cluster.data_dir, cluster_name)?;
+                if events.is_empty() {
+                    println!("No events recorded for cluster '{}'", cluster_name);
+                } else {
+                    print!("{}", render_events_table(&events));
                 }
+                return Ok(());
             }
+            // Get detailed cluster status for the specified cluster
+            let (cluster_info, container_version) =
+                match DaemonClient::connect_if_running(config).await {
+                    Some(mut daemon) => (
+                        daemon.get_cluster_status(cluster_name).await?,
+                        daemon.container_version().to_string(),
+                    ),
+                    None => {
+                        let cluster_manager = ClusterManager::new(config)?;
+                        (
+                            cluster_manager.get_cluster_status(cluster_name).await?,
+                            cluster_manager.container_version().to_string(),
+                        )
+                    }
+                };
 
-            return Ok(());
+            return self
+                .print_status(&cluster_info, config, &container_version)
+                .await;
         }
 
         // Handle the case where no specific cluster name is provided
+        let cluster_manager = ClusterManager::new(config)?;
+        let container_version = cluster_manager.container_version().to_string();
         let clusters = cluster_manager.list_clusters().await?;
 
         if clusters.is_empty() {
@@ -1782,33 +3997,62 @@ impl StatusArgs {
         // Get detailed cluster status
         let cluster_info = cluster_manager.get_cluster_status(&cluster_name).await?;
 
+        self.print_status(&cluster_info, config, &container_version)
+            .await
+    }
+
+    /// Print `cluster_info` in the requested format. The JSON and YAML
+    /// documents carry `apple_container_version` and `pending_csrs` (null when
+    /// the API server cannot be reached) next to the cluster fields.
+    async fn print_status(
+        &self,
+        cluster_info: &ClusterInfo,
+        config: &Config,
+        container_version: &str,
+    ) -> Result<()> {
+        let pending_csrs = pending_csr_count(cluster_info, config).await;
+        if self.check_csrs && !self.verbose {
+            match self.output {
+                StatusOutputFormat::Table => {
+                    println!("{}", csr_status_line(&cluster_info.name, pending_csrs))
+                }
+                StatusOutputFormat::Yaml | StatusOutputFormat::Json => {
+                    let map = serde_json::json!({
+                        "name": cluster_info.name,
+                        "pending_csrs": pending_csrs,
+                    });
+                    if matches!(self.output, StatusOutputFormat::Yaml) {
+                        println!("{}", serde_yaml::to_string(&map)?);
+                    } else {
+                        println!("{}", serde_json::to_string_pretty(&map)?);
+                    }
+                }
+            }
+            return Ok(());
+        }
+
         match self.output {
             StatusOutputFormat::Table => {
-                self.print_table_format(&cluster_info, config, &container_version)
-                    .await?
+                self.print_table_format(cluster_info, config, container_version)
+                    .await?;
+                println!("\n{}", csr_status_line(&cluster_info.name, pending_csrs));
             }
-            StatusOutputFormat::Yaml => {
-                let mut map = serde_json::to_value(&cluster_info)?;
+            StatusOutputFormat::Yaml | StatusOutputFormat::Json => {
+                let mut map = serde_json::to_value(cluster_info)?;
                 if let Some(obj) = map.as_object_mut() {
                     obj.insert(
                         "apple_container_version".to_string(),
-                        serde_json::Value::String(container_version.clone()),
+                        serde_json::Value::String(container_version.to_string()),
                     );
+                    obj.insert("pending_csrs".to_string(), serde_json::json!(pending_csrs));
                 }
-                println!("{}", serde_yaml::to_string(&map)?);
-            }
-            StatusOutputFormat::Json => {
-                let mut map = serde_json::to_value(&cluster_info)?;
-                if let Some(obj) = map.as_object_mut() {
-                    obj.insert(
-                        "apple_container_version".to_string(),
-                        serde_json::Value::String(container_version.clone()),
-                    );
+                if matches!(self.output, StatusOutputFormat::Yaml) {
+                    println!("{}", serde_yaml::to_string(&map)?);
+                } else {
+                    println!("{}", serde_json::to_string_pretty(&map)?);
                 }
-                println!("{}", serde_json::to_string_pretty(&map)?);
             }
         }
-
         Ok(())
     }
 
@@ -1856,7 +4100,14 @@ impl StatusArgs {
         println!("Status: {}", cluster_info.status);
         println!("Image: {}", cluster_info.image);
         println!("Apple Container: {}", container_version);
-        println!("Created: {}", cluster_info.created);
+        match cluster_info.creation_timestamp {
+            Some(ts) => println!(
+                "Created: {} ({} ago)",
+                cluster_info.created,
+                format_duration((chrono::Utc::now() - ts).to_std().unwrap_or_default())
+            ),
+            None => println!("Created: {}", cluster_info.created),
+        }
 
         if let Some(kubeconfig) = &cluster_info.kubeconfig_path {
             println!("Kubeconfig: {}", kubeconfig);
@@ -2355,15 +4606,148 @@ impl ApproveCSRArgs {
             }
         }
 
-        info!("Approving pending kubelet CSRs for cluster '{}'", self.name);
-        cluster_manager.approve_kubelet_csrs(&self.name).await?;
+        let kind = if self.all_types {
+            ""
+        } else {
+            "kubelet-serving "
+        };
+        info!("Approving pending {}CSRs for cluster '{}'", kind, self.name);
+        let approved = cluster_manager
+            .approve_pending_csrs(&self.name, self.all_types)
+            .await?;
 
-        println!("✅ Kubelet CSRs approved for cluster '{}'", self.name);
+        if approved.is_empty() {
+            println!("No pending {}CSRs in cluster '{}'", kind, self.name);
+            return Ok(());
+        }
+        println!(
+            "✅ Approved {} {}CSR(s) for cluster '{}': {}",
+            approved.len(),
+            kind,
+            self.name,
+            approved.join(", ")
+        );
         println!("💡 This should fix TLS errors with kubectl logs/exec commands");
         Ok(())
     }
 }
 
+impl PauseArgs {
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        let cluster_manager = ClusterManager::new(config)?;
+
+        info!("Stopping cluster '{}'", self.name);
+        cluster_manager.pause_cluster(&self.name).await?;
+        record_event(
+            &config.cluster.data_dir,
+            &self.name,
+            EventType::Pause,
+            "kina pause",
+            None,
+        );
+        println!("✅ Cluster '{}' stopped", self.name);
+
+        Ok(())
+    }
+}
+
+impl SshArgs {
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        use std::io::IsTerminal;
+
+        let interactive = self.command.is_empty();
+        let mut command: Vec<String> = Vec::new();
+        if self.root {
+            command.push("sudo".to_string());
+        }
+        if interactive {
+            command.push("bash".to_string());
+        } else {
+            command.extend(self.command.iter().cloned());
+        }
+        // A terminal only when there is one to attach, so `kina ssh dev < script` works.
+        let tty = interactive && std::io::stdin().is_terminal();
+
+        let cluster_manager = ClusterManager::new(config)?;
+        let code = cluster_manager
+            .exec_in_node(&self.cluster, self.node.as_deref(), &command, tty)
+            .await?;
+        if code != 0 {
+            std::process::exit(code);
+        }
+        Ok(())
+    }
+}
+
+impl InspectArgs {
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        let cluster_manager = ClusterManager::new(config)?;
+        let inspection = cluster_manager
+            .inspect_cluster(&self.name, self.node.as_deref())
+            .await?;
+        println!("{}", serde_json::to_string_pretty(&inspection)?);
+        Ok(())
+    }
+}
+
+impl StartArgs {
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        let cluster_manager = ClusterManager::new(config)?;
+
+        info!("Starting cluster '{}'", self.name);
+        let ip = cluster_manager.resume_cluster(&self.name).await?;
+        record_event(
+            &config.cluster.data_dir,
+            &self.name,
+            EventType::Resume,
+            "kina start",
+            Some(format!("control plane {}", ip)),
+        );
+        println!("✅ Cluster '{}' started", self.name);
+        println!("Control plane IP: {}", ip);
+        println!(
+            "If the IP changed while the cluster was stopped, run 'kina kubeconfig {}' \
+             to point your kubeconfig at it.",
+            self.name
+        );
+
+        Ok(())
+    }
+}
+
+impl BenchmarkArgs {
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        use crate::core::benchmark::render_benchmark_table;
+
+        let cluster_manager = ClusterManager::new(config)?;
+        let results = cluster_manager
+            .benchmark(&self.name, self.iterations)
+            .await?;
+
+        match self.output {
+            BenchmarkOutputFormat::Table => print!("{}", render_benchmark_table(&results)),
+            BenchmarkOutputFormat::Json => println!("{}", serde_json::to_string_pretty(&results)?),
+        }
+
+        Ok(())
+    }
+}
+
+impl WaitReadyArgs {
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        let cluster_manager = ClusterManager::new(config)?;
+        if !cluster_manager.cluster_exists(&self.name).await? {
+            return Err(anyhow::anyhow!("Cluster '{}' does not exist", self.name));
+        }
+
+        println!(
+            "Waiting up to {}s for cluster '{}' to be ready...",
+            self.timeout, self.name
+        );
+        cluster_manager.wait_ready(&self.name, self.timeout).await
+    }
+}
+
 impl KubeconfigArgs {
     pub async fn execute(&self, config: &Config) -> Result<()> {
         let cluster_manager = ClusterManager::new(config)?;
@@ -2397,6 +4781,17 @@ impl KubeconfigArgs {
     }
 }
 
+/// `kina create --default-storage-class` choices
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum StorageClassArg {
+    /// rancher local-path-provisioner (hostPath volumes on the node)
+    LocalPath,
+    /// csi-driver-nfs backed by --nfs-server:--nfs-path
+    Nfs,
+    /// No provisioner (the default)
+    None,
+}
+
 /// CNI plugin options for command line
 #[derive(Clone, Debug, ValueEnum)]
 pub enum CniPluginArg {
@@ -2404,6 +4799,10 @@ pub enum CniPluginArg {
     Ptp,
     /// Cilium CNI (advanced features, requires compatible kernel)
     Cilium,
+    /// Flannel VXLAN overlay
+    Flannel,
+    /// Calico (BGP/IP-in-IP, NetworkPolicy support)
+    Calico,
 }
 
 impl From<CniPluginArg> for CniPlugin {
@@ -2411,6 +4810,8 @@ impl From<CniPluginArg> for CniPlugin {
         match arg {
             CniPluginArg::Ptp => CniPlugin::Ptp,
             CniPluginArg::Cilium => CniPlugin::Cilium,
+            CniPluginArg::Flannel => CniPlugin::Flannel,
+            CniPluginArg::Calico => CniPlugin::Calico,
         }
     }
 }
@@ -2419,6 +4820,180 @@ impl From<CniPluginArg> for CniPlugin {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_csr_status_line_hints_at_approve_csr() {
+        assert_eq!(csr_status_line("dev", Some(0)), "CSRs pending: 0");
+        assert_eq!(
+            csr_status_line("dev", Some(2)),
+            "CSRs pending: 2 (run 'kina approve-csr dev' to fix)"
+        );
+        assert!(csr_status_line("dev", None).contains("unknown"));
+    }
+
+    #[test]
+    fn test_template_flags_are_overridden_by_the_command_line() {
+        let template: Vec<String> = ["--workers", "2", "--cni", "cilium", "--image-load", "a:1"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let matches = <crate::cli::Cli as clap::CommandFactory>::command()
+            .try_get_matches_from([
+                "kina",
+                "create",
+                "dev",
+                "--from-template",
+                "ci",
+                "--workers",
+                "3",
+                "--verbose",
+            ])
+            .unwrap();
+        let cli = explicit_create_args(matches.subcommand_matches("create").unwrap());
+        assert_eq!(cli, ["dev", "--from-template=ci", "--workers=3"]);
+        let merged = merge_template_args(&template, &cli);
+        assert!(!merged
+            .iter()
+            .any(|a| a.starts_with("--from-template") || a == "ci"));
+
+        let (args, _) = parse_create_args(&merged).unwrap();
+        assert_eq!(args.name, "dev");
+        assert_eq!(args.workers, 3);
+        assert!(matches!(args.cni, CniPluginArg::Cilium));
+        assert_eq!(args.image_load, ["a:1"]);
+        assert_eq!(args.from_template, None);
+    }
+
+    #[test]
+    fn test_istioctl_install_args_and_download_urls() {
+        let args = istioctl_install_args(IstioProfile::Demo, "/home/me/.kube/kina");
+        assert_eq!(args[..4], ["install", "--set", "profile=demo", "-y"]);
+        assert_eq!(
+            istioctl_download_url("1.24.2", "macos", "aarch64").unwrap(),
+            "https://github.com/istio/istio/releases/download/1.24.2/istioctl-1.24.2-osx-arm64.tar.gz"
+        );
+        assert!(istioctl_download_url("1.24.2", "linux", "x86_64")
+            .unwrap()
+            .ends_with("istioctl-1.24.2-linux-amd64.tar.gz"));
+        assert!(istioctl_download_url("1.24.2", "windows", "x86_64").is_err());
+    }
+
+    #[test]
+    fn test_parse_sha256_file_takes_the_leading_digest() {
+        let digest = "a".repeat(64);
+        assert_eq!(
+            parse_sha256_file(&format!("{}  istioctl-1.24.2-osx-arm64.tar.gz\n", digest)),
+            Some(digest.as_str())
+        );
+        assert_eq!(parse_sha256_file("Not Found"), None);
+        assert_eq!(parse_sha256_file(""), None);
+    }
+
+    #[test]
+    fn test_nginx_node_ports_default_to_30080_and_30443() {
+        assert_eq!(nginx_node_ports(None, None), (30080, 30443));
+        assert_eq!(nginx_node_ports(Some(31080), None), (31080, 30443));
+        // Only --node-port-https given, colliding with the HTTP default
+        assert_eq!(nginx_node_ports(None, Some(30080)), (30080, 30080));
+    }
+
+    #[test]
+    fn test_addon_default_node_ports_do_not_collide() {
+        assert_ne!(ARGOCD_NODE_PORT, NGINX_INGRESS_NODE_PORT_HTTP);
+        assert_ne!(ARGOCD_NODE_PORT, NGINX_INGRESS_NODE_PORT_HTTPS);
+        assert!(validate::node_port(&ARGOCD_NODE_PORT.to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_argocd_manifest_url_selects_ha_variant() {
+        assert_eq!(
+            argocd_manifest_url("v2.13.3", false),
+            "https://raw.githubusercontent.com/argoproj/argo-cd/v2.13.3/manifests/install.yaml"
+        );
+        assert!(argocd_manifest_url("v2.13.3", true).ends_with("/manifests/ha/install.yaml"));
+        let project: serde_yaml::Value =
+            serde_yaml::from_str(include_str!("../../manifests/argocd/default-project.yaml"))
+                .unwrap();
+        assert_eq!(project["kind"], "AppProject");
+        assert_eq!(project["metadata"]["namespace"], ARGOCD_NAMESPACE);
+    }
+
+    #[test]
+    fn test_prometheus_stack_helm_args_pass_version_and_values() {
+        let args = prometheus_stack_helm_args("/home/me/.kube/kina", None, None);
+        assert_eq!(
+            args[..4],
+            [
+                "upgrade",
+                "--install",
+                "kube-prometheus-stack",
+                "prometheus-community/kube-prometheus-stack"
+            ]
+        );
+        assert!(args.windows(2).any(|w| w == ["-n", "monitoring"]));
+        assert!(!args.contains(&"--version".to_string()));
+
+        let args = prometheus_stack_helm_args(
+            "/home/me/.kube/kina",
+            Some("65.1.0"),
+            Some(std::path::Path::new("values.yaml")),
+        );
+        assert!(args.windows(2).any(|w| w == ["--version", "65.1.0"]));
+        assert!(args.windows(2).any(|w| w == ["--values", "values.yaml"]));
+    }
+
+    #[test]
+    fn test_addon_target_workloads_match_manifests() {
+        assert_eq!(
+            AddonType::MetricsServer.target_workload(),
+            Some(("deployment", "metrics-server", "kube-system"))
+        );
+        assert_eq!(
+            AddonType::DemoApp.target_workload(),
+            Some(("deployment", "kina-demo-app", "default"))
+        );
+        assert_eq!(
+            AddonType::NginxIngress.target_workload(),
+            Some(("daemonset", "nginx-ingress", "nginx-ingress"))
+        );
+        assert_eq!(AddonType::Cni.target_workload(), None);
+    }
+
+    #[test]
+    fn test_vip_in_lb_address_pool() {
+        let ip = |s: &str| s.parse::<std::net::Ipv4Addr>().unwrap();
+        assert!(in_lb_address_pool(ip("192.168.64.245"), None).unwrap());
+        assert!(!in_lb_address_pool(ip("192.168.64.250"), None).unwrap());
+        assert!(in_lb_address_pool(ip("10.0.0.9"), Some("10.0.0.0/28")).unwrap());
+        assert!(!in_lb_address_pool(ip("10.0.0.16"), Some("10.0.0.0/28")).unwrap());
+        assert!(in_lb_address_pool(ip("1.2.3.4"), Some("0.0.0.0/0")).unwrap());
+    }
+
+    #[test]
+    fn test_metallb_pool_uses_top_of_node_subnet() {
+        assert_eq!(
+            default_lb_address_range("192.168.64.3").unwrap(),
+            "192.168.64.240-192.168.64.249"
+        );
+        assert!(default_lb_address_range("not-an-ip").is_err());
+
+        let manifest = metallb_pool_manifest("10.0.0.0/28");
+        let docs: Vec<serde_yaml::Value> = serde_yaml::Deserializer::from_str(&manifest)
+            .map(|d| serde::Deserialize::deserialize(d).unwrap())
+            .collect();
+        assert_eq!(docs[0]["kind"], "IPAddressPool");
+        assert_eq!(docs[0]["spec"]["addresses"][0], "10.0.0.0/28");
+        assert_eq!(docs[1]["kind"], "L2Advertisement");
+        assert_eq!(docs[1]["spec"]["ipAddressPools"][0], METALLB_POOL_NAME);
+    }
+
+    #[test]
+    fn test_no_cni_advisory_lists_installable_plugins() {
+        let advisory = no_cni_advisory("dev", "10.244.0.0/16");
+        assert!(advisory
+            .contains("kina install cni --cluster dev --plugin <ptp|cilium|flannel|calico>"));
+        assert!(advisory.contains("pod subnet 10.244.0.0/16"));
+    }
+
     #[test]
     fn test_kubeconfig_for_nonexistent_cluster() {
         let result = kubeconfig_for("__kina_test_nonexistent_cluster_abc123__");
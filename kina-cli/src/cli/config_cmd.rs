@@ -1,8 +1,9 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::{Args, Subcommand};
+use std::path::PathBuf;
 use tracing::info;
 
-use crate::config::Config;
+use crate::config::{Config, ConfigFormat};
 
 /// Manage kina configuration
 #[derive(Args)]
@@ -22,11 +23,19 @@ pub enum ConfigCommands {
     /// Get a configuration value
     Get(GetConfigArgs),
 
-    /// Reset configuration to defaults
-    Reset,
+    /// Reset configuration to defaults (the old file is kept as <file>.bak)
+    Reset(ResetArgs),
 
     /// Show configuration file path
     Path,
+
+    /// Print or save the active configuration as TOML, YAML or JSON
+    Export(ExportConfigArgs),
+
+    /// Check that Apple Container, kubectl and the other tools kina needs are
+    /// available, and that the host has enough disk and memory
+    #[command(name = "check-prerequisites")]
+    CheckPrerequisites,
 }
 
 #[derive(Args)]
@@ -44,6 +53,24 @@ pub struct GetConfigArgs {
     pub key: String,
 }
 
+#[derive(Args)]
+pub struct ResetArgs {
+    /// Reset without asking for confirmation
+    #[arg(short, long)]
+    pub yes: bool,
+}
+
+#[derive(Args)]
+pub struct ExportConfigArgs {
+    /// Output format
+    #[arg(long, value_enum, default_value = "toml")]
+    pub format: ConfigFormat,
+
+    /// Write to this file instead of stdout
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
 impl ConfigArgs {
     pub async fn execute(&self, config: &Config) -> Result<()> {
         match &self.command {
@@ -73,32 +100,7 @@ impl ConfigArgs {
                 println!("For now, please view the configuration file directly using:");
                 println!("  kina config show");
             }
-            ConfigCommands::Reset => {
-                info!("Resetting configuration to defaults");
-                println!("Resetting configuration to defaults...");
-                let mut default_config = Config::default();
-
-                if let Some(config_path) = &config.config_file_path {
-                    default_config.config_file_path = Some(config_path.clone());
-                    match default_config.save() {
-                        Ok(()) => println!(
-                            "Configuration reset to defaults and saved to: {}",
-                            config_path.display()
-                        ),
-                        Err(e) => println!("Error saving reset configuration: {}", e),
-                    }
-                } else {
-                    let default_path = Config::get_config_path();
-                    default_config.config_file_path = Some(default_path.clone());
-                    match default_config.save() {
-                        Ok(()) => println!(
-                            "Configuration reset to defaults and saved to: {}",
-                            default_path.display()
-                        ),
-                        Err(e) => println!("Error saving reset configuration: {}", e),
-                    }
-                }
-            }
+            ConfigCommands::Reset(args) => args.execute(config)?,
             ConfigCommands::Path => {
                 if let Some(config_path) = &config.config_file_path {
                     println!("{}", config_path.display());
@@ -108,8 +110,100 @@ impl ConfigArgs {
                     println!("{}", default_path.display());
                 }
             }
+            ConfigCommands::Export(args) => {
+                let content = config.to_string_as(args.format)?;
+                match &args.output {
+                    Some(path) => {
+                        std::fs::write(path, &content).with_context(|| {
+                            format!("Failed to write configuration to {}", path.display())
+                        })?;
+                        println!("✅ Configuration exported to '{}'", path.display());
+                    }
+                    None => print!("{}", content),
+                }
+            }
+            ConfigCommands::CheckPrerequisites => {
+                let checks = crate::core::prerequisites::run_checks(config);
+                for check in &checks {
+                    println!("{}", check.render());
+                }
+                let failed = checks.iter().filter(|c| !c.passed).count();
+                if failed > 0 {
+                    return Err(anyhow::anyhow!("{} prerequisite check(s) failed", failed));
+                }
+                println!("✅ All prerequisites met");
+            }
         }
 
         Ok(())
     }
 }
+
+impl ResetArgs {
+    pub fn execute(&self, config: &Config) -> Result<()> {
+        let config_path = config
+            .config_file_path
+            .clone()
+            .unwrap_or_else(Config::get_config_path);
+
+        if !self.yes && !confirm_reset(&config_path)? {
+            println!("Reset cancelled");
+            return Ok(());
+        }
+
+        info!("Resetting configuration to defaults");
+        let backup_path = if config_path.exists() {
+            let backup_path = Config::backup_path(&config_path);
+            std::fs::copy(&config_path, &backup_path).with_context(|| {
+                format!(
+                    "Failed to back up configuration to {}",
+                    backup_path.display()
+                )
+            })?;
+            Some(backup_path)
+        } else {
+            None
+        };
+
+        let default_config = Config {
+            config_file_path: Some(config_path.clone()),
+            ..Config::default()
+        };
+        default_config.save()?;
+
+        println!(
+            "✅ Configuration reset to defaults and saved to: {}",
+            config_path.display()
+        );
+        if let Some(backup_path) = backup_path {
+            println!(
+                "Previous configuration backed up to: {}",
+                backup_path.display()
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Ask for confirmation on a terminal; without one, `--yes` is required.
+fn confirm_reset(config_path: &std::path::Path) -> Result<bool> {
+    use std::io::{self, Write};
+    use std::os::unix::io::AsRawFd;
+
+    if unsafe { libc::isatty(io::stdin().as_raw_fd()) } != 1 {
+        return Err(anyhow::anyhow!(
+            "Refusing to reset {} without confirmation; re-run with --yes",
+            config_path.display()
+        ));
+    }
+
+    print!(
+        "Reset {} to defaults? A backup will be kept. [y/N]: ",
+        config_path.display()
+    );
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
+}
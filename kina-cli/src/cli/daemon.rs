@@ -0,0 +1,37 @@
+//! `kina daemon`: serve `list`, `status`, `get kubeconfig` and `delete` over a
+//! Unix socket so repeated invocations skip the container CLI startup probe.
+
+use anyhow::Result;
+use clap::Args;
+
+use crate::config::Config;
+use crate::core::cluster::ClusterManager;
+use crate::core::daemon::{self, NO_DAEMON_ENV};
+
+/// Run the kina daemon in the foreground
+#[derive(Args)]
+#[command(
+    after_help = "While the daemon runs, `kina list`, `kina status <name>`, \
+`kina get kubeconfig` and `kina delete <name>` are served by it. Set KINA_NO_DAEMON=1 \
+to bypass it. Stop the daemon with Ctrl-C or SIGTERM."
+)]
+pub struct DaemonArgs {}
+
+impl DaemonArgs {
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        let manager = ClusterManager::new(config)?;
+        let socket = daemon::socket_path();
+        println!(
+            "✅ kina daemon listening on {} (Apple Container {})",
+            socket.display(),
+            manager.container_version()
+        );
+        if std::env::var_os(NO_DAEMON_ENV).is_some() {
+            println!(
+                "Note: {} is set, so this shell will not use the daemon",
+                NO_DAEMON_ENV
+            );
+        }
+        daemon::serve(manager, &socket).await
+    }
+}
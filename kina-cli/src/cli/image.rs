@@ -0,0 +1,88 @@
+//! `kina image` subcommand — node image discovery and pre-pulling.
+
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use tracing::info;
+
+use crate::config::Config;
+use crate::core::cluster::ClusterManager;
+use crate::core::image_registry::{
+    filter_and_sort_tags, list_node_image_tags, normalize_platform, NODE_IMAGE_REPOSITORY,
+};
+
+/// Work with kindest/node images
+#[derive(Args)]
+pub struct ImageArgs {
+    #[command(subcommand)]
+    pub command: ImageCommands,
+}
+
+#[derive(Subcommand)]
+pub enum ImageCommands {
+    /// List published kindest/node image versions, newest first
+    List(ImageListArgs),
+
+    /// Pre-pull a node image so later `kina create` runs start faster
+    Pull(ImagePullArgs),
+}
+
+#[derive(Args)]
+pub struct ImageListArgs {
+    /// Include pre-release tags (alpha, beta, rc)
+    #[arg(long)]
+    pub all: bool,
+}
+
+#[derive(Args)]
+pub struct ImagePullArgs {
+    /// Image to pull (e.g. kindest/node:v1.36.1)
+    pub image: String,
+
+    /// Pull for another architecture (arm64, amd64, or os/arch)
+    #[arg(long, value_name = "ARCH")]
+    pub platform: Option<String>,
+}
+
+impl ImageArgs {
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        match &self.command {
+            ImageCommands::List(args) => args.execute(config).await,
+            ImageCommands::Pull(args) => args.execute(config).await,
+        }
+    }
+}
+
+impl ImageListArgs {
+    pub async fn execute(&self, _config: &Config) -> Result<()> {
+        let tags = filter_and_sort_tags(&list_node_image_tags().await?, self.all);
+
+        if tags.is_empty() {
+            println!("No {} tags found", NODE_IMAGE_REPOSITORY);
+            return Ok(());
+        }
+
+        for tag in tags {
+            println!("{}:{}", NODE_IMAGE_REPOSITORY, tag);
+        }
+        Ok(())
+    }
+}
+
+impl ImagePullArgs {
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        let platform = self
+            .platform
+            .as_deref()
+            .map(normalize_platform)
+            .transpose()?;
+        let cluster_manager = ClusterManager::new(config)?;
+
+        info!("Pulling image '{}'", self.image);
+        cluster_manager
+            .pull_image(&self.image, platform.as_deref())
+            .await?;
+
+        println!("✅ Image '{}' pulled and verified", self.image);
+        Ok(())
+    }
+}
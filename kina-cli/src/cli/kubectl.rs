@@ -0,0 +1,325 @@
+//! kubectl convenience subcommands (`kina apply`, `kina delete-resource`,
+//! `kina wait`, `kina port-forward`) that run against a
+//! cluster's kubeconfig so users don't have to pass `--kubeconfig` themselves.
+
+use anyhow::{Context, Result};
+use clap::Args;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::core::cluster::ClusterManager;
+use crate::core::kubernetes::{wait_args, DeleteResourceOptions, KubernetesClient};
+use crate::utils::validate;
+
+/// File extensions picked up when a directory is passed to `-f`
+const MANIFEST_EXTENSIONS: [&str; 3] = ["yaml", "yml", "json"];
+
+/// Apply manifests to a cluster (`kubectl apply -f`)
+#[derive(Args)]
+pub struct ApplyArgs {
+    /// Target cluster name
+    pub cluster: String,
+
+    /// Manifest file, directory of manifests, or `-` for stdin (repeatable)
+    #[arg(short, long = "file", value_name = "PATH", required = true)]
+    pub files: Vec<PathBuf>,
+
+    /// Namespace to apply namespaced resources into
+    #[arg(short, long)]
+    pub namespace: Option<String>,
+
+    /// Only print what would be applied (passed to kubectl --dry-run)
+    #[arg(long, value_name = "MODE", value_parser = ["none", "client", "server"])]
+    pub dry_run: Option<String>,
+}
+
+/// Delete Kubernetes resources from a cluster (`kubectl delete`)
+#[derive(Args)]
+pub struct DeleteResourceArgs {
+    /// Target cluster name
+    pub cluster: String,
+
+    /// Resource type (e.g. pods, deployments, configmap)
+    pub resource_type: String,
+
+    /// Name of the resource to delete
+    #[arg(required_unless_present_any = ["all", "label_selector"])]
+    pub name: Option<String>,
+
+    /// Namespace of the resources
+    #[arg(short, long)]
+    pub namespace: Option<String>,
+
+    /// Delete all resources of this type in the namespace
+    #[arg(long, conflicts_with = "name")]
+    pub all: bool,
+
+    /// Delete resources matching this label selector, e.g. app=web
+    #[arg(short = 'l', long, value_name = "SELECTOR")]
+    pub label_selector: Option<String>,
+
+    /// Seconds to give resources to terminate gracefully
+    #[arg(long, value_name = "SECONDS", allow_hyphen_values = true)]
+    pub grace_period: Option<i64>,
+
+    /// Delete immediately, without waiting for graceful termination
+    #[arg(long)]
+    pub force: bool,
+
+    /// Dependent deletion policy
+    #[arg(long, value_name = "POLICY", value_parser = ["background", "foreground", "orphan"])]
+    pub cascade: Option<String>,
+}
+
+/// Wait for a condition on Kubernetes resources (`kubectl wait`)
+#[derive(Args)]
+pub struct WaitArgs {
+    /// Target cluster name
+    pub cluster: String,
+
+    /// Resource to wait on, e.g. pod/web, deployment/api or pods
+    pub resource: String,
+
+    /// Condition to wait for: condition=<cond>, jsonpath=<path>=<value> or delete
+    #[arg(long = "for", value_name = "CONDITION")]
+    pub condition: String,
+
+    /// How long to wait before giving up (kubectl duration, e.g. 30s, 5m)
+    #[arg(long, default_value = "60s")]
+    pub timeout: String,
+
+    /// Namespace of the resource
+    #[arg(short, long)]
+    pub namespace: Option<String>,
+
+    /// Wait on matching resources in all namespaces
+    #[arg(short = 'A', long, conflicts_with = "namespace")]
+    pub all_namespaces: bool,
+}
+
+/// Forward local ports to a pod or service (`kubectl port-forward`)
+#[derive(Args)]
+pub struct PortForwardArgs {
+    /// Target cluster name
+    pub cluster: String,
+
+    /// Resource to forward to, e.g. pod/my-pod, svc/my-service or deployment/api
+    pub resource: String,
+
+    /// Ports as LOCAL:REMOTE, or PORT for the same port on both sides
+    #[arg(required = true, value_name = "LOCAL:REMOTE", value_parser = validate::port_pair)]
+    pub ports: Vec<(u16, u16)>,
+
+    /// Namespace of the resource
+    #[arg(short, long)]
+    pub namespace: Option<String>,
+}
+
+/// Expand one `-f` argument into the manifest files to apply, in order.
+///
+/// A directory yields its `.yaml`/`.yml`/`.json` files (not recursive) in
+/// alphabetical order, mirroring `kubectl apply -f <dir>`. Anything else,
+/// including `-` for stdin, is returned as-is.
+pub fn manifest_files(path: &Path) -> Result<Vec<PathBuf>> {
+    if !path.is_dir() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+
+    let mut files: Vec<PathBuf> = std::fs::read_dir(path)
+        .with_context(|| format!("Failed to read manifest directory {}", path.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| {
+            p.is_file()
+                && p.extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| MANIFEST_EXTENSIONS.contains(&ext))
+        })
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Manifests `kina create --manifests <dir>` applies, in order. Files whose
+/// name starts with a number (`01-namespace.yaml`, `2-app.yaml`) come first in
+/// numeric order, then the rest alphabetically; names starting with `_` are
+/// skipped.
+pub fn startup_manifest_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    if !dir.is_dir() {
+        return Err(anyhow::anyhow!(
+            "Manifest directory {} does not exist",
+            dir.display()
+        ));
+    }
+    let name = |p: &PathBuf| {
+        p.file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    };
+    let mut files: Vec<PathBuf> = manifest_files(dir)?
+        .into_iter()
+        .filter(|p| !name(p).starts_with('_'))
+        .collect();
+    files.sort_by_cached_key(|p| {
+        let name = name(p);
+        let digits: String = name.chars().take_while(|c| c.is_ascii_digit()).collect();
+        (digits.parse::<u64>().unwrap_or(u64::MAX), name)
+    });
+    Ok(files)
+}
+
+/// Read a manifest from a file, or from stdin for `-`
+pub(crate) fn read_manifest(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut manifest = String::new();
+        std::io::stdin()
+            .read_to_string(&mut manifest)
+            .context("Failed to read manifest from stdin")?;
+        return Ok(manifest);
+    }
+
+    std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read manifest {}", path.display()))
+}
+
+impl ApplyArgs {
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        let cluster_manager = ClusterManager::new(config)?;
+        if !cluster_manager.cluster_exists(&self.cluster).await? {
+            return Err(anyhow::anyhow!("Cluster '{}' does not exist", self.cluster));
+        }
+
+        let mut files = Vec::new();
+        for path in &self.files {
+            files.extend(manifest_files(path)?);
+        }
+        if files.is_empty() {
+            return Err(anyhow::anyhow!("No manifest files found"));
+        }
+
+        let kubeconfig = cluster_manager.kubeconfig_path(&self.cluster).await?;
+        let kubeconfig = kubeconfig.to_string_lossy();
+        let kubectl = KubernetesClient::new(config)?;
+
+        for file in &files {
+            let manifest = read_manifest(file)?;
+            let applied = kubectl
+                .apply_manifest_with(
+                    &kubeconfig,
+                    &manifest,
+                    self.namespace.as_deref(),
+                    self.dry_run.as_deref(),
+                )
+                .await
+                .with_context(|| format!("Failed to apply {}", file.display()))?;
+            for resource in applied.lines().filter(|l| !l.trim().is_empty()) {
+                println!("✅ {}", resource);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl DeleteResourceArgs {
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        let cluster_manager = ClusterManager::new(config)?;
+        if !cluster_manager.cluster_exists(&self.cluster).await? {
+            return Err(anyhow::anyhow!("Cluster '{}' does not exist", self.cluster));
+        }
+
+        let kubeconfig = cluster_manager.kubeconfig_path(&self.cluster).await?;
+        let kubectl = KubernetesClient::new(config)?;
+        let options = DeleteResourceOptions {
+            resource_type: self.resource_type.clone(),
+            name: self.name.clone(),
+            namespace: self.namespace.clone(),
+            all: self.all,
+            label_selector: self.label_selector.clone(),
+            grace_period: self.grace_period,
+            force: self.force,
+            cascade: self.cascade.clone(),
+        };
+
+        let deleted = kubectl
+            .delete_resource(&kubeconfig.to_string_lossy(), &options)
+            .await?;
+        let deleted: Vec<&str> = deleted.lines().filter(|l| !l.trim().is_empty()).collect();
+        if deleted.is_empty() {
+            println!("No {} found to delete.", self.resource_type);
+        }
+        for resource in deleted {
+            println!("✅ {}", resource);
+        }
+
+        Ok(())
+    }
+}
+
+impl WaitArgs {
+    /// Exits with kubectl's exit code when the wait does not succeed, so scripts
+    /// can branch on it the same way they would on `kubectl wait`.
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        let args = wait_args(
+            &self.resource,
+            &self.condition,
+            self.namespace.as_deref(),
+            self.all_namespaces,
+            &self.timeout,
+        )?;
+
+        let cluster_manager = ClusterManager::new(config)?;
+        if !cluster_manager.cluster_exists(&self.cluster).await? {
+            return Err(anyhow::anyhow!("Cluster '{}' does not exist", self.cluster));
+        }
+
+        let kubeconfig = cluster_manager.kubeconfig_path(&self.cluster).await?;
+        let kubectl = KubernetesClient::new(config)?;
+        let code = kubectl.wait(&kubeconfig.to_string_lossy(), &args).await?;
+        if code != 0 {
+            std::process::exit(code);
+        }
+
+        Ok(())
+    }
+}
+
+impl PortForwardArgs {
+    /// Forward until kubectl exits or Ctrl-C is pressed; on Ctrl-C the kubectl
+    /// child is killed and reaped before returning.
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        let cluster_manager = ClusterManager::new(config)?;
+        if !cluster_manager.cluster_exists(&self.cluster).await? {
+            return Err(anyhow::anyhow!("Cluster '{}' does not exist", self.cluster));
+        }
+
+        let kubeconfig = cluster_manager.kubeconfig_path(&self.cluster).await?;
+        let kubectl = KubernetesClient::new(config)?;
+        let mut child = kubectl
+            .port_forward(
+                &kubeconfig.to_string_lossy(),
+                &self.resource,
+                &self.ports,
+                self.namespace.as_deref(),
+            )
+            .await?;
+
+        tokio::select! {
+            status = child.wait() => {
+                let status = status.context("Failed to wait for kubectl port-forward")?;
+                if !status.success() {
+                    return Err(anyhow::anyhow!("kubectl port-forward exited with {}", status));
+                }
+            }
+            _ = tokio::signal::ctrl_c() => {
+                child
+                    .kill()
+                    .await
+                    .context("Failed to stop kubectl port-forward")?;
+                println!("Stopped forwarding to {}", self.resource);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -7,10 +7,28 @@ use crate::core::cluster::ClusterManager;
 mod build;
 mod cluster;
 mod config_cmd;
+mod daemon;
+mod image;
+mod kubectl;
+mod network;
+mod node;
+mod plugin;
+mod template;
+mod tui;
+mod update_check;
 
 pub use build::*;
 pub use cluster::*;
 pub use config_cmd::*;
+pub use daemon::DaemonArgs;
+pub use image::*;
+pub use kubectl::*;
+pub use network::*;
+pub use node::NodeArgs;
+pub use plugin::PluginArgs;
+pub use template::TemplateArgs;
+pub use tui::TuiArgs;
+pub use update_check::*;
 
 /// kina - Kubernetes in Apple Container
 ///
@@ -45,11 +63,26 @@ pub struct Cli {
 #[derive(Subcommand)]
 pub enum Commands {
     /// Create a new Kubernetes cluster
-    Create(CreateArgs),
+    Create(Box<CreateArgs>),
 
     /// Delete a Kubernetes cluster
     Delete(DeleteArgs),
 
+    /// Rename an existing cluster
+    Rename(RenameArgs),
+
+    /// Create a new cluster with the same configuration as a running one
+    Duplicate(DuplicateArgs),
+
+    /// Pause cluster VMs (same as 'stop')
+    Pause(PauseArgs),
+
+    /// Stop cluster VMs (same as 'pause')
+    Stop(PauseArgs),
+
+    /// Start a paused or stopped cluster's VMs
+    Start(StartArgs),
+
     /// List existing clusters
     #[command(alias = "ls")]
     List(ListArgs),
@@ -57,15 +90,48 @@ pub enum Commands {
     /// Show detailed status of a cluster
     Status(StatusArgs),
 
+    /// Print raw Apple Container inspect JSON for a cluster's nodes
+    Inspect(InspectArgs),
+
+    /// Open a shell in a cluster node, or run a command there after `--`
+    Ssh(SshArgs),
+
     /// Get information about clusters or resources
     Get(GetArgs),
 
     /// Load container images into clusters
     Load(LoadArgs),
 
+    /// Create, delete and list Apple Container networks
+    Network(NetworkArgs),
+
+    /// Drain and uncordon cluster nodes
+    Node(NodeArgs),
+
+    /// Save, list and delete cluster templates for `kina create --from-template`
+    Template(TemplateArgs),
+
     /// Install addons (ingress controllers, CNI, etc.)
     Install(InstallArgs),
 
+    /// Apply Kubernetes manifests to a cluster
+    Apply(ApplyArgs),
+
+    /// Delete Kubernetes resources from a cluster
+    #[command(name = "delete-resource")]
+    DeleteResource(DeleteResourceArgs),
+
+    /// Wait for a condition on Kubernetes resources in a cluster
+    Wait(WaitArgs),
+
+    /// Forward local ports to a pod or service in a cluster
+    #[command(name = "port-forward")]
+    PortForward(PortForwardArgs),
+
+    /// Block until a cluster's nodes are Ready and kube-system pods are Running
+    #[command(name = "wait-ready")]
+    WaitReady(WaitReadyArgs),
+
     /// Export cluster configuration
     Export(ExportArgs),
 
@@ -82,9 +148,28 @@ pub enum Commands {
     /// Verify a cluster's health end-to-end (nodes Ready, Cilium, HTTP probe)
     Verify(VerifyArgs),
 
+    /// Measure cluster performance (pod startup, DNS, volumes, API latency)
+    Benchmark(BenchmarkArgs),
+
     /// Build kina artefacts (node images, etc.)
     Build(BuildArgs),
 
+    /// Discover kindest/node images
+    Image(ImageArgs),
+
+    /// Run a kina-<name> plugin from PATH ('kina plugin list' shows them)
+    Plugin(PluginArgs),
+
+    /// Serve kina commands over a Unix socket (~/.local/share/kina/kina.sock)
+    Daemon(DaemonArgs),
+
+    /// Browse and manage clusters in an interactive terminal UI
+    Tui(TuiArgs),
+
+    /// Check whether a newer kina release is available
+    #[command(name = "update-check")]
+    UpdateCheck(UpdateCheckArgs),
+
     /// Show build provenance (git sha, build timestamp, rustc, target)
     Version(VersionArgs),
 }
@@ -103,8 +188,11 @@ impl VersionArgs {
             println!("{}", crate::version::version_json(&crate::version::BUILD));
         } else {
             println!("{}", crate::version::human_version(&crate::version::BUILD));
-            match ClusterManager::new(config) {
-                Ok(manager) => println!("Apple Container {}", manager.container_version()),
+            match ClusterManager::new(config).and_then(|m| m.container_version_info()) {
+                Ok(container) => println!(
+                    "Apple Container {} (build: {})",
+                    container.version, container.build
+                ),
                 Err(e) => println!("Apple Container: not available ({})", e),
             }
         }
@@ -113,22 +201,52 @@ impl VersionArgs {
 }
 
 impl Cli {
+    /// Keep the `kina create` arguments given on the command line, which
+    /// `--from-template` applies over the template's
+    pub fn record_explicit_args(&mut self, matches: &clap::ArgMatches) {
+        if let (Some(Commands::Create(args)), Some(("create", create))) =
+            (&mut self.command, matches.subcommand())
+        {
+            args.explicit_args = cluster::explicit_create_args(create);
+        }
+    }
+
     pub async fn execute(&self, config: &Config) -> Result<()> {
         // Execute the subcommand
         match &self.command {
             Some(Commands::Create(args)) => args.execute(config).await,
             Some(Commands::Delete(args)) => args.execute(config).await,
+            Some(Commands::Rename(args)) => args.execute(config).await,
+            Some(Commands::Duplicate(args)) => args.execute(config).await,
+            Some(Commands::Pause(args)) | Some(Commands::Stop(args)) => args.execute(config).await,
+            Some(Commands::Start(args)) => args.execute(config).await,
             Some(Commands::List(args)) => args.execute(config).await,
             Some(Commands::Status(args)) => args.execute(config).await,
+            Some(Commands::Inspect(args)) => args.execute(config).await,
+            Some(Commands::Ssh(args)) => args.execute(config).await,
             Some(Commands::Get(args)) => args.execute(config).await,
             Some(Commands::Load(args)) => args.execute(config).await,
+            Some(Commands::Network(args)) => args.execute(config).await,
+            Some(Commands::Node(args)) => args.execute(config).await,
+            Some(Commands::Template(args)) => args.execute(config).await,
             Some(Commands::Install(args)) => args.execute(config).await,
+            Some(Commands::Apply(args)) => args.execute(config).await,
+            Some(Commands::DeleteResource(args)) => args.execute(config).await,
+            Some(Commands::Wait(args)) => args.execute(config).await,
+            Some(Commands::PortForward(args)) => args.execute(config).await,
+            Some(Commands::WaitReady(args)) => args.execute(config).await,
             Some(Commands::Export(args)) => args.execute(config).await,
             Some(Commands::ApproveCSR(args)) => args.execute(config).await,
             Some(Commands::Kubeconfig(args)) => args.execute(config).await,
             Some(Commands::Config(args)) => args.execute(config).await,
             Some(Commands::Verify(args)) => args.execute(config).await,
+            Some(Commands::Benchmark(args)) => args.execute(config).await,
             Some(Commands::Build(args)) => args.execute(config).await,
+            Some(Commands::Image(args)) => args.execute(config).await,
+            Some(Commands::Plugin(args)) => args.execute(config).await,
+            Some(Commands::Daemon(args)) => args.execute(config).await,
+            Some(Commands::Tui(args)) => args.execute(config).await,
+            Some(Commands::UpdateCheck(args)) => args.execute(config).await,
             Some(Commands::Version(args)) => args.execute(config).await,
             None => {
                 println!("{}", crate::version::human_version(&crate::version::BUILD));
@@ -0,0 +1,99 @@
+//! `kina network`: manage Apple Container networks independently of clusters.
+
+use anyhow::Result;
+use clap::{Args, Subcommand};
+
+use crate::config::Config;
+use crate::core::cluster::ClusterManager;
+use crate::core::types::NETWORK_CLUSTER_LABEL;
+use crate::utils::validate;
+
+/// Manage Apple Container networks for kina clusters
+#[derive(Args)]
+pub struct NetworkArgs {
+    #[command(subcommand)]
+    pub command: NetworkCommands,
+}
+
+#[derive(Subcommand)]
+pub enum NetworkCommands {
+    /// Create a network (labelled io.kina.cluster=<name> unless --label sets it)
+    Create(NetworkCreateArgs),
+
+    /// Delete a kina-managed network
+    Delete(NetworkDeleteArgs),
+
+    /// List networks with an io.kina.cluster label
+    #[command(alias = "ls")]
+    List,
+}
+
+#[derive(Args)]
+pub struct NetworkCreateArgs {
+    /// Network name
+    pub name: String,
+
+    /// IPv4 subnet in CIDR notation (default: assigned by Apple Container)
+    #[arg(long, value_name = "CIDR", value_parser = validate::subnet)]
+    pub subnet: Option<String>,
+
+    /// Network driver. Apple Container only provides NAT networks today, so
+    /// `nat` is the only accepted value
+    #[arg(long, value_name = "NAME", default_value = "nat", value_parser = ["nat"])]
+    pub driver: String,
+
+    /// Label as <key>=<value> (repeatable)
+    #[arg(long = "label", value_name = "KEY=VALUE", value_parser = validate::label)]
+    pub labels: Vec<(String, String)>,
+}
+
+#[derive(Args)]
+pub struct NetworkDeleteArgs {
+    /// Network name
+    pub name: String,
+}
+
+impl NetworkArgs {
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        let cluster_manager = ClusterManager::new(config)?;
+
+        match &self.command {
+            NetworkCommands::Create(args) => {
+                let labels = args.labels.iter().cloned().collect();
+                let network = cluster_manager
+                    .create_network(&args.name, args.subnet.as_deref(), &labels)
+                    .await?;
+                match &network.subnet {
+                    Some(subnet) => println!("✅ Network '{}' created ({})", network.name, subnet),
+                    None => println!("✅ Network '{}' created", network.name),
+                }
+            }
+            NetworkCommands::Delete(args) => {
+                cluster_manager.delete_network(&args.name).await?;
+                println!("✅ Network '{}' deleted", args.name);
+            }
+            NetworkCommands::List => {
+                let networks = cluster_manager.list_networks().await?;
+                if networks.is_empty() {
+                    println!("No kina networks found");
+                    return Ok(());
+                }
+                println!(
+                    "{:<24} {:<10} {:<20} CLUSTER ({})",
+                    "NAME", "STATE", "SUBNET", NETWORK_CLUSTER_LABEL
+                );
+                for network in &networks {
+                    println!(
+                        "{:<24} {:<10} {:<20} {}",
+                        network.name,
+                        network.state,
+                        network.subnet.as_deref().unwrap_or("-"),
+                        network.kina_cluster().unwrap_or("")
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
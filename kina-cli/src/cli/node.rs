@@ -0,0 +1,88 @@
+//! `kina node`: node maintenance (drain, uncordon) through kubectl.
+
+use anyhow::Result;
+use clap::{Args, Subcommand};
+
+use crate::config::Config;
+use crate::core::cluster::ClusterManager;
+use crate::core::kubernetes::KubernetesClient;
+
+/// Drain and uncordon cluster nodes
+#[derive(Args)]
+pub struct NodeArgs {
+    #[command(subcommand)]
+    pub command: NodeCommands,
+}
+
+#[derive(Subcommand)]
+pub enum NodeCommands {
+    /// Cordon a node and evict its pods (DaemonSet pods stay, emptyDir data is lost)
+    Drain(NodeDrainArgs),
+
+    /// Mark a drained or cordoned node schedulable again
+    Uncordon(NodeUncordonArgs),
+}
+
+#[derive(Args)]
+pub struct NodeDrainArgs {
+    /// Target cluster name
+    pub cluster: String,
+
+    /// Node to drain, e.g. kina-worker
+    pub node: String,
+
+    /// Seconds each pod gets to terminate (default: the pod's own grace period)
+    #[arg(long, value_name = "SECONDS")]
+    pub grace_period: Option<i64>,
+
+    /// How long to wait for the drain before giving up (kubectl duration, e.g. 30s, 5m)
+    #[arg(long, default_value = "5m")]
+    pub timeout: String,
+}
+
+#[derive(Args)]
+pub struct NodeUncordonArgs {
+    /// Target cluster name
+    pub cluster: String,
+
+    /// Node to uncordon
+    pub node: String,
+}
+
+impl NodeArgs {
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        let cluster = match &self.command {
+            NodeCommands::Drain(args) => &args.cluster,
+            NodeCommands::Uncordon(args) => &args.cluster,
+        };
+        let cluster_manager = ClusterManager::new(config)?;
+        if !cluster_manager.cluster_exists(cluster).await? {
+            return Err(anyhow::anyhow!("Cluster '{}' does not exist", cluster));
+        }
+        let kubeconfig = cluster_manager.kubeconfig_path(cluster).await?;
+        let kubeconfig = kubeconfig.to_string_lossy();
+        let kubectl = KubernetesClient::new(config)?;
+
+        match &self.command {
+            NodeCommands::Drain(args) => {
+                let evicted = kubectl
+                    .drain_node(&kubeconfig, &args.node, args.grace_period, &args.timeout)
+                    .await?;
+                if evicted.is_empty() {
+                    println!("No pods evicted");
+                } else {
+                    println!("Evicted {} pod(s):", evicted.len());
+                    for pod in &evicted {
+                        println!("  {}", pod);
+                    }
+                }
+                println!("✅ Node '{}' drained", args.node);
+            }
+            NodeCommands::Uncordon(args) => {
+                kubectl.uncordon_node(&kubeconfig, &args.node).await?;
+                println!("✅ Node '{}' uncordoned", args.node);
+            }
+        }
+        Ok(())
+    }
+}
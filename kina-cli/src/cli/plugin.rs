@@ -0,0 +1,119 @@
+//! `kina plugin`: run `kina-<name>` executables from `PATH`.
+
+use anyhow::{Context, Result};
+use clap::Args;
+use tracing::debug;
+
+use crate::config::Config;
+use crate::core::cluster::ClusterManager;
+use crate::core::daemon::DaemonClient;
+use crate::core::plugin::{
+    discover_plugins, find_plugin, PLUGIN_CLUSTERS_ENV, PLUGIN_CONFIG_ENV, PLUGIN_PREFIX,
+};
+
+const PLUGIN_HELP: &str = "\
+Plugin contract:
+  A plugin is any executable named kina-<name> on PATH; the first match wins.
+  'kina plugin <name> [args]' runs it with [args] and the terminal's
+  stdin/stdout/stderr, and exits with the plugin's exit code.
+  The plugin's environment also carries:
+    KINA_CONFIG    path of the active kina config file
+    KINA_CLUSTERS  JSON array of clusters (name, image, status, created, nodes,
+                   kubeconfig_path); \"[]\" when they cannot be listed
+  Plugins should answer --version with a one-line version string, which
+  'kina plugin list' shows. A plugin named kina-list is shadowed by 'list'.";
+
+/// Run a `kina-<name>` plugin from PATH
+#[derive(Args)]
+#[command(after_help = PLUGIN_HELP)]
+pub struct PluginArgs {
+    /// Plugin to run, or `list` to show the plugins on PATH
+    pub name: String,
+
+    /// Arguments passed through to the plugin
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+    pub args: Vec<String>,
+}
+
+impl PluginArgs {
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        let path_var = std::env::var_os("PATH").unwrap_or_default();
+        if self.name == "list" {
+            return list_plugins(&path_var).await;
+        }
+
+        let plugin = find_plugin(&self.name, &path_var).with_context(|| {
+            format!(
+                "No plugin '{}' found: expected an executable named {}{} on PATH (see 'kina plugin list')",
+                self.name, PLUGIN_PREFIX, self.name
+            )
+        })?;
+        debug!("Running plugin {}", plugin.display());
+
+        let config_path = config
+            .config_file_path
+            .clone()
+            .unwrap_or_else(Config::get_config_path);
+        let status = tokio::process::Command::new(&plugin)
+            .args(&self.args)
+            .env(PLUGIN_CONFIG_ENV, config_path)
+            .env(PLUGIN_CLUSTERS_ENV, clusters_json(config).await)
+            .status()
+            .await
+            .with_context(|| format!("Failed to run plugin {}", plugin.display()))?;
+
+        if !status.success() {
+            std::process::exit(status.code().unwrap_or(1));
+        }
+        Ok(())
+    }
+}
+
+/// The cluster list handed to plugins; `[]` when it cannot be read, so a
+/// plugin that ignores clusters still runs without Apple Container.
+async fn clusters_json(config: &Config) -> String {
+    let clusters = match DaemonClient::connect_if_running(config).await {
+        Some(mut daemon) => daemon.list_clusters().await,
+        None => match ClusterManager::new(config) {
+            Ok(manager) => manager.list_clusters().await,
+            Err(e) => Err(e),
+        },
+    };
+    match clusters.and_then(|c| Ok(serde_json::to_string(&c)?)) {
+        Ok(json) => json,
+        Err(e) => {
+            debug!("Passing an empty cluster list to the plugin: {:#}", e);
+            "[]".to_string()
+        }
+    }
+}
+
+async fn list_plugins(path_var: &std::ffi::OsStr) -> Result<()> {
+    let plugins = discover_plugins(path_var);
+    if plugins.is_empty() {
+        println!(
+            "No plugins found (executables named {}<name> on PATH)",
+            PLUGIN_PREFIX
+        );
+        return Ok(());
+    }
+
+    for (name, path) in plugins {
+        let version = match tokio::process::Command::new(&path)
+            .arg("--version")
+            .stdin(std::process::Stdio::null())
+            .output()
+            .await
+        {
+            Ok(output) if output.status.success() => String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .next()
+                .unwrap_or("")
+                .trim()
+                .to_string(),
+            _ => "unknown version".to_string(),
+        };
+        println!("{:<20} {:<30} {}", name, version, path.display());
+    }
+    Ok(())
+}
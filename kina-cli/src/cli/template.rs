@@ -0,0 +1,115 @@
+//! `kina template`: save, list and delete named sets of `kina create` flags.
+
+use anyhow::Result;
+use clap::{parser::ValueSource, Args, Subcommand};
+
+use crate::cli::cluster::parse_create_args;
+use crate::config::Config;
+use crate::core::template::{
+    delete_template, list_templates, load_template, save_template, ClusterTemplate,
+};
+
+/// Manage cluster templates used by `kina create --from-template`
+#[derive(Args)]
+pub struct TemplateArgs {
+    #[command(subcommand)]
+    pub command: TemplateCommands,
+}
+
+#[derive(Subcommand)]
+pub enum TemplateCommands {
+    /// Save `kina create` flags as a template, e.g.
+    /// `kina template save ci -- --workers 2 --cni cilium`
+    Save(TemplateSaveArgs),
+
+    /// List saved templates
+    #[command(alias = "ls")]
+    List,
+
+    /// Delete a template
+    Delete(TemplateDeleteArgs),
+}
+
+#[derive(Args)]
+pub struct TemplateSaveArgs {
+    /// Template name (letters, digits, '-' and '_')
+    pub name: String,
+
+    /// `kina create` flags to save; the cluster name is not part of a template
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+    pub args: Vec<String>,
+}
+
+#[derive(Args)]
+pub struct TemplateDeleteArgs {
+    /// Template name
+    pub name: String,
+}
+
+impl TemplateArgs {
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        let data_dir = &config.cluster.data_dir;
+        match &self.command {
+            TemplateCommands::Save(args) => {
+                check_template_args(&args.args)?;
+                let path = save_template(
+                    data_dir,
+                    &args.name,
+                    &ClusterTemplate {
+                        args: args.args.clone(),
+                    },
+                )?;
+                println!("✅ Template '{}' saved to {}", args.name, path.display());
+            }
+            TemplateCommands::List => {
+                let names = list_templates(data_dir)?;
+                if names.is_empty() {
+                    println!("No templates saved (create one with 'kina template save')");
+                }
+                for name in names {
+                    let flags = load_template(data_dir, &name)
+                        .map(|t| t.args.join(" "))
+                        .unwrap_or_else(|e| format!("<{:#}>", e));
+                    println!("{:<20} {}", name, flags);
+                }
+            }
+            TemplateCommands::Delete(args) => {
+                delete_template(data_dir, &args.name)?;
+                println!("✅ Template '{}' deleted", args.name);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reject flags `kina create` would not accept, a cluster name, and nested
+/// templates.
+fn check_template_args(args: &[String]) -> Result<()> {
+    let (_, matches) = parse_create_args(args)?;
+    for (id, what) in [
+        ("name", "a cluster name"),
+        ("from_template", "--from-template"),
+    ] {
+        if matches.value_source(id) == Some(ValueSource::CommandLine) {
+            return Err(anyhow::anyhow!("A template cannot include {}", what));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn template_args_must_be_create_flags_without_a_name() {
+        assert!(check_template_args(&args(&["--workers", "2", "--cni", "calico"])).is_ok());
+        assert!(check_template_args(&args(&["--no-such-flag"])).is_err());
+        assert!(check_template_args(&args(&["dev", "--workers", "2"])).is_err());
+        assert!(check_template_args(&args(&["--from-template", "other"])).is_err());
+    }
+}
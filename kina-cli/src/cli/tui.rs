@@ -0,0 +1,780 @@
+//! `kina tui` — an interactive terminal UI over the cluster commands.
+//!
+//! A background task lists clusters on a `tokio::time::interval` and sends each
+//! snapshot to the UI loop over a `tokio::sync::mpsc` channel; the UI redraws
+//! from the latest snapshot. Create and delete leave the alternate screen while
+//! they run so their usual progress output stays visible.
+
+use anyhow::{Context, Result};
+use clap::{Args, Parser};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use crossterm::ExecutableCommand;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{
+    Block, Cell, Clear, List, ListItem, ListState, Paragraph, Row, Table, Wrap,
+};
+use ratatui::{DefaultTerminal, Frame};
+use std::io::IsTerminal;
+use std::time::Duration;
+use tokio::sync::{mpsc, watch};
+
+use super::cluster::CreateArgs;
+use crate::config::Config;
+use crate::core::cluster::ClusterManager;
+use crate::core::types::{ClusterInfo, ClusterStatus, DeleteClusterOptions};
+
+/// Kubelet journal lines shown by `l`
+const LOG_LINES: u32 = 200;
+
+/// How long the UI waits for a key before checking for new snapshots
+const INPUT_POLL: Duration = Duration::from_millis(100);
+
+/// Footer text until the first snapshot arrives
+const LOADING: &str = "Loading clusters...";
+
+/// Labels of the create dialog's fields, in display order
+const CREATE_FIELDS: [&str; 3] = ["Name", "Workers", "Kubernetes version"];
+
+/// Browse and manage clusters in an interactive terminal UI
+#[derive(Args)]
+pub struct TuiArgs {
+    /// Seconds between background refreshes of the cluster list
+    #[arg(long, default_value = "2", value_name = "SECONDS")]
+    pub refresh: u64,
+}
+
+/// `kina create` arguments parsed from the create dialog
+#[derive(Parser)]
+#[command(name = "kina create")]
+struct CreateCommand {
+    #[command(flatten)]
+    args: CreateArgs,
+}
+
+/// A cluster list snapshot from the background task (errors as text)
+type Snapshot = std::result::Result<Vec<ClusterInfo>, String>;
+
+/// The create-cluster form dialog
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateForm {
+    /// Field values, in `CREATE_FIELDS` order
+    pub fields: [String; 3],
+    /// Index of the focused field
+    pub focus: usize,
+    /// Validation error from the last submit
+    pub error: Option<String>,
+}
+
+impl Default for CreateForm {
+    fn default() -> Self {
+        Self {
+            fields: ["kina".to_string(), "0".to_string(), String::new()],
+            focus: 0,
+            error: None,
+        }
+    }
+}
+
+impl CreateForm {
+    /// `kina create` arguments for the form, or the reason it can't be submitted.
+    pub fn to_args(&self) -> std::result::Result<Vec<String>, String> {
+        let [name, workers, version] = &self.fields;
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Name is required".to_string());
+        }
+        let workers: u32 = workers
+            .trim()
+            .parse()
+            .map_err(|_| format!("Workers must be a number, got '{}'", workers.trim()))?;
+
+        let mut args = vec![
+            "kina create".to_string(),
+            name.to_string(),
+            "--workers".to_string(),
+            workers.to_string(),
+        ];
+        if !version.trim().is_empty() {
+            args.push("--kubernetes-version".to_string());
+            args.push(version.trim().to_string());
+        }
+        Ok(args)
+    }
+}
+
+/// What the UI is currently showing on top of the cluster list
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mode {
+    Browse,
+    ConfirmDelete(String),
+    Create(CreateForm),
+    Popup {
+        title: String,
+        body: String,
+        scroll: u16,
+    },
+}
+
+/// Work requested by a key press, carried out by the UI loop
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    None,
+    Quit,
+    Delete(String),
+    Status(String),
+    Logs(String),
+    Create(Vec<String>),
+}
+
+/// State of the terminal UI
+#[derive(Debug)]
+pub struct App {
+    pub clusters: Vec<ClusterInfo>,
+    pub selected: usize,
+    /// Cluster whose nodes are shown in the detail pane
+    pub expanded: Option<String>,
+    pub mode: Mode,
+    /// One-line status shown in the footer
+    pub message: Option<String>,
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self {
+            clusters: Vec::new(),
+            selected: 0,
+            expanded: None,
+            mode: Mode::Browse,
+            message: Some(LOADING.to_string()),
+        }
+    }
+}
+
+impl App {
+    /// Name of the highlighted cluster
+    pub fn selected_name(&self) -> Option<&str> {
+        self.clusters.get(self.selected).map(|c| c.name.as_str())
+    }
+
+    /// Replace the cluster list, keeping the same cluster highlighted when it
+    /// still exists.
+    pub fn apply_snapshot(&mut self, snapshot: Snapshot) {
+        match snapshot {
+            Ok(mut clusters) => {
+                clusters.sort_by(|a, b| a.name.cmp(&b.name));
+                let selected = self.selected_name().map(str::to_string);
+                self.clusters = clusters;
+                self.selected = selected
+                    .and_then(|name| self.clusters.iter().position(|c| c.name == name))
+                    .unwrap_or(self.selected)
+                    .min(self.clusters.len().saturating_sub(1));
+                if self
+                    .expanded
+                    .as_ref()
+                    .is_some_and(|name| !self.clusters.iter().any(|c| &c.name == name))
+                {
+                    self.expanded = None;
+                }
+                if self.message.as_deref() == Some(LOADING) {
+                    self.message = None;
+                }
+            }
+            Err(e) => self.message = Some(format!("Refresh failed: {}", e)),
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> Action {
+        match &mut self.mode {
+            Mode::Browse => self.handle_browse_key(key.code),
+            Mode::ConfirmDelete(name) => {
+                let name = name.clone();
+                self.mode = Mode::Browse;
+                match key.code {
+                    KeyCode::Char('y') | KeyCode::Char('Y') => Action::Delete(name),
+                    _ => Action::None,
+                }
+            }
+            Mode::Create(form) => match key.code {
+                KeyCode::Esc => {
+                    self.mode = Mode::Browse;
+                    Action::None
+                }
+                KeyCode::Tab | KeyCode::Down => {
+                    form.focus = (form.focus + 1) % CREATE_FIELDS.len();
+                    Action::None
+                }
+                KeyCode::BackTab | KeyCode::Up => {
+                    form.focus = (form.focus + CREATE_FIELDS.len() - 1) % CREATE_FIELDS.len();
+                    Action::None
+                }
+                KeyCode::Backspace => {
+                    form.fields[form.focus].pop();
+                    Action::None
+                }
+                KeyCode::Char(c) => {
+                    form.fields[form.focus].push(c);
+                    Action::None
+                }
+                KeyCode::Enter => match form.to_args() {
+                    Ok(args) => {
+                        self.mode = Mode::Browse;
+                        Action::Create(args)
+                    }
+                    Err(e) => {
+                        form.error = Some(e);
+                        Action::None
+                    }
+                },
+                _ => Action::None,
+            },
+            Mode::Popup { body, scroll, .. } => {
+                let last_line = body.lines().count().saturating_sub(1) as u16;
+                match key.code {
+                    KeyCode::Esc | KeyCode::Char('q') | KeyCode::Enter => self.mode = Mode::Browse,
+                    KeyCode::Down | KeyCode::Char('j') => *scroll = (*scroll + 1).min(last_line),
+                    KeyCode::Up | KeyCode::Char('k') => *scroll = scroll.saturating_sub(1),
+                    KeyCode::PageDown => *scroll = (*scroll + 20).min(last_line),
+                    KeyCode::PageUp => *scroll = scroll.saturating_sub(20),
+                    _ => {}
+                }
+                Action::None
+            }
+        }
+    }
+
+    fn handle_browse_key(&mut self, code: KeyCode) -> Action {
+        let selected = self.selected_name().map(str::to_string);
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => Action::Quit,
+            KeyCode::Down | KeyCode::Char('j') => {
+                if self.selected + 1 < self.clusters.len() {
+                    self.selected += 1;
+                }
+                Action::None
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = self.selected.saturating_sub(1);
+                Action::None
+            }
+            KeyCode::Enter => {
+                self.expanded = if self.expanded == selected {
+                    None
+                } else {
+                    selected
+                };
+                Action::None
+            }
+            KeyCode::Char('c') => {
+                self.mode = Mode::Create(CreateForm::default());
+                Action::None
+            }
+            KeyCode::Char('d') => {
+                if let Some(name) = selected {
+                    self.mode = Mode::ConfirmDelete(name);
+                }
+                Action::None
+            }
+            KeyCode::Char('s') => selected.map_or(Action::None, Action::Status),
+            KeyCode::Char('l') => selected.map_or(Action::None, Action::Logs),
+            _ => Action::None,
+        }
+    }
+}
+
+/// Popup text for `s`: the cluster's details and one line per node.
+pub fn status_text(cluster: &ClusterInfo) -> String {
+    let mut text = format!(
+        "Status:  {}\nImage:   {}\nCreated: {}\n",
+        cluster.status, cluster.image, cluster.created
+    );
+    if let Some(path) = &cluster.kubeconfig_path {
+        text.push_str(&format!("Kubeconfig: {}\n", path));
+    }
+    text.push_str(&format!("\nNodes ({}):\n", cluster.nodes.len()));
+    for node in &cluster.nodes {
+        text.push_str(&format!(
+            "  {}  {}  {}  {}  {}\n",
+            node.name,
+            node.role,
+            node.status,
+            node.version,
+            node.ip_address.as_deref().unwrap_or("-")
+        ));
+    }
+    text
+}
+
+/// List all clusters, with live node state for `focus` when it is running.
+async fn snapshot(manager: &ClusterManager, focus: Option<&str>) -> Snapshot {
+    let mut clusters = manager.list_clusters().await.map_err(|e| e.to_string())?;
+    if let Some(name) = focus {
+        if let Some(cluster) = clusters
+            .iter_mut()
+            .find(|c| c.name == name && c.status == ClusterStatus::Running)
+        {
+            if let Ok(live) = manager.get_cluster_status(name).await {
+                *cluster = live;
+            }
+        }
+    }
+    Ok(clusters)
+}
+
+/// Send a fresh snapshot every `every` until the UI hangs up.
+async fn refresh_loop(
+    config: Config,
+    every: Duration,
+    focus: watch::Receiver<Option<String>>,
+    tx: mpsc::Sender<Snapshot>,
+) {
+    let manager = match ClusterManager::new(&config) {
+        Ok(manager) => manager,
+        Err(e) => {
+            let _ = tx.send(Err(e.to_string())).await;
+            return;
+        }
+    };
+    let mut interval = tokio::time::interval(every);
+    loop {
+        interval.tick().await;
+        let focus = focus.borrow().clone();
+        if tx
+            .send(snapshot(&manager, focus.as_deref()).await)
+            .await
+            .is_err()
+        {
+            return;
+        }
+    }
+}
+
+/// Leave the TUI, run `work` on the normal screen, then wait for Enter.
+async fn suspended<F: std::future::Future<Output = Result<()>>>(
+    terminal: &mut DefaultTerminal,
+    work: F,
+) -> Result<()> {
+    disable_raw_mode()?;
+    std::io::stdout().execute(LeaveAlternateScreen)?;
+
+    if let Err(e) = work.await {
+        eprintln!("Error: {:#}", e);
+    }
+    println!("\nPress Enter to return to kina tui");
+    let mut line = String::new();
+    std::io::stdin().read_line(&mut line)?;
+
+    std::io::stdout().execute(EnterAlternateScreen)?;
+    enable_raw_mode()?;
+    terminal.clear()?;
+    Ok(())
+}
+
+impl TuiArgs {
+    pub async fn execute(&self, config: &Config) -> Result<()> {
+        if !std::io::stdout().is_terminal() {
+            return Err(anyhow::anyhow!("kina tui needs an interactive terminal"));
+        }
+
+        let manager = ClusterManager::new(config)?;
+        let (tx, rx) = mpsc::channel(4);
+        let (focus_tx, focus_rx) = watch::channel(None);
+        let refresh = tokio::spawn(refresh_loop(
+            config.clone(),
+            Duration::from_secs(self.refresh.max(1)),
+            focus_rx,
+            tx,
+        ));
+
+        let mut terminal = ratatui::init();
+        let result = run(&mut terminal, config, &manager, rx, focus_tx).await;
+        ratatui::restore();
+        refresh.abort();
+        result
+    }
+}
+
+async fn run(
+    terminal: &mut DefaultTerminal,
+    config: &Config,
+    manager: &ClusterManager,
+    mut rx: mpsc::Receiver<Snapshot>,
+    focus: watch::Sender<Option<String>>,
+) -> Result<()> {
+    let mut app = App::default();
+    loop {
+        while let Ok(update) = rx.try_recv() {
+            app.apply_snapshot(update);
+        }
+        terminal.draw(|frame| draw(frame, &app))?;
+
+        if !event::poll(INPUT_POLL)? {
+            continue;
+        }
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        let expanded = app.expanded.clone();
+        let action = app.handle_key(key);
+        if app.expanded != expanded {
+            focus.send_replace(app.expanded.clone());
+            app.apply_snapshot(snapshot(manager, app.expanded.as_deref()).await);
+        }
+
+        match action {
+            Action::None => {}
+            Action::Quit => return Ok(()),
+            Action::Status(name) => match manager.get_cluster_status(&name).await {
+                Ok(cluster) => {
+                    app.mode = Mode::Popup {
+                        title: format!(" {} ", name),
+                        body: status_text(&cluster),
+                        scroll: 0,
+                    }
+                }
+                Err(e) => app.message = Some(format!("Status failed: {}", e)),
+            },
+            Action::Logs(name) => {
+                let node = format!("{}-control-plane", name);
+                match manager.node_logs(&node, LOG_LINES).await {
+                    Ok(logs) => {
+                        app.mode = Mode::Popup {
+                            title: format!(" {} kubelet logs (newest first) ", node),
+                            body: logs,
+                            scroll: 0,
+                        }
+                    }
+                    Err(e) => app.message = Some(format!("Logs failed: {}", e)),
+                }
+            }
+            Action::Delete(name) => {
+                suspended(terminal, async {
+                    manager
+                        .delete_cluster(&name, &DeleteClusterOptions::default())
+                        .await?;
+                    println!("✅ Cluster '{}' deleted successfully", name);
+                    Ok(())
+                })
+                .await?;
+                app.apply_snapshot(snapshot(manager, app.expanded.as_deref()).await);
+            }
+            Action::Create(args) => {
+                suspended(terminal, async {
+                    let command =
+                        CreateCommand::try_parse_from(&args).context("Invalid create options")?;
+                    command.args.execute(config).await
+                })
+                .await?;
+                app.apply_snapshot(snapshot(manager, app.expanded.as_deref()).await);
+            }
+        }
+    }
+}
+
+/// A `width`×`height` rectangle centred in `area`
+fn centered(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+fn status_color(status: &ClusterStatus) -> Color {
+    match status {
+        ClusterStatus::Running => Color::Green,
+        ClusterStatus::Stopped => Color::Yellow,
+        ClusterStatus::Error => Color::Red,
+        _ => Color::Gray,
+    }
+}
+
+fn draw(frame: &mut Frame, app: &App) {
+    let [main, footer] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+    let [list_area, detail_area] =
+        Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)]).areas(main);
+
+    let items: Vec<ListItem> = app
+        .clusters
+        .iter()
+        .map(|c| {
+            let marker = if app.expanded.as_ref() == Some(&c.name) {
+                "▾ "
+            } else {
+                "▸ "
+            };
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{}{}  ", marker, c.name)),
+                Span::styled(
+                    c.status.to_string(),
+                    Style::new().fg(status_color(&c.status)),
+                ),
+                Span::raw(format!("  {} nodes", c.nodes.len())),
+            ]))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(Block::bordered().title(" Clusters "))
+        .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+    let mut state =
+        ListState::default().with_selected((!app.clusters.is_empty()).then_some(app.selected));
+    frame.render_stateful_widget(list, list_area, &mut state);
+
+    match app
+        .expanded
+        .as_ref()
+        .and_then(|name| app.clusters.iter().find(|c| &c.name == name))
+    {
+        Some(cluster) => {
+            let rows = cluster.nodes.iter().map(|n| {
+                Row::new(vec![
+                    Cell::from(n.name.clone()),
+                    Cell::from(n.role.to_string()),
+                    Cell::from(n.status.clone()),
+                    Cell::from(n.version.clone()),
+                    Cell::from(n.ip_address.clone().unwrap_or_else(|| "-".to_string())),
+                ])
+            });
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Fill(3),
+                    Constraint::Length(14),
+                    Constraint::Length(10),
+                    Constraint::Length(10),
+                    Constraint::Length(16),
+                ],
+            )
+            .header(
+                Row::new(vec!["NAME", "ROLE", "STATUS", "VERSION", "IP"])
+                    .style(Style::new().add_modifier(Modifier::BOLD)),
+            )
+            .block(Block::bordered().title(format!(" {} nodes ", cluster.name)));
+            frame.render_widget(table, detail_area);
+        }
+        None => {
+            let hint = if app.clusters.is_empty() {
+                "No clusters. Press c to create one."
+            } else {
+                "Press Enter to show the selected cluster's nodes."
+            };
+            frame.render_widget(
+                Paragraph::new(hint).block(Block::bordered().title(" Nodes ")),
+                detail_area,
+            );
+        }
+    }
+
+    let help = "↑/↓ select · Enter nodes · s status · l logs · c create · d delete · q quit";
+    frame.render_widget(
+        Paragraph::new(app.message.as_deref().unwrap_or(help)),
+        footer,
+    );
+
+    match &app.mode {
+        Mode::Browse => {}
+        Mode::ConfirmDelete(name) => {
+            let area = centered(frame.area(), 50, 5);
+            frame.render_widget(Clear, area);
+            frame.render_widget(
+                Paragraph::new(format!("Delete cluster '{}'? [y/N]", name))
+                    .wrap(Wrap { trim: true })
+                    .block(Block::bordered().title(" Confirm ")),
+                area,
+            );
+        }
+        Mode::Create(form) => {
+            let area = centered(frame.area(), 60, 9);
+            let mut lines: Vec<Line> = CREATE_FIELDS
+                .iter()
+                .zip(&form.fields)
+                .enumerate()
+                .map(|(i, (label, value))| {
+                    let style = if i == form.focus {
+                        Style::new().add_modifier(Modifier::REVERSED)
+                    } else {
+                        Style::new()
+                    };
+                    Line::from(vec![
+                        Span::raw(format!("{:>20}: ", label)),
+                        Span::styled(format!("{:<30}", value), style),
+                    ])
+                })
+                .collect();
+            lines.push(Line::from(""));
+            lines.push(match &form.error {
+                Some(e) => Line::styled(e.clone(), Style::new().fg(Color::Red)),
+                None => Line::from("Tab next field · Enter create · Esc cancel"),
+            });
+            frame.render_widget(Clear, area);
+            frame.render_widget(
+                Paragraph::new(lines).block(Block::bordered().title(" Create cluster ")),
+                area,
+            );
+        }
+        Mode::Popup {
+            title,
+            body,
+            scroll,
+        } => {
+            let area = frame.area();
+            let area = centered(
+                area,
+                area.width.saturating_sub(4),
+                area.height.saturating_sub(2),
+            );
+            frame.render_widget(Clear, area);
+            frame.render_widget(
+                Paragraph::new(body.as_str()).scroll((*scroll, 0)).block(
+                    Block::bordered()
+                        .title(title.as_str())
+                        .title_bottom(" ↑/↓ scroll · Esc close "),
+                ),
+                area,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn cluster(name: &str) -> ClusterInfo {
+        ClusterInfo {
+            name: name.to_string(),
+            image: "kindest/node:v1.36.1".to_string(),
+            status: ClusterStatus::Running,
+            created: "unknown".to_string(),
+            creation_timestamp: None,
+            nodes: Vec::new(),
+            kubeconfig_path: None,
+        }
+    }
+
+    fn app_with(names: &[&str]) -> App {
+        let mut app = App::default();
+        app.apply_snapshot(Ok(names.iter().map(|n| cluster(n)).collect()));
+        app
+    }
+
+    #[test]
+    fn snapshot_keeps_selection_by_name() {
+        let mut app = app_with(&["b", "c"]);
+        app.handle_key(key(KeyCode::Down));
+        assert_eq!(app.selected_name(), Some("c"));
+
+        app.apply_snapshot(Ok(vec![cluster("c"), cluster("a"), cluster("b")]));
+        assert_eq!(app.selected_name(), Some("c"));
+
+        app.apply_snapshot(Ok(vec![cluster("a")]));
+        assert_eq!(app.selected_name(), Some("a"));
+    }
+
+    #[test]
+    fn enter_toggles_expanded_cluster() {
+        let mut app = app_with(&["dev"]);
+        app.handle_key(key(KeyCode::Enter));
+        assert_eq!(app.expanded.as_deref(), Some("dev"));
+        app.handle_key(key(KeyCode::Enter));
+        assert_eq!(app.expanded, None);
+    }
+
+    #[test]
+    fn delete_requires_confirmation() {
+        let mut app = app_with(&["dev"]);
+        assert_eq!(app.handle_key(key(KeyCode::Char('d'))), Action::None);
+        assert_eq!(app.mode, Mode::ConfirmDelete("dev".to_string()));
+        assert_eq!(app.handle_key(key(KeyCode::Char('n'))), Action::None);
+        assert_eq!(app.mode, Mode::Browse);
+
+        app.handle_key(key(KeyCode::Char('d')));
+        assert_eq!(
+            app.handle_key(key(KeyCode::Char('y'))),
+            Action::Delete("dev".to_string())
+        );
+    }
+
+    #[test]
+    fn create_form_builds_create_args() {
+        let mut app = app_with(&[]);
+        app.handle_key(key(KeyCode::Char('c')));
+        for _ in 0..4 {
+            app.handle_key(key(KeyCode::Backspace));
+        }
+        for c in "dev".chars() {
+            app.handle_key(key(KeyCode::Char(c)));
+        }
+        app.handle_key(key(KeyCode::Tab));
+        app.handle_key(key(KeyCode::Backspace));
+        app.handle_key(key(KeyCode::Char('2')));
+
+        assert_eq!(
+            app.handle_key(key(KeyCode::Enter)),
+            Action::Create(vec![
+                "kina create".to_string(),
+                "dev".to_string(),
+                "--workers".to_string(),
+                "2".to_string(),
+            ])
+        );
+        assert_eq!(app.mode, Mode::Browse);
+    }
+
+    #[test]
+    fn create_form_rejects_bad_worker_count() {
+        let form = CreateForm {
+            fields: ["dev".to_string(), "two".to_string(), String::new()],
+            ..CreateForm::default()
+        };
+        assert_eq!(
+            form.to_args(),
+            Err("Workers must be a number, got 'two'".to_string())
+        );
+    }
+
+    #[test]
+    fn create_form_args_parse_as_create_command() {
+        let form = CreateForm {
+            fields: ["dev".to_string(), "1".to_string(), "v1.31".to_string()],
+            ..CreateForm::default()
+        };
+        let command = CreateCommand::try_parse_from(form.to_args().unwrap()).unwrap();
+        assert_eq!(command.args.name, "dev");
+        assert_eq!(command.args.workers, 1);
+        assert_eq!(command.args.kubernetes_version.as_deref(), Some("v1.31"));
+    }
+
+    #[test]
+    fn popup_scroll_is_clamped_to_body() {
+        let mut app = App {
+            mode: Mode::Popup {
+                title: String::new(),
+                body: "a\nb".to_string(),
+                scroll: 0,
+            },
+            ..App::default()
+        };
+        app.handle_key(key(KeyCode::PageDown));
+        assert!(matches!(app.mode, Mode::Popup { scroll: 1, .. }));
+        app.handle_key(key(KeyCode::Esc));
+        assert_eq!(app.mode, Mode::Browse);
+    }
+}
@@ -0,0 +1,67 @@
+//! `kina update-check` and the once-a-day startup update notice.
+
+use anyhow::Result;
+use clap::Args;
+use std::time::Duration;
+use tracing::debug;
+
+use crate::config::Config;
+use crate::core::update_check::{
+    is_newer, latest_release, startup_check_enabled, NO_UPDATE_CHECK_ENV,
+};
+
+/// How long `kina update-check` waits for GitHub.
+const UPDATE_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long the automatic check may delay a command's exit.
+const STARTUP_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Check whether a newer kina release is available
+#[derive(Args)]
+pub struct UpdateCheckArgs {
+    /// Query GitHub even if a result from the last 24 hours is cached
+    #[arg(long)]
+    pub force: bool,
+}
+
+impl UpdateCheckArgs {
+    pub async fn execute(&self, _config: &Config) -> Result<()> {
+        let current = env!("CARGO_PKG_VERSION");
+        let latest = latest_release(self.force, UPDATE_CHECK_TIMEOUT).await?;
+
+        if is_newer(current, &latest.latest_version) {
+            println!(
+                "A new kina release is available: {} (current: {})",
+                latest.latest_version, current
+            );
+            println!("Download: {}", latest.url);
+        } else {
+            println!("✅ kina {} is up to date", current);
+        }
+        Ok(())
+    }
+}
+
+/// Print a one-line notice on stderr when a newer release is available.
+///
+/// Runs only for interactive sessions and unless `KINA_NO_UPDATE_CHECK=1`.
+/// Any failure is logged at debug level and otherwise ignored.
+pub async fn notify_if_update_available() {
+    use std::os::unix::io::AsRawFd;
+
+    let env_value = std::env::var(NO_UPDATE_CHECK_ENV).ok();
+    let interactive = unsafe { libc::isatty(std::io::stderr().as_raw_fd()) == 1 };
+    if !interactive || !startup_check_enabled(env_value.as_deref()) {
+        return;
+    }
+
+    let current = env!("CARGO_PKG_VERSION");
+    match latest_release(false, STARTUP_CHECK_TIMEOUT).await {
+        Ok(latest) if is_newer(current, &latest.latest_version) => eprintln!(
+            "kina {} is available (current: {}): {} — set {}=1 to silence",
+            latest.latest_version, current, latest.url, NO_UPDATE_CHECK_ENV
+        ),
+        Ok(_) => {}
+        Err(e) => debug!("Update check failed: {}", e),
+    }
+}
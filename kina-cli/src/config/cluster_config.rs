@@ -5,8 +5,9 @@ use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
-use crate::core::types::NodeRole;
-use crate::errors::{KinaError, KinaResult};
+use crate::config::DEFAULT_NODE_IMAGE;
+use crate::core::types::{ClusterInfo, NodeRole};
+use crate::errors::{ConfigError, KinaError, KinaResult};
 
 /// KIND-compatible cluster configuration
 /// Based on KIND's cluster configuration schema
@@ -43,6 +44,14 @@ pub struct ClusterConfig {
     /// Runtime configuration specific to kina/Apple Container
     #[serde(rename = "runtimeConfig", skip_serializing_if = "Option::is_none")]
     pub runtime_config: Option<RuntimeConfig>,
+
+    /// OIDC provider for API server authentication (kina extension)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub oidc: Option<OidcConfig>,
+
+    /// Local etcd settings for the control plane (kina extension)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub etcd: Option<EtcdConfig>,
 }
 
 /// Node configuration for cluster nodes
@@ -92,7 +101,7 @@ pub struct NodeConfig {
 }
 
 /// Networking configuration (KIND compatible)
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
 pub struct NetworkingConfig {
     /// Disable default CNI
     #[serde(
@@ -114,6 +123,10 @@ pub struct NetworkingConfig {
     #[serde(rename = "serviceSubnet", skip_serializing_if = "Option::is_none")]
     pub service_subnet: Option<String>,
 
+    /// Cluster DNS domain
+    #[serde(rename = "dnsDomain", skip_serializing_if = "Option::is_none")]
+    pub dns_domain: Option<String>,
+
     /// API server address
     #[serde(rename = "apiServerAddress", skip_serializing_if = "Option::is_none")]
     pub api_server_address: Option<String>,
@@ -135,11 +148,11 @@ pub struct Mount {
     pub container_path: PathBuf,
 
     /// Whether the mount is read-only
-    #[serde(rename = "readOnly", skip_serializing_if = "is_false")]
+    #[serde(rename = "readOnly", skip_serializing_if = "is_false", default)]
     pub read_only: bool,
 
     /// SELinux options
-    #[serde(rename = "selinuxRelabel", skip_serializing_if = "is_false")]
+    #[serde(rename = "selinuxRelabel", skip_serializing_if = "is_false", default)]
     pub selinux_relabel: bool,
 
     /// Propagation mode
@@ -184,6 +197,58 @@ pub struct JSON6902Patch {
     pub patch: String,
 }
 
+/// Path on the control-plane node where the OIDC CA bundle is written.
+/// `/etc/kubernetes/pki` is already mounted into the kube-apiserver static pod.
+pub const OIDC_CA_NODE_PATH: &str = "/etc/kubernetes/pki/oidc-ca.crt";
+
+/// OIDC provider configuration, rendered as `--oidc-*` API server flags
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OidcConfig {
+    /// Issuer URL of the provider (must be HTTPS)
+    #[serde(rename = "issuerUrl")]
+    pub issuer_url: String,
+
+    /// Client ID that all tokens must be issued for
+    #[serde(rename = "clientId")]
+    pub client_id: String,
+
+    /// JWT claim to use as the user name
+    #[serde(rename = "usernameClaim", skip_serializing_if = "Option::is_none")]
+    pub username_claim: Option<String>,
+
+    /// JWT claim to use as the user's groups
+    #[serde(rename = "groupsClaim", skip_serializing_if = "Option::is_none")]
+    pub groups_claim: Option<String>,
+
+    /// Host path of the CA bundle that signed the provider's serving certificate
+    #[serde(rename = "caFile", skip_serializing_if = "Option::is_none")]
+    pub ca_file: Option<PathBuf>,
+}
+
+/// Local etcd configuration, rendered into `ClusterConfiguration.etcd.local`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EtcdConfig {
+    /// Extra etcd flags keyed without leading dashes, e.g. `quota-backend-bytes`
+    #[serde(
+        rename = "extraArgs",
+        skip_serializing_if = "BTreeMap::is_empty",
+        default
+    )]
+    pub extra_args: BTreeMap<String, String>,
+
+    /// etcd data directory on the control-plane node (default `/var/lib/etcd`)
+    #[serde(rename = "dataDir", skip_serializing_if = "Option::is_none")]
+    pub data_dir: Option<String>,
+
+    /// Extra Subject Alternative Names for the etcd peer certificate
+    #[serde(
+        rename = "peerCertSANs",
+        skip_serializing_if = "Vec::is_empty",
+        default
+    )]
+    pub peer_cert_sans: Vec<String>,
+}
+
 /// Runtime configuration specific to kina/Apple Container
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RuntimeConfig {
@@ -237,7 +302,319 @@ impl ClusterConfig {
             kubeadm_config_patches: Vec::new(),
             kubeadm_config_patches_json6902: Vec::new(),
             runtime_config: Some(RuntimeConfig::default()),
+            oidc: None,
+            etcd: None,
+        }
+    }
+
+    /// Reconstruct the configuration of a running cluster from its node list,
+    /// under a (possibly different) cluster name. Every node carries the
+    /// cluster's image; node order follows `info.nodes`.
+    pub fn from_cluster_info(info: &ClusterInfo, name: &str) -> Self {
+        let mut config = Self::default_with_name(name);
+        config.nodes = info
+            .nodes
+            .iter()
+            .map(|node| NodeConfig {
+                role: node.role.clone(),
+                image: Some(info.image.clone()),
+                extra_mounts: Vec::new(),
+                extra_port_mappings: Vec::new(),
+                kubeadm_config_patches: Vec::new(),
+                labels: BTreeMap::new(),
+                cluster_name: String::new(),
+                name: String::new(),
+            })
+            .collect();
+        config.assign_node_names();
+        config
+    }
+
+    /// Read a kind cluster config (`kind: Cluster`, `apiVersion:
+    /// kind.x-k8s.io/v1alpha4`) and convert it to a kina `ClusterConfig`.
+    ///
+    /// Returns the config and a warning for every field kina has no equivalent
+    /// for (e.g. `containerdConfigPatches`); those fields are dropped.
+    pub async fn from_kind_config<P: AsRef<Path>>(path: P) -> KinaResult<(Self, Vec<String>)> {
+        let path = path.as_ref();
+        let content = tokio::fs::read_to_string(path)
+            .await
+            .with_context(|| format!("Failed to read kind config file: {}", path.display()))?;
+        Self::from_kind_config_str(&content)
+            .with_context(|| format!("Invalid kind config file: {}", path.display()))
+            .map_err(Into::into)
+    }
+
+    /// [`ClusterConfig::from_kind_config`] on already-read YAML.
+    pub fn from_kind_config_str(content: &str) -> KinaResult<(Self, Vec<String>)> {
+        let invalid = |reason: String| KinaError::from(ConfigError::InvalidFormat { reason });
+        let mut doc: serde_yaml::Mapping = serde_yaml::from_str(content)
+            .map_err(|e| invalid(format!("kind config is not a YAML mapping: {}", e)))?;
+        let mut warnings = Vec::new();
+
+        let take_str = |doc: &mut serde_yaml::Mapping, key: &str| {
+            doc.remove(key).and_then(|v| v.as_str().map(str::to_string))
+        };
+        let kind = take_str(&mut doc, "kind");
+        if kind.as_deref() != Some("Cluster") {
+            return Err(invalid(format!(
+                "expected 'kind: Cluster', got {}",
+                kind.as_deref().unwrap_or("no kind")
+            )));
+        }
+        let api_version = take_str(&mut doc, "apiVersion").unwrap_or_default();
+        if !KIND_API_VERSIONS.contains(&api_version.as_str()) {
+            return Err(invalid(format!(
+                "unsupported kind apiVersion '{}' (expected one of: {})",
+                api_version,
+                KIND_API_VERSIONS.join(", ")
+            )));
+        }
+        let name = take_str(&mut doc, "name").unwrap_or_else(|| "kina".to_string());
+
+        let mut config = Self::default_with_name(&name);
+        config.api_version = "kind.x-k8s.io/v1alpha4".to_string();
+        // kind has no resource limits; leave them to the flags and kina config
+        config.runtime_config = None;
+
+        if let Some(nodes) = doc.remove("nodes") {
+            let nodes = match nodes {
+                serde_yaml::Value::Sequence(nodes) => nodes,
+                _ => return Err(invalid("'nodes' must be a list".to_string())),
+            };
+            config.nodes = nodes
+                .into_iter()
+                .enumerate()
+                .map(|(index, node)| kind_node(index, node, &mut warnings))
+                .collect::<KinaResult<_>>()?;
+        }
+
+        if let Some(serde_yaml::Value::Mapping(mut networking)) = doc.remove("networking") {
+            if let Some(family) = networking.remove("ipFamily") {
+                if family.as_str() != Some("ipv4") {
+                    warnings.push(format!(
+                        "networking.ipFamily {} is not supported; kina clusters are IPv4-only",
+                        serde_yaml::to_string(&family).unwrap_or_default().trim()
+                    ));
+                }
+            }
+            config.networking = serde_yaml::from_value(serde_yaml::Value::Mapping(networking))
+                .map_err(|e| invalid(format!("invalid networking section: {}", e)))?;
+        }
+
+        if let Some(gates) = doc.remove("featureGates") {
+            config.feature_gates = serde_yaml::from_value(gates)
+                .map_err(|e| invalid(format!("invalid featureGates: {}", e)))?;
+        }
+        if let Some(patches) = doc.remove("kubeadmConfigPatches") {
+            config.kubeadm_config_patches = serde_yaml::from_value(patches)
+                .map_err(|e| invalid(format!("invalid kubeadmConfigPatches: {}", e)))?;
+        }
+        if let Some(patches) = doc.remove("kubeadmConfigPatchesJSON6902") {
+            config.kubeadm_config_patches_json6902 = serde_yaml::from_value(patches)
+                .map_err(|e| invalid(format!("invalid kubeadmConfigPatchesJSON6902: {}", e)))?;
+        }
+
+        for key in doc.keys().filter_map(|k| k.as_str()) {
+            warnings.push(match key {
+                "containerdConfigPatches" | "containerdConfigPatchesJSON6902" => {
+                    format!("{} has no kina equivalent and is ignored", key)
+                }
+                "runtimeConfig" => "runtimeConfig (API server runtime-config) has no kina \
+                     equivalent and is ignored; use --api-server-extra-arg runtime-config=..."
+                    .to_string(),
+                _ => format!("unknown kind config field '{}' is ignored", key),
+            });
         }
+
+        config.assign_node_names();
+        config.validate()?;
+        Ok((config, warnings))
+    }
+
+    /// Render this config as a kind cluster config that `kind create cluster
+    /// --config` accepts.
+    ///
+    /// kina-only settings (Apple Container runtime limits, OIDC, the DNS
+    /// domain) and the API server address/port, which describe the kina VM,
+    /// are left out and listed in a header comment instead.
+    pub fn to_kind_yaml(&self) -> KinaResult<String> {
+        #[derive(Serialize)]
+        struct KindCluster<'a> {
+            kind: &'static str,
+            #[serde(rename = "apiVersion")]
+            api_version: &'static str,
+            name: &'a str,
+            nodes: &'a [NodeConfig],
+            #[serde(skip_serializing_if = "Option::is_none")]
+            networking: Option<NetworkingConfig>,
+            #[serde(rename = "featureGates", skip_serializing_if = "BTreeMap::is_empty")]
+            feature_gates: &'a BTreeMap<String, bool>,
+            #[serde(
+                rename = "kubeadmConfigPatches",
+                skip_serializing_if = "<[_]>::is_empty"
+            )]
+            kubeadm_config_patches: &'a [String],
+            #[serde(
+                rename = "kubeadmConfigPatchesJSON6902",
+                skip_serializing_if = "<[_]>::is_empty"
+            )]
+            kubeadm_config_patches_json6902: &'a [JSON6902Patch],
+        }
+
+        // kind's v1alpha4 networking has no dnsDomain, and strict decoding
+        // rejects unknown fields
+        let networking = NetworkingConfig {
+            api_server_address: None,
+            api_server_port: None,
+            dns_domain: None,
+            ..self.networking.clone()
+        };
+        let kind = KindCluster {
+            kind: "Cluster",
+            api_version: "kind.x-k8s.io/v1alpha4",
+            name: &self.name,
+            nodes: &self.nodes,
+            networking: (networking != NetworkingConfig::default()).then_some(networking),
+            feature_gates: &self.feature_gates,
+            kubeadm_config_patches: &self.kubeadm_config_patches,
+            kubeadm_config_patches_json6902: &self.kubeadm_config_patches_json6902,
+        };
+
+        let mut omitted = vec!["runtimeConfig (Apple Container CPU/memory limits)"];
+        if self.oidc.is_some() {
+            omitted.push("oidc (use a kubeadmConfigPatches apiServer extraArgs patch)");
+        }
+        if self.etcd.is_some() {
+            omitted.push("etcd (use a kubeadmConfigPatches etcd.local patch)");
+        }
+        if self.networking.api_server_address.is_some() || self.networking.api_server_port.is_some()
+        {
+            omitted.push("networking.apiServerAddress/apiServerPort (kina VM addressing)");
+        }
+        if self.networking.dns_domain.is_some() {
+            omitted.push("networking.dnsDomain (use a kubeadmConfigPatches networking patch)");
+        }
+        let mut yaml = format!(
+            "# Exported by kina for kind. kina-specific settings omitted:\n{}",
+            omitted
+                .iter()
+                .map(|field| format!("#   - {}\n", field))
+                .collect::<String>()
+        );
+        yaml.push_str(
+            &serde_yaml::to_string(&kind).context("Failed to serialize kind cluster config")?,
+        );
+        Ok(yaml)
+    }
+
+    /// Render a Terraform starting point for this cluster: a `null_resource`
+    /// whose `local-exec` provisioners run `kina create` and, on destroy,
+    /// `kina delete`, plus a `local_file` data source reading the kubeconfig.
+    /// The create parameters live in `triggers` so changing one recreates the
+    /// cluster and the destroy provisioner (which may only use `self`) can see
+    /// the name. Attribute alignment follows `terraform fmt`.
+    pub fn to_terraform(&self) -> String {
+        let id = self.name.replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+        let (image, control_planes, workers) = self.create_parameters();
+
+        format!(
+            r#"# Exported by kina for Terraform. Needs the hashicorp/null and
+# hashicorp/local providers, and kina on PATH where terraform runs.
+resource "null_resource" "kina_{id}" {{
+  triggers = {{
+    name                = "{name}"
+    image               = "{image}"
+    control_plane_nodes = "{control_planes}"
+    workers             = "{workers}"
+  }}
+
+  provisioner "local-exec" {{
+    command = "kina create ${{self.triggers.name}} --image ${{self.triggers.image}} --control-plane-nodes ${{self.triggers.control_plane_nodes}} --workers ${{self.triggers.workers}}"
+  }}
+
+  provisioner "local-exec" {{
+    when    = destroy
+    command = "kina delete ${{self.triggers.name}}"
+  }}
+}}
+
+data "local_file" "kina_{id}_kubeconfig" {{
+  filename   = pathexpand("~/.kube/{name}")
+  depends_on = [null_resource.kina_{id}]
+}}
+"#,
+            id = id,
+            name = self.name,
+            image = image,
+            control_planes = control_planes,
+            workers = workers,
+        )
+    }
+
+    /// Render GitHub Actions steps that install Apple Container and kina
+    /// `kina_version`, restore the node image from the Actions cache (keyed on
+    /// the image tag), and create this cluster. Paste under a job's `steps:`.
+    pub fn to_github_actions(&self, kina_version: &str) -> String {
+        let (image, control_planes, workers) = self.create_parameters();
+        let cache_key = image.replace(['/', ':', '@'], "-");
+        format!(
+            r#"# Exported by kina for GitHub Actions: paste under a job's `steps:`.
+# Apple Container needs an Apple silicon runner on macOS 26 or later.
+- name: Install Apple Container
+  run: |
+    brew install container
+    container system start
+- uses: jdx/mise-action@v2
+- name: Install kina
+  run: mise use -g github:vinnie357/kina@{kina_version}
+- name: Cache kina node image
+  id: kina-node-image
+  uses: actions/cache@v4
+  with:
+    path: ~/.cache/kina/node-image.tar
+    key: kina-node-image-{cache_key}
+- name: Load cached node image
+  if: steps.kina-node-image.outputs.cache-hit == 'true'
+  run: container image load --input ~/.cache/kina/node-image.tar
+- name: Pull and save node image
+  if: steps.kina-node-image.outputs.cache-hit != 'true'
+  run: |
+    container image pull {image}
+    mkdir -p ~/.cache/kina
+    container image save --output ~/.cache/kina/node-image.tar {image}
+- name: Create kina cluster
+  run: kina create {name} --image {image} --control-plane-nodes {control_planes} --workers {workers}
+"#,
+            kina_version = kina_version,
+            cache_key = cache_key,
+            image = image,
+            name = self.name,
+            control_planes = control_planes,
+            workers = workers,
+        )
+    }
+
+    /// The `kina create` parameters exports reproduce: node image (the first
+    /// node's, or kina's default), control-plane count and worker count.
+    fn create_parameters(&self) -> (String, usize, usize) {
+        let control_planes = self
+            .nodes
+            .iter()
+            .filter(|n| n.role == NodeRole::ControlPlane)
+            .count()
+            .max(1);
+        let workers = self
+            .nodes
+            .iter()
+            .filter(|n| n.role == NodeRole::Worker)
+            .count();
+        let image = self
+            .nodes
+            .iter()
+            .find_map(|n| n.image.clone())
+            .unwrap_or_else(|| DEFAULT_NODE_IMAGE.to_string());
+        (image, control_planes, workers)
     }
 
     /// Load cluster configuration from file
@@ -340,6 +717,14 @@ impl ClusterConfig {
         // Validate networking configuration
         self.networking.validate()?;
 
+        if let Some(oidc) = &self.oidc {
+            oidc.validate()?;
+        }
+
+        if let Some(etcd) = &self.etcd {
+            etcd.validate()?;
+        }
+
         // Validate each node configuration
         for (index, node) in self.nodes.iter().enumerate() {
             node.validate()
@@ -372,12 +757,273 @@ impl ClusterConfig {
     pub fn primary_control_plane(&self) -> Option<&NodeConfig> {
         self.control_plane_nodes().into_iter().next()
     }
+
+    /// Labels every node sets to the same value. `kina create` applies these
+    /// to all nodes; a label only some nodes carry has no kina equivalent.
+    pub fn common_node_labels(&self) -> BTreeMap<String, String> {
+        let Some((first, rest)) = self.nodes.split_first() else {
+            return BTreeMap::new();
+        };
+        first
+            .labels
+            .iter()
+            .filter(|(key, value)| rest.iter().all(|n| n.labels.get(*key) == Some(*value)))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+}
+
+/// kind config API versions `ClusterConfig::from_kind_config` accepts
+pub const KIND_API_VERSIONS: [&str; 2] = ["kind.x-k8s.io/v1alpha4", "kind.x-k8s.io/v1alpha3"];
+
+/// Convert one entry of a kind config's `nodes` list, warning about node
+/// fields kina has no equivalent for.
+fn kind_node(
+    index: usize,
+    node: serde_yaml::Value,
+    warnings: &mut Vec<String>,
+) -> KinaResult<NodeConfig> {
+    let serde_yaml::Value::Mapping(mut node) = node else {
+        return Err(ConfigError::InvalidFormat {
+            reason: format!("nodes[{}] must be a mapping", index),
+        }
+        .into());
+    };
+    if node.remove("kubeadmConfigPatchesJSON6902").is_some() {
+        warnings.push(format!(
+            "nodes[{}].kubeadmConfigPatchesJSON6902 has no kina equivalent and is ignored",
+            index
+        ));
+    }
+    let unknown: Vec<String> = node
+        .keys()
+        .filter_map(|k| k.as_str())
+        .filter(|k| !KIND_NODE_KEYS.contains(k))
+        .map(str::to_string)
+        .collect();
+    for key in unknown {
+        node.remove(key.as_str());
+        warnings.push(format!("unknown field nodes[{}].{} is ignored", index, key));
+    }
+
+    serde_yaml::from_value(serde_yaml::Value::Mapping(node)).map_err(|e| {
+        ConfigError::InvalidFormat {
+            reason: format!("invalid nodes[{}]: {}", index, e),
+        }
+        .into()
+    })
 }
 
+/// Node fields kina maps from a kind config
+const KIND_NODE_KEYS: [&str; 6] = [
+    "role",
+    "image",
+    "extraMounts",
+    "extraPortMappings",
+    "kubeadmConfigPatches",
+    "labels",
+];
+
+/// Keys accepted in a `kina create --networking-config` file
+pub const NETWORKING_PATCH_KEYS: [&str; 5] = [
+    "podSubnet",
+    "serviceSubnet",
+    "dnsDomain",
+    "apiServerAddress",
+    "apiServerPort",
+];
+
 impl NetworkingConfig {
-    #[allow(dead_code)]
+    /// Parse a partial `networking` section for `kina create --networking-config`.
+    ///
+    /// Only [`NETWORKING_PATCH_KEYS`] are accepted; any other key is rejected by
+    /// name rather than silently ignored.
+    pub fn from_patch_yaml(content: &str) -> KinaResult<Self> {
+        let value: serde_yaml::Value =
+            serde_yaml::from_str(content).map_err(|e| ConfigError::InvalidFormat {
+                reason: format!("networking config is not valid YAML: {}", e),
+            })?;
+        let mapping = match value {
+            serde_yaml::Value::Mapping(mapping) => mapping,
+            serde_yaml::Value::Null => serde_yaml::Mapping::new(),
+            _ => {
+                return Err(ConfigError::InvalidFormat {
+                    reason: "networking config must be a YAML mapping".to_string(),
+                }
+                .into())
+            }
+        };
+
+        for key in mapping.keys() {
+            let key = key.as_str().unwrap_or_default();
+            if !NETWORKING_PATCH_KEYS.contains(&key) {
+                return Err(ConfigError::ValidationFailed {
+                    reason: format!(
+                        "unknown networking key '{}' (expected one of: {})",
+                        key,
+                        NETWORKING_PATCH_KEYS.join(", ")
+                    ),
+                }
+                .into());
+            }
+        }
+
+        let networking: Self = serde_yaml::from_value(serde_yaml::Value::Mapping(mapping))
+            .map_err(|e| ConfigError::InvalidFormat {
+                reason: format!("invalid networking config: {}", e),
+            })?;
+        networking.validate()?;
+        Ok(networking)
+    }
+
+    /// The `networking` section of a kubeadm config (the `ClusterConfiguration`
+    /// document): `podSubnet`, `serviceSubnet` and `dnsDomain`.
+    pub fn from_kubeadm_config(kubeadm_yaml: &str) -> Self {
+        let cluster_configuration = serde_yaml::Deserializer::from_str(kubeadm_yaml)
+            .filter_map(|doc| serde_yaml::Value::deserialize(doc).ok())
+            .find(|doc| doc["kind"].as_str() == Some("ClusterConfiguration"));
+        let Some(doc) = cluster_configuration else {
+            return Self::default();
+        };
+        let field = |key: &str| doc["networking"][key].as_str().map(str::to_string);
+        Self {
+            pod_subnet: field("podSubnet"),
+            service_subnet: field("serviceSubnet"),
+            dns_domain: field("dnsDomain"),
+            ..Self::default()
+        }
+    }
+
     fn validate(&self) -> KinaResult<()> {
-        // Add networking validation logic as needed
+        for (key, subnet) in [
+            ("networking.podSubnet", &self.pod_subnet),
+            ("networking.serviceSubnet", &self.service_subnet),
+        ] {
+            if let Some(subnet) = subnet {
+                if !is_cidr(subnet) {
+                    return Err(ConfigError::InvalidValue {
+                        key: format!("{} (must be a CIDR, e.g. 10.244.0.0/16)", key),
+                        value: subnet.clone(),
+                    }
+                    .into());
+                }
+            }
+        }
+
+        if let Some(domain) = &self.dns_domain {
+            let valid = !domain.is_empty()
+                && domain.split('.').all(|label| {
+                    !label.is_empty()
+                        && !label.starts_with('-')
+                        && !label.ends_with('-')
+                        && label
+                            .chars()
+                            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+                });
+            if !valid {
+                return Err(ConfigError::InvalidValue {
+                    key: "networking.dnsDomain".to_string(),
+                    value: domain.clone(),
+                }
+                .into());
+            }
+        }
+
+        if let Some(address) = &self.api_server_address {
+            if address.parse::<std::net::IpAddr>().is_err() {
+                return Err(ConfigError::InvalidValue {
+                    key: "networking.apiServerAddress (must be an IP address)".to_string(),
+                    value: address.clone(),
+                }
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// `<ip>/<prefix>` with a prefix length that fits the address family
+pub(crate) fn is_cidr(value: &str) -> bool {
+    let Some((ip, prefix)) = value.split_once('/') else {
+        return false;
+    };
+    let max_prefix = match ip.parse::<std::net::IpAddr>() {
+        Ok(std::net::IpAddr::V4(_)) => 32,
+        Ok(std::net::IpAddr::V6(_)) => 128,
+        Err(_) => return false,
+    };
+    prefix.parse::<u8>().is_ok_and(|p| p <= max_prefix)
+}
+
+impl OidcConfig {
+    /// Check that the issuer is an HTTPS URL and the client ID is set
+    pub fn validate(&self) -> KinaResult<()> {
+        let is_https = url::Url::parse(&self.issuer_url)
+            .map(|issuer| issuer.scheme() == "https" && issuer.host().is_some())
+            .unwrap_or(false);
+        if !is_https {
+            return Err(ConfigError::InvalidValue {
+                key: "oidc.issuerUrl (must be an https URL)".to_string(),
+                value: self.issuer_url.clone(),
+            }
+            .into());
+        }
+
+        if self.client_id.trim().is_empty() {
+            return Err(ConfigError::MissingRequired {
+                key: "oidc.clientId".to_string(),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// The `--oidc-*` kube-apiserver flags for this provider, keyed without the
+    /// leading dashes as kubeadm `extraArgs` expects. The CA file is referenced
+    /// at [`OIDC_CA_NODE_PATH`], where kina copies it before `kubeadm init`.
+    pub fn api_server_args(&self) -> BTreeMap<String, String> {
+        let mut args = BTreeMap::new();
+        args.insert("oidc-issuer-url".to_string(), self.issuer_url.clone());
+        args.insert("oidc-client-id".to_string(), self.client_id.clone());
+        if let Some(claim) = &self.username_claim {
+            args.insert("oidc-username-claim".to_string(), claim.clone());
+        }
+        if let Some(claim) = &self.groups_claim {
+            args.insert("oidc-groups-claim".to_string(), claim.clone());
+        }
+        if self.ca_file.is_some() {
+            args.insert("oidc-ca-file".to_string(), OIDC_CA_NODE_PATH.to_string());
+        }
+        args
+    }
+}
+
+impl EtcdConfig {
+    /// Check that the data directory is an absolute node path and that the
+    /// extra args are valid flag names
+    pub fn validate(&self) -> KinaResult<()> {
+        if let Some(name) = self
+            .extra_args
+            .keys()
+            .find(|name| crate::utils::validate::extra_arg_key(name).is_err())
+        {
+            return Err(ConfigError::InvalidValue {
+                key: "etcd.extraArgs (names must be lowercase letters, digits or '-')".to_string(),
+                value: name.clone(),
+            }
+            .into());
+        }
+        if let Some(data_dir) = &self.data_dir {
+            if !data_dir.starts_with('/') {
+                return Err(ConfigError::InvalidValue {
+                    key: "etcd.dataDir (must be an absolute path)".to_string(),
+                    value: data_dir.clone(),
+                }
+                .into());
+            }
+        }
         Ok(())
     }
 }
@@ -471,4 +1117,130 @@ mod tests {
         config.nodes.clear();
         assert!(config.validate().is_err());
     }
+
+    #[test]
+    fn test_networking_patch_parses_known_keys() {
+        let networking = NetworkingConfig::from_patch_yaml(
+            "podSubnet: 10.32.0.0/16\nserviceSubnet: 10.112.0.0/12\ndnsDomain: kina.local\n",
+        )
+        .unwrap();
+        assert_eq!(networking.pod_subnet.as_deref(), Some("10.32.0.0/16"));
+        assert_eq!(networking.service_subnet.as_deref(), Some("10.112.0.0/12"));
+        assert_eq!(networking.dns_domain.as_deref(), Some("kina.local"));
+        assert_eq!(networking.api_server_port, None);
+    }
+
+    #[test]
+    fn test_networking_patch_rejects_unknown_and_invalid_values() {
+        let err = NetworkingConfig::from_patch_yaml("podSubnet: 10.32.0.0/16\npodCidr: x\n")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("unknown networking key 'podCidr'"), "{}", err);
+        assert!(err.contains("serviceSubnet"), "{}", err);
+
+        assert!(NetworkingConfig::from_patch_yaml("podSubnet: 10.32.0.0\n").is_err());
+        assert!(NetworkingConfig::from_patch_yaml("serviceSubnet: 10.96.0.0/33\n").is_err());
+        assert!(NetworkingConfig::from_patch_yaml("dnsDomain: Cluster_Local\n").is_err());
+        assert!(NetworkingConfig::from_patch_yaml("apiServerAddress: localhost\n").is_err());
+        assert!(NetworkingConfig::from_patch_yaml("apiServerPort: 70000\n").is_err());
+    }
+
+    fn test_oidc() -> OidcConfig {
+        OidcConfig {
+            issuer_url: "https://dex.example.com".to_string(),
+            client_id: "kina".to_string(),
+            username_claim: Some("email".to_string()),
+            groups_claim: None,
+            ca_file: None,
+        }
+    }
+
+    #[test]
+    fn test_oidc_validation() {
+        let mut config = ClusterConfig::default_with_name("test");
+        config.oidc = Some(test_oidc());
+        assert!(config.validate().is_ok());
+
+        config.oidc.as_mut().unwrap().issuer_url = "http://dex.example.com".to_string();
+        assert!(config.validate().is_err());
+
+        config.oidc.as_mut().unwrap().issuer_url = "dex.example.com".to_string();
+        assert!(config.validate().is_err());
+
+        let mut oidc = test_oidc();
+        oidc.client_id = " ".to_string();
+        assert!(oidc.validate().is_err());
+    }
+
+    #[test]
+    fn test_oidc_api_server_args() {
+        let mut oidc = test_oidc();
+        let args = oidc.api_server_args();
+        assert_eq!(args["oidc-issuer-url"], "https://dex.example.com");
+        assert_eq!(args["oidc-client-id"], "kina");
+        assert_eq!(args["oidc-username-claim"], "email");
+        assert!(!args.contains_key("oidc-groups-claim"));
+        assert!(!args.contains_key("oidc-ca-file"));
+
+        oidc.ca_file = Some(PathBuf::from("/tmp/ca.crt"));
+        assert_eq!(oidc.api_server_args()["oidc-ca-file"], OIDC_CA_NODE_PATH);
+    }
+
+    #[test]
+    fn test_oidc_yaml_round_trip() {
+        let yaml = r#"
+apiVersion: kind.x-k8s.io/v1alpha4
+kind: Cluster
+name: sso
+nodes:
+  - role: control-plane
+networking: {}
+featureGates: {}
+kubeadmConfigPatches: []
+kubeadmConfigPatchesJSON6902: []
+oidc:
+  issuerUrl: https://dex.example.com
+  clientId: kina
+  groupsClaim: groups
+"#;
+        let config: ClusterConfig = serde_yaml::from_str(yaml).unwrap();
+        let oidc = config.oidc.unwrap();
+        assert_eq!(oidc.groups_claim.as_deref(), Some("groups"));
+        assert_eq!(oidc.username_claim, None);
+    }
+
+    #[test]
+    fn test_etcd_config_parse_and_validate() {
+        let yaml = r#"
+apiVersion: kind.x-k8s.io/v1alpha4
+kind: Cluster
+name: etcd
+nodes:
+  - role: control-plane
+networking: {}
+featureGates: {}
+kubeadmConfigPatches: []
+kubeadmConfigPatchesJSON6902: []
+etcd:
+  extraArgs:
+    quota-backend-bytes: "8589934592"
+  dataDir: /var/lib/etcd-data
+  peerCertSANs: [etcd.example.com]
+"#;
+        let mut config: ClusterConfig = serde_yaml::from_str(yaml).unwrap();
+        let etcd = config.etcd.clone().unwrap();
+        assert_eq!(etcd.extra_args["quota-backend-bytes"], "8589934592");
+        assert_eq!(etcd.data_dir.as_deref(), Some("/var/lib/etcd-data"));
+        assert_eq!(etcd.peer_cert_sans, ["etcd.example.com"]);
+        assert!(config.validate().is_ok());
+
+        config.etcd.as_mut().unwrap().data_dir = Some("etcd".to_string());
+        assert!(config.validate().is_err());
+
+        let etcd = config.etcd.as_mut().unwrap();
+        etcd.data_dir = None;
+        etcd.extra_args
+            .insert("bad: key".to_string(), "x".to_string());
+        assert!(config.validate().is_err());
+    }
 }
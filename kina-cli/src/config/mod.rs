@@ -6,6 +6,10 @@ use tracing::{debug, info, warn};
 // Re-export cluster configuration
 pub mod cluster_config;
 
+/// Node image `kina create` and the cluster exports use unless another is
+/// given
+pub const DEFAULT_NODE_IMAGE: &str = "kindest/node:v1.36.1";
+
 /// Pinned kernel distribution configuration.
 ///
 /// Ships with defaults that point to the validated release artifact.
@@ -113,6 +117,34 @@ pub enum CniPlugin {
     Ptp,
     /// Cilium CNI (advanced features, requires compatible kernel)
     Cilium,
+    /// Flannel VXLAN overlay
+    Flannel,
+    /// Calico (BGP/IP-in-IP, NetworkPolicy support)
+    Calico,
+}
+
+impl CniPlugin {
+    /// Lowercase name, as given to `--cni`
+    pub fn name(&self) -> &'static str {
+        match self {
+            CniPlugin::Ptp => "ptp",
+            CniPlugin::Cilium => "cilium",
+            CniPlugin::Flannel => "flannel",
+            CniPlugin::Calico => "calico",
+        }
+    }
+
+    /// The plugin called `name` (see [`CniPlugin::name`])
+    pub fn from_name(name: &str) -> Option<Self> {
+        [
+            CniPlugin::Ptp,
+            CniPlugin::Cilium,
+            CniPlugin::Flannel,
+            CniPlugin::Calico,
+        ]
+        .into_iter()
+        .find(|plugin| plugin.name() == name)
+    }
 }
 
 /// Apple Container specific configuration
@@ -126,6 +158,46 @@ pub struct AppleContainerConfig {
 
     /// Network configuration
     pub network: NetworkConfig,
+
+    /// Retry policy for transient Apple Container failures
+    #[serde(default)]
+    pub retry: RetryConfig,
+}
+
+/// Exponential backoff for Apple Container operations that can fail while a VM
+/// is still settling (container readiness, IP lookup, `kubeadm init`).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct RetryConfig {
+    /// Attempts for container readiness and IP lookups
+    pub max_attempts: u32,
+
+    /// Delay before the first retry, in milliseconds
+    pub initial_delay_ms: u64,
+
+    /// Multiplier applied to the delay after each retry
+    pub backoff_factor: f64,
+
+    /// Attempts for `kubeadm init` (the node is reset between attempts)
+    pub kubeadm_init_attempts: u32,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 10,
+            initial_delay_ms: 1000,
+            backoff_factor: 1.5,
+            kubeadm_init_attempts: 2,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Delay before the first retry
+    pub fn initial_delay(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.initial_delay_ms)
+    }
 }
 
 /// Container runtime configuration
@@ -209,7 +281,7 @@ impl Default for Config {
         Self {
             cluster: ClusterDefaults {
                 default_name: "kina".to_string(),
-                default_image: "kindest/node:v1.36.1".to_string(),
+                default_image: DEFAULT_NODE_IMAGE.to_string(),
                 default_wait_timeout: 300, // 5 minutes
                 data_dir: data_dir.clone(),
                 retain_on_failure: false,
@@ -233,6 +305,7 @@ impl Default for Config {
                     enable_ipv6: false,
                     dns_servers: vec![],
                 },
+                retry: RetryConfig::default(),
             },
             kubernetes: KubernetesConfig {
                 default_version: "v1.36.1".to_string(),
@@ -251,7 +324,36 @@ impl Default for Config {
     }
 }
 
+/// Serialization formats for `kina config export`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ConfigFormat {
+    /// TOML (the on-disk format)
+    Toml,
+    /// YAML
+    Yaml,
+    /// JSON
+    Json,
+}
+
 impl Config {
+    /// Serialize the configuration in the given format
+    pub fn to_string_as(&self, format: ConfigFormat) -> Result<String> {
+        Ok(match format {
+            ConfigFormat::Toml => toml::to_string_pretty(self)?,
+            ConfigFormat::Yaml => serde_yaml::to_string(self)?,
+            ConfigFormat::Json => serde_json::to_string_pretty(self)?,
+        })
+    }
+
+    /// Parse a configuration serialized in the given format
+    pub fn from_str_as(content: &str, format: ConfigFormat) -> Result<Self> {
+        Ok(match format {
+            ConfigFormat::Toml => toml::from_str(content)?,
+            ConfigFormat::Yaml => serde_yaml::from_str(content)?,
+            ConfigFormat::Json => serde_json::from_str(content)?,
+        })
+    }
+
     /// Load configuration from file, falling back to defaults
     pub fn load() -> Result<Self> {
         let config_path = Self::get_config_path();
@@ -288,9 +390,9 @@ impl Config {
         let content = std::fs::read_to_string(path)?;
 
         let mut config: Self = match path.extension().and_then(|ext| ext.to_str()) {
-            Some("toml") => toml::from_str(&content)?,
-            Some("yaml") | Some("yml") => serde_yaml::from_str(&content)?,
-            Some("json") => serde_json::from_str(&content)?,
+            Some("toml") => Self::from_str_as(&content, ConfigFormat::Toml)?,
+            Some("yaml") | Some("yml") => Self::from_str_as(&content, ConfigFormat::Yaml)?,
+            Some("json") => Self::from_str_as(&content, ConfigFormat::Json)?,
             _ => {
                 // Try to detect format by content
                 if let Ok(config) = toml::from_str::<Self>(&content) {
@@ -303,6 +405,10 @@ impl Config {
             }
         };
 
+        crate::utils::validate::kubernetes_version(&config.kubernetes.default_version).map_err(
+            |e| anyhow::anyhow!("{}: kubernetes.default_version: {}", path.display(), e),
+        )?;
+
         config.config_file_path = Some(path.to_path_buf());
         config.ensure_directories()?;
 
@@ -317,7 +423,7 @@ impl Config {
                 std::fs::create_dir_all(parent)?;
             }
 
-            let content = toml::to_string_pretty(self)?;
+            let content = self.to_string_as(ConfigFormat::Toml)?;
             std::fs::write(config_path, content)?;
 
             info!("Configuration saved to: {}", config_path.display());
@@ -328,6 +434,14 @@ impl Config {
         Ok(())
     }
 
+    /// Backup path used by `kina config reset`: the config path with `.bak`
+    /// appended (`config.toml` → `config.toml.bak`)
+    pub fn backup_path(config_path: &Path) -> PathBuf {
+        let mut backup = config_path.as_os_str().to_owned();
+        backup.push(".bak");
+        PathBuf::from(backup)
+    }
+
     /// Get the default configuration file path
     pub fn get_config_path() -> PathBuf {
         // Use XDG Base Directory specification: ~/.config/kina/config.toml
@@ -1,15 +1,25 @@
 use anyhow::{Context, Result};
+use once_cell::sync::OnceCell;
 use sha2::{Digest, Sha256};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::io::Write;
 use tracing::{debug, info, warn};
 
+use super::kubernetes::{label_node_args, parse_live_nodes, ReadinessSnapshot};
+use super::provider::{
+    ClusterClientTrait, ContainerSpec, ContainerSpecBuilder, NetworkSpec, NetworkSpecBuilder,
+};
 use super::types::{
-    ClusterInfo, ClusterStatus, CreateClusterOptions, KubeadmJoinInfo, LoadImageOptions, NodeInfo,
-    NodeRole,
+    AuditLevel, AuditPolicy, ClusterInfo, ClusterStatus, ContainerRuntime, ContainerVersion,
+    CreateClusterOptions, DeleteClusterOptions, KubeadmJoinInfo, LoadImageOptions, NetworkInfo,
+    NodeInfo, NodeRole, API_SERVER_PORT_LABEL, CNI_LABEL, CONTAINER_RUNTIME_LABEL,
+    DEFAULT_API_SERVER_PORT, NO_CNI_LABEL_VALUE,
 };
+use crate::config::cluster_config::{EtcdConfig, NetworkingConfig, OIDC_CA_NODE_PATH};
 use crate::config::{CniPlugin, Config};
+use crate::utils::kubeconfig::resolve_primary_kubeconfig_path;
+use crate::utils::retry::retry_with_backoff;
 
 /// Minimum supported Apple Container version (major, minor, patch).
 /// Raised to 1.0.0: config.toml replaces system property get/set/clear,
@@ -27,6 +37,50 @@ pub const CILIUM_CLI_VERSION: &str = "v0.19.4";
 /// Stays on the validated 1.18 minor; treat 1.19.x as a separate upgrade.
 pub const CILIUM_VERSION: &str = "1.18.10";
 
+/// Pinned Flannel release whose `kube-flannel.yml` is applied by `--cni flannel`.
+pub const FLANNEL_VERSION: &str = "v0.27.4";
+
+/// Pinned Calico release whose `calico.yaml` is applied by `--cni calico`.
+pub const CALICO_VERSION: &str = "v3.30.3";
+
+/// Upstream manifest for the manifest-based CNI plugins (Flannel, Calico).
+///
+/// PTP is a per-node config file and Cilium is installed with cilium-cli, so
+/// both return `None`. Flannel's default network and Calico's kubeadm pod-CIDR
+/// autodetection both line up with kina's `podSubnet` of `10.244.0.0/16`.
+pub fn cni_manifest_url(cni: &CniPlugin) -> Option<String> {
+    match cni {
+        CniPlugin::Ptp | CniPlugin::Cilium => None,
+        CniPlugin::Flannel => Some(format!(
+            "https://github.com/flannel-io/flannel/releases/download/{}/kube-flannel.yml",
+            FLANNEL_VERSION
+        )),
+        CniPlugin::Calico => Some(format!(
+            "https://raw.githubusercontent.com/projectcalico/calico/{}/manifests/calico.yaml",
+            CALICO_VERSION
+        )),
+    }
+}
+
+/// Build the in-node command that removes a CNI plugin's cluster workloads,
+/// used when `kina install cni` replaces it. PTP has no workloads, so `None`.
+pub fn cni_removal_cmd(cni: &CniPlugin) -> Option<&'static str> {
+    match cni {
+        CniPlugin::Ptp => None,
+        CniPlugin::Cilium => Some(
+            "kubectl --kubeconfig=/etc/kubernetes/admin.conf -n kube-system delete \
+             daemonset/cilium daemonset/cilium-envoy deployment/cilium-operator --ignore-not-found",
+        ),
+        CniPlugin::Flannel => Some(
+            "kubectl --kubeconfig=/etc/kubernetes/admin.conf delete namespace kube-flannel --ignore-not-found",
+        ),
+        CniPlugin::Calico => Some(
+            "kubectl --kubeconfig=/etc/kubernetes/admin.conf -n kube-system delete \
+             daemonset/calico-node deployment/calico-kube-controllers --ignore-not-found",
+        ),
+    }
+}
+
 /// Build the shell snippet that downloads and installs the cilium-cli binary
 /// inside a node container. All values are derived from the pinned `cli_version`
 /// const — no runtime version discovery via external HTTP.
@@ -78,7 +132,7 @@ rm -f cilium-linux-arm64.tar.gz cilium-linux-arm64.tar.gz.sha256sum"#,
 ///   see cilium/cilium#31168
 /// - `k8sServiceHost` / `k8sServicePort` remove the kube-proxy bootstrap dependency for
 ///   joining workers
-pub fn build_cilium_install_cmd(version: &str, cp_ip: &str) -> String {
+pub fn build_cilium_install_cmd(version: &str, cp_ip: &str, api_server_port: u16) -> String {
     format!(
         "KUBECONFIG=/etc/kubernetes/admin.conf cilium install --version {version} \
          --set kubeProxyReplacement=false \
@@ -93,10 +147,11 @@ pub fn build_cilium_install_cmd(version: &str, cp_ip: &str) -> String {
          --set nodePort.enabled=true \
          --set hostPort.enabled=true \
          --set k8sServiceHost={cp_ip} \
-         --set k8sServicePort=6443 \
+         --set k8sServicePort={api_server_port} \
          --set operator.replicas=1",
         version = version,
         cp_ip = cp_ip,
+        api_server_port = api_server_port,
     )
 }
 
@@ -123,7 +178,7 @@ pub fn build_cilium_install_cmd(version: &str, cp_ip: &str) -> String {
 ///
 /// Note: l7Proxy is intentionally omitted (default true) rather than set explicitly.
 /// See cilium/cilium#32448 and kubernetes/minikube#18851 for the stock-kernel context.
-pub fn build_cilium_install_cmd_ebpf(version: &str, cp_ip: &str) -> String {
+pub fn build_cilium_install_cmd_ebpf(version: &str, cp_ip: &str, api_server_port: u16) -> String {
     format!(
         "KUBECONFIG=/etc/kubernetes/admin.conf cilium install --version {version} \
          --set kubeProxyReplacement=true \
@@ -132,10 +187,11 @@ pub fn build_cilium_install_cmd_ebpf(version: &str, cp_ip: &str) -> String {
          --set bpf.hostLegacyRouting=false \
          --set hubble.enabled=true \
          --set k8sServiceHost={cp_ip} \
-         --set k8sServicePort=6443 \
+         --set k8sServicePort={api_server_port} \
          --set operator.replicas=1",
         version = version,
         cp_ip = cp_ip,
+        api_server_port = api_server_port,
     )
 }
 
@@ -261,6 +317,33 @@ pub fn resolve_memory(cli: Option<&str>, config: Option<&str>, builtin: &str) ->
     cli.or(config).unwrap_or(builtin).to_string()
 }
 
+/// Convert a `<number>[KMG]i?` size (see `utils::validate::memory_limit`) to the
+/// `<n><m|g>` form `container run --memory` takes. Binary and decimal suffixes are
+/// treated alike; kilobyte values are rounded up to whole megabytes. Values that
+/// don't parse are returned unchanged for `validate_resources` to reject.
+pub fn container_memory_arg(limit: &str) -> String {
+    let lower = limit.to_lowercase();
+    let trimmed = lower.strip_suffix('i').unwrap_or(&lower);
+    let Some(unit) = trimmed.chars().last() else {
+        return limit.to_string();
+    };
+    match (trimmed[..trimmed.len() - 1].parse::<u64>(), unit) {
+        (Ok(n), 'k') => format!("{}m", (n + 1023) / 1024),
+        (Ok(n), 'm' | 'g') => format!("{}{}", n, unit),
+        _ => limit.to_string(),
+    }
+}
+
+/// Parse a cluster config `cpuLimit` (whole CPUs) for `container run --cpus`.
+pub fn parse_cpu_limit(limit: &str) -> Result<u32> {
+    limit.trim().parse::<u32>().map_err(|_| {
+        anyhow::anyhow!(
+            "cpus: cpuLimit \"{}\" must be a whole number of CPUs",
+            limit
+        )
+    })
+}
+
 /// Resolve the effective kernel path: CLI flag takes precedence over the config default.
 ///
 /// Mirrors the `select_cni` precedence model — CLI flag always wins over config default.
@@ -297,12 +380,617 @@ pub fn build_kubeadm_init_args(full_ebpf: bool) -> Vec<String> {
     }
 }
 
+/// Preflight flags shared by `kubeadm init` and `kubeadm join`:
+/// `--ignore-preflight-errors=all` under `--skip-kubeadm-preflight`, otherwise
+/// `--ignore-preflight-errors=Swap` under `--disable-swap-check`, for VMs that
+/// report swap to kubeadm even with the preflight phase skipped.
+pub fn kubeadm_preflight_args(kubeadm: &KubeadmConfigOptions) -> Vec<String> {
+    if kubeadm.skip_preflight {
+        vec!["--ignore-preflight-errors=all".to_string()]
+    } else if kubeadm.disable_swap_check {
+        vec!["--ignore-preflight-errors=Swap".to_string()]
+    } else {
+        Vec::new()
+    }
+}
+
 /// Resolve the effective CNI plugin: CLI flag takes precedence over the
 /// config file default when present.
 pub fn select_cni(cli_flag: Option<CniPlugin>, config_default: CniPlugin) -> CniPlugin {
     cli_flag.unwrap_or(config_default)
 }
 
+/// [`CNI_LABEL`] value for a cluster created with `cni`, or without one
+pub fn cni_label(cni: &CniPlugin, skip_cni: bool) -> &'static str {
+    if skip_cni {
+        NO_CNI_LABEL_VALUE
+    } else {
+        cni.name()
+    }
+}
+
+/// Default timeout (seconds) applied by `--wait-for-ready` when `--wait` is not set.
+pub const DEFAULT_READY_TIMEOUT_SECS: u64 = 300;
+
+/// Resolve the effective `--wait` timeout. An explicit `--wait` always wins;
+/// `--wait-for-ready` on its own implies `DEFAULT_READY_TIMEOUT_SECS`.
+pub fn resolve_wait_timeout(wait: Option<u64>, wait_for_ready: bool) -> Option<u64> {
+    match wait {
+        Some(secs) => Some(secs),
+        None if wait_for_ready => Some(DEFAULT_READY_TIMEOUT_SECS),
+        None => None,
+    }
+}
+
+/// Parse `kubectl get pods --no-headers -o custom-columns=NAME:.metadata.name,PHASE:.status.phase`
+/// output into `(pod, phase)` pairs. Blank and malformed lines are skipped.
+pub fn parse_pod_phases(no_headers_stdout: &str) -> Vec<(String, String)> {
+    no_headers_stdout
+        .lines()
+        .filter_map(|line| {
+            let mut it = line.split_whitespace();
+            match (it.next(), it.next()) {
+                (Some(name), Some(phase)) => Some((name.to_string(), phase.to_string())),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// True when at least one pod is listed and every pod is `Running` or `Succeeded`.
+///
+/// An empty list is treated as not settled: right after `kubeadm init` the
+/// kube-system namespace can briefly report no pods at all.
+pub fn pods_settled(phases: &[(String, String)]) -> bool {
+    !phases.is_empty()
+        && phases
+            .iter()
+            .all(|(_, phase)| phase == "Running" || phase == "Succeeded")
+}
+
+/// Path inside a node container where the `kubeadm init` output is persisted so
+/// `--retain` diagnostics can collect it after a failed init.
+pub const KUBEADM_LOG_PATH: &str = "/var/log/kubeadm.log";
+
+/// Directory of the `--retain` failure diagnostics inside the data directory
+pub const DIAGNOSTICS_DIR: &str = "diagnostics";
+
+/// Directory that `--retain` failure diagnostics are written to:
+/// `<data_dir>/diagnostics/<cluster>/<timestamp>`. Keeping them under
+/// [`DIAGNOSTICS_DIR`] stops a cluster named after another data directory
+/// entry (e.g. `events`) from writing into it.
+pub fn failure_diagnostics_dir(
+    data_dir: &std::path::Path,
+    cluster_name: &str,
+    timestamp: &str,
+) -> std::path::PathBuf {
+    data_dir
+        .join(DIAGNOSTICS_DIR)
+        .join(cluster_name)
+        .join(timestamp)
+}
+
+/// Host directory that a cluster's API server audit log is written to:
+/// `<data_dir>/<cluster>/audit`, mounted at [`AUDIT_LOG_NODE_DIR`].
+pub fn audit_log_dir(data_dir: &std::path::Path, cluster_name: &str) -> std::path::PathBuf {
+    data_dir.join(cluster_name).join("audit")
+}
+
+/// Node directory holding the audit log (host-mounted, see [`audit_log_dir`]).
+pub const AUDIT_LOG_NODE_DIR: &str = "/var/log/kubernetes/audit";
+
+/// Node path of the audit policy passed to `--audit-policy-file`.
+pub const AUDIT_POLICY_NODE_PATH: &str = "/etc/kubernetes/audit/policy.yaml";
+
+/// Node path of the `--scheduler-config` file passed to kube-scheduler `--config`.
+pub const SCHEDULER_CONFIG_NODE_PATH: &str = "/etc/kubernetes/scheduler/config.yaml";
+
+/// kubeadm's kubeconfig for kube-scheduler. With `--config` set,
+/// kube-scheduler ignores its `--kubeconfig` flag and only reads
+/// `clientConnection.kubeconfig` from the file.
+pub const SCHEDULER_KUBECONFIG_NODE_PATH: &str = "/etc/kubernetes/scheduler.conf";
+
+/// Check that `contents` is a `KubeSchedulerConfiguration` document and return
+/// it ready for the node: when it sets no `clientConnection.kubeconfig`, that
+/// is pointed at [`SCHEDULER_KUBECONFIG_NODE_PATH`] so the scheduler can still
+/// reach the API server.
+pub fn prepare_scheduler_config(contents: &[u8]) -> Result<Vec<u8>> {
+    let mut config: serde_yaml::Value =
+        serde_yaml::from_slice(contents).context("scheduler config is not valid YAML")?;
+    match config.get("kind").and_then(|kind| kind.as_str()) {
+        Some("KubeSchedulerConfiguration") => {}
+        kind => {
+            return Err(anyhow::anyhow!(
+                "scheduler config has kind {:?}; expected KubeSchedulerConfiguration",
+                kind.unwrap_or("<missing>")
+            ))
+        }
+    }
+    if config["clientConnection"]["kubeconfig"].as_str().is_some() {
+        return Ok(contents.to_vec());
+    }
+
+    let connection = config
+        .as_mapping_mut()
+        .context("scheduler config is not a mapping")?
+        .entry("clientConnection".into())
+        .or_insert_with(|| serde_yaml::Mapping::new().into());
+    connection
+        .as_mapping_mut()
+        .context("scheduler config clientConnection is not a mapping")?
+        .insert("kubeconfig".into(), SCHEDULER_KUBECONFIG_NODE_PATH.into());
+    Ok(serde_yaml::to_string(&config)
+        .context("Failed to serialize scheduler config")?
+        .into_bytes())
+}
+
+/// kina's `KubeletConfiguration` for every node, uploaded by `kubeadm init` to
+/// the cluster-wide kubelet config that joining nodes fetch.
+pub const DEFAULT_KUBELET_CONFIGURATION: &str = "\
+apiVersion: kubelet.config.k8s.io/v1beta1
+kind: KubeletConfiguration
+cgroupDriver: systemd
+failSwapOn: false
+authentication:
+  anonymous:
+    enabled: false
+  webhook:
+    enabled: true
+authorization:
+  mode: Webhook
+serverTLSBootstrap: true
+";
+
+/// `KubeletConfiguration` fields kina depends on (the systemd cgroup driver,
+/// running with swap, serving certificates via CSRs), which
+/// `--kubelet-config` cannot override.
+pub const KUBELET_CONFIG_LOCKED_FIELDS: [&str; 5] = [
+    "apiVersion",
+    "kind",
+    "cgroupDriver",
+    "failSwapOn",
+    "serverTLSBootstrap",
+];
+
+/// Merge `value` into `base`: mappings present in both merge recursively,
+/// anything else from `value` replaces `base`'s.
+fn merge_yaml_mappings(base: &mut serde_yaml::Mapping, value: serde_yaml::Mapping) {
+    for (key, value) in value {
+        if let serde_yaml::Value::Mapping(value) = value {
+            if let Some(serde_yaml::Value::Mapping(existing)) = base.get_mut(&key) {
+                merge_yaml_mappings(existing, value);
+                continue;
+            }
+            base.insert(key, serde_yaml::Value::Mapping(value));
+        } else {
+            base.insert(key, value);
+        }
+    }
+}
+
+/// [`DEFAULT_KUBELET_CONFIGURATION`] with a `--kubelet-config` file merged
+/// over it. Returns the merged document and the
+/// [`KUBELET_CONFIG_LOCKED_FIELDS`] the file tried to change, which keep
+/// kina's values.
+pub fn merge_kubelet_config(contents: &[u8]) -> Result<(String, Vec<String>)> {
+    let user: serde_yaml::Mapping =
+        serde_yaml::from_slice(contents).context("kubelet config is not a YAML mapping")?;
+    match user.get("kind").and_then(|kind| kind.as_str()) {
+        Some("KubeletConfiguration") => {}
+        kind => {
+            return Err(anyhow::anyhow!(
+                "kubelet config has kind {:?}; expected KubeletConfiguration",
+                kind.unwrap_or("<missing>")
+            ))
+        }
+    }
+
+    let mut merged: serde_yaml::Mapping = serde_yaml::from_str(DEFAULT_KUBELET_CONFIGURATION)
+        .expect("default kubelet configuration is valid YAML");
+    let mut ignored = Vec::new();
+    let mut overrides = serde_yaml::Mapping::new();
+    for (key, value) in user {
+        let locked = key
+            .as_str()
+            .filter(|k| KUBELET_CONFIG_LOCKED_FIELDS.contains(k));
+        match locked {
+            Some(field) => {
+                if merged.get(field) != Some(&value) {
+                    ignored.push(field.to_string());
+                }
+            }
+            None => {
+                overrides.insert(key, value);
+            }
+        }
+    }
+    merge_yaml_mappings(&mut merged, overrides);
+    let yaml = serde_yaml::to_string(&merged).context("Failed to render kubelet config")?;
+    Ok((yaml, ignored))
+}
+
+/// Audit policy used by `--audit-log` when no policy is given.
+pub const DEFAULT_AUDIT_POLICY: &str = include_str!("../../manifests/audit/metadata-policy.yaml");
+
+/// Bundled audit policy for `--audit-policy <level>`, from `manifests/audit/`.
+pub fn audit_policy_yaml(level: AuditLevel) -> &'static str {
+    match level {
+        AuditLevel::None => include_str!("../../manifests/audit/none-policy.yaml"),
+        AuditLevel::Metadata => DEFAULT_AUDIT_POLICY,
+        AuditLevel::Request => include_str!("../../manifests/audit/request-policy.yaml"),
+        AuditLevel::RequestResponse => {
+            include_str!("../../manifests/audit/requestresponse-policy.yaml")
+        }
+    }
+}
+
+/// Pod Security Standards levels accepted by `kina create --psp`.
+pub const POD_SECURITY_LEVELS: [&str; 3] = ["restricted", "baseline", "privileged"];
+
+/// kube-apiserver admission plugins with `--psp`: kubeadm's default plus
+/// PodSecurity.
+const POD_SECURITY_ADMISSION_PLUGINS: &str = "NodeRestriction,PodSecurity";
+
+/// Script run on the control plane after `kubeadm init` for `--psp <level>`:
+/// enforce `level` on the `default` namespace and keep `kube-system`
+/// privileged so system pods (CNI, kube-proxy) are never rejected.
+pub fn pod_security_label_script(level: &str) -> String {
+    let label = |namespace: &str, level: &str| {
+        format!(
+            "kubectl --kubeconfig=/etc/kubernetes/admin.conf label --overwrite namespace {} \
+             pod-security.kubernetes.io/enforce={} pod-security.kubernetes.io/enforce-version=latest",
+            namespace, level
+        )
+    };
+    format!(
+        "{} && {}",
+        label("kube-system", "privileged"),
+        label("default", level)
+    )
+}
+
+/// Node path of the registry credentials from `--docker-config-json`. The
+/// kubelet's credential keyring reads this file for every image pull, so no
+/// credential provider plugin is needed in the node image.
+pub const KUBELET_DOCKER_CONFIG_PATH: &str = "/var/lib/kubelet/config.json";
+
+/// containerd's registry host directory. kindest/node sets the CRI
+/// `registry.config_path` to it, which makes containerd reject the older
+/// `registry.mirrors`/`registry.configs` tables, so `--insecure-registry`
+/// writes a `hosts.toml` per registry here instead. containerd reads these on
+/// every pull, so no restart is needed.
+pub const CONTAINERD_CERTS_D_DIR: &str = "/etc/containerd/certs.d";
+
+/// Node path of the `hosts.toml` for `registry` (`host[:port]`).
+pub fn registry_hosts_toml_path(registry: &str) -> String {
+    format!("{}/{}/hosts.toml", CONTAINERD_CERTS_D_DIR, registry)
+}
+
+/// `container stop` attempts (signal, `--time` seconds) for deleting a node:
+/// SIGTERM with `grace_period` seconds to exit, then SIGKILL. A zero grace
+/// period goes straight to SIGKILL.
+pub fn container_stop_attempts(grace_period: u64) -> Vec<(Option<&'static str>, u64)> {
+    if grace_period == 0 {
+        vec![(Some("SIGKILL"), 0)]
+    } else {
+        vec![(None, grace_period), (Some("SIGKILL"), 10)]
+    }
+}
+
+/// Stop containerd and start CRI-O in its place (`--cri-o`), before kubeadm
+/// starts the kubelet against the CRI-O socket.
+pub const CRIO_ENABLE_SCRIPT: &str =
+    "systemctl disable --now containerd && systemctl enable --now crio";
+
+/// containerd `hosts.toml` for an insecure `registry`: HTTPS without TLS
+/// verification (`skip_verify`), falling back to plain HTTP.
+pub fn insecure_registry_hosts_toml(registry: &str) -> String {
+    format!(
+        "server = \"http://{0}\"\n\
+         \n[host.\"https://{0}\"]\n  \
+         capabilities = [\"pull\", \"resolve\", \"push\"]\n  \
+         skip_verify = true\n\
+         \n[host.\"http://{0}\"]\n  \
+         capabilities = [\"pull\", \"resolve\", \"push\"]\n",
+        registry
+    )
+}
+
+/// `container exec` arguments running `command` in `node` with stdin kept
+/// open, plus a terminal when `tty` is set (`kina ssh`).
+pub fn node_exec_args(node: &str, command: &[String], tty: bool) -> Vec<String> {
+    let mut args = vec!["exec".to_string(), "-i".to_string()];
+    if tty {
+        args.push("-t".to_string());
+    }
+    args.push(node.to_string());
+    args.extend(command.iter().cloned());
+    args
+}
+
+/// `container create` arguments for the throwaway container
+/// [`AppleContainerClient::verify_image`] creates, passing the pull platform
+/// through so multi-arch images are checked for the variant that was pulled.
+pub fn verify_image_create_args(probe: &str, image: &str, platform: Option<&str>) -> Vec<String> {
+    let mut args = vec![
+        "create".to_string(),
+        "--name".to_string(),
+        probe.to_string(),
+    ];
+    if let Some(platform) = platform {
+        args.push("--platform".to_string());
+        args.push(platform.to_string());
+    }
+    args.push(image.to_string());
+    args
+}
+
+/// Node path of containerd's main config, which `--containerd-config` merges into
+pub const CONTAINERD_CONFIG_PATH: &str = "/etc/containerd/config.toml";
+
+/// Parse a `--containerd-config` snippet, rejecting invalid TOML.
+pub fn validate_containerd_config(contents: &str) -> Result<toml::Table> {
+    contents
+        .parse::<toml::Table>()
+        .context("containerd config is not valid TOML")
+}
+
+/// Merge `overlay` into `base`: tables present in both merge recursively,
+/// any other value from `overlay` replaces the one in `base`.
+fn merge_toml_tables(base: &mut toml::Table, overlay: toml::Table) {
+    for (key, value) in overlay {
+        if let toml::Value::Table(overlay) = value {
+            if let Some(toml::Value::Table(existing)) = base.get_mut(&key) {
+                merge_toml_tables(existing, overlay);
+                continue;
+            }
+            base.insert(key, toml::Value::Table(overlay));
+        } else {
+            base.insert(key, value);
+        }
+    }
+}
+
+/// A node's containerd `config.toml` with a `--containerd-config` snippet
+/// merged over it. Comments in the node's config are not preserved.
+pub fn merge_containerd_config(existing: &str, snippet: &str) -> Result<String> {
+    let mut config = existing
+        .parse::<toml::Table>()
+        .context("node containerd config is not valid TOML")?;
+    merge_toml_tables(&mut config, validate_containerd_config(snippet)?);
+    toml::to_string(&config).context("Failed to serialize merged containerd config")
+}
+
+/// Namespaces that get the `--image-pull-secret` Secret.
+pub const IMAGE_PULL_SECRET_NAMESPACES: [&str; 2] = ["default", "kube-system"];
+
+/// Check that `contents` is a docker `config.json` with an `auths` object.
+pub fn validate_docker_config_json(contents: &[u8]) -> Result<()> {
+    let config: serde_json::Value =
+        serde_json::from_slice(contents).context("docker config is not valid JSON")?;
+    if !config.get("auths").is_some_and(|auths| auths.is_object()) {
+        return Err(anyhow::anyhow!(
+            "docker config has no \"auths\" object (expected the format of ~/.docker/config.json)"
+        ));
+    }
+    Ok(())
+}
+
+/// Script run on the control plane for `--image-pull-secret <name>`: create or
+/// update a `kubernetes.io/dockerconfigjson` Secret from the node's copy of the
+/// docker config in every [`IMAGE_PULL_SECRET_NAMESPACES`] namespace.
+pub fn image_pull_secret_script(secret_name: &str) -> String {
+    IMAGE_PULL_SECRET_NAMESPACES
+        .iter()
+        .map(|namespace| {
+            format!(
+                "kubectl --kubeconfig=/etc/kubernetes/admin.conf create secret generic '{name}' \
+                 --namespace={namespace} --type=kubernetes.io/dockerconfigjson \
+                 --from-file=.dockerconfigjson={path} --dry-run=client -o yaml | \
+                 kubectl --kubeconfig=/etc/kubernetes/admin.conf apply -f -",
+                name = secret_name,
+                namespace = namespace,
+                path = KUBELET_DOCKER_CONFIG_PATH,
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" && ")
+}
+
+/// Build `--volume <host>:<node>` arguments for `container run`.
+pub fn node_volume_args(volumes: &[(std::path::PathBuf, String)]) -> Vec<String> {
+    volumes
+        .iter()
+        .flat_map(|(host, node)| {
+            [
+                "--volume".to_string(),
+                format!("{}:{}", host.display(), node),
+            ]
+        })
+        .collect()
+}
+
+/// Container name a node gets when its cluster is renamed: the `<old>` prefix is
+/// swapped for `<new>` and the role suffix (`-control-plane`, `-worker-2`, ...)
+/// is kept. Names that don't carry the old prefix are returned unchanged.
+pub fn renamed_node_name(node_name: &str, old_cluster: &str, new_cluster: &str) -> String {
+    match node_name.strip_prefix(old_cluster) {
+        Some(suffix) if suffix.is_empty() || suffix.starts_with('-') => {
+            format!("{}{}", new_cluster, suffix)
+        }
+        _ => node_name.to_string(),
+    }
+}
+
+/// Whether `container --help` output lists `subcommand` in its subcommand table,
+/// where each entry is the command name followed by its description.
+pub fn help_lists_subcommand(help: &str, subcommand: &str) -> bool {
+    help.lines()
+        .any(|line| line.split_whitespace().next() == Some(subcommand))
+}
+
+/// Steps of a cluster rename that have been applied, in order.
+#[derive(Debug, Default)]
+struct RenameProgress {
+    /// Original names of the nodes stopped for the rename
+    stopped: Vec<String>,
+    /// `(original, new)` names of the renamed nodes
+    renamed: Vec<(String, String)>,
+    /// New names of the nodes whose `io.kina.cluster` label was updated
+    relabeled: Vec<String>,
+}
+
+/// Save `kubeconfig` as `~/.kube/<name>` and merge it into the primary kubeconfig
+/// with kubectl; with `set_context`, also switch the current context to `name`.
+pub async fn merge_kubeconfig(name: &str, kubeconfig: &str, set_context: bool) -> Result<()> {
+    let home_dir = std::env::var("HOME").context("HOME environment variable not set")?;
+    let kube_dir = std::path::Path::new(&home_dir).join(".kube");
+    // Merge target follows kubectl: first KUBECONFIG entry, else ~/.kube/config
+    let global_config_path = resolve_primary_kubeconfig_path();
+    let individual_path = kube_dir.join(name);
+
+    // Ensure the .kube directory (and the merge target's directory) exist
+    fs::create_dir_all(&kube_dir).context("Failed to create .kube directory")?;
+    if let Some(parent) = global_config_path.parent() {
+        fs::create_dir_all(parent).context("Failed to create kubeconfig directory")?;
+    }
+
+    // Save individual file for backward compatibility and direct access
+    fs::write(&individual_path, kubeconfig)
+        .context("Failed to write individual kubeconfig file")?;
+
+    // Use kubectl config merge approach by setting KUBECONFIG environment variable
+    // This leverages kubectl's built-in merging logic which is much more reliable
+    let existing_kubeconfig = if global_config_path.exists() {
+        format!(
+            "{}:{}",
+            global_config_path.display(),
+            individual_path.display()
+        )
+    } else {
+        individual_path.display().to_string()
+    };
+
+    // Use kubectl config view --flatten to merge configs
+    let mut cmd = tokio::process::Command::new("kubectl");
+    cmd.env("KUBECONFIG", &existing_kubeconfig)
+        .arg("config")
+        .arg("view")
+        .arg("--flatten");
+
+    let output = cmd
+        .output()
+        .await
+        .context("Failed to run kubectl config view")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow::anyhow!("kubectl config view failed: {}", stderr));
+    }
+
+    // Write the merged config back to the global config file
+    let merged_config = String::from_utf8_lossy(&output.stdout);
+    fs::write(&global_config_path, merged_config.as_bytes())
+        .context("Failed to write merged kubeconfig")?;
+
+    if !set_context {
+        info!(
+            "Saved kubeconfig to: {} and merged into: {} using kubectl",
+            individual_path.display(),
+            global_config_path.display()
+        );
+        return Ok(());
+    }
+
+    // Set the current context to the new cluster
+    let context_name = name; // Use cluster name as context name
+    let mut use_context_cmd = tokio::process::Command::new("kubectl");
+    use_context_cmd
+        .arg("--kubeconfig")
+        .arg(&global_config_path)
+        .arg("config")
+        .arg("use-context")
+        .arg(context_name);
+
+    let use_output = use_context_cmd
+        .output()
+        .await
+        .context("Failed to set current context")?;
+    if !use_output.status.success() {
+        let stderr = String::from_utf8_lossy(&use_output.stderr);
+        warn!(
+            "Failed to set current context to '{}': {}",
+            context_name, stderr
+        );
+    }
+
+    info!(
+        "Saved kubeconfig to: {} and merged into: {} using kubectl",
+        individual_path.display(),
+        global_config_path.display()
+    );
+    Ok(())
+}
+
+/// Rewrite the cluster, context and `<cluster>-admin` user entries of a kina
+/// kubeconfig from `old_cluster` to `new_cluster`, including the references
+/// inside each context and `current-context`.
+pub fn rename_kubeconfig_entries(
+    kubeconfig: &str,
+    old_cluster: &str,
+    new_cluster: &str,
+) -> Result<String> {
+    use serde_yaml::Value;
+
+    let mut doc: Value =
+        serde_yaml::from_str(kubeconfig).context("Failed to parse kubeconfig YAML")?;
+    let old_user = format!("{}-admin", old_cluster);
+    let new_user = format!("{}-admin", new_cluster);
+
+    let rename = |value: &mut Value, from: &str, to: &str| {
+        if value.as_str() == Some(from) {
+            *value = Value::String(to.to_string());
+        }
+    };
+
+    if let Some(clusters) = doc.get_mut("clusters").and_then(Value::as_sequence_mut) {
+        for entry in clusters {
+            if let Some(name) = entry.get_mut("name") {
+                rename(name, old_cluster, new_cluster);
+            }
+        }
+    }
+
+    if let Some(users) = doc.get_mut("users").and_then(Value::as_sequence_mut) {
+        for entry in users {
+            if let Some(name) = entry.get_mut("name") {
+                rename(name, &old_user, &new_user);
+            }
+        }
+    }
+
+    if let Some(contexts) = doc.get_mut("contexts").and_then(Value::as_sequence_mut) {
+        for entry in contexts {
+            if let Some(name) = entry.get_mut("name") {
+                rename(name, old_cluster, new_cluster);
+            }
+            if let Some(context) = entry.get_mut("context") {
+                if let Some(cluster) = context.get_mut("cluster") {
+                    rename(cluster, old_cluster, new_cluster);
+                }
+                if let Some(user) = context.get_mut("user") {
+                    rename(user, &old_user, &new_user);
+                }
+            }
+        }
+    }
+
+    if let Some(current) = doc.get_mut("current-context") {
+        rename(current, old_cluster, new_cluster);
+    }
+
+    serde_yaml::to_string(&doc).context("Failed to serialize kubeconfig YAML")
+}
+
 /// Strategy for resolving the Apple Container CLI binary path.
 ///
 /// `Which(name)` asks the shell PATH resolver (`which <name>`) — preferred
@@ -376,6 +1064,53 @@ pub fn parse_version_output(raw: &str) -> Result<String> {
     ))
 }
 
+/// Parse `container version --output json` output into its version and build
+/// type. The build is the `buildType` (or `build`) field, or `unknown` when the
+/// output has neither.
+pub fn parse_version_json(raw: &str) -> Result<ContainerVersion> {
+    let value: serde_json::Value =
+        serde_json::from_str(raw.trim()).context("Apple Container version output is not JSON")?;
+    let version = value
+        .get("version")
+        .and_then(|v| v.as_str())
+        .map(|v| v.trim().trim_start_matches('v'))
+        .filter(|v| v.chars().next().is_some_and(|c| c.is_ascii_digit()))
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Could not parse Apple Container CLI version from output: {}",
+                raw.trim()
+            )
+        })?;
+    let build = ["buildType", "build"]
+        .iter()
+        .find_map(|key| value.get(*key).and_then(|b| b.as_str()))
+        .filter(|build| !build.is_empty())
+        .unwrap_or("unknown");
+    debug!("Detected Apple Container CLI version: {}", version);
+    Ok(ContainerVersion {
+        version: version.to_string(),
+        build: build.to_string(),
+    })
+}
+
+/// Parse `container --version` output into its version and build type.
+///
+/// The version comes from [`parse_version_output`]; the build is the value of
+/// the `build:` field (`container CLI version 1.0.0 (build: release, ...)`),
+/// or `unknown` when the output has none.
+pub fn parse_container_version(raw: &str) -> Result<ContainerVersion> {
+    let version = parse_version_output(raw)?;
+    let build = raw
+        .find("build: ")
+        .map(|pos| &raw[pos + "build: ".len()..])
+        .and_then(|rest| rest.split([',', ')']).next())
+        .map(str::trim)
+        .filter(|build| !build.is_empty())
+        .unwrap_or("unknown")
+        .to_string();
+    Ok(ContainerVersion { version, build })
+}
+
 /// Validate that `version` meets MIN_VERSION (1.0.0).
 ///
 /// Returns `Err` with migration guidance if the version is too old;
@@ -432,6 +1167,19 @@ pub fn validate_version(version: &str) -> Result<()> {
     Ok(())
 }
 
+/// Parse an Apple Container version such as `1.0.0` or `1.1` (a missing patch
+/// is 0) into a semver version.
+pub fn parse_semver(version: &str) -> Result<semver::Version> {
+    let version = version.trim().trim_start_matches('v');
+    let padded = match version.split('.').count() {
+        1 => format!("{}.0.0", version),
+        2 => format!("{}.0", version),
+        _ => version.to_string(),
+    };
+    semver::Version::parse(&padded)
+        .with_context(|| format!("Invalid Apple Container CLI version '{}'", version))
+}
+
 /// Format Apple Container's `creationDate` (ISO-8601, e.g. "2026-06-14T21:52:43Z")
 /// into a friendly "YYYY-MM-DD HH:MM UTC" string. Returns the raw input on parse
 /// failure and "unknown" when empty. (Apple Container 1.0.0 emits ISO strings,
@@ -462,10 +1210,21 @@ pub struct ParsedContainer {
     /// suffix stripped (e.g., `"192.168.65.2"` not `"192.168.65.2/24"`).
     /// `None` when the container has no network attachment.
     pub ipv4: Option<String>,
-    /// `configuration.creationDate` (ISO-8601 string), if present.
+    /// `configuration.creationDate` (ISO-8601 string), falling back to
+    /// `status.startTime` when the runtime omits it.
     pub created: Option<String>,
 }
 
+impl ParsedContainer {
+    /// `created` as a timestamp, when it is RFC 3339
+    pub fn creation_timestamp(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let created = self.created.as_deref()?;
+        chrono::DateTime::parse_from_rfc3339(created)
+            .ok()
+            .map(|dt| dt.with_timezone(&chrono::Utc))
+    }
+}
+
 /// Parse the JSON output of `container list --format json` using the 1.0.0 shape.
 ///
 /// Returns `Ok(vec![])` for empty or whitespace-only input.
@@ -522,10 +1281,11 @@ pub fn parse_container_list(json: &str) -> Result<Vec<ParsedContainer>> {
             .and_then(|v| v.as_str())
             .map(|cidr| cidr.split('/').next().unwrap_or(cidr).to_string());
 
-        // configuration.creationDate (ISO-8601 string)
+        // configuration.creationDate (ISO-8601 string), else status.startTime
         let created = elem
             .get("configuration")
             .and_then(|c| c.get("creationDate"))
+            .or_else(|| elem.get("status").and_then(|s| s.get("startTime")))
             .and_then(|v| v.as_str())
             .map(|s| s.to_string());
 
@@ -541,6 +1301,127 @@ pub fn parse_container_list(json: &str) -> Result<Vec<ParsedContainer>> {
     Ok(result)
 }
 
+/// Parse `container inspect` output. The CLI prints a one-element array for a
+/// single container; that element is returned on its own.
+pub fn parse_inspect_output(json: &str) -> Result<serde_json::Value> {
+    let value: serde_json::Value =
+        serde_json::from_str(json).context("Failed to parse container inspect JSON")?;
+    Ok(match value {
+        serde_json::Value::Array(mut items) if items.len() == 1 => items.remove(0),
+        other => other,
+    })
+}
+
+/// The [`API_SERVER_PORT_LABEL`] of a `container inspect` result, or
+/// [`DEFAULT_API_SERVER_PORT`] for nodes created without it.
+pub fn api_server_port_from_inspect(inspect: &serde_json::Value) -> u16 {
+    inspect["configuration"]["labels"][API_SERVER_PORT_LABEL]
+        .as_str()
+        .and_then(|port| port.parse().ok())
+        .unwrap_or(DEFAULT_API_SERVER_PORT)
+}
+
+/// The [`CONTAINER_RUNTIME_LABEL`] of a `container inspect` result;
+/// containerd for nodes created without it.
+pub fn container_runtime_from_inspect(inspect: &serde_json::Value) -> ContainerRuntime {
+    match inspect["configuration"]["labels"][CONTAINER_RUNTIME_LABEL].as_str() {
+        Some(runtime) if runtime == ContainerRuntime::CriO.to_string() => ContainerRuntime::CriO,
+        _ => ContainerRuntime::Containerd,
+    }
+}
+
+/// `(cpus, memory)` of a `container inspect` result, with the memory in the
+/// `<n><m|g>` form `container run --memory` takes; `None` when not reported.
+pub fn resources_from_inspect(inspect: &serde_json::Value) -> Option<(u32, String)> {
+    const MIB: u64 = 1024 * 1024;
+    const GIB: u64 = 1024 * MIB;
+    let resources = &inspect["configuration"]["resources"];
+    let cpus = u32::try_from(resources["cpus"].as_u64()?).ok()?;
+    let bytes = resources["memoryInBytes"].as_u64()?;
+    let memory = if bytes % GIB == 0 {
+        format!("{}g", bytes / GIB)
+    } else {
+        format!("{}m", (bytes + MIB - 1) / MIB)
+    };
+    Some((cpus, memory))
+}
+
+/// Parse the JSON output of `container network list --format json`.
+///
+/// Apple Container releases disagree on where the configuration lives
+/// (`config` vs `configuration`) and how the subnet is spelled, so each field
+/// is looked up under every known name.
+pub fn parse_network_list(json: &str) -> Result<Vec<NetworkInfo>> {
+    if json.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let value: serde_json::Value =
+        serde_json::from_str(json).context("Failed to parse network list JSON")?;
+    let array = value
+        .as_array()
+        .ok_or_else(|| anyhow::anyhow!("Expected a JSON array at top level"))?;
+
+    let str_at = |elem: &serde_json::Value, paths: &[&[&str]]| {
+        paths.iter().find_map(|path| {
+            path.iter()
+                .try_fold(elem, |v, key| v.get(key))
+                .and_then(|v| v.as_str())
+                .map(str::to_string)
+        })
+    };
+
+    Ok(array
+        .iter()
+        .map(|elem| {
+            let config = elem.get("config").or_else(|| elem.get("configuration"));
+            let labels = config
+                .and_then(|c| c.get("labels"))
+                .and_then(|l| l.as_object())
+                .map(|obj| {
+                    obj.iter()
+                        .filter_map(|(k, v)| v.as_str().map(|s| (k.clone(), s.to_string())))
+                        .collect()
+                })
+                .unwrap_or_default();
+            NetworkInfo {
+                name: str_at(
+                    elem,
+                    &[&["id"], &["config", "id"], &["configuration", "id"]],
+                )
+                .unwrap_or_default(),
+                state: str_at(elem, &[&["state"], &["status", "state"]])
+                    .unwrap_or_else(|| "unknown".to_string()),
+                subnet: str_at(
+                    elem,
+                    &[
+                        &["status", "address"],
+                        &["status", "ipv4Subnet"],
+                        &["config", "subnet"],
+                        &["configuration", "ipv4Subnet"],
+                    ],
+                ),
+                labels,
+            }
+        })
+        .collect())
+}
+
+/// Arguments for `container network create` from a [`NetworkSpec`].
+pub fn network_create_args(spec: &NetworkSpec) -> Vec<String> {
+    let mut args = vec!["network".to_string(), "create".to_string()];
+    for (key, value) in &spec.labels {
+        args.push("--label".to_string());
+        args.push(format!("{}={}", key, value));
+    }
+    if let Some(subnet) = &spec.subnet {
+        args.push("--subnet".to_string());
+        args.push(subnet.clone());
+    }
+    args.push(spec.name.clone());
+    args
+}
+
 /// Returns the capability arguments required for Kubernetes node containers.
 ///
 /// Apple Container has no privileged-mode flag. Since 0.12.0 the default
@@ -551,6 +1432,41 @@ pub fn node_cap_args() -> Vec<&'static str> {
     vec!["--cap-add", "ALL"]
 }
 
+/// Build the `container run` arguments for a node [`ContainerSpec`].
+///
+/// Every node also gets [`node_cap_args`]: Apple Container has no
+/// privileged-mode flag, and since 0.12.0 the default cap set is insufficient
+/// for systemd, kubeadm, kubelet, containerd, and Cilium eBPF.
+pub fn container_run_args(spec: &ContainerSpec) -> Vec<String> {
+    let mut args = vec![
+        "run".to_string(),
+        "-d".to_string(), // Run in detached mode
+        "--name".to_string(),
+        spec.name.clone(),
+    ];
+    for (key, value) in &spec.labels {
+        args.extend(["--label".to_string(), format!("{}={}", key, value)]);
+    }
+    for path in &spec.tmpfs {
+        args.extend(["--tmpfs".to_string(), path.clone()]);
+    }
+    args.extend(node_cap_args().into_iter().map(String::from));
+    if let Some(cpus) = spec.cpus {
+        args.extend(["--cpus".to_string(), cpus.to_string()]);
+    }
+    if let Some(memory) = &spec.memory {
+        args.extend(["--memory".to_string(), memory.clone()]);
+    }
+    args.extend(node_kernel_args(spec.kernel.as_deref()));
+    args.extend(node_volume_args(&spec.volumes));
+    for (name, value) in &spec.env {
+        args.extend(["--env".to_string(), format!("{}={}", name, value)]);
+    }
+    args.push(spec.image.clone());
+    args.extend(spec.command.iter().cloned());
+    args
+}
+
 /// Generate kubeadm init configuration YAML (v1beta4, K8s v1.36.1).
 ///
 /// Emits three stanzas separated by "---":
@@ -567,70 +1483,444 @@ pub fn generate_kubeadm_init_config(
     vm_ip: &str,
     cluster_name: &str,
 ) -> String {
-    format!(
-        r#"apiVersion: kubeadm.k8s.io/v1beta4
-kind: InitConfiguration
-localAPIEndpoint:
-  advertiseAddress: "{vm_ip}"
-  bindPort: 6443
-nodeRegistration:
-  criSocket: unix:///run/containerd/containerd.sock
-  kubeletExtraArgs:
-  - name: node-ip
-    value: "{vm_ip}"
-  - name: provider-id
-    value: "kind://docker/{cluster_name}/{container_name}"
----
-apiVersion: kubeadm.k8s.io/v1beta4
-kind: ClusterConfiguration
-kubernetesVersion: v1.36.1
-clusterName: "{cluster_name}"
-controlPlaneEndpoint: "{vm_ip}:6443"
-apiServer:
+    generate_kubeadm_init_config_with(
+        container_name,
+        vm_ip,
+        cluster_name,
+        &KubeadmConfigOptions::default(),
+    )
+}
+
+/// Default kubeadm `networking.serviceSubnet`.
+pub const DEFAULT_SERVICE_SUBNET: &str = "10.96.0.0/16";
+
+/// Default kubeadm `networking.podSubnet`; also the single-node PTP subnet.
+pub const DEFAULT_POD_SUBNET: &str = "10.244.0.0/16";
+
+/// Default kubeadm `networking.dnsDomain`.
+pub const DEFAULT_DNS_DOMAIN: &str = "cluster.local";
+
+/// Default kubeadm `etcd.local.dataDir`.
+pub const DEFAULT_ETCD_DATA_DIR: &str = "/var/lib/etcd";
+
+/// Optional additions to the generated kubeadm init configuration.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KubeadmConfigOptions {
+    /// Extra `ClusterConfiguration.apiServer.extraArgs`, appended after kina's defaults
+    pub api_server_extra_args: BTreeMap<String, String>,
+    /// Extra `ClusterConfiguration.apiServer.extraVolumes` (node hostPath mounts)
+    pub api_server_extra_volumes: Vec<HostPathVolume>,
+    /// `ClusterConfiguration.scheduler.extraArgs`
+    pub scheduler_extra_args: BTreeMap<String, String>,
+    /// `ClusterConfiguration.scheduler.extraVolumes` (node hostPath mounts)
+    pub scheduler_extra_volumes: Vec<HostPathVolume>,
+    /// Files written into the control-plane node before `kubeadm init`, as
+    /// (absolute node path, contents)
+    pub files: Vec<(String, Vec<u8>)>,
+    /// Files written into every node (control planes and workers) before the
+    /// kubelet starts, as (absolute node path, contents)
+    pub node_files: Vec<(String, Vec<u8>)>,
+    /// Host directories mounted into the control-plane node container, as
+    /// (host path, node path)
+    pub control_plane_volumes: Vec<(std::path::PathBuf, String)>,
+    /// Overrides merged over kina's default `ClusterConfiguration.networking`
+    pub networking: NetworkingConfig,
+    /// Extra `nodeRegistration.kubeletExtraArgs` for every node, appended after
+    /// kina's own `node-ip`/`provider-id`
+    pub kubelet_extra_args: BTreeMap<String, String>,
+    /// Extra `ClusterConfiguration.controllerManager.extraArgs`, appended after
+    /// kina's defaults
+    pub controller_manager_extra_args: BTreeMap<String, String>,
+    /// `ClusterConfiguration.etcd.local` settings; `extra_args` already
+    /// includes any `--etcd-extra-arg` flags
+    pub etcd: EtcdConfig,
+    /// Shared `controlPlaneEndpoint` host (the kube-vip VIP) for an HA control
+    /// plane; `None` uses the first control-plane node's IP
+    pub control_plane_endpoint: Option<String>,
+    /// `InitConfiguration.certificateKey` used to upload the control-plane
+    /// certificates for joining control-plane nodes
+    pub certificate_key: Option<String>,
+    /// `InitConfiguration.bootstrapTokens` token to use instead of a random one
+    pub bootstrap_token: Option<String>,
+    /// containerd config snippet merged into every node's
+    /// [`CONTAINERD_CONFIG_PATH`] before the kubelet starts
+    pub containerd_config: Option<String>,
+    /// `KubeletConfiguration` document replacing
+    /// [`DEFAULT_KUBELET_CONFIGURATION`] (see [`merge_kubelet_config`])
+    pub kubelet_config: Option<String>,
+    /// Extra `ClusterConfiguration.apiServer.certSANs` entries
+    pub api_server_cert_sans: Vec<String>,
+    /// Runtime whose socket `nodeRegistration.criSocket` points at; CRI-O is
+    /// started in place of containerd on every node
+    pub container_runtime: ContainerRuntime,
+    /// Tell kubeadm to ignore its Swap preflight check
+    /// (see [`kubeadm_preflight_args`])
+    pub disable_swap_check: bool,
+    /// Tell kubeadm to ignore every preflight error
+    pub skip_preflight: bool,
+    /// API server port; see [`Self::api_server_port`]
+    pub api_server_port: Option<u16>,
+    /// `InitConfiguration.nodeRegistration.name`; `None` lets the node
+    /// register under its container name
+    pub node_name: Option<String>,
+}
+
+/// A kubeadm `extraVolumes` entry exposing a node directory to a static pod.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostPathVolume {
+    pub name: String,
+    pub host_path: String,
+    pub mount_path: String,
+    pub read_only: bool,
+}
+
+impl KubeadmConfigOptions {
+    /// Port the API server binds and `controlPlaneEndpoint` names
+    pub fn api_server_port(&self) -> u16 {
+        self.api_server_port.unwrap_or(DEFAULT_API_SERVER_PORT)
+    }
+
+    /// Collect the kubeadm additions requested by a create, reading any host
+    /// files (e.g. the OIDC CA bundle) that must be copied into the node.
+    /// Host-side state such as the audit log directory lives under `data_dir`.
+    pub fn from_create_options(
+        options: &CreateClusterOptions,
+        data_dir: &std::path::Path,
+    ) -> Result<Self> {
+        let mut kubeadm = Self {
+            networking: options.networking.clone().unwrap_or_default(),
+            bootstrap_token: options.bootstrap_token.clone(),
+            containerd_config: options.containerd_config.clone(),
+            api_server_cert_sans: options.api_server_sans.clone(),
+            container_runtime: options.container_runtime,
+            disable_swap_check: options.disable_swap_check,
+            skip_preflight: options.skip_preflight,
+            api_server_port: options.api_server_port,
+            node_name: options.node_name.clone(),
+            ..Self::default()
+        };
+
+        if let Some(oidc) = &options.oidc {
+            kubeadm.api_server_extra_args.extend(oidc.api_server_args());
+            if let Some(ca_file) = &oidc.ca_file {
+                let contents = fs::read(ca_file).with_context(|| {
+                    format!("Failed to read OIDC CA file {}", ca_file.display())
+                })?;
+                kubeadm
+                    .files
+                    .push((OIDC_CA_NODE_PATH.to_string(), contents));
+            }
+        }
+
+        if let Some(path) = &options.kubelet_config_file {
+            let contents = fs::read(path).with_context(|| {
+                format!("Failed to read kubelet config file {}", path.display())
+            })?;
+            let (merged, ignored) = merge_kubelet_config(&contents)
+                .with_context(|| format!("Invalid kubelet config file {}", path.display()))?;
+            for field in ignored {
+                warn!(
+                    "{}: ignoring {}, which kina requires to keep its default",
+                    path.display(),
+                    field
+                );
+            }
+            kubeadm.kubelet_config = Some(merged);
+        }
+
+        if let Some(path) = &options.pull_secret_config {
+            let contents = fs::read(path)
+                .with_context(|| format!("Failed to read docker config file {}", path.display()))?;
+            validate_docker_config_json(&contents)
+                .with_context(|| format!("Invalid docker config file {}", path.display()))?;
+            kubeadm
+                .node_files
+                .push((KUBELET_DOCKER_CONFIG_PATH.to_string(), contents));
+        }
+
+        for registry in &options.insecure_registries {
+            kubeadm.node_files.push((
+                registry_hosts_toml_path(registry),
+                insecure_registry_hosts_toml(registry).into_bytes(),
+            ));
+        }
+
+        if let Some(path) = &options.scheduler_config_file {
+            let contents = fs::read(path).with_context(|| {
+                format!("Failed to read scheduler config file {}", path.display())
+            })?;
+            let contents = prepare_scheduler_config(&contents)
+                .with_context(|| format!("Invalid scheduler config file {}", path.display()))?;
+            kubeadm.add_scheduler_config(contents);
+        }
+
+        if options.pod_security_standard.is_some() {
+            kubeadm.api_server_extra_args.insert(
+                "enable-admission-plugins".to_string(),
+                POD_SECURITY_ADMISSION_PLUGINS.to_string(),
+            );
+        }
+
+        if options.audit_log {
+            let policy = match &options.audit_policy {
+                Some(AuditPolicy::File(path)) => fs::read(path).with_context(|| {
+                    format!("Failed to read audit policy file {}", path.display())
+                })?,
+                Some(AuditPolicy::Level(level)) => audit_policy_yaml(*level).as_bytes().to_vec(),
+                None => DEFAULT_AUDIT_POLICY.as_bytes().to_vec(),
+            };
+            kubeadm.add_audit_log(policy, audit_log_dir(data_dir, &options.name));
+        }
+
+        // User-supplied flags go last so they override kina's own settings.
+        kubeadm
+            .api_server_extra_args
+            .extend(options.api_server_extra_args.clone());
+        kubeadm.kubelet_extra_args = options.kubelet_extra_args.clone();
+        kubeadm.controller_manager_extra_args = options.controller_manager_extra_args.clone();
+        kubeadm.etcd = options.etcd.clone().unwrap_or_default();
+        kubeadm
+            .etcd
+            .extra_args
+            .extend(options.etcd_extra_args.clone());
+
+        Ok(kubeadm)
+    }
+
+    /// Run kube-scheduler with `config` (a `KubeSchedulerConfiguration`),
+    /// written to [`SCHEDULER_CONFIG_NODE_PATH`] and mounted into its static pod.
+    fn add_scheduler_config(&mut self, config: Vec<u8>) {
+        let config_dir = std::path::Path::new(SCHEDULER_CONFIG_NODE_PATH)
+            .parent()
+            .map(|p| p.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        self.files
+            .push((SCHEDULER_CONFIG_NODE_PATH.to_string(), config));
+        self.scheduler_extra_args
+            .insert("config".to_string(), SCHEDULER_CONFIG_NODE_PATH.to_string());
+        self.scheduler_extra_volumes.push(HostPathVolume {
+            name: "scheduler-config".to_string(),
+            host_path: config_dir.clone(),
+            mount_path: config_dir,
+            read_only: true,
+        });
+    }
+
+    /// Enable API server audit logging with the given policy, writing the log to
+    /// [`AUDIT_LOG_NODE_DIR`] which is backed by `host_log_dir`.
+    fn add_audit_log(&mut self, policy: Vec<u8>, host_log_dir: std::path::PathBuf) {
+        let policy_dir = std::path::Path::new(AUDIT_POLICY_NODE_PATH)
+            .parent()
+            .map(|p| p.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        self.files
+            .push((AUDIT_POLICY_NODE_PATH.to_string(), policy));
+        self.control_plane_volumes
+            .push((host_log_dir, AUDIT_LOG_NODE_DIR.to_string()));
+
+        for (name, value) in [
+            (
+                "audit-log-path",
+                format!("{}/audit.log", AUDIT_LOG_NODE_DIR),
+            ),
+            ("audit-log-maxage", "7".to_string()),
+            ("audit-log-maxsize", "100".to_string()),
+            ("audit-policy-file", AUDIT_POLICY_NODE_PATH.to_string()),
+        ] {
+            self.api_server_extra_args.insert(name.to_string(), value);
+        }
+
+        self.api_server_extra_volumes.push(HostPathVolume {
+            name: "audit-policy".to_string(),
+            host_path: policy_dir.clone(),
+            mount_path: policy_dir,
+            read_only: true,
+        });
+        self.api_server_extra_volumes.push(HostPathVolume {
+            name: "audit-logs".to_string(),
+            host_path: AUDIT_LOG_NODE_DIR.to_string(),
+            mount_path: AUDIT_LOG_NODE_DIR.to_string(),
+            read_only: false,
+        });
+    }
+}
+
+/// Render kubeadm v1beta4 list-form `extraArgs` entries, one `- name/value`
+/// pair per key, at the given indent.
+fn render_extra_args(args: &BTreeMap<String, String>, indent: &str) -> String {
+    args.iter()
+        .map(|(name, value)| {
+            let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+            format!("{indent}- name: {name}\n{indent}  value: \"{value}\"\n")
+        })
+        .collect()
+}
+
+/// Render the `ClusterConfiguration.scheduler` section; `{}` when there is
+/// nothing to add.
+fn render_scheduler(kubeadm: &KubeadmConfigOptions) -> String {
+    if kubeadm.scheduler_extra_args.is_empty() && kubeadm.scheduler_extra_volumes.is_empty() {
+        return "scheduler: {}\n".to_string();
+    }
+    let mut out = "scheduler:\n".to_string();
+    if !kubeadm.scheduler_extra_args.is_empty() {
+        out.push_str("  extraArgs:\n");
+        out.push_str(&render_extra_args(&kubeadm.scheduler_extra_args, "  "));
+    }
+    out.push_str(&render_extra_volumes(
+        &kubeadm.scheduler_extra_volumes,
+        "  ",
+    ));
+    out
+}
+
+/// Render the `ClusterConfiguration.etcd` section for a local etcd.
+fn render_etcd(etcd: &EtcdConfig) -> String {
+    let data_dir = etcd.data_dir.as_deref().unwrap_or(DEFAULT_ETCD_DATA_DIR);
+    let mut out = format!("etcd:\n  local:\n    dataDir: \"{}\"\n", data_dir);
+    if !etcd.extra_args.is_empty() {
+        out.push_str("    extraArgs:\n");
+        out.push_str(&render_extra_args(&etcd.extra_args, "    "));
+    }
+    if !etcd.peer_cert_sans.is_empty() {
+        out.push_str("    peerCertSANs:\n");
+        for san in &etcd.peer_cert_sans {
+            out.push_str(&format!("    - \"{}\"\n", san));
+        }
+    }
+    out
+}
+
+/// Render kubeadm `extraVolumes` (including the key) at the given indent, or
+/// nothing when there are no volumes.
+fn render_extra_volumes(volumes: &[HostPathVolume], indent: &str) -> String {
+    if volumes.is_empty() {
+        return String::new();
+    }
+    let mut out = format!("{indent}extraVolumes:\n");
+    for volume in volumes {
+        out.push_str(&format!(
+            "{indent}- name: {}\n{indent}  hostPath: \"{}\"\n{indent}  mountPath: \"{}\"\n{indent}  readOnly: {}\n{indent}  pathType: DirectoryOrCreate\n",
+            volume.name, volume.host_path, volume.mount_path, volume.read_only
+        ));
+    }
+    out
+}
+
+/// [`generate_kubeadm_init_config`] with user-supplied additions.
+pub fn generate_kubeadm_init_config_with(
+    container_name: &str,
+    vm_ip: &str,
+    cluster_name: &str,
+    kubeadm: &KubeadmConfigOptions,
+) -> String {
+    let api_server_extra_args = render_extra_args(&kubeadm.api_server_extra_args, "  ");
+    let api_server_extra_volumes = render_extra_volumes(&kubeadm.api_server_extra_volumes, "  ");
+    let kubelet_extra_args = render_extra_args(&kubeadm.kubelet_extra_args, "  ");
+    let cri_socket = kubeadm.container_runtime.cri_socket();
+    let node_registration_name = kubeadm
+        .node_name
+        .as_deref()
+        .map(|name| format!("  name: \"{}\"\n", name))
+        .unwrap_or_default();
+    let controller_manager_extra_args =
+        render_extra_args(&kubeadm.controller_manager_extra_args, "  ");
+    let scheduler = render_scheduler(kubeadm);
+    let etcd = render_etcd(&kubeadm.etcd);
+    let kubelet_configuration = kubeadm
+        .kubelet_config
+        .as_deref()
+        .unwrap_or(DEFAULT_KUBELET_CONFIGURATION);
+    let networking = &kubeadm.networking;
+    let service_subnet = networking
+        .service_subnet
+        .as_deref()
+        .unwrap_or(DEFAULT_SERVICE_SUBNET);
+    let pod_subnet = networking
+        .pod_subnet
+        .as_deref()
+        .unwrap_or(DEFAULT_POD_SUBNET);
+    let dns_domain = networking
+        .dns_domain
+        .as_deref()
+        .unwrap_or(DEFAULT_DNS_DOMAIN);
+    // The API server always listens on the node VM IP; a configured
+    // apiServerAddress is added as an extra SAN so clients can reach it there.
+    let extra_cert_sans: String = networking
+        .api_server_address
+        .iter()
+        .chain(kubeadm.control_plane_endpoint.iter())
+        .chain(kubeadm.api_server_cert_sans.iter())
+        .map(|address| format!("  - \"{}\"\n", address))
+        .collect();
+    let control_plane_endpoint = kubeadm.control_plane_endpoint.as_deref().unwrap_or(vm_ip);
+    let api_server_port = kubeadm.api_server_port();
+    let certificate_key = kubeadm
+        .certificate_key
+        .as_deref()
+        .map(|key| format!("certificateKey: \"{}\"\n", key))
+        .unwrap_or_default();
+    // Same groups, usages and TTL kubeadm gives its generated token.
+    let bootstrap_tokens = kubeadm
+        .bootstrap_token
+        .as_deref()
+        .map(|token| {
+            format!(
+                "bootstrapTokens:\n- token: \"{}\"\n  ttl: 24h0m0s\n  usages:\n  - signing\n  - authentication\n  groups:\n  - system:bootstrappers:kubeadm:default-node-token\n",
+                token
+            )
+        })
+        .unwrap_or_default();
+    format!(
+        r#"apiVersion: kubeadm.k8s.io/v1beta4
+kind: InitConfiguration
+{bootstrap_tokens}{certificate_key}localAPIEndpoint:
+  advertiseAddress: "{vm_ip}"
+  bindPort: {api_server_port}
+nodeRegistration:
+{node_registration_name}  criSocket: {cri_socket}
+  kubeletExtraArgs:
+  - name: node-ip
+    value: "{vm_ip}"
+  - name: provider-id
+    value: "kind://docker/{cluster_name}/{container_name}"
+{kubelet_extra_args}---
+apiVersion: kubeadm.k8s.io/v1beta4
+kind: ClusterConfiguration
+kubernetesVersion: v1.36.1
+clusterName: "{cluster_name}"
+controlPlaneEndpoint: "{control_plane_endpoint}:{api_server_port}"
+apiServer:
   certSANs:
   - "{vm_ip}"
   - "{container_name}"
   - "localhost"
   - "127.0.0.1"
-  extraArgs:
+{extra_cert_sans}  extraArgs:
   - name: runtime-config
     value: "api/all=true"
-networking:
-  serviceSubnet: "10.96.0.0/16"
-  podSubnet: "10.244.0.0/16"
-  dnsDomain: "cluster.local"
+{api_server_extra_args}{api_server_extra_volumes}networking:
+  serviceSubnet: "{service_subnet}"
+  podSubnet: "{pod_subnet}"
+  dnsDomain: "{dns_domain}"
 controllerManager:
   extraArgs:
   - name: enable-hostpath-provisioner
     value: "true"
-scheduler: {{}}
-etcd:
-  local:
-    dataDir: "/var/lib/etcd"
----
+{controller_manager_extra_args}{scheduler}{etcd}---
 apiVersion: kubeadm.k8s.io/v1beta4
 kind: JoinConfiguration
 nodeRegistration:
-  criSocket: unix:///run/containerd/containerd.sock
+  criSocket: {cri_socket}
   kubeletExtraArgs:
   - name: node-ip
     value: "{vm_ip}"
   - name: provider-id
     value: "kind://docker/{cluster_name}/{container_name}"
----
-apiVersion: kubelet.config.k8s.io/v1beta1
-kind: KubeletConfiguration
-cgroupDriver: systemd
-failSwapOn: false
-authentication:
-  anonymous:
-    enabled: false
-  webhook:
-    enabled: true
-authorization:
-  mode: Webhook
-serverTLSBootstrap: true
----
+{kubelet_extra_args}---
+{kubelet_configuration}---
 apiVersion: kubeproxy.config.k8s.io/v1alpha1
 kind: KubeProxyConfiguration
 bindAddress: "0.0.0.0"
@@ -648,10 +1938,28 @@ clusterCIDR: "10.244.0.0/16"
 ///
 /// The map→list migration mirrors PR #14 (vinnie357/kina).
 pub fn generate_worker_join_config(
+    worker_name: &str,
+    worker_ip: &str,
+    join_info: &KubeadmJoinInfo,
+) -> String {
+    generate_worker_join_config_with(
+        worker_name,
+        worker_ip,
+        join_info,
+        &KubeadmConfigOptions::default(),
+    )
+}
+
+/// [`generate_worker_join_config`] with the create's extra kubelet flags
+/// appended to `kubeletExtraArgs` and its runtime's `criSocket`.
+pub fn generate_worker_join_config_with(
     _worker_name: &str,
     worker_ip: &str,
     join_info: &KubeadmJoinInfo,
+    kubeadm: &KubeadmConfigOptions,
 ) -> String {
+    let kubelet_extra_args = render_extra_args(&kubeadm.kubelet_extra_args, "  ");
+    let cri_socket = kubeadm.container_runtime.cri_socket();
     format!(
         r#"apiVersion: kubeadm.k8s.io/v1beta4
 kind: JoinConfiguration
@@ -662,11 +1970,11 @@ discovery:
     caCertHashes:
     - "{hash}"
 nodeRegistration:
-  criSocket: unix:///run/containerd/containerd.sock
+  criSocket: {cri_socket}
   kubeletExtraArgs:
   - name: node-ip
     value: "{worker_ip}"
----
+{kubelet_extra_args}---
 apiVersion: kubelet.config.k8s.io/v1beta1
 kind: KubeletConfiguration
 cgroupDriver: systemd
@@ -676,9 +1984,382 @@ failSwapOn: false
         token = join_info.token,
         hash = join_info.ca_cert_hash,
         worker_ip = worker_ip,
+        kubelet_extra_args = kubelet_extra_args,
+        cri_socket = cri_socket,
+    )
+}
+
+// ---------------------------------------------------------------------------
+// HA control plane (`--control-plane-nodes`)
+//
+// Additional control-plane nodes join with `kubeadm join --control-plane`,
+// fetching the shared certificates uploaded by the first node. All nodes reach
+// the API through a VIP that kube-vip announces (ARP) from the current leader.
+// VM-to-VM traffic needs macOS 26 or later.
+// ---------------------------------------------------------------------------
+
+/// First macOS release where Apple Container VMs can reach each other
+pub const HA_MIN_MACOS_MAJOR: u32 = 26;
+
+/// kube-vip image run as a static pod on every control-plane node
+pub const KUBE_VIP_IMAGE: &str = "ghcr.io/kube-vip/kube-vip:v0.8.9";
+
+/// Node path of the kube-vip static pod manifest
+pub const KUBE_VIP_MANIFEST_PATH: &str = "/etc/kubernetes/manifests/kube-vip.yaml";
+
+/// Name of the `index`th control-plane node (1-based), following kind:
+/// `<cluster>-control-plane`, `<cluster>-control-plane2`, ...
+pub fn control_plane_node_name(cluster_name: &str, index: u32) -> String {
+    if index <= 1 {
+        format!("{}-control-plane", cluster_name)
+    } else {
+        format!("{}-control-plane{}", cluster_name, index)
+    }
+}
+
+/// Major version from `sw_vers -productVersion` output such as `26.0.1`
+pub fn parse_macos_major(product_version: &str) -> Option<u32> {
+    product_version.trim().split('.').next()?.parse().ok()
+}
+
+/// The host's macOS major version; `None` off macOS or if `sw_vers` fails
+pub fn macos_major_version() -> Option<u32> {
+    let output = std::process::Command::new("sw_vers")
+        .arg("-productVersion")
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_macos_major(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Check `--control-plane-nodes` against the host. More than one control
+/// plane needs VM-to-VM networking, which arrived in macOS 26.
+pub fn validate_control_plane_count(count: u32, macos_major: Option<u32>) -> Result<()> {
+    if count == 0 {
+        return Err(anyhow::anyhow!("--control-plane-nodes must be at least 1"));
+    }
+    if count == 1 {
+        return Ok(());
+    }
+    match macos_major {
+        Some(major) if major >= HA_MIN_MACOS_MAJOR => {
+            if count % 2 == 0 {
+                warn!(
+                    "{} control-plane nodes tolerate no more failures than {}; \
+                     an odd count is recommended for etcd quorum",
+                    count,
+                    count - 1
+                );
+            }
+            Ok(())
+        }
+        found => Err(anyhow::anyhow!(
+            "--control-plane-nodes {} requires macOS {} or later, where Apple Container VMs \
+             can reach each other (this host: {}). Use --control-plane-nodes 1.",
+            count,
+            HA_MIN_MACOS_MAJOR,
+            found.map_or_else(
+                || "not macOS".to_string(),
+                |major| format!("macOS {}", major)
+            )
+        )),
+    }
+}
+
+/// The VIP announced by kube-vip: the highest of `.250-.254` in the first
+/// control plane's /24 that no container holds (`used_ips`). vmnet hands out
+/// VM addresses from the bottom of the subnet upwards, and the MetalLB default
+/// pool stops at `.249`.
+pub fn control_plane_vip(first_control_plane_ip: &str, used_ips: &[String]) -> Result<String> {
+    let ip: std::net::Ipv4Addr = first_control_plane_ip.parse().with_context(|| {
+        format!(
+            "Control-plane IP '{}' is not an IPv4 address",
+            first_control_plane_ip
+        )
+    })?;
+    let [a, b, c, _] = ip.octets();
+    (250..=254)
+        .rev()
+        .map(|host| std::net::Ipv4Addr::new(a, b, c, host).to_string())
+        .find(|vip| vip != first_control_plane_ip && !used_ips.contains(vip))
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "No free control-plane VIP in {a}.{b}.{c}.250-{a}.{b}.{c}.254; \
+                 pass --control-plane-vip"
+            )
+        })
+}
+
+/// kube-vip static pod announcing `vip` on eth0 with leader election.
+///
+/// `kubeconfig` is `/etc/kubernetes/super-admin.conf` on the node running
+/// `kubeadm init` (admin.conf is not yet authorized while init runs) and
+/// `/etc/kubernetes/admin.conf` on nodes that join.
+pub fn kube_vip_manifest(vip: &str, api_server_port: u16, kubeconfig: &str) -> String {
+    format!(
+        r#"apiVersion: v1
+kind: Pod
+metadata:
+  name: kube-vip
+  namespace: kube-system
+spec:
+  containers:
+  - name: kube-vip
+    image: {image}
+    args: ["manager"]
+    env:
+    - name: vip_arp
+      value: "true"
+    - name: port
+      value: "{api_server_port}"
+    - name: vip_interface
+      value: eth0
+    - name: cp_enable
+      value: "true"
+    - name: cp_namespace
+      value: kube-system
+    - name: vip_leaderelection
+      value: "true"
+    - name: address
+      value: "{vip}"
+    securityContext:
+      capabilities:
+        add: ["NET_ADMIN", "NET_RAW"]
+    volumeMounts:
+    - mountPath: /etc/kubernetes/admin.conf
+      name: kubeconfig
+  hostAliases:
+  - hostnames: ["kubernetes"]
+    ip: 127.0.0.1
+  hostNetwork: true
+  volumes:
+  - name: kubeconfig
+    hostPath:
+      path: {kubeconfig}
+"#,
+        image = KUBE_VIP_IMAGE,
+        vip = vip,
+        api_server_port = api_server_port,
+        kubeconfig = kubeconfig,
+    )
+}
+
+/// kubeadm JoinConfiguration for an additional control-plane node: the
+/// worker join plus a local API endpoint and the certificate key.
+pub fn generate_control_plane_join_config(
+    node_ip: &str,
+    join_info: &KubeadmJoinInfo,
+    certificate_key: &str,
+    kubeadm: &KubeadmConfigOptions,
+) -> String {
+    let worker = generate_worker_join_config_with("", node_ip, join_info, kubeadm);
+    let (join, kubelet) = worker
+        .split_once("---\n")
+        .expect("worker join config has a KubeletConfiguration stanza");
+    let api_server_port = kubeadm.api_server_port();
+    format!(
+        "{join}controlPlane:\n  localAPIEndpoint:\n    advertiseAddress: \"{node_ip}\"\n    bindPort: {api_server_port}\n  certificateKey: \"{certificate_key}\"\n---\n{kubelet}"
     )
 }
 
+/// Size and hex SHA-256 of a file, read in chunks so large image tarballs are
+/// never held in memory.
+fn file_size_and_sha256(path: &std::path::Path) -> Result<(u64, String)> {
+    use std::io::Read;
+
+    let mut file = fs::File::open(path)
+        .with_context(|| format!("Failed to read image tar from {}", path.display()))?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; 1 << 20];
+    let mut len = 0u64;
+    loop {
+        let n = file
+            .read(&mut buf)
+            .with_context(|| format!("Failed to read image tar from {}", path.display()))?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+        len += n as u64;
+    }
+    Ok((len, hex::encode(hasher.finalize())))
+}
+
+/// Run `load` for every node in `container_ids` concurrently, one task each.
+///
+/// Fails only when every node fails; when some nodes succeed, the failures
+/// are logged as warnings and the load counts as done.
+async fn load_into_nodes<F, Fut>(
+    image: &str,
+    cluster: &str,
+    container_ids: &[String],
+    load: F,
+) -> Result<()>
+where
+    F: Fn(String) -> Fut,
+    Fut: std::future::Future<Output = Result<()>> + Send + 'static,
+{
+    let handles: Vec<tokio::task::JoinHandle<Result<()>>> = container_ids
+        .iter()
+        .map(|container_id| tokio::spawn(load(container_id.clone())))
+        .collect();
+    let results = futures::future::try_join_all(handles)
+        .await
+        .context("Image load task panicked")?;
+
+    let mut failures = Vec::new();
+    for (container_id, result) in container_ids.iter().zip(results) {
+        match result {
+            Ok(()) => info!("Loaded image '{}' into node '{}'", image, container_id),
+            Err(e) => {
+                warn!(
+                    "Failed to load image '{}' into node '{}': {:#}",
+                    image, container_id, e
+                );
+                failures.push(format!("{}: {:#}", container_id, e));
+            }
+        }
+    }
+
+    if failures.len() == container_ids.len() {
+        return Err(anyhow::anyhow!(
+            "Failed to load image '{}' into any node of cluster '{}':\n  {}",
+            image,
+            cluster,
+            failures.join("\n  ")
+        ));
+    }
+    if !failures.is_empty() {
+        warn!(
+            "Image '{}' loaded into {} of {} nodes of cluster '{}'",
+            image,
+            container_ids.len() - failures.len(),
+            container_ids.len(),
+            cluster
+        );
+    } else {
+        info!(
+            "Image '{}' loaded successfully into cluster '{}'",
+            image, cluster
+        );
+    }
+    Ok(())
+}
+
+/// Stream an exported image tarball into one node container and import it
+/// with `ctr`. `digest` is the tarball's (size, sha256), checked after the
+/// copy. Runs as its own task so [`AppleContainerClient::load_image`] can load
+/// every node from the same tarball at once.
+async fn load_image_into_container(
+    cli_path: &str,
+    container_id: &str,
+    image: &str,
+    image_tar: &std::path::Path,
+    digest: &(u64, String),
+) -> Result<()> {
+    use tokio::process::Command;
+
+    debug!(
+        "Loading image '{}' into container '{}'",
+        image, container_id
+    );
+    let (local_len, local_sha) = digest;
+
+    // Inject tar bytes into the container via exec-stdin (`container exec -i
+    // <id> sh -c 'cat > /path'`).  This replaces `container cp` which silently
+    // exits 0 without transferring the file in some container 1.0.0 configurations.
+    let dest_path = "/tmp/image.tar";
+    let inject_args = build_inject_tar_args(container_id, dest_path);
+    let mut child = Command::new(cli_path)
+        .args(&inject_args)
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .with_context(|| {
+            format!(
+                "Failed to spawn exec-stdin injection for container '{}'",
+                container_id
+            )
+        })?;
+
+    // Stream the tarball to child stdin; drop the handle to close stdin so the
+    // `cat >` shell command sees EOF and terminates.
+    if let Some(mut stdin) = child.stdin.take() {
+        let copied = match tokio::fs::File::open(image_tar).await {
+            Ok(mut tar) => tokio::io::copy(&mut tar, &mut stdin).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = copied {
+            let _ = child.wait().await;
+            return Err(anyhow::anyhow!(
+                "Failed to write tar bytes to container stdin: {}",
+                e
+            ));
+        }
+    }
+
+    let inject_status = child
+        .wait()
+        .await
+        .context("Failed to wait for exec-stdin injection")?;
+    if !inject_status.success() {
+        return Err(anyhow::anyhow!(
+            "exec-stdin injection into container '{}' failed (exit {:?})",
+            container_id,
+            inject_status.code(),
+        ));
+    }
+
+    // Post-injection verification: compare remote size and sha256 against the
+    // local tarball before running ctr images import.  A size mismatch of 0
+    // (or any value ≠ local_len) indicates a silent no-op and aborts with a
+    // hard error so the import step is never attempted on a corrupt/missing file.
+    let size_output = Command::new(cli_path)
+        .args(build_remote_size_args(container_id, dest_path))
+        .output()
+        .await
+        .context("Failed to run remote size check")?;
+    let remote_size_raw = String::from_utf8_lossy(&size_output.stdout).into_owned();
+
+    let sha_output = Command::new(cli_path)
+        .args(build_remote_sha256_args(container_id, dest_path))
+        .output()
+        .await
+        .context("Failed to run remote sha256 check")?;
+    let remote_sha_raw = String::from_utf8_lossy(&sha_output.stdout).into_owned();
+
+    verify_injection(*local_len, local_sha, &remote_size_raw, &remote_sha_raw).map_err(|e| {
+        e.context(format!(
+            "post-injection verification failed for container '{}'; \
+             the exec-stdin transfer may have produced an incomplete file",
+            container_id,
+        ))
+    })?;
+
+    // Import the image inside the container using ctr (containerd CLI).
+    let output = Command::new(cli_path)
+        .args(["exec", container_id, "ctr", "images", "import", dest_path])
+        .output()
+        .await
+        .context("Failed to load image in container")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow::anyhow!(
+            "Failed to load image in container: {}",
+            stderr
+        ));
+    }
+
+    debug!(
+        "Successfully loaded image '{}' into container '{}'",
+        image, container_id
+    );
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Image injection helpers (kina-12)
 //
@@ -840,7 +2521,8 @@ pub fn verify_injection(
 pub struct AppleContainerClient {
     config: Config,
     cli_path: String,
-    container_version: String,
+    /// Filled by the first [`container_version`](Self::container_version) call
+    container_version: OnceCell<ContainerVersion>,
 }
 
 impl AppleContainerClient {
@@ -853,23 +2535,53 @@ impl AppleContainerClient {
             Self::detect_cli_path()?
         };
 
-        let container_version = Self::detect_version(&cli_path)?;
-        validate_version(&container_version)?;
-
-        Ok(Self {
+        let client = Self {
             config: config.clone(),
             cli_path,
-            container_version,
-        })
+            container_version: OnceCell::new(),
+        };
+        validate_version(&client.container_version()?.version)?;
+
+        Ok(client)
     }
 
     /// Get the detected Apple Container CLI version
     pub fn version(&self) -> &str {
-        &self.container_version
+        self.container_version
+            .get()
+            .map(|v| v.version.as_str())
+            .unwrap_or_default()
+    }
+
+    /// The Apple Container CLI version, detected with
+    /// `container version --output json` on first use and cached for the
+    /// lifetime of the client.
+    pub fn container_version(&self) -> Result<&ContainerVersion> {
+        self.container_version
+            .get_or_try_init(|| Self::detect_version(&self.cli_path))
+    }
+
+    /// The detected Apple Container CLI version as semver. Uses the cached
+    /// version rather than running the CLI again.
+    pub fn get_version(&self) -> Result<semver::Version> {
+        parse_semver(&self.container_version()?.version)
     }
 
-    /// Detect the Apple Container CLI version by running `<cli_path> --version`
-    fn detect_version(cli_path: &str) -> Result<String> {
+    /// Detect the Apple Container CLI version by running
+    /// `<cli_path> version --output json`, falling back to `<cli_path> --version`
+    /// for CLIs without the structured form
+    fn detect_version(cli_path: &str) -> Result<ContainerVersion> {
+        let output = std::process::Command::new(cli_path)
+            .args(["version", "--output", "json"])
+            .output()
+            .context("Failed to execute Apple Container CLI for version detection")?;
+        if output.status.success() {
+            match parse_version_json(&String::from_utf8_lossy(&output.stdout)) {
+                Ok(version) => return Ok(version),
+                Err(e) => debug!("{:#}; falling back to --version", e),
+            }
+        }
+
         let output = std::process::Command::new(cli_path)
             .arg("--version")
             .output()
@@ -884,7 +2596,7 @@ impl AppleContainerClient {
         }
 
         let stdout = String::from_utf8_lossy(&output.stdout);
-        parse_version_output(stdout.trim())
+        parse_container_version(stdout.trim())
     }
 
     /// Detect Apple Container CLI path.
@@ -934,7 +2646,19 @@ impl AppleContainerClient {
             self.config.cluster.default_cni.clone(),
         );
 
-        if worker_count == 0 {
+        let kubeadm =
+            KubeadmConfigOptions::from_create_options(options, &self.config.cluster.data_dir)?;
+        if options.container_runtime == ContainerRuntime::CriO {
+            self.check_image_supports_crio(&options.image)?;
+        }
+        for (host_dir, _) in &kubeadm.control_plane_volumes {
+            fs::create_dir_all(host_dir)
+                .with_context(|| format!("Failed to create {}", host_dir.display()))?;
+        }
+
+        let control_plane_count = options.control_plane_nodes.unwrap_or(1).max(1);
+
+        if worker_count == 0 && control_plane_count == 1 {
             // Single-node cluster with combined control-plane/worker roles
             let node_name = format!("{}-control-plane", options.name);
             info!(
@@ -949,15 +2673,19 @@ impl AppleContainerClient {
                 options.node_kernel_path.as_deref(),
                 options.control_plane_cpus,
                 &options.control_plane_memory,
+                options.skip_kubeconfig,
+                options.skip_cni,
+                &kubeadm,
+                &options.node_labels,
             )
             .await?;
         } else {
-            // Multi-node cluster: 1 control-plane + N workers
+            // Multi-node cluster: M control-planes + N workers
             info!(
-                "Creating multi-node cluster with 1 control-plane + {} workers",
-                worker_count
+                "Creating multi-node cluster with {} control-plane(s) + {} workers",
+                control_plane_count, worker_count
             );
-            self.create_multi_node_cluster(options, worker_count)
+            self.create_multi_node_cluster(options, worker_count, &kubeadm)
                 .await?;
         }
 
@@ -965,13 +2693,16 @@ impl AppleContainerClient {
         Ok(())
     }
 
-    /// Create a multi-node cluster with separate control-plane and worker nodes
+    /// Create a multi-node cluster with separate control-plane and worker nodes.
+    /// With `control_plane_nodes > 1` the control plane is HA behind a kube-vip VIP.
     async fn create_multi_node_cluster(
         &self,
         options: &CreateClusterOptions,
         worker_count: u32,
+        kubeadm: &KubeadmConfigOptions,
     ) -> Result<()> {
         let cp_name = format!("{}-control-plane", options.name);
+        let control_plane_count = options.control_plane_nodes.unwrap_or(1).max(1);
 
         // Resolve the effective CNI plugin: CLI flag (options.cni_plugin) overrides config default.
         let cni = select_cni(
@@ -988,6 +2719,10 @@ impl AppleContainerClient {
             options.node_kernel_path.as_deref(),
             options.control_plane_cpus,
             &options.control_plane_memory,
+            &kubeadm.control_plane_volumes,
+            kubeadm.api_server_port(),
+            cni_label(&cni, options.skip_cni),
+            kubeadm.container_runtime,
         )
         .await?;
 
@@ -996,41 +2731,154 @@ impl AppleContainerClient {
         let cp_ip = self.get_container_ip(&cp_name).await?;
         info!("Control-plane '{}' running at IP: {}", cp_name, cp_ip);
 
+        // HA: every node reaches the API through a VIP held by kube-vip, and
+        // kubeadm uploads the control-plane certificates for the joining nodes.
+        let mut kubeadm = kubeadm.clone();
+        if control_plane_count > 1 {
+            let vip = match &options.control_plane_vip {
+                Some(vip) => vip.clone(),
+                None => control_plane_vip(&cp_ip, &self.container_ips()?)?,
+            };
+            info!("Control-plane endpoint VIP: {}", vip);
+            let certificate_key = self
+                .run_node_script(&cp_name, "kubeadm certs certificate-key")
+                .await?
+                .trim()
+                .to_string();
+            self.run_container_command(&[
+                "exec",
+                &cp_name,
+                "mkdir",
+                "-p",
+                "/etc/kubernetes/manifests",
+            ])?;
+            self.write_file_to_container(
+                &cp_name,
+                KUBE_VIP_MANIFEST_PATH,
+                kube_vip_manifest(
+                    &vip,
+                    kubeadm.api_server_port(),
+                    "/etc/kubernetes/super-admin.conf",
+                )
+                .as_bytes(),
+            )?;
+            kubeadm.control_plane_endpoint = Some(vip);
+            kubeadm.certificate_key = Some(certificate_key);
+        }
+        let kubeadm = &kubeadm;
+        let endpoint_host = kubeadm
+            .control_plane_endpoint
+            .clone()
+            .unwrap_or_else(|| cp_ip.clone());
+
         // 3. Initialize Kubernetes on control-plane and get join info
-        let join_info = self
+        let mut join_info = self
             .initialize_kubernetes_cluster_with_join_info(
                 &cp_name,
                 &cp_ip,
                 &options.name,
                 options.node_kernel_path.as_deref(),
+                kubeadm,
             )
             .await?;
+        join_info.control_plane_endpoint =
+            format!("{}:{}", endpoint_host, kubeadm.api_server_port());
+
+        if kubeadm.certificate_key.is_some() {
+            info!("Uploading control-plane certificates for joining control-plane nodes");
+            self.run_node_script(
+                &cp_name,
+                "kubeadm init phase upload-certs --upload-certs --config=/kind/kubeadm.conf",
+            )
+            .await
+            .context("Failed to upload control-plane certificates")?;
+        }
 
         // 4. Setup kubeconfig early (user gets kubectl access even if workers fail)
-        self.setup_kubeconfig(&options.name, &cp_name, &cp_ip)
+        if options.skip_kubeconfig {
+            info!("Skipping kubeconfig setup for cluster '{}'", options.name);
+        } else {
+            self.setup_kubeconfig(
+                &options.name,
+                &cp_name,
+                &endpoint_host,
+                kubeadm.api_server_port(),
+            )
             .await?;
+        }
 
         // 5. Install CNI on control-plane (must be before workers join)
         // Pass kernel_path so Cilium selects the full-eBPF or stock workaround profile.
-        self.install_cni_plugin(&cp_name, cni.clone(), options.node_kernel_path.as_deref())
-            .await?;
+        if options.skip_cni {
+            info!("Skipping CNI installation for cluster '{}'", options.name);
+        } else {
+            self.install_cni_plugin(&cp_name, cni.clone(), options.node_kernel_path.as_deref())
+                .await?;
+        }
 
         // Track every node and its VM IP so PTP cross-node routing can be set up
         // once all workers have joined and been assigned pod CIDRs.
         let mut all_nodes: Vec<(String, String)> = vec![(cp_name.clone(), cp_ip.clone())];
 
-        // 6. Create and join worker nodes sequentially
-        for i in 0..worker_count {
-            // KIND convention: first worker is {name}-worker, subsequent are {name}-worker-N
-            let worker_name = if i == 0 {
-                format!("{}-worker", options.name)
-            } else {
-                format!("{}-worker-{}", options.name, i + 1)
-            };
-
+        // 6. Join the additional control-plane nodes (HA)
+        for index in 2..=control_plane_count {
+            let node_name = control_plane_node_name(&options.name, index);
             info!(
-                "Creating worker node {}/{}: {}",
-                i + 1,
+                "Creating control-plane node {}/{}: {}",
+                index, control_plane_count, node_name
+            );
+            self.create_control_plane_node(
+                &options.name,
+                &node_name,
+                &options.image,
+                false,
+                options.node_kernel_path.as_deref(),
+                options.control_plane_cpus,
+                &options.control_plane_memory,
+                &kubeadm.control_plane_volumes,
+                kubeadm.api_server_port(),
+                cni_label(&cni, options.skip_cni),
+                kubeadm.container_runtime,
+            )
+            .await?;
+
+            self.wait_for_container_ready(&node_name).await?;
+            let node_ip = self.get_container_ip(&node_name).await?;
+            info!("Control-plane '{}' running at IP: {}", node_name, node_ip);
+
+            self.write_kubeadm_files(&node_name, kubeadm)?;
+            let certificate_key = kubeadm.certificate_key.as_deref().unwrap_or_default();
+            let join_config =
+                generate_control_plane_join_config(&node_ip, &join_info, certificate_key, kubeadm);
+            self.run_kubeadm_join(&node_name, &join_config, kubeadm)
+                .await?;
+            if let Some(vip) = &kubeadm.control_plane_endpoint {
+                self.write_file_to_container(
+                    &node_name,
+                    KUBE_VIP_MANIFEST_PATH,
+                    kube_vip_manifest(vip, kubeadm.api_server_port(), "/etc/kubernetes/admin.conf")
+                        .as_bytes(),
+                )?;
+            }
+
+            if !options.skip_cni && matches!(cni, CniPlugin::Ptp) {
+                self.install_ptp_cni(&node_name).await?;
+            }
+            all_nodes.push((node_name, node_ip));
+        }
+
+        // 7. Create and join worker nodes sequentially
+        for i in 0..worker_count {
+            // KIND convention: first worker is {name}-worker, subsequent are {name}-worker-N
+            let worker_name = if i == 0 {
+                format!("{}-worker", options.name)
+            } else {
+                format!("{}-worker-{}", options.name, i + 1)
+            };
+
+            info!(
+                "Creating worker node {}/{}: {}",
+                i + 1,
                 worker_count,
                 worker_name
             );
@@ -1049,13 +2897,21 @@ impl AppleContainerClient {
             let worker_ip = self.get_container_ip(&worker_name).await?;
             info!("Worker '{}' running at IP: {}", worker_name, worker_ip);
 
-            self.join_worker_node(&worker_name, &worker_ip, &join_info)
+            let worker_files = KubeadmConfigOptions {
+                node_files: kubeadm.node_files.clone(),
+                containerd_config: kubeadm.containerd_config.clone(),
+                container_runtime: kubeadm.container_runtime,
+                ..Default::default()
+            };
+            self.write_kubeadm_files(&worker_name, &worker_files)?;
+
+            self.join_worker_node(&worker_name, &worker_ip, &join_info, kubeadm)
                 .await?;
 
             // PTP CNI requires the config file on each node (it's not a DaemonSet).
             // Cilium deploys as a DaemonSet from the control-plane and auto-rolls to workers.
             // Use the resolved cni (via select_cni) so --cni flag is honoured per-worker too.
-            if matches!(cni, CniPlugin::Ptp) {
+            if !options.skip_cni && matches!(cni, CniPlugin::Ptp) {
                 self.install_ptp_cni(&worker_name).await?;
             }
 
@@ -1066,7 +2922,7 @@ impl AppleContainerClient {
         // routes so pods on workers can reach CoreDNS (and other cross-node pods). This
         // must run after all workers have joined so every node has an assigned podCIDR.
         // A routing failure is non-fatal: the cluster is usable for same-node workloads.
-        if matches!(cni, CniPlugin::Ptp) && all_nodes.len() > 1 {
+        if !options.skip_cni && matches!(cni, CniPlugin::Ptp) && all_nodes.len() > 1 {
             if let Err(e) = self
                 .configure_ptp_cross_node_routing(&cp_name, &all_nodes)
                 .await
@@ -1080,7 +2936,7 @@ impl AppleContainerClient {
 
         // After all workers have joined, re-run the Cilium readiness gate and then
         // wait for all nodes to be Ready before reporting success.
-        if matches!(cni, CniPlugin::Cilium) {
+        if !options.skip_cni && matches!(cni, CniPlugin::Cilium) {
             // Issue #43: worker kubelets submit their serving CSRs only after they join,
             // so the approval during control-plane Cilium install does not cover them.
             // Re-approve now so `cilium status` can exec into the worker cilium pods;
@@ -1105,21 +2961,34 @@ impl AppleContainerClient {
         }
 
         // Final gate: wait for all nodes to be Ready before reporting success.
-        info!("Waiting for all nodes to be Ready");
-        let node_wait_cmd =
-            "kubectl wait --for=condition=Ready node --all --timeout=300s --kubeconfig=/etc/kubernetes/admin.conf";
-        let mut cmd = std::process::Command::new(&self.cli_path);
-        cmd.args(["exec", &cp_name, "sh", "-c", node_wait_cmd]);
-        let output = cmd.output().context("Failed to run node readiness gate")?;
-        if !output.status.success() {
-            warn!(
-                "Node readiness gate timed out: {}",
-                String::from_utf8_lossy(&output.stderr)
-            );
+        // Nodes never become Ready without a CNI, so there is nothing to wait for.
+        if !options.skip_cni {
+            info!("Waiting for all nodes to be Ready");
+            let node_wait_cmd =
+                "kubectl wait --for=condition=Ready node --all --timeout=300s --kubeconfig=/etc/kubernetes/admin.conf";
+            let mut cmd = std::process::Command::new(&self.cli_path);
+            cmd.args(["exec", &cp_name, "sh", "-c", node_wait_cmd]);
+            let output = cmd.output().context("Failed to run node readiness gate")?;
+            if !output.status.success() {
+                warn!(
+                    "Node readiness gate timed out: {}",
+                    String::from_utf8_lossy(&output.stderr)
+                );
+            }
         }
 
-        // Note: Do NOT remove control-plane taint in multi-node mode.
-        // Workers handle workloads; control-plane stays dedicated.
+        let node_names: Vec<String> = all_nodes.iter().map(|(name, _)| name.clone()).collect();
+        self.label_nodes(&cp_name, &node_names, &options.node_labels)
+            .await?;
+
+        // Keep control planes dedicated when there are workers; an HA control
+        // plane without workers has to run the workloads itself.
+        if worker_count == 0 {
+            for index in 1..=control_plane_count {
+                let node = control_plane_node_name(&options.name, index);
+                self.remove_control_plane_taint(&node, &node).await?;
+            }
+        }
 
         info!(
             "Multi-node Kubernetes cluster '{}' initialized successfully",
@@ -1188,39 +3057,31 @@ impl AppleContainerClient {
         kernel_path: Option<&std::path::Path>,
         cpus: u32,
         memory: &str,
+        skip_kubeconfig: bool,
+        skip_cni: bool,
+        kubeadm: &KubeadmConfigOptions,
+        node_labels: &BTreeMap<String, String>,
     ) -> Result<()> {
         info!("Creating single Kubernetes node '{}'", node_name);
 
-        let cluster_label = format!("io.kina.cluster={}", cluster_name);
-        let image_label = format!("io.kina.image={}", image);
-
-        // Create container with appropriate labels for single-node cluster
-        let mut cmd = std::process::Command::new(&self.cli_path);
-
-        let mut args = vec![
-            "run",
-            "-d", // Run in detached mode
-            "--name",
-            node_name,
-            "--label",
-            &cluster_label,
-            "--label",
-            "io.kina.role=control-plane,worker", // Combined roles
-            "--label",
-            "io.kina.primary=true",
-            "--label",
-            "io.kina.single-node=true",
-            "--label",
-            &image_label,
-        ];
+        // Container with the labels of both roles for a single-node cluster
+        let mut spec = ContainerSpecBuilder::new(node_name, image)
+            .label("io.kina.cluster", cluster_name)
+            .label("io.kina.role", "control-plane,worker") // Combined roles
+            .label("io.kina.primary", "true")
+            .label("io.kina.single-node", "true")
+            .label("io.kina.image", image)
+            .label(API_SERVER_PORT_LABEL, &kubeadm.api_server_port().to_string())
+            .label(CNI_LABEL, cni_label(&cni, skip_cni))
+            .label(
+                CONTAINER_RUNTIME_LABEL,
+                &kubeadm.container_runtime.to_string(),
+            );
 
         // Add tmpfs mounts for systemd in VM
-        args.extend_from_slice(&["--tmpfs", "/tmp", "--tmpfs", "/run", "--tmpfs", "/run/lock"]);
-
-        // Add required capabilities for Kubernetes node workloads.
-        // Apple Container has no privileged-mode flag; since 0.12.0 the default cap set
-        // is insufficient for systemd, kubeadm, kubelet, containerd, and Cilium eBPF.
-        args.extend_from_slice(&node_cap_args());
+        for path in ["/tmp", "/run", "/run/lock"] {
+            spec = spec.tmpfs(path);
+        }
 
         // Resource allocation: resolved CPUs and memory per node.
         // The Apple Container default (4 vCPUs / 1024 MB) is insufficient for a full
@@ -1228,55 +3089,25 @@ impl AppleContainerClient {
         // cilium-operator + Envoy DaemonSet + Hubble). OOM kills cascade into
         // control-plane component crashes that look like TLS / leader-election failures.
         // 4 GB is the minimum for a stable full-eBPF Cilium cluster.
-        let res_owned = node_resource_args(cpus, memory);
-        let res_refs: Vec<&str> = res_owned.iter().map(|s| s.as_str()).collect();
-        args.extend_from_slice(&res_refs);
-
-        // Inject custom kernel args when a kernel path is configured.
-        // node_kernel_args returns ["--kernel", "<path>"] or [] for stock kernel.
-        let kernel_args_owned = node_kernel_args(kernel_path);
-        let kernel_args_refs: Vec<&str> = kernel_args_owned.iter().map(|s| s.as_str()).collect();
-        args.extend_from_slice(&kernel_args_refs);
-
+        //
         // Note: No port mapping needed - Apple Container VM gets its own IP
-        // Kubernetes API server will be accessible at <vm-ip>:6443
+        // Kubernetes API server will be accessible at <vm-ip>:6443 (or --api-server-port)
         // Ingress controllers will be accessible at <vm-ip>:80, <vm-ip>:443
         // Services can be reached directly at VM IP address
-
-        // Set up environment for containerized systemd in VM
-        let hostname_env = format!("HOSTNAME={}", node_name);
-        args.extend_from_slice(&[
-            "--env",
-            "container=docker",
-            "--env",
-            &hostname_env,
-            "--env",
-            "KINA_NODE_TYPE=single-node",
-            image,
-            "/sbin/init", // Start systemd in VM
-        ]);
-
-        cmd.args(&args);
-
-        // Debug: Print the exact command being executed
-        debug!(
-            "Executing Apple Container command: {} {:?}",
-            self.cli_path, args
-        );
-
-        let output = cmd
-            .output()
+        let spec = spec
+            .resources(cpus, memory)
+            .kernel(kernel_path)
+            .volumes(&kubeadm.control_plane_volumes)
+            // Set up environment for containerized systemd in VM
+            .env("container", "docker")
+            .env("HOSTNAME", node_name)
+            .env("KINA_NODE_TYPE", "single-node")
+            .command(["/sbin/init"]) // Start systemd in VM
+            .build()?;
+
+        self.run_container_spec(&spec)
             .context("Failed to create single-node cluster")?;
 
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(anyhow::anyhow!(
-                "Failed to create single-node cluster '{}': {}",
-                node_name,
-                stderr
-            ));
-        }
-
         info!("Container '{}' created, waiting for startup...", node_name);
 
         // Wait for container to be fully running
@@ -1287,21 +3118,39 @@ impl AppleContainerClient {
         info!("Container '{}' running at IP: {}", node_name, vm_ip);
 
         // Initialize Kubernetes cluster (kernel_path determines full-eBPF vs stock kubeadm profile)
-        self.initialize_kubernetes_cluster(node_name, &vm_ip, kernel_path)
+        self.initialize_kubernetes_cluster(node_name, &vm_ip, kernel_path, kubeadm)
             .await?;
 
         // Generate and save kubeconfig immediately after cluster init
         // This ensures user has kubectl access even if CNI installation fails
-        self.setup_kubeconfig(cluster_name, node_name, &vm_ip)
+        if skip_kubeconfig {
+            info!("Skipping kubeconfig setup for cluster '{}'", cluster_name);
+        } else {
+            self.setup_kubeconfig(cluster_name, node_name, &vm_ip, kubeadm.api_server_port())
+                .await?;
+        }
+
+        // The Kubernetes node name differs from the container's under --node-name
+        let kube_node = kubeadm.node_name.as_deref().unwrap_or(node_name);
+        self.label_nodes(node_name, &[kube_node.to_string()], node_labels)
             .await?;
 
         // Remove control-plane taint for single-node scheduling
-        self.remove_control_plane_taint(node_name).await?;
+        self.remove_control_plane_taint(node_name, kube_node)
+            .await?;
 
         // Install CNI plugin (now user has kubectl access if this fails)
         // Use the resolved CNI plugin (CLI flag overrides config default).
         // Pass kernel_path so Cilium selects the full-eBPF or stock workaround profile.
-        self.install_cni_plugin(node_name, cni, kernel_path).await?;
+        // A custom podSubnet replaces PTP's default single-node subnet.
+        let pod_subnet = kubeadm.networking.pod_subnet.as_deref();
+        if skip_cni {
+            info!("Skipping CNI installation for cluster '{}'", cluster_name);
+        } else if let (CniPlugin::Ptp, Some(subnet)) = (&cni, pod_subnet) {
+            self.install_ptp_cni_with_subnet(node_name, subnet).await?;
+        } else {
+            self.install_cni_plugin(node_name, cni, kernel_path).await?;
+        }
 
         info!(
             "Kubernetes cluster '{}' initialized successfully",
@@ -1321,11 +3170,18 @@ impl AppleContainerClient {
         kernel_path: Option<&std::path::Path>,
         cpus: u32,
         memory: &str,
+        volumes: &[(std::path::PathBuf, String)],
+        api_server_port: u16,
+        cni: &str,
+        runtime: ContainerRuntime,
     ) -> Result<()> {
         info!("Creating control plane node '{}'", node_name);
 
         let cluster_label = format!("io.kina.cluster={}", cluster_name);
         let image_label = format!("io.kina.image={}", image);
+        let port_label = format!("{}={}", API_SERVER_PORT_LABEL, api_server_port);
+        let cni_label = format!("{}={}", CNI_LABEL, cni);
+        let runtime_label = format!("{}={}", CONTAINER_RUNTIME_LABEL, runtime);
 
         // Create container with appropriate labels and configuration
         // Apple Container automatically assigns VM and IP address - no explicit network needed
@@ -1342,6 +3198,12 @@ impl AppleContainerClient {
             "io.kina.role=control-plane",
             "--label",
             &image_label,
+            "--label",
+            &port_label,
+            "--label",
+            &cni_label,
+            "--label",
+            &runtime_label,
         ];
 
         if is_primary {
@@ -1369,6 +3231,11 @@ impl AppleContainerClient {
             cp_kernel_args_owned.iter().map(|s| s.as_str()).collect();
         args.extend_from_slice(&cp_kernel_args_refs);
 
+        let cp_volume_args_owned = node_volume_args(volumes);
+        let cp_volume_args_refs: Vec<&str> =
+            cp_volume_args_owned.iter().map(|s| s.as_str()).collect();
+        args.extend_from_slice(&cp_volume_args_refs);
+
         // Set up environment for containerized systemd in VM
         let hostname_env = format!("HOSTNAME={}", node_name);
         args.extend_from_slice(&[
@@ -1482,8 +3349,100 @@ impl AppleContainerClient {
         Ok(())
     }
 
+    /// Raw `container inspect <name>` data
+    pub async fn inspect_container(&self, name: &str) -> Result<serde_json::Value> {
+        let output = std::process::Command::new(&self.cli_path)
+            .args(["inspect", name])
+            .output()
+            .context("Failed to execute Apple Container CLI")?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Failed to inspect '{}': {}",
+                name,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        parse_inspect_output(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    /// All Apple Container networks
+    pub async fn list_networks(&self) -> Result<Vec<NetworkInfo>> {
+        let output = std::process::Command::new(&self.cli_path)
+            .args(["network", "list", "--format", "json"])
+            .output()
+            .context("Failed to execute Apple Container CLI")?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "container network list failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        parse_network_list(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    /// Create a kina-labelled network
+    pub async fn create_network(
+        &self,
+        name: &str,
+        subnet: Option<&str>,
+        labels: &BTreeMap<String, String>,
+    ) -> Result<NetworkInfo> {
+        let spec = NetworkSpecBuilder::new()
+            .name(name)
+            .subnet(subnet)
+            .labels(labels)
+            .build()?;
+        self.run_network_create(&spec).await
+    }
+
+    /// Create the network described by `spec` (see [`network_create_args`])
+    /// and return it as Apple Container now lists it.
+    async fn run_network_create(&self, spec: &NetworkSpec) -> Result<NetworkInfo> {
+        info!("Creating network '{}'", spec.name);
+        let output = std::process::Command::new(&self.cli_path)
+            .args(network_create_args(spec))
+            .output()
+            .context("Failed to execute Apple Container CLI")?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Failed to create network '{}': {}",
+                spec.name,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        let created = self
+            .list_networks()
+            .await?
+            .into_iter()
+            .find(|n| n.name == spec.name);
+        Ok(created.unwrap_or_else(|| NetworkInfo {
+            name: spec.name.clone(),
+            state: "unknown".to_string(),
+            subnet: spec.subnet.clone(),
+            labels: spec.labels.clone(),
+        }))
+    }
+
+    /// Delete a network
+    pub async fn delete_network(&self, name: &str) -> Result<()> {
+        info!("Deleting network '{}'", name);
+        let output = std::process::Command::new(&self.cli_path)
+            .args(["network", "delete", name])
+            .output()
+            .context("Failed to execute Apple Container CLI")?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Failed to delete network '{}': {}",
+                name,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(())
+    }
+
     /// Delete a cluster
-    pub async fn delete_cluster(&self, name: &str) -> Result<()> {
+    pub async fn delete_cluster(&self, name: &str, options: &DeleteClusterOptions) -> Result<()> {
         info!("Deleting cluster '{}'", name);
 
         // Find all containers belonging to this cluster
@@ -1494,12 +3453,25 @@ impl AppleContainerClient {
             // Delete all containers in the cluster
             for node in &cluster.nodes {
                 if let Some(container_id) = &node.container_id {
-                    self.delete_container(container_id).await?;
+                    if let Err(e) = self.delete_container(container_id, options).await {
+                        if !options.force {
+                            return Err(e);
+                        }
+                        warn!("Ignoring failure to delete '{}': {:#}", node.name, e);
+                    }
                 }
             }
 
             // Remove kubeconfig context
-            self.remove_kubeconfig_context(name).await?;
+            if let Err(e) = self.remove_kubeconfig_context(name).await {
+                if !options.force {
+                    return Err(e);
+                }
+                warn!(
+                    "Ignoring kubeconfig cleanup failure for '{}': {:#}",
+                    name, e
+                );
+            }
 
             // Note: No explicit network cleanup needed for Apple Container
             // VM-per-container architecture handles networking automatically
@@ -1511,95 +3483,443 @@ impl AppleContainerClient {
         Ok(())
     }
 
-    /// Delete a container
-    async fn delete_container(&self, container_id: &str) -> Result<()> {
-        debug!("Deleting container '{}'", container_id);
-
-        // Try graceful stop, then force stop with SIGKILL
-        let stop_attempts = [
-            ("stop", None, None),                  // Graceful stop (SIGTERM, 5s default)
-            ("stop", Some("SIGKILL"), Some("10")), // Force stop with SIGKILL and 10s timeout
-        ];
+    /// Rename a cluster: stop its nodes, rename each container and relabel it
+    /// with the new `io.kina.cluster` value, restart the nodes, then move the
+    /// kubeconfig entries over to the new name.
+    ///
+    /// If a container can't be renamed or relabelled, the containers already
+    /// renamed are moved back and the original nodes are restarted.
+    pub async fn rename_cluster(&self, old_name: &str, new_name: &str) -> Result<()> {
+        info!("Renaming cluster '{}' to '{}'", old_name, new_name);
 
-        for (attempt, (cmd_name, signal, timeout)) in stop_attempts.iter().enumerate() {
-            let signal_desc = signal.map(|s| format!(" with {}", s)).unwrap_or_default();
-            info!(
-                "Attempting to {} container '{}'{} (attempt {})",
-                cmd_name,
-                container_id,
-                signal_desc,
-                attempt + 1
-            );
+        let clusters = self.list_clusters().await?;
+        let cluster = clusters
+            .into_iter()
+            .find(|c| c.name == old_name)
+            .ok_or_else(|| anyhow::anyhow!("Cluster '{}' not found", old_name))?;
 
-            let mut cmd = std::process::Command::new(&self.cli_path);
-            cmd.arg(cmd_name);
+        self.check_rename_support()?;
 
-            if let Some(sig) = signal {
-                cmd.args(["--signal", sig]);
-            }
-            if let Some(time) = timeout {
-                cmd.args(["--time", time]);
-            }
+        let mut progress = RenameProgress::default();
+        if let Err(e) = self.rename_nodes(&cluster, old_name, new_name, &mut progress) {
+            warn!("Rename failed, rolling back: {}", e);
+            self.rollback_rename(old_name, &progress);
+            return Err(e.context(format!(
+                "Failed to rename cluster '{}' to '{}'",
+                old_name, new_name
+            )));
+        }
 
-            cmd.arg(container_id);
+        // Only the nodes that were running before the rename are started again
+        for original in &progress.stopped {
+            let new_node = renamed_node_name(original, old_name, new_name);
+            info!("Starting node '{}'", new_node);
+            self.run_container_command(&["start", &new_node])?;
+            self.wait_for_container_ready(&new_node).await?;
+        }
 
-            let output = cmd
-                .output()
-                .context("Failed to execute container command")?;
+        self.rename_kubeconfig(old_name, new_name).await?;
 
-            if output.status.success() {
-                let stop_desc = if let Some(sig) = signal {
-                    format!("{} ({})", cmd_name, sig)
-                } else {
-                    cmd_name.to_string()
-                };
-                info!("Successfully {} container '{}'", stop_desc, container_id);
+        info!("Cluster '{}' renamed to '{}'", old_name, new_name);
+        Ok(())
+    }
 
-                // Wait for container to fully stop - increased timeout for Apple Container VMs
-                tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
-                break;
-            } else {
-                let stderr = String::from_utf8_lossy(&output.stderr);
-                if stderr.contains("is not running")
-                    || stderr.contains("No such container")
-                    || stderr.contains("cannot kill: container is not running")
-                {
-                    debug!(
-                        "Container '{}' already stopped or doesn't exist",
-                        container_id
-                    );
-                    break;
-                } else if attempt == 0 {
-                    warn!(
-                        "Graceful stop failed for '{}': {}, trying force stop",
-                        container_id, stderr
-                    );
-                    continue;
-                } else {
-                    warn!(
-                        "All stop attempts failed for '{}': {}, proceeding with removal anyway",
-                        container_id, stderr
-                    );
-                    break; // Try removal anyway - Apple Container might have state sync issues
-                }
+    /// Fail before any node is touched when the container CLI lacks the
+    /// `rename` subcommand or `update --label`, which a rename relies on.
+    fn check_rename_support(&self) -> Result<()> {
+        let root_help = self.container_help(&[])?;
+        for subcommand in ["rename", "update"] {
+            if !help_lists_subcommand(&root_help, subcommand) {
+                return Err(anyhow::anyhow!(
+                    "Apple Container CLI has no `container {}` subcommand; \
+                     upgrade it to rename clusters",
+                    subcommand
+                ));
             }
         }
+        if !self.container_help(&["update"])?.contains("--label") {
+            return Err(anyhow::anyhow!(
+                "Apple Container CLI does not support `container update --label`; \
+                 upgrade it to rename clusters"
+            ));
+        }
+        Ok(())
+    }
 
-        // Remove the container - try normal removal first, then force if needed
-        for (attempt, use_force) in [false, true].iter().enumerate() {
-            let mut cmd = std::process::Command::new(&self.cli_path);
-            cmd.arg("delete"); // Use 'delete' not 'rm'
+    /// `container <args> --help` output
+    fn container_help(&self, args: &[&str]) -> Result<String> {
+        let output = std::process::Command::new(&self.cli_path)
+            .args(args)
+            .arg("--help")
+            .output()
+            .context("Failed to execute Apple Container CLI")?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "container {} --help failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
 
-            if *use_force {
-                cmd.arg("--force");
-                info!("Attempting force removal of container '{}'", container_id);
-            } else {
-                info!("Attempting removal of container '{}'", container_id);
-            }
+    /// Stop the running nodes, then rename and relabel every node, recording
+    /// each step in `progress` so a failure can be undone.
+    fn rename_nodes(
+        &self,
+        cluster: &ClusterInfo,
+        old_name: &str,
+        new_name: &str,
+        progress: &mut RenameProgress,
+    ) -> Result<()> {
+        for node in cluster.nodes.iter().filter(|n| n.status == "running") {
+            info!("Stopping node '{}'", node.name);
+            self.run_container_command(&["stop", &node.name])?;
+            progress.stopped.push(node.name.clone());
+        }
 
-            cmd.arg(container_id);
+        for node in &cluster.nodes {
+            let new_node = renamed_node_name(&node.name, old_name, new_name);
+            self.run_container_command(&["rename", &node.name, &new_node])?;
+            progress.renamed.push((node.name.clone(), new_node.clone()));
+            self.run_container_command(&[
+                "update",
+                "--label",
+                &format!("io.kina.cluster={}", new_name),
+                &new_node,
+            ])
+            .context("Apple Container could not update the io.kina.cluster label")?;
+            progress.relabeled.push(new_node);
+        }
+        Ok(())
+    }
 
-            let output = cmd.output().context("Failed to remove container")?;
+    /// Undo a partial rename in reverse: restore labels and names, then start
+    /// the nodes that were stopped for it. Failures are logged, not returned.
+    fn rollback_rename(&self, old_name: &str, progress: &RenameProgress) {
+        let old_label = format!("io.kina.cluster={}", old_name);
+        for new_node in progress.relabeled.iter().rev() {
+            if let Err(e) = self.run_container_command(&["update", "--label", &old_label, new_node])
+            {
+                warn!("Failed to restore the label of '{}': {}", new_node, e);
+            }
+        }
+        for (original, new_node) in progress.renamed.iter().rev() {
+            if let Err(e) = self.run_container_command(&["rename", new_node, original]) {
+                warn!("Failed to restore container name '{}': {}", original, e);
+            }
+        }
+        for original in &progress.stopped {
+            if let Err(e) = self.run_container_command(&["start", original]) {
+                warn!("Failed to restart node '{}': {}", original, e);
+            }
+        }
+    }
+
+    /// Stop every running node of a cluster, workers first so the control
+    /// plane doesn't spend its last seconds marking them NotReady. Containers
+    /// and their disks are kept; `container start` brings them back.
+    pub async fn pause_cluster(&self, name: &str) -> Result<()> {
+        info!("Pausing cluster '{}'", name);
+
+        let cluster = self
+            .list_clusters()
+            .await?
+            .into_iter()
+            .find(|c| c.name == name)
+            .ok_or_else(|| anyhow::anyhow!("Cluster '{}' not found", name))?;
+
+        let mut running: Vec<&NodeInfo> = cluster
+            .nodes
+            .iter()
+            .filter(|n| n.status == "running")
+            .collect();
+        running.sort_by_key(|n| n.role == NodeRole::ControlPlane);
+
+        for node in running {
+            info!("Stopping node '{}'", node.name);
+            self.run_container_command(&["stop", &node.name])
+                .with_context(|| format!("Failed to stop node '{}'", node.name))?;
+        }
+
+        info!("Cluster '{}' paused", name);
+        Ok(())
+    }
+
+    /// Start every stopped node of a cluster, control plane first, and wait
+    /// until the API server answers again. Serving CSRs are re-approved since
+    /// kubelet certificates may have rotated while the nodes were down.
+    ///
+    /// Returns the control plane's IP, which may differ from before the stop.
+    pub async fn resume_cluster(&self, name: &str) -> Result<String> {
+        info!("Resuming cluster '{}'", name);
+
+        let cluster = self
+            .list_clusters()
+            .await?
+            .into_iter()
+            .find(|c| c.name == name)
+            .ok_or_else(|| anyhow::anyhow!("Cluster '{}' not found", name))?;
+
+        let mut stopped: Vec<&NodeInfo> = cluster
+            .nodes
+            .iter()
+            .filter(|n| n.status != "running")
+            .collect();
+        stopped.sort_by_key(|n| n.role != NodeRole::ControlPlane);
+
+        for node in stopped {
+            info!("Starting node '{}'", node.name);
+            self.run_container_command(&["start", &node.name])
+                .with_context(|| format!("Failed to start node '{}'", node.name))?;
+            self.wait_for_container_ready(&node.name).await?;
+        }
+
+        let cp_name = format!("{}-control-plane", name);
+        info!("Waiting for the API server on '{}'", cp_name);
+        let retry = &self.config.apple_container.retry;
+        retry_with_backoff(
+            retry.max_attempts,
+            retry.initial_delay(),
+            retry.backoff_factor,
+            || async { self.check_api_server_ready(&cp_name) },
+        )
+        .await
+        .context("API server did not become ready after resume")?;
+
+        self.approve_cluster_kubelet_csrs(name);
+
+        let ip = self.get_container_ip(&cp_name).await?;
+        info!("Cluster '{}' resumed", name);
+        Ok(ip)
+    }
+
+    /// Succeed when the API server's `/readyz` endpoint answers inside the node.
+    fn check_api_server_ready(&self, cp_name: &str) -> Result<()> {
+        let output = std::process::Command::new(&self.cli_path)
+            .args([
+                "exec",
+                cp_name,
+                "sh",
+                "-c",
+                "kubectl get --raw /readyz --kubeconfig=/etc/kubernetes/admin.conf",
+            ])
+            .output()
+            .context("Failed to query API server readiness")?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "API server not ready yet: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Move the `~/.kube/<old>` kubeconfig to `~/.kube/<new>` with its entries
+    /// renamed, and replace the old context in the merged kubeconfig.
+    async fn rename_kubeconfig(&self, old_name: &str, new_name: &str) -> Result<()> {
+        let home_dir = std::env::var("HOME").context("HOME environment variable not set")?;
+        let old_path = std::path::Path::new(&home_dir).join(".kube").join(old_name);
+
+        if !old_path.exists() {
+            warn!(
+                "No kubeconfig found at {}; skipping kubeconfig rename",
+                old_path.display()
+            );
+            return Ok(());
+        }
+
+        let kubeconfig =
+            fs::read_to_string(&old_path).context("Failed to read individual kubeconfig file")?;
+        let renamed = rename_kubeconfig_entries(&kubeconfig, old_name, new_name)?;
+
+        self.remove_kubeconfig_context(old_name).await?;
+        self.save_kubeconfig(new_name, &renamed).await
+    }
+
+    /// Read the kubeadm init configuration (`/kind/kubeadm.conf`) from a cluster's
+    /// control-plane node.
+    pub async fn read_kubeadm_config(&self, cluster_name: &str) -> Result<String> {
+        let cp_name = format!("{}-control-plane", cluster_name);
+        let output = std::process::Command::new(&self.cli_path)
+            .args(["exec", &cp_name, "cat", "/kind/kubeadm.conf"])
+            .output()
+            .context("Failed to execute Apple Container CLI")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow::anyhow!(
+                "Failed to read kubeadm config from '{}': {}",
+                cp_name,
+                stderr.trim()
+            ));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// Pull a node image into the local Apple Container image store, optionally
+    /// for another platform (`linux/amd64`).
+    pub async fn pull_image(&self, image: &str, platform: Option<&str>) -> Result<()> {
+        info!("Pulling image '{}'", image);
+        let mut args = vec!["image", "pull"];
+        if let Some(platform) = platform {
+            args.extend(["--platform", platform]);
+        }
+        args.push(image);
+        self.run_container_command(&args)
+    }
+
+    /// Check that a container can be created from `image` by creating, then
+    /// deleting, a throwaway container for `platform`. Nothing is started.
+    pub async fn verify_image(&self, image: &str, platform: Option<&str>) -> Result<()> {
+        let probe = format!("kina-image-check-{}", std::process::id());
+        let args = verify_image_create_args(&probe, image, platform);
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        self.run_container_command(&args)
+            .with_context(|| format!("Image '{}' cannot be used to create a container", image))?;
+        if let Err(e) = self.run_container_command(&["delete", &probe]) {
+            warn!("Failed to remove image check container '{}': {}", probe, e);
+        }
+        Ok(())
+    }
+
+    /// IPv4 addresses of every container, kina-managed or not
+    fn container_ips(&self) -> Result<Vec<String>> {
+        let output = std::process::Command::new(&self.cli_path)
+            .args(["list", "--format", "json", "--all"])
+            .output()
+            .context("Failed to execute Apple Container CLI")?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "container list failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(
+            parse_container_list(&String::from_utf8_lossy(&output.stdout))?
+                .into_iter()
+                .filter_map(|container| container.ipv4)
+                .collect(),
+        )
+    }
+
+    /// Run a one-shot Apple Container CLI command, failing with its stderr.
+    fn run_container_command(&self, args: &[&str]) -> Result<()> {
+        let output = std::process::Command::new(&self.cli_path)
+            .args(args)
+            .output()
+            .context("Failed to execute Apple Container CLI")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow::anyhow!(
+                "container {} failed: {}",
+                args.join(" "),
+                stderr.trim()
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Delete a container, giving it the grace period to stop cleanly; with
+    /// `force`, skip the stop and `container delete --force` it right away
+    async fn delete_container(
+        &self,
+        container_id: &str,
+        options: &DeleteClusterOptions,
+    ) -> Result<()> {
+        debug!("Deleting container '{}'", container_id);
+
+        let stop_attempts = if options.force {
+            Vec::new()
+        } else {
+            container_stop_attempts(options.grace_period)
+        };
+        for (attempt, (signal, timeout)) in stop_attempts.iter().enumerate() {
+            let cmd_name = "stop";
+            let signal_desc = signal.map(|s| format!(" with {}", s)).unwrap_or_default();
+            info!(
+                "Attempting to {} container '{}'{} (attempt {})",
+                cmd_name,
+                container_id,
+                signal_desc,
+                attempt + 1
+            );
+
+            let mut cmd = std::process::Command::new(&self.cli_path);
+            cmd.arg(cmd_name);
+
+            if let Some(sig) = signal {
+                cmd.args(["--signal", sig]);
+            }
+            cmd.args(["--time", &timeout.to_string()]);
+
+            cmd.arg(container_id);
+
+            let output = cmd
+                .output()
+                .context("Failed to execute container command")?;
+
+            if output.status.success() {
+                let stop_desc = if let Some(sig) = signal {
+                    format!("{} ({})", cmd_name, sig)
+                } else {
+                    cmd_name.to_string()
+                };
+                info!("Successfully {} container '{}'", stop_desc, container_id);
+
+                // Wait for container to fully stop - increased timeout for Apple Container VMs
+                tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+                break;
+            } else {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                if stderr.contains("is not running")
+                    || stderr.contains("No such container")
+                    || stderr.contains("cannot kill: container is not running")
+                {
+                    debug!(
+                        "Container '{}' already stopped or doesn't exist",
+                        container_id
+                    );
+                    break;
+                } else if attempt + 1 < stop_attempts.len() {
+                    warn!(
+                        "Graceful stop failed for '{}': {}, trying force stop",
+                        container_id, stderr
+                    );
+                    continue;
+                } else {
+                    warn!(
+                        "All stop attempts failed for '{}': {}, proceeding with removal anyway",
+                        container_id, stderr
+                    );
+                    break; // Try removal anyway - Apple Container might have state sync issues
+                }
+            }
+        }
+
+        // Remove the container - try normal removal first, then force if needed
+        let removals: &[bool] = if options.force {
+            &[true]
+        } else {
+            &[false, true]
+        };
+        for (attempt, use_force) in removals.iter().enumerate() {
+            let mut cmd = std::process::Command::new(&self.cli_path);
+            cmd.arg("delete"); // Use 'delete' not 'rm'
+
+            if *use_force {
+                cmd.arg("--force");
+                info!("Attempting force removal of container '{}'", container_id);
+            } else {
+                info!("Attempting removal of container '{}'", container_id);
+            }
+
+            cmd.arg(container_id);
+
+            let output = cmd.output().context("Failed to remove container")?;
 
             if output.status.success() {
                 info!("Successfully removed container '{}'", container_id);
@@ -1689,13 +4009,30 @@ impl AppleContainerClient {
                                 .as_deref()
                                 .map(format_created)
                                 .unwrap_or_else(|| "unknown".to_string()),
+                            creation_timestamp: container.creation_timestamp(),
                             kubeconfig_path: None,
                         });
 
+                // The cluster is as old as its oldest node
+                if let Some(ts) = container.creation_timestamp() {
+                    let older = match cluster_info.creation_timestamp {
+                        Some(current) => ts < current,
+                        None => true,
+                    };
+                    if older {
+                        cluster_info.creation_timestamp = Some(ts);
+                        cluster_info.created = format_created(&ts.to_rfc3339());
+                    }
+                }
+
                 // Add node information
                 cluster_info.nodes.push(NodeInfo {
                     name: container_name.clone(),
-                    role: if role.contains("control-plane") {
+                    // Single-node clusters carry "control-plane,worker"
+                    role: if role
+                        .split(',')
+                        .any(|r| r.parse::<NodeRole>().ok() == Some(NodeRole::ControlPlane))
+                    {
                         NodeRole::ControlPlane
                     } else {
                         NodeRole::Worker
@@ -1731,11 +4068,13 @@ impl AppleContainerClient {
             return fs::read_to_string(kubeconfig_path).context("Failed to read kubeconfig file");
         }
 
-        // If no local kubeconfig, try to generate one from the cluster
+        // If no local kubeconfig (e.g. `--no-kubeconfig`), generate one from the
+        // cluster without saving it or touching the user's kubeconfig
         self.generate_kubeconfig(name).await
     }
 
-    /// Generate kubeconfig from a running cluster
+    /// Generate kubeconfig from a running cluster. The result is only returned;
+    /// callers that want it in the user's kubeconfig merge it explicitly.
     /// Note: Apple Container provides DNS resolution for container names,
     /// so kubeconfig can use container hostnames directly instead of IP addresses
     async fn generate_kubeconfig(&self, name: &str) -> Result<String> {
@@ -1769,7 +4108,8 @@ impl AppleContainerClient {
                 // Rewrite server URL to the live VM IP (pure fn, idempotent, handles any host).
                 let kubeconfig = if let Some(vm_ip) = &control_plane_node.ip_address {
                     info!("Updating kubeconfig server URL to use VM IP: {}", vm_ip);
-                    crate::core::verify::rewrite_kubeconfig_server(&kubeconfig_raw, vm_ip)
+                    let port = self.api_server_port(&control_plane_node.name).await;
+                    crate::core::verify::rewrite_kubeconfig_server(&kubeconfig_raw, vm_ip, port)
                 } else {
                     kubeconfig_raw
                 };
@@ -1777,9 +4117,6 @@ impl AppleContainerClient {
                 // Make user names cluster-specific to prevent conflicts in merged config
                 let kubeconfig = self.make_user_names_cluster_specific(&kubeconfig, name)?;
 
-                // Save the kubeconfig locally for future use
-                self.save_kubeconfig(name, &kubeconfig).await?;
-
                 return Ok(kubeconfig);
             } else {
                 let stderr = String::from_utf8_lossy(&output.stderr);
@@ -1838,80 +4175,12 @@ impl AppleContainerClient {
 
     /// Save kubeconfig using kubectl-native merging for proper context management
     async fn save_kubeconfig(&self, name: &str, kubeconfig: &str) -> Result<()> {
-        let home_dir = std::env::var("HOME").context("HOME environment variable not set")?;
-        let kube_dir = std::path::Path::new(&home_dir).join(".kube");
-        let global_config_path = kube_dir.join("config");
-        let individual_path = kube_dir.join(name);
-
-        // Ensure the .kube directory exists
-        fs::create_dir_all(&kube_dir).context("Failed to create .kube directory")?;
-
-        // Save individual file for backward compatibility and direct access
-        fs::write(&individual_path, kubeconfig)
-            .context("Failed to write individual kubeconfig file")?;
-
-        // Use kubectl config merge approach by setting KUBECONFIG environment variable
-        // This leverages kubectl's built-in merging logic which is much more reliable
-        let existing_kubeconfig = if global_config_path.exists() {
-            format!(
-                "{}:{}",
-                global_config_path.display(),
-                individual_path.display()
-            )
-        } else {
-            individual_path.display().to_string()
-        };
-
-        // Use kubectl config view --flatten to merge configs
-        let mut cmd = tokio::process::Command::new("kubectl");
-        cmd.env("KUBECONFIG", &existing_kubeconfig)
-            .arg("config")
-            .arg("view")
-            .arg("--flatten");
-
-        let output = cmd
-            .output()
-            .await
-            .context("Failed to run kubectl config view")?;
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(anyhow::anyhow!("kubectl config view failed: {}", stderr));
-        }
-
-        // Write the merged config back to the global config file
-        let merged_config = String::from_utf8_lossy(&output.stdout);
-        fs::write(&global_config_path, merged_config.as_bytes())
-            .context("Failed to write merged kubeconfig")?;
-
-        // Set the current context to the new cluster
-        let context_name = name; // Use cluster name as context name
-        let mut use_context_cmd = tokio::process::Command::new("kubectl");
-        use_context_cmd
-            .arg("config")
-            .arg("use-context")
-            .arg(context_name);
-
-        let use_output = use_context_cmd
-            .output()
-            .await
-            .context("Failed to set current context")?;
-        if !use_output.status.success() {
-            let stderr = String::from_utf8_lossy(&use_output.stderr);
-            warn!(
-                "Failed to set current context to '{}': {}",
-                context_name, stderr
-            );
-        }
-
-        info!(
-            "Saved kubeconfig to: {} and merged into: {} using kubectl",
-            individual_path.display(),
-            global_config_path.display()
-        );
-        Ok(())
+        merge_kubeconfig(name, kubeconfig, true).await
     }
 
-    /// Remove kubeconfig context for deleted cluster using kubectl commands
+    /// Remove kubeconfig context for deleted cluster using kubectl commands.
+    /// Entries are removed from the primary kubeconfig (first `KUBECONFIG` entry,
+    /// else `~/.kube/config`) — the same file `save_kubeconfig` merges into.
     async fn remove_kubeconfig_context(&self, cluster_name: &str) -> Result<()> {
         let home_dir = std::env::var("HOME").context("HOME environment variable not set")?;
         let kube_dir = std::path::Path::new(&home_dir).join(".kube");
@@ -1949,7 +4218,11 @@ impl AppleContainerClient {
     /// Delete context using kubectl config delete-context
     async fn kubectl_delete_context(&self, context_name: &str) -> Result<()> {
         let mut cmd = tokio::process::Command::new("kubectl");
-        cmd.arg("config").arg("delete-context").arg(context_name);
+        cmd.arg("--kubeconfig")
+            .arg(resolve_primary_kubeconfig_path())
+            .arg("config")
+            .arg("delete-context")
+            .arg(context_name);
 
         let output = cmd
             .output()
@@ -1969,7 +4242,11 @@ impl AppleContainerClient {
     /// Delete cluster using kubectl config delete-cluster
     async fn kubectl_delete_cluster(&self, cluster_name: &str) -> Result<()> {
         let mut cmd = tokio::process::Command::new("kubectl");
-        cmd.arg("config").arg("delete-cluster").arg(cluster_name);
+        cmd.arg("--kubeconfig")
+            .arg(resolve_primary_kubeconfig_path())
+            .arg("config")
+            .arg("delete-cluster")
+            .arg(cluster_name);
 
         let output = cmd
             .output()
@@ -1989,7 +4266,11 @@ impl AppleContainerClient {
     /// Delete user using kubectl config delete-user
     async fn kubectl_delete_user(&self, user_name: &str) -> Result<()> {
         let mut cmd = tokio::process::Command::new("kubectl");
-        cmd.arg("config").arg("delete-user").arg(user_name);
+        cmd.arg("--kubeconfig")
+            .arg(resolve_primary_kubeconfig_path())
+            .arg("config")
+            .arg("delete-user")
+            .arg(user_name);
 
         let output = cmd
             .output()
@@ -2006,7 +4287,14 @@ impl AppleContainerClient {
         Ok(())
     }
 
-    /// Load image into cluster
+    /// Load image into cluster.
+    ///
+    /// The image is exported once to a temp tarball that every node streams in
+    /// parallel; the tarball is removed once all nodes are done. With
+    /// `options.archive` set, that tarball is streamed instead and left in
+    /// place for the caller. Every node is attempted; the load fails only when
+    /// no node received the image, and otherwise warns about the ones that
+    /// did not.
     pub async fn load_image(&self, options: &LoadImageOptions) -> Result<()> {
         info!(
             "Loading image '{}' into cluster '{}'",
@@ -2020,210 +4308,158 @@ impl AppleContainerClient {
             .find(|c| c.name == options.cluster)
             .ok_or_else(|| anyhow::anyhow!("Cluster '{}' not found", options.cluster))?;
 
-        if cluster.nodes.is_empty() {
+        let container_ids: Vec<String> = cluster
+            .nodes
+            .iter()
+            .filter_map(|node| node.container_id.clone())
+            .collect();
+        if container_ids.is_empty() {
             return Err(anyhow::anyhow!(
                 "No nodes found in cluster '{}'",
                 options.cluster
             ));
         }
 
-        // Load image into each node container
-        for node in &cluster.nodes {
-            if let Some(container_id) = &node.container_id {
-                self.load_image_into_container(container_id, &options.image)
-                    .await?;
+        // An export is removed when dropped, after every node task below has
+        // finished
+        let (image_tar, exported) = match &options.archive {
+            Some(archive) => (archive.clone(), None),
+            None => {
+                let exported = self.export_image_tar(&options.image).await?;
+                (exported.to_path_buf(), Some(exported))
             }
-        }
-
-        info!(
-            "Image '{}' loaded successfully into cluster '{}'",
-            options.image, options.cluster
-        );
-        Ok(())
+        };
+        let image_tar = std::sync::Arc::new(image_tar);
+        let digest = std::sync::Arc::new(file_size_and_sha256(&image_tar)?);
+
+        // Load image into every node container concurrently
+        let cli_path = self.cli_path.clone();
+        let image = options.image.clone();
+        let loaded =
+            load_into_nodes(&options.image, &options.cluster, &container_ids, |id| {
+                let cli_path = cli_path.clone();
+                let image = image.clone();
+                let image_tar = image_tar.clone();
+                let digest = digest.clone();
+                async move {
+                    load_image_into_container(&cli_path, &id, &image, &image_tar, &digest).await
+                }
+            })
+            .await;
+        drop(exported);
+        loaded
     }
 
-    /// Load image into a specific container
-    async fn load_image_into_container(&self, container_id: &str, image: &str) -> Result<()> {
-        debug!(
-            "Loading image '{}' into container '{}'",
-            image, container_id
-        );
+    /// Export `image` with `container image save` to a fresh temp tarball. The
+    /// file is removed when the returned path is dropped.
+    async fn export_image_tar(&self, image: &str) -> Result<tempfile::TempPath> {
+        let image_tar = tempfile::Builder::new()
+            .prefix("kina-image-")
+            .suffix(".tar")
+            .tempfile()
+            .context("Failed to create a temp file for the image export")?
+            .into_temp_path();
 
-        // First, save the image to a tar file using Apple Container's native image save
-        let temp_dir = std::env::temp_dir();
-        let image_tar = temp_dir.join(format!("{}.tar", image.replace(['/', ':'], "_")));
-
-        // Export the image using container image save
         let mut cmd = std::process::Command::new(&self.cli_path);
         cmd.args(["image", "save", image, "-o", &image_tar.to_string_lossy()]);
-
-        let output = cmd
-            .output()
+        let output = tokio::task::spawn_blocking(move || cmd.output())
+            .await
+            .context("Image export task panicked")?
             .context("Failed to export image with container image save")?;
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
             return Err(anyhow::anyhow!("Failed to export image: {}", stderr));
         }
+        Ok(image_tar)
+    }
 
-        // Read the tar bytes to compute local size and digest for post-injection
-        // verification.  Reading into memory is acceptable for node images (~100–500 MB)
-        // on an Apple Silicon dev machine; this also allows a single fs read to compute
-        // both size and sha256 before streaming.
-        let tar_bytes = fs::read(&image_tar)
-            .with_context(|| format!("Failed to read image tar from {}", image_tar.display()));
-        let tar_bytes = match tar_bytes {
-            Ok(b) => b,
-            Err(e) => {
-                let _ = fs::remove_file(&image_tar);
-                return Err(e);
-            }
-        };
-        let local_len = tar_bytes.len() as u64;
-        let local_sha = sha256_hex(&tar_bytes);
-
-        // Inject tar bytes into the container via exec-stdin (`container exec -i
-        // <id> sh -c 'cat > /path'`).  This replaces `container cp` which silently
-        // exits 0 without transferring the file in some container 1.0.0 configurations.
-        let dest_path = "/tmp/image.tar";
-        let inject_args = build_inject_tar_args(container_id, dest_path);
-        let spawn_result = std::process::Command::new(&self.cli_path)
-            .args(&inject_args)
-            .stdin(std::process::Stdio::piped())
-            .stdout(std::process::Stdio::null())
-            .stderr(std::process::Stdio::piped())
-            .spawn()
-            .with_context(|| {
-                format!(
-                    "Failed to spawn exec-stdin injection for container '{}'",
-                    container_id
-                )
-            });
-        let mut child = match spawn_result {
-            Ok(c) => c,
-            Err(e) => {
-                let _ = fs::remove_file(&image_tar);
-                return Err(e);
-            }
-        };
-
-        // Write tar bytes to child stdin; drop the handle to close stdin so the
-        // `cat >` shell command sees EOF and terminates.
-        if let Some(mut stdin) = child.stdin.take() {
-            if let Err(e) = stdin.write_all(&tar_bytes) {
-                let _ = child.wait();
-                let _ = fs::remove_file(&image_tar);
-                return Err(anyhow::anyhow!(
-                    "Failed to write tar bytes to container stdin: {}",
-                    e
-                ));
-            }
-        }
-        // Drop tar_bytes after writing to free memory before waiting.
-        drop(tar_bytes);
-
-        let inject_status = child
-            .wait()
-            .context("Failed to wait for exec-stdin injection")?;
-        if !inject_status.success() {
-            let _ = fs::remove_file(&image_tar);
-            return Err(anyhow::anyhow!(
-                "exec-stdin injection into container '{}' failed (exit {:?})",
-                container_id,
-                inject_status.code(),
-            ));
-        }
-
-        // Post-injection verification: compare remote size and sha256 against the
-        // local tarball before running ctr images import.  A size mismatch of 0
-        // (or any value ≠ local_len) indicates a silent no-op and aborts with a
-        // hard error so the import step is never attempted on a corrupt/missing file.
-        let size_args = build_remote_size_args(container_id, dest_path);
-        let size_output = std::process::Command::new(&self.cli_path)
-            .args(&size_args)
-            .output()
-            .context("Failed to run remote size check")?;
-        let remote_size_raw = String::from_utf8_lossy(&size_output.stdout).into_owned();
+    /// Start the container described by `spec` with `container run` and return
+    /// the new container's ID.
+    fn run_container_spec(&self, spec: &ContainerSpec) -> Result<String> {
+        let args = container_run_args(spec);
+        debug!(
+            "Executing Apple Container command: {} {:?}",
+            self.cli_path, args
+        );
 
-        let sha_args = build_remote_sha256_args(container_id, dest_path);
-        let sha_output = std::process::Command::new(&self.cli_path)
-            .args(&sha_args)
+        let output = std::process::Command::new(&self.cli_path)
+            .args(&args)
             .output()
-            .context("Failed to run remote sha256 check")?;
-        let remote_sha_raw = String::from_utf8_lossy(&sha_output.stdout).into_owned();
-
-        if let Err(e) = verify_injection(local_len, &local_sha, &remote_size_raw, &remote_sha_raw) {
-            let _ = fs::remove_file(&image_tar);
-            return Err(e.context(format!(
-                "post-injection verification failed for container '{}'; \
-                 the exec-stdin transfer may have produced an incomplete file",
-                container_id,
-            )));
-        }
-
-        // Import the image inside the container using ctr (containerd CLI).
-        let mut cmd = std::process::Command::new(&self.cli_path);
-        cmd.args(["exec", container_id, "ctr", "images", "import", dest_path]);
-        let output = cmd.output().context("Failed to load image in container")?;
-
-        // Clean up the temp file on all paths.
-        let _ = fs::remove_file(&image_tar);
-
+            .with_context(|| format!("Failed to run container '{}'", spec.name))?;
         if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
             return Err(anyhow::anyhow!(
-                "Failed to load image in container: {}",
-                stderr
+                "Failed to create container '{}': {}",
+                spec.name,
+                String::from_utf8_lossy(&output.stderr)
             ));
         }
-
-        debug!(
-            "Successfully loaded image '{}' into container '{}'",
-            image, container_id
-        );
-        Ok(())
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
     }
 
     /// Wait for container to be ready
     async fn wait_for_container_ready(&self, container_name: &str) -> Result<()> {
         info!("Waiting for container '{}' to be ready...", container_name);
 
-        for attempt in 1..=30 {
-            tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+        let retry = &self.config.apple_container.retry;
+        retry_with_backoff(
+            retry.max_attempts,
+            retry.initial_delay(),
+            retry.backoff_factor,
+            || async { self.check_container_running(container_name) },
+        )
+        .await
+        .with_context(|| format!("Container '{}' failed to become ready", container_name))
+    }
 
-            let mut cmd = std::process::Command::new(&self.cli_path);
-            cmd.args(["list", "--format", "json"]);
-
-            if let Ok(output) = cmd.output() {
-                if output.status.success() {
-                    let stdout = String::from_utf8_lossy(&output.stdout);
-                    if let Ok(containers) = parse_container_list(&stdout) {
-                        for container in containers {
-                            if container.id == container_name && container.state == "running" {
-                                debug!(
-                                    "Container '{}' is running after {} attempts",
-                                    container_name, attempt
-                                );
-                                return Ok(());
-                            }
-                        }
-                    }
-                }
+    /// Succeed when `container list` reports the container as running.
+    fn check_container_running(&self, container_name: &str) -> Result<()> {
+        let containers = self.list_container_entries()?;
+        match containers.iter().find(|c| c.id == container_name) {
+            Some(container) if container.state == "running" => {
+                debug!("Container '{}' is running", container_name);
+                Ok(())
             }
+            Some(container) => Err(anyhow::anyhow!(
+                "Container '{}' is not running yet (state: {})",
+                container_name,
+                container.state
+            )),
+            None => Err(anyhow::anyhow!("Container '{}' not found", container_name)),
+        }
+    }
 
-            debug!(
-                "Container '{}' not ready yet, attempt {}/30",
-                container_name, attempt
-            );
+    /// Get container IP address, retrying while the VM's network comes up
+    async fn get_container_ip(&self, container_name: &str) -> Result<String> {
+        let retry = &self.config.apple_container.retry;
+        retry_with_backoff(
+            retry.max_attempts,
+            retry.initial_delay(),
+            retry.backoff_factor,
+            || async { self.lookup_container_ip(container_name) },
+        )
+        .await
+    }
+
+    /// Read a container's IPv4 address from `container list`
+    fn lookup_container_ip(&self, container_name: &str) -> Result<String> {
+        let containers = self.list_container_entries()?;
+
+        for container in containers {
+            if container.id == container_name {
+                if let Some(ip) = container.ipv4 {
+                    return Ok(ip);
+                }
+            }
         }
 
         Err(anyhow::anyhow!(
-            "Container '{}' failed to become ready within 60 seconds",
+            "Could not find IP address for container '{}'",
             container_name
         ))
     }
 
-    /// Get container IP address
-    async fn get_container_ip(&self, container_name: &str) -> Result<String> {
+    /// Run `container list --format json` and parse the entries
+    fn list_container_entries(&self) -> Result<Vec<ParsedContainer>> {
         let mut cmd = std::process::Command::new(&self.cli_path);
         cmd.args(["list", "--format", "json"]);
 
@@ -2237,31 +4473,82 @@ impl AppleContainerClient {
         }
 
         let stdout = String::from_utf8_lossy(&output.stdout);
-        let containers =
-            parse_container_list(&stdout).context("Failed to parse container list JSON")?;
+        parse_container_list(&stdout).context("Failed to parse container list JSON")
+    }
 
-        for container in containers {
-            if container.id == container_name {
-                if let Some(ip) = container.ipv4 {
-                    return Ok(ip);
-                }
+    /// Copy the files a kubeadm config refers to (e.g. the OIDC CA bundle) into
+    /// the control-plane node ahead of `kubeadm init`.
+    fn write_kubeadm_files(
+        &self,
+        container_name: &str,
+        kubeadm: &KubeadmConfigOptions,
+    ) -> Result<()> {
+        for (path, contents) in kubeadm.files.iter().chain(&kubeadm.node_files) {
+            if let Some(parent) = std::path::Path::new(path).parent() {
+                let parent = parent.to_string_lossy();
+                self.run_container_command(&["exec", container_name, "mkdir", "-p", &parent])?;
             }
+            self.write_file_to_container(container_name, path, contents)?;
+        }
+        if let Some(snippet) = &kubeadm.containerd_config {
+            debug!("Merging custom containerd config in '{}'", container_name);
+            let existing = self.read_container_file(container_name, CONTAINERD_CONFIG_PATH)?;
+            let merged = merge_containerd_config(&existing, snippet)?;
+            self.write_file_to_container(
+                container_name,
+                CONTAINERD_CONFIG_PATH,
+                merged.as_bytes(),
+            )?;
+        }
+        if kubeadm.containerd_config.is_some() {
+            debug!("Restarting containerd in '{}'", container_name);
+            self.run_container_command(&[
+                "exec",
+                container_name,
+                "systemctl",
+                "restart",
+                "containerd",
+            ])?;
+        }
+        if kubeadm.container_runtime == ContainerRuntime::CriO {
+            debug!("Switching '{}' from containerd to CRI-O", container_name);
+            self.run_container_command(&["exec", container_name, "sh", "-c", CRIO_ENABLE_SCRIPT])?;
         }
+        Ok(())
+    }
 
-        Err(anyhow::anyhow!(
-            "Could not find IP address for container '{}'",
-            container_name
-        ))
+    /// Fail unless `image` ships the `crio` binary `--cri-o` switches nodes to.
+    fn check_image_supports_crio(&self, image: &str) -> Result<()> {
+        // `|| true` keeps a missing crio from failing the command, so an error
+        // here means the image could not be run at all.
+        let path = self
+            .run_image_command(image, "sh", &["-c", "command -v crio || true"])
+            .with_context(|| format!("Could not check node image '{}' for CRI-O", image))?;
+        if path.trim().is_empty() {
+            return Err(anyhow::anyhow!(
+                "Node image '{}' does not include CRI-O (no crio binary); use an image built \
+                 with CRI-O or create the cluster without --cri-o",
+                image
+            ));
+        }
+        Ok(())
     }
 
-    /// Generate kubeadm init configuration YAML (method wrapper for compatibility)
-    fn generate_kubeadm_init_config(
-        &self,
-        container_name: &str,
-        vm_ip: &str,
-        cluster_name: &str,
-    ) -> String {
-        generate_kubeadm_init_config(container_name, vm_ip, cluster_name)
+    /// Read a file from a node container.
+    fn read_container_file(&self, container_name: &str, path: &str) -> Result<String> {
+        let output = std::process::Command::new(&self.cli_path)
+            .args(["exec", container_name, "cat", path])
+            .output()
+            .context("Failed to execute Apple Container CLI")?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Failed to read {} from '{}': {}",
+                path,
+                container_name,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
     }
 
     /// Write kubeadm config and run kubeadm init in a container.
@@ -2270,12 +4557,14 @@ impl AppleContainerClient {
     /// `--skip-phases=preflight,addon/kube-proxy` so kubeadm does not deploy kube-proxy.
     /// When false (stock kernel), only `--skip-phases=preflight` is passed.
     ///
-    /// Uses `build_kubeadm_init_args(full_ebpf)` to build the argument list.
+    /// Uses `build_kubeadm_init_args(full_ebpf)` to build the argument list,
+    /// followed by `preflight_args` (see [`kubeadm_preflight_args`]).
     fn run_kubeadm_init(
         &self,
         container_name: &str,
         kubeadm_config: &str,
         full_ebpf: bool,
+        preflight_args: &[String],
     ) -> Result<std::process::Output> {
         // Write kubeadm config to container
         let mut cmd = std::process::Command::new(&self.cli_path);
@@ -2298,7 +4587,8 @@ impl AppleContainerClient {
 
         // Initialize cluster with kubeadm using the computed arg list.
         // build_kubeadm_init_args handles the kube-proxy skip for full-eBPF mode.
-        let kubeadm_args = build_kubeadm_init_args(full_ebpf);
+        let mut kubeadm_args = build_kubeadm_init_args(full_ebpf);
+        kubeadm_args.extend_from_slice(preflight_args);
 
         let mut cmd = std::process::Command::new(&self.cli_path);
         let mut exec_args = vec!["exec", container_name, "kubeadm", "init"];
@@ -2307,7 +4597,120 @@ impl AppleContainerClient {
         cmd.args(&exec_args);
 
         info!("Running kubeadm init (this may take a few minutes)...");
-        cmd.output().context("Failed to run kubeadm init")
+        let output = cmd.output().context("Failed to run kubeadm init")?;
+
+        // Keep a copy of the init output inside the node; --retain diagnostics read it
+        // back after a failed create. Best-effort: a missing log never fails the init.
+        let mut log = output.stdout.clone();
+        log.extend_from_slice(&output.stderr);
+        if let Err(e) = self.write_file_to_container(container_name, KUBEADM_LOG_PATH, &log) {
+            debug!(
+                "Failed to persist kubeadm log in '{}': {}",
+                container_name, e
+            );
+        }
+
+        Ok(output)
+    }
+
+    /// Run `kubeadm init`, retrying up to `kubeadm_init_attempts` times. The node
+    /// is reset with `kubeadm reset -f` before each retry so the next init starts
+    /// clean; the last failed attempt is left as-is for `--retain` diagnostics.
+    async fn kubeadm_init_with_retry(
+        &self,
+        container_name: &str,
+        kubeadm_config: &str,
+        full_ebpf: bool,
+        preflight_args: &[String],
+    ) -> Result<std::process::Output> {
+        let retry = &self.config.apple_container.retry;
+        let attempt = std::sync::atomic::AtomicU32::new(0);
+        retry_with_backoff(
+            retry.kubeadm_init_attempts,
+            retry.initial_delay(),
+            retry.backoff_factor,
+            || {
+                let attempt = &attempt;
+                async move {
+                    let n = attempt.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
+                    if n > 1 {
+                        warn!(
+                            "Retrying kubeadm init in '{}' (attempt {})",
+                            container_name, n
+                        );
+                        if let Err(e) = self.run_container_command(&[
+                            "exec",
+                            container_name,
+                            "kubeadm",
+                            "reset",
+                            "-f",
+                        ]) {
+                            debug!("kubeadm reset before retry failed: {}", e);
+                        }
+                    }
+
+                    let output = self.run_kubeadm_init(
+                        container_name,
+                        kubeadm_config,
+                        full_ebpf,
+                        preflight_args,
+                    )?;
+                    if !output.status.success() {
+                        return Err(anyhow::anyhow!(
+                            "kubeadm init failed:\nStdout: {}\nStderr: {}",
+                            String::from_utf8_lossy(&output.stdout),
+                            String::from_utf8_lossy(&output.stderr)
+                        ));
+                    }
+                    Ok(output)
+                }
+            },
+        )
+        .await
+    }
+
+    /// Write `contents` to `dest_path` inside a container.
+    ///
+    /// Uses the same exec-stdin transport as image loading (`build_inject_tar_args`),
+    /// so arbitrary bytes can be written without heredoc quoting concerns.
+    fn write_file_to_container(
+        &self,
+        container_name: &str,
+        dest_path: &str,
+        contents: &[u8],
+    ) -> Result<()> {
+        let args = build_inject_tar_args(container_name, dest_path);
+        let mut child = std::process::Command::new(&self.cli_path)
+            .args(&args)
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .with_context(|| {
+                format!(
+                    "Failed to spawn exec-stdin write of {} in '{}'",
+                    dest_path, container_name
+                )
+            })?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(contents)
+                .with_context(|| format!("Failed to write {} to container stdin", dest_path))?;
+        }
+
+        let output = child
+            .wait_with_output()
+            .context("Failed to wait for exec-stdin write")?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Failed to write {} in container '{}': {}",
+                dest_path,
+                container_name,
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+        Ok(())
     }
 
     /// Initialize Kubernetes cluster with kubeadm (single-node, no join info needed).
@@ -2318,6 +4721,7 @@ impl AppleContainerClient {
         container_name: &str,
         vm_ip: &str,
         kernel_path: Option<&std::path::Path>,
+        kubeadm: &KubeadmConfigOptions,
     ) -> Result<()> {
         info!(
             "Initializing Kubernetes cluster in container '{}'",
@@ -2329,19 +4733,16 @@ impl AppleContainerClient {
             .strip_suffix("-control-plane")
             .unwrap_or(container_name);
 
-        let kubeadm_config = self.generate_kubeadm_init_config(container_name, vm_ip, cluster_name);
-        let output =
-            self.run_kubeadm_init(container_name, &kubeadm_config, kernel_path.is_some())?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            return Err(anyhow::anyhow!(
-                "kubeadm init failed:\nStdout: {}\nStderr: {}",
-                stdout,
-                stderr
-            ));
-        }
+        self.write_kubeadm_files(container_name, kubeadm)?;
+        let kubeadm_config =
+            generate_kubeadm_init_config_with(container_name, vm_ip, cluster_name, kubeadm);
+        self.kubeadm_init_with_retry(
+            container_name,
+            &kubeadm_config,
+            kernel_path.is_some(),
+            &kubeadm_preflight_args(kubeadm),
+        )
+        .await?;
 
         info!("Kubernetes cluster initialized successfully");
         Ok(())
@@ -2356,28 +4757,27 @@ impl AppleContainerClient {
         vm_ip: &str,
         cluster_name: &str,
         kernel_path: Option<&std::path::Path>,
+        kubeadm: &KubeadmConfigOptions,
     ) -> Result<KubeadmJoinInfo> {
         info!(
             "Initializing Kubernetes cluster in container '{}' (multi-node)",
             container_name
         );
 
-        let kubeadm_config = self.generate_kubeadm_init_config(container_name, vm_ip, cluster_name);
-        let output =
-            self.run_kubeadm_init(container_name, &kubeadm_config, kernel_path.is_some())?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            return Err(anyhow::anyhow!(
-                "kubeadm init failed:\nStdout: {}\nStderr: {}",
-                stdout,
-                stderr
-            ));
-        }
+        self.write_kubeadm_files(container_name, kubeadm)?;
+        let kubeadm_config =
+            generate_kubeadm_init_config_with(container_name, vm_ip, cluster_name, kubeadm);
+        let output = self
+            .kubeadm_init_with_retry(
+                container_name,
+                &kubeadm_config,
+                kernel_path.is_some(),
+                &kubeadm_preflight_args(kubeadm),
+            )
+            .await?;
 
         let stdout = String::from_utf8_lossy(&output.stdout);
-        let join_info = Self::parse_kubeadm_join_info(&stdout, vm_ip)?;
+        let join_info = Self::parse_kubeadm_join_info(&stdout, vm_ip, kubeadm.api_server_port())?;
 
         info!("Kubernetes cluster initialized, join token extracted for workers");
         Ok(join_info)
@@ -2385,7 +4785,12 @@ impl AppleContainerClient {
 
     /// Parse kubeadm join info from kubeadm init output
     /// Looks for: kubeadm join <endpoint> --token <token> --discovery-token-ca-cert-hash <hash>
-    pub fn parse_kubeadm_join_info(output: &str, cp_ip: &str) -> Result<KubeadmJoinInfo> {
+    /// The endpoint is `cp_ip:api_server_port`.
+    pub fn parse_kubeadm_join_info(
+        output: &str,
+        cp_ip: &str,
+        api_server_port: u16,
+    ) -> Result<KubeadmJoinInfo> {
         let mut token = None;
         let mut ca_cert_hash = None;
 
@@ -2423,7 +4828,7 @@ impl AppleContainerClient {
         Ok(KubeadmJoinInfo {
             token,
             ca_cert_hash,
-            control_plane_endpoint: format!("{}:6443", cp_ip),
+            control_plane_endpoint: format!("{}:{}", cp_ip, api_server_port),
         })
     }
 
@@ -2433,17 +4838,33 @@ impl AppleContainerClient {
         worker_name: &str,
         worker_ip: &str,
         join_info: &KubeadmJoinInfo,
+        kubeadm: &KubeadmConfigOptions,
     ) -> Result<()> {
         info!("Joining worker '{}' to cluster", worker_name);
 
         // Write a JoinConfiguration YAML to the worker (v1beta4, list form for kubeletExtraArgs)
-        let join_config = generate_worker_join_config(worker_name, worker_ip, join_info);
+        let join_config =
+            generate_worker_join_config_with(worker_name, worker_ip, join_info, kubeadm);
+        self.run_kubeadm_join(worker_name, &join_config, kubeadm)
+            .await?;
+
+        info!("Worker '{}' joined cluster successfully", worker_name);
+        Ok(())
+    }
 
-        // Write join config to worker container
+    /// Write `join_config` to `/kind/kubeadm-join.conf` in a node and run
+    /// `kubeadm join` with it.
+    async fn run_kubeadm_join(
+        &self,
+        node_name: &str,
+        join_config: &str,
+        kubeadm: &KubeadmConfigOptions,
+    ) -> Result<()> {
+        // Write join config to the node container
         let mut cmd = std::process::Command::new(&self.cli_path);
         cmd.args([
             "exec",
-            worker_name,
+            node_name,
             "sh",
             "-c",
             &format!(
@@ -2454,12 +4875,12 @@ impl AppleContainerClient {
 
         let output = cmd
             .output()
-            .context("Failed to write join config to worker")?;
+            .context("Failed to write join config to node")?;
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
             return Err(anyhow::anyhow!(
-                "Failed to write join config to worker '{}': {}",
-                worker_name,
+                "Failed to write join config to node '{}': {}",
+                node_name,
                 stderr
             ));
         }
@@ -2468,34 +4889,68 @@ impl AppleContainerClient {
         let mut cmd = std::process::Command::new(&self.cli_path);
         cmd.args([
             "exec",
-            worker_name,
+            node_name,
             "kubeadm",
             "join",
             "--config=/kind/kubeadm-join.conf",
             "--skip-phases=preflight",
             "--v=1",
         ]);
+        cmd.args(kubeadm_preflight_args(kubeadm));
 
-        info!("Running kubeadm join on worker '{}'...", worker_name);
+        info!("Running kubeadm join on '{}'...", node_name);
         let output = cmd.output().context("Failed to run kubeadm join")?;
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
             let stdout = String::from_utf8_lossy(&output.stdout);
             return Err(anyhow::anyhow!(
-                "kubeadm join failed on worker '{}':\nStdout: {}\nStderr: {}",
-                worker_name,
+                "kubeadm join failed on '{}':\nStdout: {}\nStderr: {}",
+                node_name,
                 stdout,
                 stderr
             ));
         }
+        Ok(())
+    }
 
-        info!("Worker '{}' joined cluster successfully", worker_name);
+    /// Apply `labels` (`--node-extra-label`) to every node in `nodes`, running
+    /// kubectl inside `control_plane`.
+    async fn label_nodes(
+        &self,
+        control_plane: &str,
+        nodes: &[String],
+        labels: &BTreeMap<String, String>,
+    ) -> Result<()> {
+        if labels.is_empty() {
+            return Ok(());
+        }
+        for node in nodes {
+            info!("Labelling node '{}'", node);
+            let mut cmd = std::process::Command::new(&self.cli_path);
+            cmd.args([
+                "exec",
+                control_plane,
+                "kubectl",
+                "--kubeconfig=/etc/kubernetes/admin.conf",
+            ])
+            .args(label_node_args(node, labels));
+
+            let output = cmd.output().context("Failed to run kubectl label")?;
+            if !output.status.success() {
+                return Err(anyhow::anyhow!(
+                    "Failed to label node '{}': {}",
+                    node,
+                    String::from_utf8_lossy(&output.stderr)
+                ));
+            }
+        }
         Ok(())
     }
 
-    /// Remove control-plane taint for single-node scheduling
-    async fn remove_control_plane_taint(&self, container_name: &str) -> Result<()> {
+    /// Remove control-plane taint for single-node scheduling, untainting the
+    /// Kubernetes node `node` with kubectl run in `container_name`
+    async fn remove_control_plane_taint(&self, container_name: &str, node: &str) -> Result<()> {
         info!("Removing control-plane taint for single-node scheduling");
 
         let mut cmd = std::process::Command::new(&self.cli_path);
@@ -2506,7 +4961,7 @@ impl AppleContainerClient {
             "--kubeconfig=/etc/kubernetes/admin.conf",
             "taint",
             "nodes",
-            container_name,
+            node,
             "node-role.kubernetes.io/control-plane:NoSchedule-",
         ]);
 
@@ -2544,7 +4999,136 @@ impl AppleContainerClient {
         match cni {
             CniPlugin::Ptp => self.install_ptp_cni(container_name).await,
             CniPlugin::Cilium => self.install_cilium_cni(container_name, kernel_path).await,
+            CniPlugin::Flannel | CniPlugin::Calico => {
+                self.install_manifest_cni(container_name, &cni).await
+            }
+        }
+    }
+
+    /// Install or replace the CNI plugin on an existing cluster.
+    ///
+    /// Workloads of every other CNI plugin are deleted and each node's
+    /// `/etc/cni/net.d/` is cleared (with a kubelet restart) so the old plugin's
+    /// config cannot shadow the new one. PTP is written to every node and its
+    /// cross-node routes rebuilt; the other plugins roll out from the control
+    /// plane. Fails if the nodes are not Ready within five minutes.
+    pub async fn install_cni(
+        &self,
+        cluster_name: &str,
+        cni: CniPlugin,
+        kernel_path: Option<&std::path::Path>,
+    ) -> Result<()> {
+        let cp_name = format!("{}-control-plane", cluster_name);
+        let clusters = self.list_clusters().await?;
+        let cluster = clusters
+            .iter()
+            .find(|c| c.name == cluster_name)
+            .ok_or_else(|| anyhow::anyhow!("Cluster '{}' not found", cluster_name))?;
+
+        let mut nodes = Vec::new();
+        for node in &cluster.nodes {
+            let ip = self.get_container_ip(&node.name).await?;
+            nodes.push((node.name.clone(), ip));
+        }
+
+        for previous in [
+            CniPlugin::Ptp,
+            CniPlugin::Cilium,
+            CniPlugin::Flannel,
+            CniPlugin::Calico,
+        ] {
+            if previous == cni {
+                continue;
+            }
+            if let Some(remove_cmd) = cni_removal_cmd(&previous) {
+                let output = std::process::Command::new(&self.cli_path)
+                    .args(["exec", &cp_name, "sh", "-c", remove_cmd])
+                    .output()
+                    .context("Failed to remove existing CNI workloads")?;
+                if !output.status.success() {
+                    warn!(
+                        "Removing {:?} workloads returned non-zero: {}",
+                        previous,
+                        String::from_utf8_lossy(&output.stderr)
+                    );
+                }
+            }
+        }
+
+        for (node, _) in &nodes {
+            info!("Clearing existing CNI configuration on '{}'", node);
+            let clear_cmd =
+                "rm -rf /etc/cni/net.d/* /var/lib/cni/networks/* && systemctl restart kubelet";
+            let output = std::process::Command::new(&self.cli_path)
+                .args(["exec", node, "sh", "-c", clear_cmd])
+                .output()
+                .context("Failed to clear CNI configuration")?;
+            if !output.status.success() {
+                return Err(anyhow::anyhow!(
+                    "Failed to clear CNI configuration on '{}': {}",
+                    node,
+                    String::from_utf8_lossy(&output.stderr)
+                ));
+            }
+        }
+
+        if matches!(cni, CniPlugin::Ptp) {
+            for (node, _) in &nodes {
+                self.install_ptp_cni(node).await?;
+            }
+            if nodes.len() > 1 {
+                self.configure_ptp_cross_node_routing(&cp_name, &nodes)
+                    .await?;
+            }
+        } else {
+            self.install_cni_plugin(&cp_name, cni, kernel_path).await?;
+        }
+
+        info!("Waiting for all nodes to be Ready");
+        let node_wait_cmd =
+            "kubectl wait --for=condition=Ready node --all --timeout=300s --kubeconfig=/etc/kubernetes/admin.conf";
+        let output = std::process::Command::new(&self.cli_path)
+            .args(["exec", &cp_name, "sh", "-c", node_wait_cmd])
+            .output()
+            .context("Failed to run node readiness gate")?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Nodes did not become Ready after installing the CNI: {}",
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Install a manifest-based CNI plugin (Flannel, Calico) by applying its
+    /// pinned upstream manifest from inside the control-plane container.
+    async fn install_manifest_cni(&self, container_name: &str, cni: &CniPlugin) -> Result<()> {
+        let url = cni_manifest_url(cni)
+            .ok_or_else(|| anyhow::anyhow!("{:?} is not a manifest-based CNI plugin", cni))?;
+        info!("Installing {:?} CNI plugin from {}", cni, url);
+
+        let apply_cmd = format!(
+            "kubectl apply -f {} --kubeconfig=/etc/kubernetes/admin.conf",
+            url
+        );
+        let mut cmd = std::process::Command::new(&self.cli_path);
+        cmd.args(["exec", container_name, "sh", "-c", &apply_cmd]);
+
+        let output = cmd
+            .output()
+            .with_context(|| format!("Failed to apply {:?} manifest", cni))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow::anyhow!(
+                "Failed to install {:?} CNI plugin: {}",
+                cni,
+                stderr
+            ));
         }
+
+        info!("{:?} CNI plugin installed", cni);
+        Ok(())
     }
 
     /// Install PTP CNI plugin optimized for Apple Container VMs.
@@ -2553,7 +5137,7 @@ impl AppleContainerClient {
     /// re-run [`install_ptp_cni_with_subnet`] per node with that node's assigned
     /// pod CIDR (see [`configure_ptp_cross_node_routing`]).
     async fn install_ptp_cni(&self, container_name: &str) -> Result<()> {
-        self.install_ptp_cni_with_subnet(container_name, "10.244.0.0/16")
+        self.install_ptp_cni_with_subnet(container_name, DEFAULT_POD_SUBNET)
             .await
     }
 
@@ -2781,17 +5365,313 @@ EOF"#,
         self.approve_pending_kubelet_csrs(&cp_name);
     }
 
-    /// Install Cilium CNI plugin using the pinned cilium-cli and topology-correct helm values.
-    ///
-    /// Uses [`build_cilium_cli_install_script`] and either [`build_cilium_install_cmd`] (stock
-    /// kernel workaround profile) or [`build_cilium_install_cmd_ebpf`] (full-eBPF profile for
-    /// nodes booted on the custom kina kernel) so the exact commands are unit-testable without
-    /// spawning containers.
+    /// Save diagnostics for every node of a failed cluster into `dest_dir`.
     ///
-    /// Profile selection: when `kernel_path` is `Some`, the full-eBPF profile is used (custom
-    /// kernel has all required BPF options). When `None`, the stock workaround profile is used.
-    ///
-    /// After `cilium install`, runs a readiness gate (`cilium status --wait --wait-duration 5m`)
+    /// Per node this writes `<node>-inspect.json` (`container inspect`),
+    /// `<node>-kubeadm.log` (the persisted `kubeadm init` output) and
+    /// `<node>-journal.log` (the systemd journal). Individual collection failures are
+    /// recorded in the corresponding file rather than aborting the dump.
+    pub async fn collect_failure_diagnostics(
+        &self,
+        cluster_name: &str,
+        dest_dir: &std::path::Path,
+    ) -> Result<()> {
+        fs::create_dir_all(dest_dir).with_context(|| {
+            format!(
+                "Failed to create diagnostics directory {}",
+                dest_dir.display()
+            )
+        })?;
+
+        let clusters = self.list_clusters().await?;
+        let nodes: Vec<String> = clusters
+            .iter()
+            .find(|c| c.name == cluster_name)
+            .map(|c| c.nodes.iter().map(|n| n.name.clone()).collect())
+            .unwrap_or_default();
+
+        if nodes.is_empty() {
+            warn!(
+                "No containers found for cluster '{}'; nothing to collect",
+                cluster_name
+            );
+        }
+
+        let capture = |args: &[&str]| -> String {
+            match std::process::Command::new(&self.cli_path)
+                .args(args)
+                .output()
+            {
+                Ok(out) => format!(
+                    "{}{}",
+                    String::from_utf8_lossy(&out.stdout),
+                    String::from_utf8_lossy(&out.stderr)
+                ),
+                Err(e) => format!("failed to run {} {:?}: {}\n", self.cli_path, args, e),
+            }
+        };
+
+        let kubeadm_log_cmd = format!("cat {} 2>&1 || true", KUBEADM_LOG_PATH);
+        for node in &nodes {
+            let files = [
+                ("inspect.json", capture(&["inspect", node])),
+                (
+                    "kubeadm.log",
+                    capture(&["exec", node, "sh", "-c", &kubeadm_log_cmd]),
+                ),
+                (
+                    "journal.log",
+                    capture(&["exec", node, "journalctl", "--no-pager"]),
+                ),
+            ];
+            for (suffix, contents) in files {
+                let path = dest_dir.join(format!("{}-{}", node, suffix));
+                fs::write(&path, contents)
+                    .with_context(|| format!("Failed to write {}", path.display()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Block until every kube-system pod is `Running` or `Succeeded`, polling every 5s.
+    ///
+    /// Polls from inside the control-plane container for the same reason as
+    /// `approve_cluster_kubelet_csrs`: the host cannot route to the in-VM API server.
+    /// Each pod's phase is printed whenever it changes so the user can follow progress.
+    pub async fn wait_for_kube_system_pods(
+        &self,
+        cluster_name: &str,
+        timeout_seconds: u64,
+    ) -> Result<()> {
+        let cp_name = format!("{}-control-plane", cluster_name);
+        info!(
+            "Waiting for kube-system pods in cluster '{}' (timeout: {}s)",
+            cluster_name, timeout_seconds
+        );
+
+        let pods_cmd = "kubectl get pods -n kube-system --no-headers \
+             -o custom-columns=NAME:.metadata.name,PHASE:.status.phase \
+             --kubeconfig=/etc/kubernetes/admin.conf";
+        let start_time = std::time::Instant::now();
+        let timeout = std::time::Duration::from_secs(timeout_seconds);
+        let mut last_seen: HashMap<String, String> = HashMap::new();
+
+        loop {
+            let mut cmd = std::process::Command::new(&self.cli_path);
+            cmd.args(["exec", &cp_name, "sh", "-c", pods_cmd]);
+            let phases = match cmd.output() {
+                Ok(out) if out.status.success() => {
+                    parse_pod_phases(&String::from_utf8_lossy(&out.stdout))
+                }
+                Ok(out) => {
+                    debug!(
+                        "kube-system pod query failed: {}",
+                        String::from_utf8_lossy(&out.stderr)
+                    );
+                    Vec::new()
+                }
+                Err(e) => {
+                    debug!("Failed to run kube-system pod query: {}", e);
+                    Vec::new()
+                }
+            };
+
+            for (pod, phase) in &phases {
+                if last_seen.get(pod) != Some(phase) {
+                    println!("  {:<50} {}", pod, phase);
+                    last_seen.insert(pod.clone(), phase.clone());
+                }
+            }
+
+            if pods_settled(&phases) {
+                info!(
+                    "All kube-system pods in cluster '{}' are running",
+                    cluster_name
+                );
+                return Ok(());
+            }
+
+            if start_time.elapsed() > timeout {
+                let pending: Vec<String> = phases
+                    .iter()
+                    .filter(|(_, phase)| phase != "Running" && phase != "Succeeded")
+                    .map(|(pod, phase)| format!("{} ({})", pod, phase))
+                    .collect();
+                return Err(anyhow::anyhow!(
+                    "Timeout waiting for kube-system pods in cluster '{}' after {}s; not ready: {}",
+                    cluster_name,
+                    timeout_seconds,
+                    if pending.is_empty() {
+                        "no pods reported".to_string()
+                    } else {
+                        pending.join(", ")
+                    }
+                ));
+            }
+
+            tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+        }
+    }
+
+    /// Current node and kube-system pod readiness, read from inside the
+    /// control-plane container (see `wait_for_kube_system_pods`).
+    pub async fn readiness_snapshot(&self, cluster_name: &str) -> Result<ReadinessSnapshot> {
+        let cp_name = format!("{}-control-plane", cluster_name);
+        let exec_kubectl = |kubectl_cmd: &str| -> Result<String> {
+            let output = std::process::Command::new(&self.cli_path)
+                .args(["exec", &cp_name, "sh", "-c", kubectl_cmd])
+                .output()
+                .context("Failed to query cluster readiness")?;
+            if !output.status.success() {
+                return Err(anyhow::anyhow!(
+                    "Readiness query failed: {}",
+                    String::from_utf8_lossy(&output.stderr)
+                ));
+            }
+            Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+        };
+
+        let nodes = parse_live_nodes(&exec_kubectl(
+            "kubectl get nodes -o json --kubeconfig=/etc/kubernetes/admin.conf",
+        )?)?;
+        let pods = parse_pod_phases(&exec_kubectl(
+            "kubectl get pods -n kube-system --no-headers \
+             -o custom-columns=NAME:.metadata.name,PHASE:.status.phase \
+             --kubeconfig=/etc/kubernetes/admin.conf",
+        )?);
+        Ok(ReadinessSnapshot::from_parts(&nodes, &pods))
+    }
+
+    /// Apply the `--psp` namespace labels (see [`pod_security_label_script`]).
+    pub async fn apply_pod_security_labels(&self, cluster_name: &str, level: &str) -> Result<()> {
+        let cp_name = format!("{}-control-plane", cluster_name);
+        info!(
+            "Enforcing Pod Security Standard '{}' on namespace 'default'",
+            level
+        );
+        self.run_node_script(&cp_name, &pod_security_label_script(level))
+            .await
+            .context("Failed to label namespaces for Pod Security admission")?;
+        Ok(())
+    }
+
+    /// Create the `--image-pull-secret` Secret in every
+    /// [`IMAGE_PULL_SECRET_NAMESPACES`] namespace from the docker config that
+    /// was copied to the control plane.
+    pub async fn create_image_pull_secrets(
+        &self,
+        cluster_name: &str,
+        secret_name: &str,
+    ) -> Result<()> {
+        let cp_name = format!("{}-control-plane", cluster_name);
+        info!(
+            "Creating image pull secret '{}' in namespaces {}",
+            secret_name,
+            IMAGE_PULL_SECRET_NAMESPACES.join(", ")
+        );
+        self.run_node_script(&cp_name, &image_pull_secret_script(secret_name))
+            .await
+            .context("Failed to create image pull secret")?;
+        Ok(())
+    }
+
+    /// Run a shell script inside a node and return its stdout; a non-zero exit
+    /// is an error carrying the script's stderr.
+    pub async fn run_node_script(&self, node_name: &str, script: &str) -> Result<String> {
+        // Async and killed on drop, so callers can bound it with a timeout
+        let output = tokio::process::Command::new(&self.cli_path)
+            .args(["exec", node_name, "sh", "-c", script])
+            .kill_on_drop(true)
+            .output()
+            .await
+            .with_context(|| format!("Failed to exec into '{}'", node_name))?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Script failed in '{}': {}",
+                node_name,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// Run `command` in a node with kina's stdin/stdout/stderr inherited, so an
+    /// interactive shell gets the real terminal (including resizes).
+    pub async fn exec_attached(
+        &self,
+        node_name: &str,
+        command: &[String],
+        tty: bool,
+    ) -> Result<i32> {
+        let status = std::process::Command::new(&self.cli_path)
+            .args(node_exec_args(node_name, command, tty))
+            .status()
+            .with_context(|| format!("Failed to exec into '{}'", node_name))?;
+        Ok(status.code().unwrap_or(1))
+    }
+
+    /// Run `entrypoint args...` in a throwaway container from `image` and
+    /// return its stdout
+    pub fn run_image_command(
+        &self,
+        image: &str,
+        entrypoint: &str,
+        args: &[&str],
+    ) -> Result<String> {
+        let output = std::process::Command::new(&self.cli_path)
+            .args(["run", "--rm", "--entrypoint", entrypoint, image])
+            .args(args)
+            .output()
+            .context("Failed to execute Apple Container CLI")?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "{} failed in {}: {}",
+                entrypoint,
+                image,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// The last `lines` kubelet journal entries from a node, newest first.
+    pub async fn node_logs(&self, node_name: &str, lines: u32) -> Result<String> {
+        let output = std::process::Command::new(&self.cli_path)
+            .args([
+                "exec",
+                node_name,
+                "journalctl",
+                "-u",
+                "kubelet",
+                "--no-pager",
+                "--reverse",
+                "-n",
+                &lines.to_string(),
+            ])
+            .output()
+            .with_context(|| format!("Failed to read kubelet logs from '{}'", node_name))?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "Failed to read kubelet logs from '{}': {}",
+                node_name,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// Install Cilium CNI plugin using the pinned cilium-cli and topology-correct helm values.
+    ///
+    /// Uses [`build_cilium_cli_install_script`] and either [`build_cilium_install_cmd`] (stock
+    /// kernel workaround profile) or [`build_cilium_install_cmd_ebpf`] (full-eBPF profile for
+    /// nodes booted on the custom kina kernel) so the exact commands are unit-testable without
+    /// spawning containers.
+    ///
+    /// Profile selection: when `kernel_path` is `Some`, the full-eBPF profile is used (custom
+    /// kernel has all required BPF options). When `None`, the stock workaround profile is used.
+    ///
+    /// After `cilium install`, runs a readiness gate (`cilium status --wait --wait-duration 5m`)
     /// with `KUBECONFIG=/etc/kubernetes/admin.conf`. On failure, captures diagnostics from
     /// `cilium status` (bare), `kubectl -n kube-system get pods -o wide`, and
     /// `kubectl -n kube-system logs ds/cilium --tail=100` and includes them in the error.
@@ -2825,15 +5705,16 @@ EOF"#,
         // Step 2: We need the control-plane VM IP to set k8sServiceHost.
         // The container_name is the control-plane node; fetch its IP.
         let cp_ip = self.get_container_ip(container_name).await?;
+        let api_server_port = self.api_server_port(container_name).await;
 
         // Step 3: Install Cilium with topology-correct --set values.
         // Profile selection: full-eBPF when custom kernel is set; stock workaround otherwise.
         // build_cilium_install_cmd_ebpf (custom kernel) retires all workarounds.
         // build_cilium_install_cmd (stock kernel) retains workarounds for kata-kernel gaps.
         let cilium_install_cmd = if kernel_path.is_some() {
-            build_cilium_install_cmd_ebpf(CILIUM_VERSION, &cp_ip)
+            build_cilium_install_cmd_ebpf(CILIUM_VERSION, &cp_ip, api_server_port)
         } else {
-            build_cilium_install_cmd(CILIUM_VERSION, &cp_ip)
+            build_cilium_install_cmd(CILIUM_VERSION, &cp_ip, api_server_port)
         };
 
         let mut cmd = std::process::Command::new(&self.cli_path);
@@ -2896,6 +5777,7 @@ EOF"#,
         cluster_name: &str,
         container_name: &str,
         vm_ip: &str,
+        api_server_port: u16,
     ) -> Result<()> {
         info!("Setting up kubeconfig for external access");
 
@@ -2916,7 +5798,8 @@ EOF"#,
 
         // Rewrite server URL to the live VM IP using the pure helper (idempotent,
         // handles any existing host: localhost, 127.0.0.1, old VM IP, cluster IP).
-        kubeconfig = crate::core::verify::rewrite_kubeconfig_server(&kubeconfig, vm_ip);
+        kubeconfig =
+            crate::core::verify::rewrite_kubeconfig_server(&kubeconfig, vm_ip, api_server_port);
 
         // Replace context and user names to be cluster-specific
         // kubeadm generates names like "kubernetes-admin@{clusterName}" and "kubernetes-admin"
@@ -2936,17 +5819,17 @@ EOF"#,
         // Save kubeconfig
         self.save_kubeconfig(cluster_name, &kubeconfig).await?;
 
-        // Verify host→<vm_ip>:6443 TCP reachability (bounded retry, non-fatal).
+        // Verify host→<vm_ip>:<api_server_port> TCP reachability (bounded retry, non-fatal).
         // Apple Container uses VM-per-container networking; the host may not always have a
         // direct route to the VM IP. We warn rather than hard-fail so the cluster remains
         // usable for in-container kubectl even when host access is blocked.
-        let reachable = check_tcp_reachable(vm_ip, 6443, 5).await;
+        let reachable = check_tcp_reachable(vm_ip, api_server_port, 5).await;
         if !reachable {
             let (bridge, gateway) = inspect_network_bridge(cluster_name, &self.cli_path);
             let diag = crate::core::verify::build_unreachable_diagnostic(
                 cluster_name,
                 vm_ip,
-                6443,
+                api_server_port,
                 bridge.as_deref(),
                 gateway.as_deref(),
             );
@@ -2957,6 +5840,29 @@ EOF"#,
         Ok(())
     }
 
+    /// The API server endpoint of a cluster: its control-plane VM IP and the
+    /// port from [`API_SERVER_PORT_LABEL`].
+    pub async fn get_api_server_endpoint(&self, cluster_name: &str) -> Result<(String, u16)> {
+        let cp_name = format!("{}-control-plane", cluster_name);
+        let ip = self.get_container_ip(&cp_name).await?;
+        Ok((ip, self.api_server_port(&cp_name).await))
+    }
+
+    /// API server port of control-plane container `container_name`, falling
+    /// back to [`DEFAULT_API_SERVER_PORT`] when it cannot be inspected.
+    async fn api_server_port(&self, container_name: &str) -> u16 {
+        match self.inspect_container(container_name).await {
+            Ok(inspect) => api_server_port_from_inspect(&inspect),
+            Err(e) => {
+                debug!(
+                    "Assuming API server port {} for '{}': {}",
+                    DEFAULT_API_SERVER_PORT, container_name, e
+                );
+                DEFAULT_API_SERVER_PORT
+            }
+        }
+    }
+
     /// Re-resolve the control-plane VM IP, rewrite the saved kubeconfig to use it,
     /// and verify host→control-plane TCP reachability.
     ///
@@ -2971,11 +5877,11 @@ EOF"#,
         let cp_name = format!("{}-control-plane", cluster_name);
 
         // Re-resolve the live VM IP in case the node restarted with a new address.
-        let vm_ip = self
-            .get_container_ip(&cp_name)
+        let (vm_ip, port) = self
+            .get_api_server_endpoint(cluster_name)
             .await
             .context("Failed to get control-plane VM IP; is the cluster running?")?;
-        info!("Control-plane current IP: {}", vm_ip);
+        info!("Control-plane current endpoint: {}:{}", vm_ip, port);
 
         // Read the saved kubeconfig; fall back to fetching from the container if absent.
         let home_dir = std::env::var("HOME").context("HOME environment variable not set")?;
@@ -3003,23 +5909,23 @@ EOF"#,
         };
 
         // Rewrite the server URL to the current VM IP (idempotent pure fn).
-        let rewritten = crate::core::verify::rewrite_kubeconfig_server(&current, &vm_ip);
+        let rewritten = crate::core::verify::rewrite_kubeconfig_server(&current, &vm_ip, port);
 
         // Persist the updated kubeconfig.
         self.save_kubeconfig(cluster_name, &rewritten).await?;
         info!("Kubeconfig rewritten for cluster '{}'", cluster_name);
 
         // Re-check reachability after the rewrite.
-        println!("Checking host reachability to {}:6443 ...", vm_ip);
-        let reachable = check_tcp_reachable(&vm_ip, 6443, 5).await;
+        println!("Checking host reachability to {}:{} ...", vm_ip, port);
+        let reachable = check_tcp_reachable(&vm_ip, port, 5).await;
         if reachable {
-            println!("  OK  {}:6443 is reachable from the host", vm_ip);
+            println!("  OK  {}:{} is reachable from the host", vm_ip, port);
         } else {
             let (bridge, gateway) = inspect_network_bridge(cluster_name, &self.cli_path);
             let diag = crate::core::verify::build_unreachable_diagnostic(
                 cluster_name,
                 &vm_ip,
-                6443,
+                port,
                 bridge.as_deref(),
                 gateway.as_deref(),
             );
@@ -3172,10 +6078,213 @@ impl AppleContainerClient {
     }
 }
 
+#[async_trait::async_trait]
+impl ClusterClientTrait for AppleContainerClient {
+    fn version(&self) -> &str {
+        AppleContainerClient::version(self)
+    }
+
+    fn container_version(&self) -> Result<&ContainerVersion> {
+        AppleContainerClient::container_version(self)
+    }
+
+    async fn create_cluster(&self, options: &CreateClusterOptions) -> Result<()> {
+        AppleContainerClient::create_cluster(self, options).await
+    }
+
+    async fn delete_cluster(&self, name: &str, options: &DeleteClusterOptions) -> Result<()> {
+        AppleContainerClient::delete_cluster(self, name, options).await
+    }
+
+    async fn rename_cluster(&self, old_name: &str, new_name: &str) -> Result<()> {
+        AppleContainerClient::rename_cluster(self, old_name, new_name).await
+    }
+
+    async fn pause_cluster(&self, name: &str) -> Result<()> {
+        AppleContainerClient::pause_cluster(self, name).await
+    }
+
+    async fn resume_cluster(&self, name: &str) -> Result<String> {
+        AppleContainerClient::resume_cluster(self, name).await
+    }
+
+    async fn list_clusters(&self) -> Result<Vec<ClusterInfo>> {
+        AppleContainerClient::list_clusters(self).await
+    }
+
+    async fn get_kubeconfig(&self, name: &str) -> Result<String> {
+        AppleContainerClient::get_kubeconfig(self, name).await
+    }
+
+    async fn repair_kubeconfig(&self, cluster_name: &str) -> Result<()> {
+        AppleContainerClient::repair_kubeconfig(self, cluster_name).await
+    }
+
+    async fn load_image(&self, options: &LoadImageOptions) -> Result<()> {
+        AppleContainerClient::load_image(self, options).await
+    }
+
+    async fn export_image(&self, image: &str) -> Result<tempfile::TempPath> {
+        self.export_image_tar(image).await
+    }
+
+    async fn pull_image(&self, image: &str, platform: Option<&str>) -> Result<()> {
+        AppleContainerClient::pull_image(self, image, platform).await
+    }
+
+    async fn verify_image(&self, image: &str, platform: Option<&str>) -> Result<()> {
+        AppleContainerClient::verify_image(self, image, platform).await
+    }
+
+    async fn list_networks(&self) -> Result<Vec<NetworkInfo>> {
+        AppleContainerClient::list_networks(self).await
+    }
+
+    async fn create_network(
+        &self,
+        name: &str,
+        subnet: Option<&str>,
+        labels: &BTreeMap<String, String>,
+    ) -> Result<NetworkInfo> {
+        AppleContainerClient::create_network(self, name, subnet, labels).await
+    }
+
+    async fn delete_network(&self, name: &str) -> Result<()> {
+        AppleContainerClient::delete_network(self, name).await
+    }
+
+    async fn inspect_container(&self, name: &str) -> Result<serde_json::Value> {
+        AppleContainerClient::inspect_container(self, name).await
+    }
+
+    async fn read_kubeadm_config(&self, cluster_name: &str) -> Result<String> {
+        AppleContainerClient::read_kubeadm_config(self, cluster_name).await
+    }
+
+    async fn readiness_snapshot(&self, cluster_name: &str) -> Result<ReadinessSnapshot> {
+        AppleContainerClient::readiness_snapshot(self, cluster_name).await
+    }
+
+    async fn run_node_script(&self, node_name: &str, script: &str) -> Result<String> {
+        AppleContainerClient::run_node_script(self, node_name, script).await
+    }
+
+    async fn node_logs(&self, node_name: &str, lines: u32) -> Result<String> {
+        AppleContainerClient::node_logs(self, node_name, lines).await
+    }
+
+    async fn exec_attached(&self, node_name: &str, command: &[String], tty: bool) -> Result<i32> {
+        AppleContainerClient::exec_attached(self, node_name, command, tty).await
+    }
+
+    async fn install_cni(
+        &self,
+        cluster_name: &str,
+        cni: CniPlugin,
+        kernel_path: Option<&std::path::Path>,
+    ) -> Result<()> {
+        AppleContainerClient::install_cni(self, cluster_name, cni, kernel_path).await
+    }
+
+    fn approve_cluster_kubelet_csrs(&self, cluster_name: &str) {
+        AppleContainerClient::approve_cluster_kubelet_csrs(self, cluster_name)
+    }
+
+    async fn apply_pod_security_labels(&self, cluster_name: &str, level: &str) -> Result<()> {
+        AppleContainerClient::apply_pod_security_labels(self, cluster_name, level).await
+    }
+
+    async fn create_image_pull_secrets(&self, cluster_name: &str, secret_name: &str) -> Result<()> {
+        AppleContainerClient::create_image_pull_secrets(self, cluster_name, secret_name).await
+    }
+
+    async fn wait_for_kube_system_pods(
+        &self,
+        cluster_name: &str,
+        timeout_seconds: u64,
+    ) -> Result<()> {
+        AppleContainerClient::wait_for_kube_system_pods(self, cluster_name, timeout_seconds).await
+    }
+
+    async fn collect_failure_diagnostics(
+        &self,
+        cluster_name: &str,
+        dest_dir: &std::path::Path,
+    ) -> Result<()> {
+        AppleContainerClient::collect_failure_diagnostics(self, cluster_name, dest_dir).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_container_version_reads_build() {
+        let parsed = parse_container_version(
+            "container CLI version 1.0.0 (build: release, commit: 1a2b3c4)",
+        )
+        .unwrap();
+        assert_eq!(
+            parsed,
+            ContainerVersion {
+                version: "1.0.0".to_string(),
+                build: "release".to_string(),
+            }
+        );
+
+        let parsed = parse_container_version("container CLI version 1.0.0 (stub)").unwrap();
+        assert_eq!(parsed.build, "unknown");
+        assert!(parse_container_version("not a version").is_err());
+    }
+
+    #[test]
+    fn test_parse_version_json() {
+        let parsed =
+            parse_version_json(r#"{"version":"1.0.0","buildType":"release","commit":"ee848e3"}"#)
+                .unwrap();
+        assert_eq!(parsed.version, "1.0.0");
+        assert_eq!(parsed.build, "release");
+        assert_eq!(
+            parse_version_json(r#"{"version":"v1.1.0"}"#).unwrap().build,
+            "unknown"
+        );
+        assert!(parse_version_json("[]").is_err());
+        assert!(parse_version_json(r#"{"version":"dev"}"#).is_err());
+        assert!(parse_version_json("container CLI version 1.0.0").is_err());
+
+        assert_eq!(parse_semver("1.1").unwrap(), semver::Version::new(1, 1, 0));
+        assert_eq!(parse_semver("v2").unwrap(), semver::Version::new(2, 0, 0));
+        assert!(parse_semver("one.two").is_err());
+        assert!(validate_version("1").is_err());
+    }
+
+    #[test]
+    fn test_cni_manifest_url_pins_versions() {
+        assert_eq!(cni_manifest_url(&CniPlugin::Ptp), None);
+        assert_eq!(cni_manifest_url(&CniPlugin::Cilium), None);
+        let flannel = cni_manifest_url(&CniPlugin::Flannel).unwrap();
+        assert!(flannel.contains(FLANNEL_VERSION));
+        assert!(flannel.ends_with("kube-flannel.yml"));
+        let calico = cni_manifest_url(&CniPlugin::Calico).unwrap();
+        assert!(calico.contains(CALICO_VERSION));
+        assert!(calico.ends_with("calico.yaml"));
+    }
+
+    #[test]
+    fn test_cni_removal_cmd_targets_plugin_workloads() {
+        assert_eq!(cni_removal_cmd(&CniPlugin::Ptp), None);
+        assert!(cni_removal_cmd(&CniPlugin::Cilium)
+            .unwrap()
+            .contains("daemonset/cilium"));
+        assert!(cni_removal_cmd(&CniPlugin::Flannel)
+            .unwrap()
+            .contains("namespace kube-flannel"));
+        assert!(cni_removal_cmd(&CniPlugin::Calico)
+            .unwrap()
+            .contains("daemonset/calico-node"));
+    }
+
     #[test]
     fn test_parse_kubeadm_join_info_standard() {
         let output = r#"
@@ -3186,7 +6295,8 @@ Then you can join any number of worker nodes by running the following on each as
 kubeadm join 192.168.64.5:6443 --token abcdef.0123456789abcdef \
     --discovery-token-ca-cert-hash sha256:abc123def456
 "#;
-        let result = AppleContainerClient::parse_kubeadm_join_info(output, "192.168.64.5").unwrap();
+        let result =
+            AppleContainerClient::parse_kubeadm_join_info(output, "192.168.64.5", 6443).unwrap();
         assert_eq!(result.token, "abcdef.0123456789abcdef");
         assert_eq!(result.ca_cert_hash, "sha256:abc123def456");
         assert_eq!(result.control_plane_endpoint, "192.168.64.5:6443");
@@ -3195,16 +6305,25 @@ kubeadm join 192.168.64.5:6443 --token abcdef.0123456789abcdef \
     #[test]
     fn test_parse_kubeadm_join_info_single_line() {
         let output = "kubeadm join 10.0.0.1:6443 --token mytoken.1234567890abcdef --discovery-token-ca-cert-hash sha256:deadbeef";
-        let result = AppleContainerClient::parse_kubeadm_join_info(output, "10.0.0.1").unwrap();
+        let result =
+            AppleContainerClient::parse_kubeadm_join_info(output, "10.0.0.1", 6443).unwrap();
         assert_eq!(result.token, "mytoken.1234567890abcdef");
         assert_eq!(result.ca_cert_hash, "sha256:deadbeef");
         assert_eq!(result.control_plane_endpoint, "10.0.0.1:6443");
     }
 
+    #[test]
+    fn test_parse_kubeadm_join_info_custom_api_server_port() {
+        let output = "kubeadm join 10.0.0.1:8443 --token mytoken.1234567890abcdef --discovery-token-ca-cert-hash sha256:deadbeef";
+        let result =
+            AppleContainerClient::parse_kubeadm_join_info(output, "10.0.0.1", 8443).unwrap();
+        assert_eq!(result.control_plane_endpoint, "10.0.0.1:8443");
+    }
+
     #[test]
     fn test_parse_kubeadm_join_info_missing_token() {
         let output = "kubeadm join 10.0.0.1:6443 --discovery-token-ca-cert-hash sha256:deadbeef";
-        let result = AppleContainerClient::parse_kubeadm_join_info(output, "10.0.0.1");
+        let result = AppleContainerClient::parse_kubeadm_join_info(output, "10.0.0.1", 6443);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("token"));
     }
@@ -3212,7 +6331,7 @@ kubeadm join 192.168.64.5:6443 --token abcdef.0123456789abcdef \
     #[test]
     fn test_parse_kubeadm_join_info_missing_hash() {
         let output = "kubeadm join 10.0.0.1:6443 --token mytoken.1234567890abcdef";
-        let result = AppleContainerClient::parse_kubeadm_join_info(output, "10.0.0.1");
+        let result = AppleContainerClient::parse_kubeadm_join_info(output, "10.0.0.1", 6443);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("CA cert hash"));
     }
@@ -3220,7 +6339,7 @@ kubeadm join 192.168.64.5:6443 --token abcdef.0123456789abcdef \
     #[test]
     fn test_parse_kubeadm_join_info_no_join_command() {
         let output = "Some other output without join info";
-        let result = AppleContainerClient::parse_kubeadm_join_info(output, "10.0.0.1");
+        let result = AppleContainerClient::parse_kubeadm_join_info(output, "10.0.0.1", 6443);
         assert!(result.is_err());
     }
 
@@ -3331,5 +6450,889 @@ kubeadm join 192.168.64.5:6443 --token abcdef.0123456789abcdef \
         let json = r#"[{"id":"n1","configuration":{"creationDate":"2026-06-14T21:52:43Z","labels":{"io.kina.cluster":"c"}},"status":{"state":"running"}}]"#;
         let parsed = parse_container_list(json).unwrap();
         assert_eq!(parsed[0].created.as_deref(), Some("2026-06-14T21:52:43Z"));
+        assert_eq!(
+            parsed[0].creation_timestamp(),
+            Some("2026-06-14T21:52:43Z".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn parse_container_list_falls_back_to_start_time() {
+        let json = r#"[{"id":"n1","configuration":{"labels":{}},"status":{"state":"running","startTime":"2026-06-14T21:50:00+02:00"}},
+                       {"id":"n2","configuration":{"creationDate":"soon"},"status":{}}]"#;
+        let parsed = parse_container_list(json).unwrap();
+        assert_eq!(
+            parsed[0].creation_timestamp(),
+            Some("2026-06-14T19:50:00Z".parse().unwrap())
+        );
+        assert_eq!(parsed[1].creation_timestamp(), None);
+    }
+
+    #[test]
+    fn parse_inspect_output_unwraps_single_container() {
+        let value = parse_inspect_output(r#"[{"configuration":{"id":"dev-worker"}}]"#).unwrap();
+        assert_eq!(value["configuration"]["id"], "dev-worker");
+
+        let value = parse_inspect_output(r#"[{"id":"a"},{"id":"b"}]"#).unwrap();
+        assert_eq!(value.as_array().unwrap().len(), 2);
+        assert!(parse_inspect_output("not json").is_err());
+    }
+
+    #[test]
+    fn parse_network_list_reads_config_and_status() {
+        let json = r#"[
+            {"id":"default","state":"running","config":{"id":"default","labels":{}},
+             "status":{"address":"192.168.64.0/24","gateway":"192.168.64.1"}},
+            {"configuration":{"id":"lab","labels":{"io.kina.cluster":"lab"},"ipv4Subnet":"10.50.0.0/24"},
+             "status":{"state":"running"}}
+        ]"#;
+        let networks = parse_network_list(json).unwrap();
+        assert_eq!(networks[0].name, "default");
+        assert_eq!(networks[0].subnet.as_deref(), Some("192.168.64.0/24"));
+        assert_eq!(networks[0].kina_cluster(), None);
+        assert_eq!(networks[1].name, "lab");
+        assert_eq!(networks[1].state, "running");
+        assert_eq!(networks[1].subnet.as_deref(), Some("10.50.0.0/24"));
+        assert_eq!(networks[1].kina_cluster(), Some("lab"));
+        assert!(parse_network_list("  ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn network_create_args_label_the_network_for_kina() {
+        let spec = NetworkSpecBuilder::new()
+            .name("lab")
+            .subnet(Some("10.50.0.0/24"))
+            .build()
+            .unwrap();
+        let args = network_create_args(&spec);
+        assert_eq!(
+            args,
+            [
+                "network",
+                "create",
+                "--label",
+                "io.kina.cluster=lab",
+                "--subnet",
+                "10.50.0.0/24",
+                "lab"
+            ]
+        );
+
+        let labels = BTreeMap::from([("io.kina.cluster".to_string(), "dev".to_string())]);
+        let spec = NetworkSpecBuilder::new()
+            .name("shared")
+            .labels(&labels)
+            .build()
+            .unwrap();
+        let args = network_create_args(&spec);
+        assert_eq!(
+            args,
+            [
+                "network",
+                "create",
+                "--label",
+                "io.kina.cluster=dev",
+                "shared"
+            ]
+        );
+    }
+
+    #[test]
+    fn kubeadm_init_config_with_defaults_matches_plain_config() {
+        assert_eq!(
+            generate_kubeadm_init_config_with(
+                "kina-control-plane",
+                "10.0.0.5",
+                "kina",
+                &KubeadmConfigOptions::default()
+            ),
+            generate_kubeadm_init_config("kina-control-plane", "10.0.0.5", "kina")
+        );
+    }
+
+    #[test]
+    fn kubeadm_init_config_renders_api_server_extra_args() {
+        let mut kubeadm = KubeadmConfigOptions::default();
+        kubeadm.api_server_extra_args.insert(
+            "oidc-issuer-url".to_string(),
+            "https://dex.example.com".to_string(),
+        );
+        kubeadm
+            .api_server_extra_args
+            .insert("oidc-username-prefix".to_string(), "say \"hi\"".to_string());
+
+        let config =
+            generate_kubeadm_init_config_with("kina-control-plane", "10.0.0.5", "kina", &kubeadm);
+
+        assert!(config.contains(
+            "    value: \"api/all=true\"\n  - name: oidc-issuer-url\n    value: \"https://dex.example.com\"\n"
+        ));
+        assert!(config.contains("    value: \"say \\\"hi\\\"\"\n"));
+
+        let cluster_stanza = config.split("---").nth(1).unwrap();
+        let parsed: serde_yaml::Value = serde_yaml::from_str(cluster_stanza).unwrap();
+        assert_eq!(
+            parsed["apiServer"]["extraArgs"][1]["value"],
+            "https://dex.example.com"
+        );
+        assert_eq!(parsed["apiServer"]["extraArgs"][2]["value"], "say \"hi\"");
+    }
+
+    #[test]
+    fn kubelet_extra_args_render_on_init_and_join_configs() {
+        let mut kubeadm = KubeadmConfigOptions::default();
+        kubeadm
+            .kubelet_extra_args
+            .insert("max-pods".to_string(), "250".to_string());
+
+        let init =
+            generate_kubeadm_init_config_with("kina-control-plane", "10.0.0.5", "kina", &kubeadm);
+        let init_stanza: serde_yaml::Value =
+            serde_yaml::from_str(init.split("---").next().unwrap()).unwrap();
+        let args = &init_stanza["nodeRegistration"]["kubeletExtraArgs"];
+        assert_eq!(args[2]["name"], "max-pods");
+        assert_eq!(args[2]["value"], "250");
+
+        let join_info = KubeadmJoinInfo {
+            token: "abcdef.0123456789abcdef".to_string(),
+            ca_cert_hash: "sha256:abc".to_string(),
+            control_plane_endpoint: "10.0.0.5:6443".to_string(),
+        };
+        let join =
+            generate_worker_join_config_with("kina-worker", "10.0.0.6", &join_info, &kubeadm);
+        let join_stanza: serde_yaml::Value =
+            serde_yaml::from_str(join.split("---").next().unwrap()).unwrap();
+        let args = &join_stanza["nodeRegistration"]["kubeletExtraArgs"];
+        assert_eq!(args[1]["name"], "max-pods");
+    }
+
+    #[test]
+    fn api_server_port_sets_bind_port_and_endpoint() {
+        let kubeadm = KubeadmConfigOptions {
+            api_server_port: Some(8443),
+            ..KubeadmConfigOptions::default()
+        };
+        let init =
+            generate_kubeadm_init_config_with("kina-control-plane", "10.0.0.5", "kina", &kubeadm);
+        let docs: Vec<serde_yaml::Value> = init
+            .split("---\n")
+            .map(|doc| serde_yaml::from_str(doc).unwrap())
+            .collect();
+        assert_eq!(docs[0]["localAPIEndpoint"]["bindPort"], 8443);
+        assert_eq!(docs[1]["controlPlaneEndpoint"], "10.0.0.5:8443");
+
+        let join_info = KubeadmJoinInfo {
+            token: "abcdef.0123456789abcdef".to_string(),
+            ca_cert_hash: "sha256:00".to_string(),
+            control_plane_endpoint: "10.0.0.5:8443".to_string(),
+        };
+        let join = generate_control_plane_join_config("10.0.0.6", &join_info, "c0ffee", &kubeadm);
+        assert!(join.contains("    bindPort: 8443\n"));
+    }
+
+    #[test]
+    fn api_server_port_from_inspect_reads_the_label() {
+        let inspect = serde_json::json!({
+            "configuration": { "labels": { "io.kina.api-server-port": "8443" } }
+        });
+        assert_eq!(api_server_port_from_inspect(&inspect), 8443);
+        let inspect = serde_json::json!({ "configuration": { "labels": {} } });
+        assert_eq!(
+            api_server_port_from_inspect(&inspect),
+            DEFAULT_API_SERVER_PORT
+        );
+    }
+
+    #[test]
+    fn node_name_sets_init_node_registration_name() {
+        let kubeadm = KubeadmConfigOptions {
+            node_name: Some("fixture-node".to_string()),
+            ..KubeadmConfigOptions::default()
+        };
+        let init =
+            generate_kubeadm_init_config_with("kina-control-plane", "10.0.0.5", "kina", &kubeadm);
+        let init_stanza: serde_yaml::Value =
+            serde_yaml::from_str(init.split("---").next().unwrap()).unwrap();
+        assert_eq!(init_stanza["nodeRegistration"]["name"], "fixture-node");
+
+        let plain = generate_kubeadm_init_config("kina-control-plane", "10.0.0.5", "kina");
+        let init_stanza: serde_yaml::Value =
+            serde_yaml::from_str(plain.split("---").next().unwrap()).unwrap();
+        assert!(init_stanza["nodeRegistration"]["name"].is_null());
+    }
+
+    #[test]
+    fn kubeadm_preflight_args_ignore_swap_when_disabled() {
+        let kubeadm = KubeadmConfigOptions {
+            disable_swap_check: true,
+            ..KubeadmConfigOptions::default()
+        };
+        assert_eq!(
+            kubeadm_preflight_args(&kubeadm),
+            ["--ignore-preflight-errors=Swap"]
+        );
+        assert!(kubeadm_preflight_args(&KubeadmConfigOptions::default()).is_empty());
+
+        let kubeadm = KubeadmConfigOptions {
+            disable_swap_check: true,
+            skip_preflight: true,
+            ..KubeadmConfigOptions::default()
+        };
+        assert_eq!(
+            kubeadm_preflight_args(&kubeadm),
+            ["--ignore-preflight-errors=all"]
+        );
+    }
+
+    #[test]
+    fn container_stop_attempts_honour_the_grace_period() {
+        assert_eq!(
+            container_stop_attempts(30),
+            [(None, 30), (Some("SIGKILL"), 10)]
+        );
+        assert_eq!(container_stop_attempts(0), [(Some("SIGKILL"), 0)]);
+    }
+
+    #[test]
+    fn cri_o_runtime_sets_cri_socket_on_init_and_join_configs() {
+        let kubeadm = KubeadmConfigOptions {
+            container_runtime: ContainerRuntime::CriO,
+            ..KubeadmConfigOptions::default()
+        };
+        let init =
+            generate_kubeadm_init_config_with("kina-control-plane", "10.0.0.5", "kina", &kubeadm);
+        assert_eq!(
+            init.matches("criSocket: unix:///var/run/crio/crio.sock")
+                .count(),
+            2
+        );
+        assert!(!init.contains("containerd.sock"));
+
+        let join_info = KubeadmJoinInfo {
+            token: "abcdef.0123456789abcdef".to_string(),
+            ca_cert_hash: "sha256:abc".to_string(),
+            control_plane_endpoint: "10.0.0.5:6443".to_string(),
+        };
+        let join = generate_control_plane_join_config("10.0.0.6", &join_info, "c0ffee", &kubeadm);
+        let join_stanza: serde_yaml::Value =
+            serde_yaml::from_str(join.split("---").next().unwrap()).unwrap();
+        assert_eq!(
+            join_stanza["nodeRegistration"]["criSocket"],
+            "unix:///var/run/crio/crio.sock"
+        );
+
+        let default = generate_worker_join_config("kina-worker", "10.0.0.6", &join_info);
+        assert!(default.contains("criSocket: unix:///run/containerd/containerd.sock"));
+    }
+
+    #[test]
+    fn kubeadm_init_config_renders_controller_manager_extra_args() {
+        let mut kubeadm = KubeadmConfigOptions::default();
+        kubeadm
+            .controller_manager_extra_args
+            .insert("allocate-node-cidrs".to_string(), "false".to_string());
+
+        let config =
+            generate_kubeadm_init_config_with("kina-control-plane", "10.0.0.5", "kina", &kubeadm);
+        let cluster_stanza = config.split("---").nth(1).unwrap();
+        let parsed: serde_yaml::Value = serde_yaml::from_str(cluster_stanza).unwrap();
+        let args = &parsed["controllerManager"]["extraArgs"];
+        assert_eq!(args[0]["name"], "enable-hostpath-provisioner");
+        assert_eq!(args[1]["name"], "allocate-node-cidrs");
+        assert_eq!(args[1]["value"], "false");
+        assert_eq!(
+            parsed["scheduler"],
+            serde_yaml::Value::Mapping(Default::default())
+        );
+    }
+
+    #[test]
+    fn kubelet_config_merges_over_defaults_but_keeps_locked_fields() {
+        let user = b"apiVersion: kubelet.config.k8s.io/v1beta1
+kind: KubeletConfiguration
+cgroupDriver: cgroupfs
+systemReserved:
+  cpu: 500m
+  memory: 512Mi
+evictionHard:
+  memory.available: 200Mi
+authentication:
+  anonymous:
+    enabled: true
+";
+        let (merged, ignored) = merge_kubelet_config(user).unwrap();
+        assert_eq!(ignored, ["cgroupDriver"]);
+
+        let kubeadm = KubeadmConfigOptions {
+            kubelet_config: Some(merged),
+            ..Default::default()
+        };
+        let config =
+            generate_kubeadm_init_config_with("kina-control-plane", "10.0.0.5", "kina", &kubeadm);
+        let stanza = config
+            .split("---")
+            .find(|doc| doc.contains("kind: KubeletConfiguration"))
+            .unwrap();
+        let parsed: serde_yaml::Value = serde_yaml::from_str(stanza).unwrap();
+        assert_eq!(parsed["cgroupDriver"], "systemd");
+        assert_eq!(parsed["serverTLSBootstrap"], true);
+        assert_eq!(parsed["systemReserved"]["memory"], "512Mi");
+        assert_eq!(parsed["evictionHard"]["memory.available"], "200Mi");
+        assert_eq!(parsed["authentication"]["anonymous"]["enabled"], true);
+        assert_eq!(parsed["authentication"]["webhook"]["enabled"], true);
+
+        assert!(merge_kubelet_config(b"kind: KubeProxyConfiguration\n").is_err());
+        assert!(merge_kubelet_config(b"- not a mapping\n").is_err());
+    }
+
+    #[test]
+    fn kubeadm_init_config_mounts_scheduler_config() {
+        let prepared = prepare_scheduler_config(
+            b"apiVersion: kubescheduler.config.k8s.io/v1\nkind: KubeSchedulerConfiguration\n",
+        )
+        .unwrap();
+        let prepared: serde_yaml::Value = serde_yaml::from_slice(&prepared).unwrap();
+        assert_eq!(
+            prepared["clientConnection"]["kubeconfig"],
+            SCHEDULER_KUBECONFIG_NODE_PATH
+        );
+        let custom = b"kind: KubeSchedulerConfiguration\nclientConnection:\n  kubeconfig: /etc/custom.conf\n";
+        assert_eq!(prepare_scheduler_config(custom).unwrap(), custom);
+        let qps = prepare_scheduler_config(
+            b"kind: KubeSchedulerConfiguration\nclientConnection:\n  qps: 50\n",
+        )
+        .unwrap();
+        let qps: serde_yaml::Value = serde_yaml::from_slice(&qps).unwrap();
+        assert_eq!(qps["clientConnection"]["qps"], 50);
+        assert_eq!(
+            qps["clientConnection"]["kubeconfig"],
+            SCHEDULER_KUBECONFIG_NODE_PATH
+        );
+        assert!(prepare_scheduler_config(b"kind: KubeletConfiguration\n").is_err());
+
+        let mut kubeadm = KubeadmConfigOptions::default();
+        kubeadm.add_scheduler_config(b"kind: KubeSchedulerConfiguration\n".to_vec());
+        assert_eq!(kubeadm.files[0].0, SCHEDULER_CONFIG_NODE_PATH);
+
+        let config =
+            generate_kubeadm_init_config_with("kina-control-plane", "10.0.0.5", "kina", &kubeadm);
+        let cluster_stanza = config.split("---").nth(1).unwrap();
+        let parsed: serde_yaml::Value = serde_yaml::from_str(cluster_stanza).unwrap();
+        let scheduler = &parsed["scheduler"];
+        assert_eq!(scheduler["extraArgs"][0]["name"], "config");
+        assert_eq!(
+            scheduler["extraArgs"][0]["value"],
+            SCHEDULER_CONFIG_NODE_PATH
+        );
+        assert_eq!(
+            scheduler["extraVolumes"][0]["mountPath"],
+            "/etc/kubernetes/scheduler"
+        );
+        assert_eq!(scheduler["extraVolumes"][0]["readOnly"], true);
+    }
+
+    #[tokio::test]
+    async fn load_into_nodes_fails_only_when_every_node_fails() {
+        let nodes: Vec<String> = ["dev-control-plane", "dev-worker", "dev-worker-2"]
+            .iter()
+            .map(|n| n.to_string())
+            .collect();
+        let failing = |failed: &'static [&'static str]| {
+            move |node: String| async move {
+                if failed.contains(&node.as_str()) {
+                    Err(anyhow::anyhow!("ctr import failed"))
+                } else {
+                    Ok(())
+                }
+            }
+        };
+
+        load_into_nodes("app:dev", "dev", &nodes, failing(&[]))
+            .await
+            .unwrap();
+        load_into_nodes("app:dev", "dev", &nodes, failing(&["dev-worker"]))
+            .await
+            .unwrap();
+        let err = load_into_nodes(
+            "app:dev",
+            "dev",
+            &nodes,
+            failing(&["dev-control-plane", "dev-worker", "dev-worker-2"]),
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("into any node of cluster 'dev'"));
+        assert!(err.to_string().contains("dev-worker-2: ctr import failed"));
+    }
+
+    #[test]
+    fn file_size_and_sha256_matches_in_memory_digest() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("image.tar");
+        let bytes: Vec<u8> = (0..3_000_000u32).map(|i| (i % 251) as u8).collect();
+        fs::write(&path, &bytes).unwrap();
+
+        let (len, sha) = file_size_and_sha256(&path).unwrap();
+        assert_eq!(len, bytes.len() as u64);
+        assert_eq!(sha, sha256_hex(&bytes));
+        assert!(file_size_and_sha256(&dir.path().join("missing.tar")).is_err());
+    }
+
+    #[test]
+    fn container_run_args_match_single_node_layout() {
+        let spec = ContainerSpecBuilder::new("kina-control-plane", "kindest/node:v1.36.1")
+            .label("io.kina.cluster", "kina")
+            .label("io.kina.role", "control-plane,worker")
+            .tmpfs("/tmp")
+            .resources(4, "4g")
+            .kernel(Some(std::path::Path::new("/k/vmlinux")))
+            .volumes(&[(
+                std::path::PathBuf::from("/host/audit"),
+                "/var/log/kubernetes".to_string(),
+            )])
+            .env("container", "docker")
+            .command(["/sbin/init"])
+            .build()
+            .unwrap();
+        assert_eq!(
+            container_run_args(&spec),
+            [
+                "run",
+                "-d",
+                "--name",
+                "kina-control-plane",
+                "--label",
+                "io.kina.cluster=kina",
+                "--label",
+                "io.kina.role=control-plane,worker",
+                "--tmpfs",
+                "/tmp",
+                "--cap-add",
+                "ALL",
+                "--cpus",
+                "4",
+                "--memory",
+                "4g",
+                "--kernel",
+                "/k/vmlinux",
+                "--volume",
+                "/host/audit:/var/log/kubernetes",
+                "--env",
+                "container=docker",
+                "kindest/node:v1.36.1",
+                "/sbin/init",
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_image_create_args_pass_the_platform_through() {
+        assert_eq!(
+            verify_image_create_args("probe", "nginx:latest", None),
+            ["create", "--name", "probe", "nginx:latest"]
+        );
+        assert_eq!(
+            verify_image_create_args("probe", "nginx:latest", Some("linux/amd64")),
+            [
+                "create",
+                "--name",
+                "probe",
+                "--platform",
+                "linux/amd64",
+                "nginx:latest"
+            ]
+        );
+    }
+
+    #[test]
+    fn node_exec_args_add_tty_only_when_interactive() {
+        assert_eq!(
+            node_exec_args("dev-control-plane", &["bash".to_string()], true),
+            ["exec", "-i", "-t", "dev-control-plane", "bash"]
+        );
+        assert_eq!(
+            node_exec_args(
+                "dev-worker",
+                &["sudo".to_string(), "crictl".to_string(), "ps".to_string()],
+                false
+            ),
+            ["exec", "-i", "dev-worker", "sudo", "crictl", "ps"]
+        );
+    }
+
+    #[test]
+    fn containerd_config_snippet_merges_into_node_config() {
+        let existing = r#"version = 2
+
+[plugins."io.containerd.grpc.v1.cri"]
+  sandbox_image = "registry.k8s.io/pause:3.7"
+
+[plugins."io.containerd.grpc.v1.cri".containerd]
+  snapshotter = "overlayfs"
+  default_runtime_name = "runc"
+"#;
+        let snippet = r#"[plugins."io.containerd.grpc.v1.cri"]
+sandbox_image = "registry.k8s.io/pause:3.10"
+
+[plugins."io.containerd.grpc.v1.cri".containerd]
+snapshotter = "native"
+
+[plugins."io.containerd.grpc.v1.cri".cni]
+bin_dir = "/opt/cni/custom"
+"#;
+        let merged: toml::Value =
+            toml::from_str(&merge_containerd_config(existing, snippet).unwrap()).unwrap();
+        let cri = &merged["plugins"]["io.containerd.grpc.v1.cri"];
+        assert_eq!(merged["version"].as_integer(), Some(2));
+        assert_eq!(
+            cri["sandbox_image"].as_str(),
+            Some("registry.k8s.io/pause:3.10")
+        );
+        assert_eq!(cri["containerd"]["snapshotter"].as_str(), Some("native"));
+        assert_eq!(
+            cri["containerd"]["default_runtime_name"].as_str(),
+            Some("runc")
+        );
+        assert_eq!(cri["cni"]["bin_dir"].as_str(), Some("/opt/cni/custom"));
+
+        assert!(validate_containerd_config("[plugins\nbroken").is_err());
+        assert!(merge_containerd_config(existing, "sandbox_image = ").is_err());
+    }
+
+    #[test]
+    fn insecure_registry_hosts_toml_skips_verification() {
+        assert_eq!(
+            registry_hosts_toml_path("localhost:5000"),
+            "/etc/containerd/certs.d/localhost:5000/hosts.toml"
+        );
+        let toml = insecure_registry_hosts_toml("localhost:5000");
+        let parsed: toml::Value = toml::from_str(&toml).unwrap();
+        assert_eq!(parsed["server"].as_str(), Some("http://localhost:5000"));
+        assert_eq!(
+            parsed["host"]["https://localhost:5000"]["skip_verify"].as_bool(),
+            Some(true)
+        );
+        assert!(parsed["host"]["http://localhost:5000"]["capabilities"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|c| c.as_str() == Some("pull")));
+        assert!(!toml.contains("registry.mirrors"));
+    }
+
+    #[test]
+    fn docker_config_json_needs_auths() {
+        assert!(validate_docker_config_json(
+            br#"{"auths": {"registry.example.com": {"auth": "dXNlcjpwYXNz"}}}"#
+        )
+        .is_ok());
+        assert!(validate_docker_config_json(br#"{"credsStore": "desktop"}"#).is_err());
+        assert!(validate_docker_config_json(b"auths:").is_err());
+
+        let script = image_pull_secret_script("regcred");
+        assert!(script.contains("create secret generic 'regcred' --namespace=default"));
+        assert!(script.contains("--namespace=kube-system"));
+        assert!(script.contains("--from-file=.dockerconfigjson=/var/lib/kubelet/config.json"));
+    }
+
+    #[test]
+    fn kubeadm_init_config_renders_etcd_settings() {
+        let mut kubeadm = KubeadmConfigOptions::default();
+        kubeadm
+            .etcd
+            .extra_args
+            .insert("auto-compaction-mode".to_string(), "periodic".to_string());
+        kubeadm.etcd.data_dir = Some("/var/lib/etcd-data".to_string());
+        kubeadm.etcd.peer_cert_sans = vec!["etcd.example.com".to_string()];
+
+        let config =
+            generate_kubeadm_init_config_with("kina-control-plane", "10.0.0.5", "kina", &kubeadm);
+        let cluster_stanza = config.split("---").nth(1).unwrap();
+        let parsed: serde_yaml::Value = serde_yaml::from_str(cluster_stanza).unwrap();
+        let local = &parsed["etcd"]["local"];
+        assert_eq!(local["dataDir"], "/var/lib/etcd-data");
+        assert_eq!(local["extraArgs"][0]["name"], "auto-compaction-mode");
+        assert_eq!(local["extraArgs"][0]["value"], "periodic");
+        assert_eq!(local["peerCertSANs"][0], "etcd.example.com");
+
+        let plain = generate_kubeadm_init_config("kina-control-plane", "10.0.0.5", "kina");
+        assert!(plain.contains("etcd:\n  local:\n    dataDir: \"/var/lib/etcd\"\n---"));
+    }
+
+    #[test]
+    fn control_plane_count_requires_macos_26_for_ha() {
+        assert!(validate_control_plane_count(1, None).is_ok());
+        assert!(validate_control_plane_count(3, Some(26)).is_ok());
+        assert!(validate_control_plane_count(2, Some(27)).is_ok());
+        assert!(validate_control_plane_count(0, Some(26)).is_err());
+
+        let err = validate_control_plane_count(3, Some(15)).unwrap_err();
+        assert!(err.to_string().contains("macOS 15"), "{}", err);
+        let err = validate_control_plane_count(3, None).unwrap_err();
+        assert!(err.to_string().contains("requires macOS 26"), "{}", err);
+
+        assert_eq!(parse_macos_major("26.0.1\n"), Some(26));
+        assert_eq!(parse_macos_major("15.6"), Some(15));
+        assert_eq!(parse_macos_major(""), None);
+    }
+
+    #[test]
+    fn control_plane_names_and_vip() {
+        assert_eq!(control_plane_node_name("kina", 1), "kina-control-plane");
+        assert_eq!(control_plane_node_name("kina", 3), "kina-control-plane3");
+        assert_eq!(
+            control_plane_vip("192.168.64.3", &[]).unwrap(),
+            "192.168.64.254"
+        );
+        assert_eq!(
+            control_plane_vip("192.168.64.254", &[]).unwrap(),
+            "192.168.64.253"
+        );
+        let used: Vec<String> = ["192.168.64.254", "192.168.64.253", "192.168.64.3"]
+            .iter()
+            .map(|ip| ip.to_string())
+            .collect();
+        assert_eq!(
+            control_plane_vip("192.168.64.3", &used).unwrap(),
+            "192.168.64.252"
+        );
+        let all_used: Vec<String> = (250..=254).map(|h| format!("192.168.64.{}", h)).collect();
+        assert!(control_plane_vip("192.168.64.3", &all_used).is_err());
+        assert!(control_plane_vip("not-an-ip", &[]).is_err());
+    }
+
+    #[test]
+    fn init_config_uses_a_given_bootstrap_token() {
+        let kubeadm = KubeadmConfigOptions {
+            bootstrap_token: Some("abcdef.0123456789abcdef".to_string()),
+            ..Default::default()
+        };
+        let init = generate_kubeadm_init_config_with(
+            "kina-control-plane",
+            "192.168.64.3",
+            "kina",
+            &kubeadm,
+        );
+        let init_config: serde_yaml::Value =
+            serde_yaml::from_str(init.split("---").next().unwrap()).unwrap();
+        let token = &init_config["bootstrapTokens"][0];
+        assert_eq!(token["token"], "abcdef.0123456789abcdef");
+        assert_eq!(
+            token["groups"][0],
+            "system:bootstrappers:kubeadm:default-node-token"
+        );
+    }
+
+    #[test]
+    fn ha_init_and_control_plane_join_configs() {
+        let kubeadm = KubeadmConfigOptions {
+            control_plane_endpoint: Some("192.168.64.254".to_string()),
+            certificate_key: Some("c0ffee".to_string()),
+            ..Default::default()
+        };
+        let init = generate_kubeadm_init_config_with(
+            "kina-control-plane",
+            "192.168.64.3",
+            "kina",
+            &kubeadm,
+        );
+        let stanzas: Vec<serde_yaml::Value> = init
+            .split("---")
+            .map(|s| serde_yaml::from_str(s).unwrap())
+            .collect();
+        assert_eq!(stanzas[0]["certificateKey"], "c0ffee");
+        assert!(stanzas[0].get("bootstrapTokens").is_none());
+        assert_eq!(stanzas[1]["controlPlaneEndpoint"], "192.168.64.254:6443");
+        assert!(init.contains("192.168.64.254"));
+
+        let join_info = KubeadmJoinInfo {
+            token: "abcdef.0123456789abcdef".to_string(),
+            ca_cert_hash: "sha256:abc".to_string(),
+            control_plane_endpoint: "192.168.64.254:6443".to_string(),
+        };
+        let join = generate_control_plane_join_config(
+            "192.168.64.4",
+            &join_info,
+            "c0ffee",
+            &KubeadmConfigOptions::default(),
+        );
+        let join_stanza: serde_yaml::Value =
+            serde_yaml::from_str(join.split("---").next().unwrap()).unwrap();
+        assert_eq!(join_stanza["controlPlane"]["certificateKey"], "c0ffee");
+        assert_eq!(
+            join_stanza["controlPlane"]["localAPIEndpoint"]["advertiseAddress"],
+            "192.168.64.4"
+        );
+        assert_eq!(
+            join_stanza["discovery"]["bootstrapToken"]["apiServerEndpoint"],
+            "192.168.64.254:6443"
+        );
+        assert!(join.contains("kind: KubeletConfiguration"));
+
+        let manifest: serde_yaml::Value = serde_yaml::from_str(&kube_vip_manifest(
+            "192.168.64.254",
+            6443,
+            "/etc/kubernetes/admin.conf",
+        ))
+        .unwrap();
+        assert_eq!(manifest["spec"]["hostNetwork"], true);
+    }
+
+    #[test]
+    fn kubeadm_init_config_merges_networking_overrides() {
+        let kubeadm = KubeadmConfigOptions {
+            networking: NetworkingConfig {
+                pod_subnet: Some("10.32.0.0/16".to_string()),
+                api_server_address: Some("192.168.64.100".to_string()),
+                ..NetworkingConfig::default()
+            },
+            ..KubeadmConfigOptions::default()
+        };
+
+        let config =
+            generate_kubeadm_init_config_with("kina-control-plane", "10.0.0.5", "kina", &kubeadm);
+        let cluster_stanza = config.split("---").nth(1).unwrap();
+        let parsed: serde_yaml::Value = serde_yaml::from_str(cluster_stanza).unwrap();
+
+        assert_eq!(parsed["networking"]["podSubnet"], "10.32.0.0/16");
+        assert_eq!(
+            parsed["networking"]["serviceSubnet"],
+            DEFAULT_SERVICE_SUBNET
+        );
+        assert_eq!(parsed["networking"]["dnsDomain"], DEFAULT_DNS_DOMAIN);
+        assert_eq!(parsed["apiServer"]["certSANs"][4], "192.168.64.100");
+    }
+
+    #[test]
+    fn kubeadm_init_config_appends_extra_cert_sans() {
+        let kubeadm = KubeadmConfigOptions {
+            api_server_cert_sans: vec!["kina.local".to_string(), "*.dev.test".to_string()],
+            ..KubeadmConfigOptions::default()
+        };
+
+        let config =
+            generate_kubeadm_init_config_with("kina-control-plane", "10.0.0.5", "kina", &kubeadm);
+        let cluster_stanza = config.split("---").nth(1).unwrap();
+        let parsed: serde_yaml::Value = serde_yaml::from_str(cluster_stanza).unwrap();
+        let sans: Vec<&str> = parsed["apiServer"]["certSANs"]
+            .as_sequence()
+            .unwrap()
+            .iter()
+            .filter_map(|san| san.as_str())
+            .collect();
+        assert_eq!(
+            sans,
+            [
+                "10.0.0.5",
+                "kina-control-plane",
+                "localhost",
+                "127.0.0.1",
+                "kina.local",
+                "*.dev.test"
+            ]
+        );
+    }
+
+    #[test]
+    fn kubeadm_init_config_renders_audit_log_settings() {
+        let mut kubeadm = KubeadmConfigOptions::default();
+        kubeadm.add_audit_log(
+            DEFAULT_AUDIT_POLICY.as_bytes().to_vec(),
+            std::path::PathBuf::from("/data/kina/dev/audit"),
+        );
+
+        assert_eq!(kubeadm.files[0].0, AUDIT_POLICY_NODE_PATH);
+        assert_eq!(
+            node_volume_args(&kubeadm.control_plane_volumes),
+            vec!["--volume", "/data/kina/dev/audit:/var/log/kubernetes/audit"]
+        );
+
+        let config =
+            generate_kubeadm_init_config_with("dev-control-plane", "10.0.0.5", "dev", &kubeadm);
+        let cluster_stanza = config.split("---").nth(1).unwrap();
+        let parsed: serde_yaml::Value = serde_yaml::from_str(cluster_stanza).unwrap();
+
+        let args: Vec<(&str, &str)> = parsed["apiServer"]["extraArgs"]
+            .as_sequence()
+            .unwrap()
+            .iter()
+            .map(|a| (a["name"].as_str().unwrap(), a["value"].as_str().unwrap()))
+            .collect();
+        assert!(args.contains(&("audit-log-path", "/var/log/kubernetes/audit/audit.log")));
+        assert!(args.contains(&("audit-policy-file", AUDIT_POLICY_NODE_PATH)));
+        assert!(args.contains(&("audit-log-maxage", "7")));
+        assert!(args.contains(&("audit-log-maxsize", "100")));
+
+        let volumes = parsed["apiServer"]["extraVolumes"].as_sequence().unwrap();
+        assert_eq!(volumes.len(), 2);
+        assert_eq!(volumes[0]["hostPath"], "/etc/kubernetes/audit");
+        assert_eq!(volumes[0]["readOnly"], true);
+        assert_eq!(volumes[1]["mountPath"], AUDIT_LOG_NODE_DIR);
+        assert_eq!(parsed["networking"]["podSubnet"], "10.244.0.0/16");
+    }
+
+    #[test]
+    fn pod_security_script_keeps_kube_system_privileged() {
+        let script = pod_security_label_script("restricted");
+        let (system, default) = script.split_once(" && ").unwrap();
+        assert!(system.contains("namespace kube-system"));
+        assert!(system.contains("pod-security.kubernetes.io/enforce=privileged"));
+        assert!(default.contains("namespace default"));
+        assert!(default.contains("pod-security.kubernetes.io/enforce=restricted"));
+    }
+
+    #[test]
+    fn default_audit_policy_is_an_audit_policy() {
+        let policy: serde_yaml::Value = serde_yaml::from_str(DEFAULT_AUDIT_POLICY).unwrap();
+        assert_eq!(policy["apiVersion"], "audit.k8s.io/v1");
+        assert_eq!(policy["kind"], "Policy");
+    }
+
+    #[test]
+    fn bundled_audit_policies_end_with_their_level() {
+        for (level, expected) in [
+            (AuditLevel::None, "None"),
+            (AuditLevel::Metadata, "Metadata"),
+            (AuditLevel::Request, "Request"),
+            (AuditLevel::RequestResponse, "RequestResponse"),
+        ] {
+            let policy: serde_yaml::Value = serde_yaml::from_str(audit_policy_yaml(level)).unwrap();
+            assert_eq!(policy["kind"], "Policy");
+            let rules = policy["rules"].as_sequence().unwrap();
+            assert_eq!(rules.last().unwrap()["level"], expected, "{}", level);
+        }
+    }
+
+    #[tokio::test]
+    async fn export_image_tar_uses_a_private_temp_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let cli = dir.path().join("container");
+        std::fs::write(
+            &cli,
+            "#!/bin/sh\n\
+             case \"$1 $3\" in\n\
+             --version*) echo 'container CLI version 1.0.0 (stub)' ;;\n\
+             'image missing:latest') echo 'no such image' >&2; exit 1 ;;\n\
+             image*) echo tarball > \"$5\" ;;\n\
+             esac\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&cli, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let mut config = Config::default();
+        config.apple_container.cli_path = Some(cli);
+        let client = AppleContainerClient::new(&config).unwrap();
+
+        let first = client.export_image_tar("app:latest").await.unwrap();
+        let second = client.export_image_tar("app:latest").await.unwrap();
+        assert_ne!(first.to_path_buf(), second.to_path_buf());
+        assert_eq!(std::fs::read_to_string(&first).unwrap(), "tarball\n");
+        let path = first.to_path_buf();
+        drop(first);
+        assert!(!path.exists());
+
+        let err = client.export_image_tar("missing:latest").await.unwrap_err();
+        assert!(err.to_string().contains("no such image"), "{}", err);
     }
 }
@@ -0,0 +1,311 @@
+//! `kina benchmark` — a quick performance smoke test of a running cluster.
+//!
+//! Every measurement runs as a shell script inside the control-plane node and
+//! is timed there with `date +%s%N`, so `container exec` overhead stays out of
+//! the numbers. Scripts print one `ok <ms>` or `fail <ms>` line per sample;
+//! everything else in this module turns those lines into percentiles.
+
+use serde::Serialize;
+
+/// Namespace the benchmark workloads run in; deleted afterwards
+pub const BENCHMARK_NAMESPACE: &str = "kina-benchmark";
+
+/// Image for the pod-creation latency test
+pub const BENCHMARK_POD_IMAGE: &str = "nginx:alpine";
+
+/// Image for the DNS and volume tests
+pub const BENCHMARK_BUSYBOX_IMAGE: &str = "busybox:1.36";
+
+/// Concurrent pod-list requests per API server burst
+pub const API_BURST_REQUESTS: usize = 50;
+
+/// Local port `kubectl proxy` listens on during the API burst
+const API_PROXY_PORT: u16 = 8011;
+
+const KUBECONFIG_EXPORT: &str = "export KUBECONFIG=/etc/kubernetes/admin.conf";
+
+/// Latency percentiles for one benchmark test, in milliseconds.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BenchmarkResult {
+    /// Test name, e.g. `pod-startup`
+    pub test: String,
+    /// Successful samples the percentiles are computed from
+    pub samples: usize,
+    /// Samples that failed
+    pub failures: usize,
+    pub min_ms: Option<f64>,
+    pub p50_ms: Option<f64>,
+    pub p90_ms: Option<f64>,
+    pub p99_ms: Option<f64>,
+    pub max_ms: Option<f64>,
+    /// Extra context, e.g. throughput or why the test was skipped
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl BenchmarkResult {
+    /// Percentiles over `samples_ms`; all `None` when there are no samples.
+    pub fn from_samples(test: &str, mut samples_ms: Vec<f64>, failures: usize) -> Self {
+        samples_ms.sort_by(|a, b| a.total_cmp(b));
+        Self {
+            test: test.to_string(),
+            samples: samples_ms.len(),
+            failures,
+            min_ms: samples_ms.first().copied(),
+            p50_ms: percentile(&samples_ms, 50.0),
+            p90_ms: percentile(&samples_ms, 90.0),
+            p99_ms: percentile(&samples_ms, 99.0),
+            max_ms: samples_ms.last().copied(),
+            note: None,
+        }
+    }
+
+    /// A test that could not run, with the reason as its note.
+    pub fn skipped(test: &str, reason: &str) -> Self {
+        Self {
+            note: Some(format!("skipped: {}", reason)),
+            ..Self::from_samples(test, Vec::new(), 0)
+        }
+    }
+
+    pub fn with_note(mut self, note: String) -> Self {
+        self.note = Some(note);
+        self
+    }
+}
+
+/// Nearest-rank percentile of already sorted samples.
+pub fn percentile(sorted: &[f64], pct: f64) -> Option<f64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+    Some(sorted[rank.clamp(1, sorted.len()) - 1])
+}
+
+/// Split `ok <ms>` / `fail <ms>` lines into successful samples and a failure
+/// count. Other lines are ignored.
+pub fn parse_samples(stdout: &str) -> (Vec<f64>, usize) {
+    let mut samples = Vec::new();
+    let mut failures = 0;
+    for line in stdout.lines() {
+        let mut fields = line.split_whitespace();
+        match (
+            fields.next(),
+            fields.next().and_then(|ms| ms.parse::<f64>().ok()),
+        ) {
+            (Some("ok"), Some(ms)) => samples.push(ms),
+            (Some("fail"), Some(_)) => failures += 1,
+            _ => {}
+        }
+    }
+    (samples, failures)
+}
+
+/// The `wall <ms>` line printed by the API burst script.
+pub fn parse_wall_ms(stdout: &str) -> Option<f64> {
+    stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("wall "))
+        .and_then(|ms| ms.trim().parse().ok())
+}
+
+/// Create the benchmark namespace (idempotent).
+pub fn setup_script() -> String {
+    format!(
+        "{}; kubectl create namespace {} --dry-run=client -o yaml | kubectl apply -f - >/dev/null",
+        KUBECONFIG_EXPORT, BENCHMARK_NAMESPACE
+    )
+}
+
+/// Delete the benchmark namespace and everything in it, without waiting.
+pub fn cleanup_script() -> String {
+    format!(
+        "{}; kubectl delete namespace {} --wait=false --ignore-not-found >/dev/null",
+        KUBECONFIG_EXPORT, BENCHMARK_NAMESPACE
+    )
+}
+
+/// Time one nginx pod from `kubectl run` until it is Ready.
+pub fn pod_startup_script(pod: &str) -> String {
+    format!(
+        "{export}; ns={ns}; \
+         s=$(date +%s%N); \
+         if kubectl -n $ns run {pod} --image={image} --restart=Never >/dev/null && \
+            kubectl -n $ns wait --for=condition=Ready pod/{pod} --timeout=120s >/dev/null; \
+         then r=ok; else r=fail; fi; \
+         e=$(date +%s%N); \
+         kubectl -n $ns delete pod {pod} --wait=false >/dev/null 2>&1; \
+         echo \"$r $(( (e - s) / 1000000 ))\"",
+        export = KUBECONFIG_EXPORT,
+        ns = BENCHMARK_NAMESPACE,
+        pod = pod,
+        image = BENCHMARK_POD_IMAGE,
+    )
+}
+
+/// Resolve `kubernetes.default` `lookups` times from inside a pod and print
+/// the pod's timings.
+pub fn dns_script(lookups: u32) -> String {
+    let pod = "kina-bench-dns";
+    let lookup_loop = format!(
+        "for i in $(seq {}); do s=$(date +%s%N); \
+         if nslookup kubernetes.default >/dev/null 2>&1; then r=ok; else r=fail; fi; \
+         e=$(date +%s%N); echo \"$r $(( (e - s) / 1000000 ))\"; done",
+        lookups
+    );
+    format!(
+        "{export}; ns={ns}; \
+         kubectl -n $ns run {pod} --image={image} --restart=Never --command -- sh -c '{lookup_loop}' >/dev/null && \
+         kubectl -n $ns wait --for=jsonpath='{{.status.phase}}'=Succeeded pod/{pod} --timeout=180s >/dev/null; \
+         kubectl -n $ns logs {pod}; \
+         kubectl -n $ns delete pod {pod} --wait=false >/dev/null 2>&1",
+        export = KUBECONFIG_EXPORT,
+        ns = BENCHMARK_NAMESPACE,
+        pod = pod,
+        image = BENCHMARK_BUSYBOX_IMAGE,
+        lookup_loop = lookup_loop,
+    )
+}
+
+/// Print `true` when the cluster has a default StorageClass.
+pub fn default_storage_class_script() -> String {
+    format!(
+        "{}; kubectl get storageclass -o \
+         jsonpath='{{range .items[*]}}{{.metadata.annotations.storageclass\\.kubernetes\\.io/is-default-class}}{{\"\\n\"}}{{end}}'",
+        KUBECONFIG_EXPORT
+    )
+}
+
+/// Time a 1Gi claim (plus a pod consuming it, for WaitForFirstConsumer
+/// classes) from creation until it is Bound.
+pub fn volume_provision_script(claim: &str) -> String {
+    format!(
+        "{export}; ns={ns}; \
+         s=$(date +%s%N); \
+         if cat <<'EOF' | kubectl -n $ns apply -f - >/dev/null && \
+            kubectl -n $ns wait --for=jsonpath='{{.status.phase}}'=Bound pvc/{claim} --timeout=120s >/dev/null
+apiVersion: v1
+kind: PersistentVolumeClaim
+metadata:
+  name: {claim}
+spec:
+  accessModes: [ReadWriteOnce]
+  resources:
+    requests:
+      storage: 1Gi
+---
+apiVersion: v1
+kind: Pod
+metadata:
+  name: {claim}
+spec:
+  containers:
+  - name: consumer
+    image: {image}
+    command: [sleep, \"3600\"]
+    volumeMounts:
+    - name: data
+      mountPath: /data
+  volumes:
+  - name: data
+    persistentVolumeClaim:
+      claimName: {claim}
+EOF
+         then r=ok; else r=fail; fi; \
+         e=$(date +%s%N); \
+         kubectl -n $ns delete pod/{claim} pvc/{claim} --wait=false >/dev/null 2>&1; \
+         echo \"$r $(( (e - s) / 1000000 ))\"",
+        export = KUBECONFIG_EXPORT,
+        ns = BENCHMARK_NAMESPACE,
+        claim = claim,
+        image = BENCHMARK_BUSYBOX_IMAGE,
+    )
+}
+
+/// Fire `API_BURST_REQUESTS` concurrent pod-list requests through
+/// `kubectl proxy` and print each request's latency plus the burst's wall time.
+pub fn api_burst_script() -> String {
+    format!(
+        "{export}; \
+         kubectl proxy --port={port} >/dev/null 2>&1 & proxy=$!; \
+         for i in $(seq 20); do curl -s -o /dev/null http://127.0.0.1:{port}/version && break; sleep 0.5; done; \
+         s=$(date +%s%N); \
+         {{ pids=; for i in $(seq {requests}); do \
+              curl -s -o /dev/null -w '%{{http_code}} %{{time_total}}\\n' http://127.0.0.1:{port}/api/v1/pods & \
+              pids=\"$pids $!\"; \
+            done; wait $pids; }} | \
+         awk '{{ printf \"%s %.1f\\n\", ($1 == \"200\" ? \"ok\" : \"fail\"), $2 * 1000 }}'; \
+         e=$(date +%s%N); \
+         kill $proxy; \
+         echo \"wall $(( (e - s) / 1000000 ))\"",
+        export = KUBECONFIG_EXPORT,
+        port = API_PROXY_PORT,
+        requests = API_BURST_REQUESTS,
+    )
+}
+
+/// Render results as an aligned table, one row per test.
+pub fn render_benchmark_table(results: &[BenchmarkResult]) -> String {
+    let ms = |v: Option<f64>| v.map_or_else(|| "-".to_string(), |v| format!("{:.0}ms", v));
+    let mut out = format!(
+        "{:<18} {:>7} {:>6} {:>8} {:>8} {:>8} {:>8}  {}\n",
+        "TEST", "SAMPLES", "FAILED", "P50", "P90", "P99", "MAX", "NOTE"
+    );
+    for r in results {
+        out.push_str(&format!(
+            "{:<18} {:>7} {:>6} {:>8} {:>8} {:>8} {:>8}  {}\n",
+            r.test,
+            r.samples,
+            r.failures,
+            ms(r.p50_ms),
+            ms(r.p90_ms),
+            ms(r.p99_ms),
+            ms(r.max_ms),
+            r.note.as_deref().unwrap_or("")
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentile_uses_nearest_rank() {
+        let sorted: Vec<f64> = (1..=10).map(f64::from).collect();
+        assert_eq!(percentile(&sorted, 50.0), Some(5.0));
+        assert_eq!(percentile(&sorted, 90.0), Some(9.0));
+        assert_eq!(percentile(&sorted, 99.0), Some(10.0));
+        assert_eq!(percentile(&[], 50.0), None);
+    }
+
+    #[test]
+    fn parse_samples_counts_failures_and_skips_noise() {
+        let (samples, failures) = parse_samples("ok 12\nfail 30000\nwall 80\nok 8.5\ngarbage\n");
+        assert_eq!(samples, vec![12.0, 8.5]);
+        assert_eq!(failures, 1);
+        assert_eq!(parse_wall_ms("ok 1\nwall 80\n"), Some(80.0));
+    }
+
+    #[test]
+    fn result_from_samples_sorts_before_ranking() {
+        let result = BenchmarkResult::from_samples("dns", vec![30.0, 10.0, 20.0], 1);
+        assert_eq!(result.samples, 3);
+        assert_eq!(result.min_ms, Some(10.0));
+        assert_eq!(result.p50_ms, Some(20.0));
+        assert_eq!(result.max_ms, Some(30.0));
+    }
+
+    #[test]
+    fn table_shows_dashes_for_skipped_tests() {
+        let table = render_benchmark_table(&[BenchmarkResult::skipped(
+            "volume-provision",
+            "no default StorageClass",
+        )]);
+        assert!(table.starts_with("TEST"));
+        assert!(table.contains("skipped: no default StorageClass"));
+        assert!(table.lines().nth(1).unwrap().contains(" - "));
+    }
+}
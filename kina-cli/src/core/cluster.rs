@@ -1,30 +1,309 @@
 use anyhow::{Context, Result};
+use std::collections::BTreeMap;
 use tracing::{debug, info, warn};
 
-use super::apple_container::AppleContainerClient;
-use super::types::{ClusterInfo, ClusterStatus, CreateClusterOptions, LoadImageOptions};
-use crate::config::Config;
+use super::apple_container::{
+    container_runtime_from_inspect, control_plane_node_name, failure_diagnostics_dir, resolve_cpus,
+    resolve_memory, resources_from_inspect, AppleContainerClient, DEFAULT_NODE_CPUS,
+    DEFAULT_NODE_MEMORY,
+};
+use super::benchmark::{self, BenchmarkResult};
+use super::events::move_events;
+use super::image_registry::{
+    load_pulled_images, pulled_images_path, record_pulled_image, save_pulled_images,
+};
+use super::kubernetes::{
+    merge_live_node_info, parse_pending_csrs, KubernetesClient, ReadinessSnapshot, CSR_JSONPATH,
+    KUBELET_SERVING_SIGNER,
+};
+use super::provider::ClusterClientTrait;
+use super::storage::{self, StorageProvisioner};
+use super::types::{
+    ClusterInfo, ClusterStatus, ContainerRuntime, ContainerVersion, CreateClusterOptions,
+    DeleteClusterOptions, LoadImageOptions, NetworkInfo, NodeInfo, NodeRole, API_SERVER_PORT_LABEL,
+    CNI_LABEL, DEFAULT_API_SERVER_PORT, NO_CNI_LABEL_VALUE,
+};
+use crate::config::cluster_config::{ClusterConfig, NetworkingConfig};
+use crate::config::{CniPlugin, Config};
+use crate::errors::ClusterError;
+use crate::utils::validate;
+
+/// Outcome of loading an image into one cluster
+#[derive(Debug)]
+pub struct ClusterLoadResult {
+    pub cluster: String,
+    pub result: Result<()>,
+    pub elapsed: std::time::Duration,
+}
+
+/// What `kina get config` shows for a cluster
+#[derive(Debug)]
+pub struct StoredClusterConfig {
+    /// Control-plane container the labels were read from
+    pub node: String,
+    /// The node's `io.kina.*` labels
+    pub labels: BTreeMap<String, String>,
+    /// Best-effort `ClusterConfig` rebuilt from the labels and kubeadm config
+    pub config: ClusterConfig,
+    /// `/kind/kubeadm.conf` from the node, when it could be read
+    pub kubeadm_config: Option<String>,
+}
+
+impl StoredClusterConfig {
+    /// Render as YAML: the labels as a comment header, the `ClusterConfig`,
+    /// then the kubeadm config as further documents.
+    pub fn to_yaml(&self) -> Result<String> {
+        let mut yaml = format!("# Labels on {}:\n", self.node);
+        for (key, value) in &self.labels {
+            yaml.push_str(&format!("#   {}={}\n", key, value));
+        }
+        yaml.push_str(
+            &serde_yaml::to_string(&self.config).context("Failed to serialize cluster config")?,
+        );
+        if let Some(kubeadm_config) = &self.kubeadm_config {
+            yaml.push_str("---\n# /kind/kubeadm.conf\n");
+            yaml.push_str(kubeadm_config.trim_start_matches("---\n").trim_end());
+            yaml.push('\n');
+        }
+        Ok(yaml)
+    }
+
+    /// Create options that rebuild this cluster as `name`.
+    ///
+    /// The image and node counts come from the nodes, the CNI and API server
+    /// port from the labels, and the networking, container runtime, extra SANs
+    /// and component flags from the kubeadm config (minus the flags kina adds
+    /// itself). Settings that depend on files or secrets on the host (OIDC,
+    /// audit logging, pull secrets, scheduler/kubelet config files) are not
+    /// recorded on the cluster and keep their defaults, as do node resources.
+    pub fn create_options(&self, name: &str) -> CreateClusterOptions {
+        let count = |role: NodeRole| {
+            self.config
+                .nodes
+                .iter()
+                .filter(|node| node.role == role)
+                .count() as u32
+        };
+        let workers = count(NodeRole::Worker);
+        let control_plane_nodes = count(NodeRole::ControlPlane);
+        let image = self
+            .config
+            .nodes
+            .iter()
+            .find_map(|node| node.image.clone())
+            .or_else(|| self.labels.get("io.kina.image").cloned())
+            .unwrap_or_default();
+        let cni = self.labels.get(CNI_LABEL).map(String::as_str);
+        let api_server_port = self
+            .labels
+            .get(API_SERVER_PORT_LABEL)
+            .and_then(|port| port.parse().ok())
+            .filter(|port| *port != DEFAULT_API_SERVER_PORT);
+        let networking = &self.config.networking;
+        let has_networking = networking.pod_subnet.is_some()
+            || networking.service_subnet.is_some()
+            || networking.dns_domain.is_some();
+        let kubeadm = KubeadmSettings::parse(self.kubeadm_config.as_deref().unwrap_or_default());
+
+        CreateClusterOptions {
+            name: name.to_string(),
+            image,
+            config_file: None,
+            kubernetes_version: None,
+            workers: (workers > 0).then_some(workers),
+            control_plane_nodes: (control_plane_nodes > 1).then_some(control_plane_nodes),
+            wait_timeout: None,
+            wait_for_ready: false,
+            no_wait: false,
+            retain_on_failure: false,
+            skip_csr_approval: false,
+            skip_kubeconfig: false,
+            oidc: None,
+            audit_log: false,
+            audit_policy: None,
+            pod_security_standard: None,
+            node_image_pull_always: false,
+            skip_cni: cni == Some(NO_CNI_LABEL_VALUE),
+            networking: has_networking.then(|| networking.clone()),
+            api_server_extra_args: kubeadm.api_server_extra_args,
+            kubelet_extra_args: kubeadm.kubelet_extra_args,
+            controller_manager_extra_args: kubeadm.controller_manager_extra_args,
+            etcd_extra_args: kubeadm.etcd_extra_args,
+            node_labels: Default::default(),
+            image_pull_secret: None,
+            pull_secret_config: None,
+            insecure_registries: Vec::new(),
+            bootstrap_token: None,
+            scheduler_config_file: None,
+            containerd_config: None,
+            kubelet_config_file: None,
+            api_server_sans: kubeadm.api_server_sans,
+            container_runtime: kubeadm.container_runtime,
+            disable_swap_check: false,
+            skip_preflight: false,
+            api_server_port,
+            node_name: kubeadm
+                .node_name
+                .filter(|_| workers == 0 && control_plane_nodes <= 1),
+            // The source cluster still holds its VIP
+            control_plane_vip: None,
+            etcd: None,
+            cni_plugin: cni.and_then(CniPlugin::from_name).unwrap_or(CniPlugin::Ptp),
+            node_kernel_path: None,
+            control_plane_cpus: DEFAULT_NODE_CPUS,
+            control_plane_memory: DEFAULT_NODE_MEMORY.to_string(),
+            worker_cpus: DEFAULT_NODE_CPUS,
+            worker_memory: DEFAULT_NODE_MEMORY.to_string(),
+        }
+    }
+}
+
+/// The user-chosen parts of a kina-rendered `/kind/kubeadm.conf`
+#[derive(Debug, Default)]
+struct KubeadmSettings {
+    api_server_extra_args: BTreeMap<String, String>,
+    kubelet_extra_args: BTreeMap<String, String>,
+    controller_manager_extra_args: BTreeMap<String, String>,
+    etcd_extra_args: BTreeMap<String, String>,
+    api_server_sans: Vec<String>,
+    container_runtime: ContainerRuntime,
+    node_name: Option<String>,
+}
+
+/// API server flags kina sets for its own features; copying them without the
+/// files and volumes they refer to would break the API server
+fn is_kina_api_server_arg(name: &str) -> bool {
+    matches!(name, "runtime-config" | "enable-admission-plugins")
+        || name.starts_with("oidc-")
+        || name.starts_with("audit-")
+}
+
+impl KubeadmSettings {
+    /// Read the settings from `kubeadm_yaml`; missing documents leave defaults
+    fn parse(kubeadm_yaml: &str) -> Self {
+        use serde::Deserialize;
+
+        let docs: Vec<serde_yaml::Value> = serde_yaml::Deserializer::from_str(kubeadm_yaml)
+            .filter_map(|doc| serde_yaml::Value::deserialize(doc).ok())
+            .collect();
+        let kind = |kind: &str| {
+            docs.iter()
+                .find(|doc| doc["kind"].as_str() == Some(kind))
+                .cloned()
+                .unwrap_or_default()
+        };
+        let init = kind("InitConfiguration");
+        let cluster = kind("ClusterConfiguration");
+        let registration = &init["nodeRegistration"];
+
+        let mut kubelet_extra_args = extra_args(&registration["kubeletExtraArgs"]);
+        kubelet_extra_args.retain(|name, _| name != "node-ip" && name != "provider-id");
+        let mut api_server_extra_args = extra_args(&cluster["apiServer"]["extraArgs"]);
+        api_server_extra_args.retain(|name, _| !is_kina_api_server_arg(name));
+        let mut controller_manager_extra_args =
+            extra_args(&cluster["controllerManager"]["extraArgs"]);
+        controller_manager_extra_args.remove("enable-hostpath-provisioner");
+
+        // kina always adds the node's address and name, localhost and the
+        // control-plane endpoint; the rest came from --api-server-san
+        let advertise_address = init["localAPIEndpoint"]["advertiseAddress"].as_str();
+        let endpoint_host = cluster["controlPlaneEndpoint"]
+            .as_str()
+            .and_then(|endpoint| endpoint.rsplit_once(':'))
+            .map(|(host, _)| host);
+        let control_plane_prefix = format!(
+            "{}-control-plane",
+            cluster["clusterName"].as_str().unwrap_or_default()
+        );
+        let node_name = registration["name"].as_str().map(str::to_string);
+        let api_server_sans = cluster["apiServer"]["certSANs"]
+            .as_sequence()
+            .into_iter()
+            .flatten()
+            .filter_map(|san| san.as_str())
+            .filter(|san| {
+                !matches!(*san, "localhost" | "127.0.0.1")
+                    && Some(*san) != advertise_address
+                    && Some(*san) != endpoint_host
+                    && !san.starts_with(&control_plane_prefix)
+            })
+            .map(str::to_string)
+            .collect();
+
+        let container_runtime = match registration["criSocket"].as_str() {
+            Some(socket) if socket == ContainerRuntime::CriO.cri_socket() => ContainerRuntime::CriO,
+            _ => ContainerRuntime::Containerd,
+        };
+
+        Self {
+            api_server_extra_args,
+            kubelet_extra_args,
+            controller_manager_extra_args,
+            etcd_extra_args: extra_args(&cluster["etcd"]["local"]["extraArgs"]),
+            api_server_sans,
+            container_runtime,
+            node_name,
+        }
+    }
+}
+
+/// kubeadm `extraArgs` in either the v1beta4 list form (`- name/value`) or
+/// the older map form
+fn extra_args(value: &serde_yaml::Value) -> BTreeMap<String, String> {
+    let text = |value: &serde_yaml::Value| match value {
+        serde_yaml::Value::String(s) => Some(s.clone()),
+        serde_yaml::Value::Number(n) => Some(n.to_string()),
+        serde_yaml::Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    };
+    match value {
+        serde_yaml::Value::Sequence(entries) => entries
+            .iter()
+            .filter_map(|entry| Some((entry["name"].as_str()?.to_string(), text(&entry["value"])?)))
+            .collect(),
+        serde_yaml::Value::Mapping(map) => map
+            .iter()
+            .filter_map(|(name, value)| Some((name.as_str()?.to_string(), text(value)?)))
+            .collect(),
+        _ => BTreeMap::new(),
+    }
+}
 
 /// Cluster manager handles all cluster operations
 pub struct ClusterManager {
     config: Config,
-    apple_container: AppleContainerClient,
+    client: Box<dyn ClusterClientTrait>,
 }
 
 impl ClusterManager {
-    /// Create a new cluster manager
+    /// Create a new cluster manager backed by Apple Container
     pub fn new(config: &Config) -> Result<Self> {
-        let apple_container = AppleContainerClient::new(config)?;
+        let client = AppleContainerClient::new(config)?;
+        Ok(Self::new_with_client(config, Box::new(client)))
+    }
 
-        Ok(Self {
+    /// Create a cluster manager that drives `client` instead of detecting the
+    /// Apple Container CLI, e.g. `core::mock::MockAppleContainerClient` in tests
+    pub fn new_with_client(config: &Config, client: Box<dyn ClusterClientTrait>) -> Self {
+        Self {
             config: config.clone(),
-            apple_container,
-        })
+            client,
+        }
+    }
+
+    /// The configuration this manager was created with
+    pub fn config(&self) -> &Config {
+        &self.config
     }
 
     /// Get the detected Apple Container CLI version
     pub fn container_version(&self) -> &str {
-        self.apple_container.version()
+        self.client.version()
+    }
+
+    /// Apple Container CLI version and build type
+    pub fn container_version_info(&self) -> Result<ContainerVersion> {
+        self.client.container_version().cloned()
     }
 
     /// Create a new Kubernetes cluster
@@ -39,15 +318,54 @@ impl ClusterManager {
             return Err(anyhow::anyhow!("Cluster '{}' already exists", options.name));
         }
 
+        // `container image pull` always resolves the tag against the registry,
+        // so a re-pushed tag is picked up even when an older copy is present.
+        if options.node_image_pull_always {
+            println!("Pulling node image '{}'...", options.image);
+            self.client
+                .pull_image(&options.image, None)
+                .await
+                .with_context(|| format!("Failed to pull node image '{}'", options.image))?;
+        }
+
+        match self.provision_cluster(&options).await {
+            Ok(()) => {
+                info!("Cluster '{}' created successfully", options.name);
+                Ok(())
+            }
+            Err(e) => {
+                self.handle_create_failure(&options).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// Run every creation step after the existence check. Any `Err` is handled by
+    /// `handle_create_failure` (delete, or retain and dump diagnostics).
+    async fn provision_cluster(&self, options: &CreateClusterOptions) -> Result<()> {
         // Create the cluster using Apple Container
-        self.apple_container
-            .create_cluster(&options)
+        self.client
+            .create_cluster(options)
             .await
             .context("Failed to create cluster using Apple Container")?;
 
+        if let Some(level) = &options.pod_security_standard {
+            self.client
+                .apply_pod_security_labels(&options.name, level)
+                .await?;
+        }
+
+        if let Some(secret_name) = &options.image_pull_secret {
+            self.client
+                .create_image_pull_secrets(&options.name, secret_name)
+                .await?;
+        }
+
         // Wait for cluster to be ready if requested
         if let Some(timeout) = options.wait_timeout {
             self.wait_for_cluster_ready(&options.name, timeout).await?;
+        } else if options.no_wait {
+            debug!("Skipping readiness wait (--no-wait)");
         } else {
             // Even without explicit wait, give the cluster a moment to initialize
             // This ensures the API server is ready for CSR operations
@@ -77,12 +395,70 @@ impl ClusterManager {
             }
         }
 
-        info!("Cluster '{}' created successfully", options.name);
+        // --wait-for-ready: block until kube-system pods are Running, not just the VM
+        if options.wait_for_ready {
+            let timeout = options
+                .wait_timeout
+                .unwrap_or(super::apple_container::DEFAULT_READY_TIMEOUT_SECS);
+            println!("Waiting for kube-system pods to be Running...");
+            self.client
+                .wait_for_kube_system_pods(&options.name, timeout)
+                .await?;
+        }
+
         Ok(())
     }
 
-    /// Delete a Kubernetes cluster
-    pub async fn delete_cluster(&self, name: &str) -> Result<()> {
+    /// Clean up after a failed create.
+    ///
+    /// Without `retain_on_failure` the partially created containers are deleted. With it,
+    /// the containers are kept and `container inspect`, the kubeadm log and the systemd
+    /// journal of every node are saved under the data directory for inspection.
+    async fn handle_create_failure(&self, options: &CreateClusterOptions) {
+        if options.retain_on_failure {
+            let timestamp = chrono::Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+            let dir =
+                failure_diagnostics_dir(&self.config.cluster.data_dir, &options.name, &timestamp);
+            match self
+                .client
+                .collect_failure_diagnostics(&options.name, &dir)
+                .await
+            {
+                Ok(()) => {
+                    println!(
+                        "Cluster '{}' retained after failure. Diagnostics saved to: {}",
+                        options.name,
+                        dir.display()
+                    );
+                    println!("Delete it when finished with: kina delete {}", options.name);
+                }
+                Err(e) => warn!(
+                    "Failed to collect diagnostics for cluster '{}': {}",
+                    options.name, e
+                ),
+            }
+        } else {
+            warn!(
+                "Cluster creation failed; deleting partially created cluster '{}' \
+                 (re-run with --retain to keep it for debugging)",
+                options.name
+            );
+            if let Err(e) = self
+                .client
+                .delete_cluster(&options.name, &DeleteClusterOptions::default())
+                .await
+            {
+                warn!(
+                    "Failed to clean up cluster '{}' after failure: {}",
+                    options.name, e
+                );
+            }
+        }
+    }
+
+    /// Delete a Kubernetes cluster. With `options.force`, cleanup failures
+    /// are logged and skipped rather than returned.
+    pub async fn delete_cluster(&self, name: &str, options: &DeleteClusterOptions) -> Result<()> {
         info!("Deleting cluster '{}'", name);
 
         if !self.cluster_exists(name).await? {
@@ -90,20 +466,390 @@ impl ClusterManager {
             return Ok(());
         }
 
-        self.apple_container
-            .delete_cluster(name)
+        self.client
+            .delete_cluster(name, options)
             .await
             .context("Failed to delete cluster")?;
 
         // Clean up kubeconfig
-        self.cleanup_kubeconfig(name).await?;
+        if let Err(e) = self.cleanup_kubeconfig(name).await {
+            if !options.force {
+                return Err(e);
+            }
+            warn!(
+                "Ignoring kubeconfig cleanup failure for '{}': {:#}",
+                name, e
+            );
+        }
 
         info!("Cluster '{}' deleted successfully", name);
         Ok(())
     }
 
-    /// Delete all clusters
-    pub async fn delete_all_clusters(&self) -> Result<()> {
+    /// Rename a cluster's containers, labels and kubeconfig entries
+    pub async fn rename_cluster(&self, old_name: &str, new_name: &str) -> Result<()> {
+        info!("Renaming cluster '{}' to '{}'", old_name, new_name);
+
+        validate::cluster_name(new_name)?;
+        if old_name == new_name {
+            return Err(anyhow::anyhow!(
+                "New cluster name must differ from '{}'",
+                old_name
+            ));
+        }
+
+        let clusters = self.list_clusters().await?;
+        if !clusters.iter().any(|c| c.name == old_name) {
+            return Err(ClusterError::NotFound {
+                name: old_name.to_string(),
+            }
+            .into());
+        }
+        if clusters.iter().any(|c| c.name == new_name) {
+            return Err(ClusterError::RenameConflict {
+                name: new_name.to_string(),
+            }
+            .into());
+        }
+
+        self.client
+            .rename_cluster(old_name, new_name)
+            .await
+            .context("Failed to rename cluster")?;
+
+        // Drop the stale per-cluster kubeconfig copy kept under kubeconfig_dir
+        self.cleanup_kubeconfig(old_name).await?;
+        if let Err(e) = move_events(&self.config.cluster.data_dir, old_name, new_name) {
+            warn!(
+                "Could not move the event log of '{}' to '{}': {:#}",
+                old_name, new_name, e
+            );
+        }
+
+        info!("Cluster '{}' renamed to '{}'", old_name, new_name);
+        Ok(())
+    }
+
+    /// `container inspect` data for a cluster's nodes: a JSON array in node
+    /// order, or a single object when `node` is given. `node` may be the full
+    /// node name or the part after `<cluster>-` (e.g. `worker-2`).
+    pub async fn inspect_cluster(
+        &self,
+        name: &str,
+        node: Option<&str>,
+    ) -> Result<serde_json::Value> {
+        let info = self.find_cluster(name).await?;
+
+        match node {
+            Some(node) => {
+                let found = find_node(&info, node)?;
+                self.client.inspect_container(found).await
+            }
+            None => {
+                let mut nodes = Vec::with_capacity(info.nodes.len());
+                for node in &info.nodes {
+                    nodes.push(self.client.inspect_container(&node.name).await?);
+                }
+                Ok(serde_json::Value::Array(nodes))
+            }
+        }
+    }
+
+    /// Run `command` in a cluster node with the terminal attached (`kina ssh`),
+    /// returning its exit code. `node` is resolved as in
+    /// [`Self::inspect_cluster`]; `None` is the first control-plane node.
+    pub async fn exec_in_node(
+        &self,
+        name: &str,
+        node: Option<&str>,
+        command: &[String],
+        tty: bool,
+    ) -> Result<i32> {
+        let info = self.find_cluster(name).await?;
+        let node_name = match node {
+            Some(node) => find_node(&info, node)?.to_string(),
+            None => control_plane_node_name(name, 1),
+        };
+        if info.status != ClusterStatus::Running {
+            return Err(ClusterError::NotRunning {
+                name: name.to_string(),
+            }
+            .into());
+        }
+        debug!("Running {:?} in '{}'", command, node_name);
+        self.client.exec_attached(&node_name, command, tty).await
+    }
+
+    async fn find_cluster(&self, name: &str) -> Result<ClusterInfo> {
+        self.list_clusters()
+            .await?
+            .into_iter()
+            .find(|c| c.name == name)
+            .ok_or_else(|| {
+                ClusterError::NotFound {
+                    name: name.to_string(),
+                }
+                .into()
+            })
+    }
+
+    /// Stop a cluster's node VMs without deleting them (`kina pause`/`kina stop`)
+    pub async fn pause_cluster(&self, name: &str) -> Result<()> {
+        let info = self
+            .list_clusters()
+            .await?
+            .into_iter()
+            .find(|c| c.name == name)
+            .ok_or_else(|| ClusterError::NotFound {
+                name: name.to_string(),
+            })?;
+        if !info.nodes.iter().any(|n| n.status == "running") {
+            return Err(ClusterError::InvalidState {
+                state: format!("cluster '{}' is already stopped", name),
+            }
+            .into());
+        }
+
+        self.client
+            .pause_cluster(name)
+            .await
+            .context("Failed to pause cluster")
+    }
+
+    /// Start a paused or stopped cluster's node VMs (`kina start`).
+    ///
+    /// Returns the control plane's IP once the API server answers again.
+    pub async fn resume_cluster(&self, name: &str) -> Result<String> {
+        let info = self
+            .list_clusters()
+            .await?
+            .into_iter()
+            .find(|c| c.name == name)
+            .ok_or_else(|| ClusterError::NotFound {
+                name: name.to_string(),
+            })?;
+        if info.status != ClusterStatus::Stopped {
+            return Err(ClusterError::InvalidState {
+                state: format!("cluster '{}' is {}, not stopped", name, info.status),
+            }
+            .into());
+        }
+
+        self.client
+            .resume_cluster(name)
+            .await
+            .context("Failed to resume cluster")
+    }
+
+    /// The effective configuration of a cluster: its nodes and image, plus the
+    /// networking read from the control plane's kubeadm config when it is running.
+    pub async fn export_cluster_config(&self, name: &str) -> Result<ClusterConfig> {
+        let info = self
+            .list_clusters()
+            .await?
+            .into_iter()
+            .find(|c| c.name == name)
+            .ok_or_else(|| ClusterError::NotFound {
+                name: name.to_string(),
+            })?;
+
+        let mut cluster_config = ClusterConfig::from_cluster_info(&info, name);
+        match self.client.read_kubeadm_config(name).await {
+            Ok(kubeadm_config) => {
+                cluster_config.networking = NetworkingConfig::from_kubeadm_config(&kubeadm_config)
+            }
+            Err(e) => warn!(
+                "Could not read networking from '{}', exporting nodes only: {}",
+                name, e
+            ),
+        }
+        Ok(cluster_config)
+    }
+
+    /// The configuration a cluster was created with, as far as it can be
+    /// recovered: the `io.kina.*` labels from `container inspect` on the first
+    /// control-plane node, and its `/kind/kubeadm.conf` if readable.
+    pub async fn stored_cluster_config(&self, name: &str) -> Result<StoredClusterConfig> {
+        let info = self
+            .list_clusters()
+            .await?
+            .into_iter()
+            .find(|c| c.name == name)
+            .ok_or_else(|| ClusterError::NotFound {
+                name: name.to_string(),
+            })?;
+        let node = info
+            .nodes
+            .iter()
+            .find(|n| n.role == NodeRole::ControlPlane)
+            .or_else(|| info.nodes.first())
+            .map(|n| n.name.clone())
+            .with_context(|| format!("Cluster '{}' has no nodes", name))?;
+
+        let inspect = self.client.inspect_container(&node).await?;
+        let labels = inspect["configuration"]["labels"]
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter(|(key, _)| key.starts_with("io.kina."))
+            .map(|(key, value)| {
+                let value = value
+                    .as_str()
+                    .map(str::to_string)
+                    .unwrap_or_else(|| value.to_string());
+                (key.clone(), value)
+            })
+            .collect();
+
+        let mut config = ClusterConfig::from_cluster_info(&info, name);
+        let kubeadm_config = match self.client.read_kubeadm_config(name).await {
+            Ok(kubeadm_config) => {
+                config.networking = NetworkingConfig::from_kubeadm_config(&kubeadm_config);
+                Some(kubeadm_config)
+            }
+            Err(e) => {
+                warn!("Could not read the kubeadm config from '{}': {}", node, e);
+                None
+            }
+        };
+
+        Ok(StoredClusterConfig {
+            node,
+            labels,
+            config,
+            kubeadm_config,
+        })
+    }
+
+    /// Create `dest` as a copy of a running `source` cluster.
+    ///
+    /// The create options are rebuilt from the source with
+    /// [`StoredClusterConfig::create_options`], and the node CPUs and memory
+    /// from `container inspect`; `customize` then applies per-create settings
+    /// such as waits. The source's `ClusterConfig` and kubeadm config are saved
+    /// under `<data_dir>/<dest>/` for reference, and the image is pulled up
+    /// front before the usual create runs.
+    pub async fn duplicate_cluster<F>(&self, source: &str, dest: &str, customize: F) -> Result<()>
+    where
+        F: FnOnce(&mut CreateClusterOptions) -> Result<()>,
+    {
+        info!("Duplicating cluster '{}' as '{}'", source, dest);
+
+        let info = self
+            .list_clusters()
+            .await?
+            .into_iter()
+            .find(|c| c.name == source)
+            .ok_or_else(|| ClusterError::NotFound {
+                name: source.to_string(),
+            })?;
+        if info.status != ClusterStatus::Running {
+            return Err(ClusterError::NotRunning {
+                name: source.to_string(),
+            }
+            .into());
+        }
+        if self.cluster_exists(dest).await? {
+            return Err(ClusterError::AlreadyExists {
+                name: dest.to_string(),
+            }
+            .into());
+        }
+
+        let stored = self.stored_cluster_config(source).await?;
+        let mut options = stored.create_options(dest);
+        if options.image.is_empty() {
+            options.image = info.image.clone();
+        }
+        let settings = &self.config.cluster;
+        let (cpus, memory) = self.node_resources(&info, NodeRole::ControlPlane).await;
+        options.control_plane_cpus =
+            resolve_cpus(cpus, settings.control_plane_cpus, DEFAULT_NODE_CPUS);
+        options.control_plane_memory = resolve_memory(
+            memory.as_deref(),
+            settings.control_plane_memory.as_deref(),
+            DEFAULT_NODE_MEMORY,
+        );
+        let (cpus, memory) = self.node_resources(&info, NodeRole::Worker).await;
+        options.worker_cpus = resolve_cpus(cpus, settings.worker_cpus, DEFAULT_NODE_CPUS);
+        options.worker_memory = resolve_memory(
+            memory.as_deref(),
+            settings.worker_memory.as_deref(),
+            DEFAULT_NODE_MEMORY,
+        );
+        customize(&mut options)?;
+
+        let mut cluster_config = ClusterConfig::from_cluster_info(&info, dest);
+        cluster_config.networking = stored.config.networking.clone();
+        let export_dir = self.config.cluster.data_dir.join(dest);
+        cluster_config
+            .to_file(export_dir.join("cluster-config.yaml"))
+            .await?;
+        if let Some(kubeadm_config) = &stored.kubeadm_config {
+            std::fs::write(export_dir.join("source-kubeadm.conf"), kubeadm_config)
+                .context("Failed to save source kubeadm configuration")?;
+        }
+
+        self.client
+            .pull_image(&options.image, None)
+            .await
+            .with_context(|| format!("Failed to pre-pull image '{}'", options.image))?;
+
+        self.create_cluster(options).await
+    }
+
+    /// CPUs and memory of the first `role` node in `info`, if inspect reports them
+    async fn node_resources(
+        &self,
+        info: &ClusterInfo,
+        role: NodeRole,
+    ) -> (Option<u32>, Option<String>) {
+        let Some(node) = info.nodes.iter().find(|n| n.role == role) else {
+            return (None, None);
+        };
+        match self.client.inspect_container(&node.name).await {
+            Ok(inspect) => match resources_from_inspect(&inspect) {
+                Some((cpus, memory)) => (Some(cpus), Some(memory)),
+                None => (None, None),
+            },
+            Err(e) => {
+                warn!("Could not inspect '{}' for its resources: {}", node.name, e);
+                (None, None)
+            }
+        }
+    }
+
+    /// Pre-pull a node image, check a container can be created from it, and
+    /// record it in `~/.local/share/kina/pulled-images.json`.
+    pub async fn pull_image(&self, image: &str, platform: Option<&str>) -> Result<()> {
+        self.client
+            .pull_image(image, platform)
+            .await
+            .with_context(|| format!("Failed to pull image '{}'", image))?;
+        self.client.verify_image(image, platform).await?;
+
+        let path = pulled_images_path();
+        let mut records = load_pulled_images(&path);
+        record_pulled_image(
+            &mut records,
+            image,
+            platform,
+            &chrono::Utc::now().to_rfc3339(),
+        );
+        if let Err(e) = save_pulled_images(&path, &records) {
+            warn!("Failed to record pulled image: {}", e);
+        }
+        Ok(())
+    }
+
+    /// Delete all clusters, calling `on_deleted` with each cluster's name as
+    /// soon as it is gone. A cluster that fails to delete is logged and
+    /// skipped.
+    pub async fn delete_all_clusters(
+        &self,
+        options: &DeleteClusterOptions,
+        mut on_deleted: impl FnMut(&str),
+    ) -> Result<()> {
         let clusters = self.list_clusters().await?;
 
         if clusters.is_empty() {
@@ -112,19 +858,67 @@ impl ClusterManager {
         }
 
         for cluster in clusters {
-            if let Err(e) = self.delete_cluster(&cluster.name).await {
-                warn!("Failed to delete cluster '{}': {}", cluster.name, e);
+            match self.delete_cluster(&cluster.name, options).await {
+                Ok(()) => on_deleted(&cluster.name),
+                Err(e) => warn!("Failed to delete cluster '{}': {}", cluster.name, e),
             }
         }
 
         Ok(())
     }
 
+    /// Apple Container networks labelled `io.kina.cluster`
+    pub async fn list_networks(&self) -> Result<Vec<NetworkInfo>> {
+        Ok(self
+            .client
+            .list_networks()
+            .await?
+            .into_iter()
+            .filter(|n| n.kina_cluster().is_some())
+            .collect())
+    }
+
+    /// Create an Apple Container network for kina clusters
+    pub async fn create_network(
+        &self,
+        name: &str,
+        subnet: Option<&str>,
+        labels: &std::collections::BTreeMap<String, String>,
+    ) -> Result<NetworkInfo> {
+        if self
+            .client
+            .list_networks()
+            .await?
+            .iter()
+            .any(|n| n.name == name)
+        {
+            return Err(anyhow::anyhow!("Network '{}' already exists", name));
+        }
+        self.client.create_network(name, subnet, labels).await
+    }
+
+    /// Delete a kina-managed network. Networks without the `io.kina.cluster`
+    /// label (such as Apple Container's `default`) are refused.
+    pub async fn delete_network(&self, name: &str) -> Result<()> {
+        let networks = self.client.list_networks().await?;
+        let network = networks
+            .iter()
+            .find(|n| n.name == name)
+            .ok_or_else(|| anyhow::anyhow!("Network '{}' does not exist", name))?;
+        if network.kina_cluster().is_none() {
+            return Err(anyhow::anyhow!(
+                "Network '{}' is not managed by kina (no io.kina.cluster label)",
+                name
+            ));
+        }
+        self.client.delete_network(name).await
+    }
+
     /// List all existing clusters
     pub async fn list_clusters(&self) -> Result<Vec<ClusterInfo>> {
         debug!("Listing clusters");
 
-        self.apple_container
+        self.client
             .list_clusters()
             .await
             .context("Failed to list clusters")
@@ -141,15 +935,47 @@ impl ClusterManager {
         debug!("Getting kubeconfig for cluster '{}'", name);
 
         if !self.cluster_exists(name).await? {
-            return Err(anyhow::anyhow!("Cluster '{}' does not exist", name));
+            return Err(ClusterError::NotFound {
+                name: name.to_string(),
+            }
+            .into());
         }
 
-        self.apple_container
+        self.client
             .get_kubeconfig(name)
             .await
             .context("Failed to get kubeconfig")
     }
 
+    /// Path of a kubeconfig file for internal kubectl/helm calls: `~/.kube/<name>`
+    /// when kina saved one, otherwise (e.g. `--no-kubeconfig`) a private copy
+    /// generated from the control plane under `kubeconfig_dir`. The private copy
+    /// is never merged into the user's kubeconfig and is removed on delete.
+    pub async fn kubeconfig_path(&self, name: &str) -> Result<std::path::PathBuf> {
+        let home_dir = std::env::var("HOME").context("HOME environment variable not set")?;
+        let saved = std::path::Path::new(&home_dir).join(".kube").join(name);
+        if saved.exists() {
+            return Ok(saved);
+        }
+
+        let kubeconfig = self.get_kubeconfig(name).await?;
+        let kubeconfig_dir = &self.config.kubernetes.kubeconfig_dir;
+        std::fs::create_dir_all(kubeconfig_dir).with_context(|| {
+            format!(
+                "Failed to create kubeconfig directory {}",
+                kubeconfig_dir.display()
+            )
+        })?;
+        let path = kubeconfig_dir.join(format!("{}.yaml", name));
+        write_private_file(&path, &kubeconfig)?;
+        debug!(
+            "Wrote internal kubeconfig for '{}' to {}",
+            name,
+            path.display()
+        );
+        Ok(path)
+    }
+
     /// Get nodes in a cluster
     pub async fn get_nodes(&self, name: &str) -> Result<Vec<String>> {
         debug!("Getting nodes for cluster '{}'", name);
@@ -174,14 +1000,34 @@ impl ClusterManager {
             options.image, options.cluster
         );
 
-        if !self.cluster_exists(&options.cluster).await? {
-            return Err(anyhow::anyhow!(
-                "Cluster '{}' does not exist",
-                options.cluster
-            ));
+        let cluster = self
+            .list_clusters()
+            .await?
+            .into_iter()
+            .find(|c| c.name == options.cluster)
+            .ok_or_else(|| anyhow::anyhow!("Cluster '{}' does not exist", options.cluster))?;
+
+        // Images are imported with containerd's ctr, which CRI-O nodes lack
+        if let Some(node) = cluster
+            .nodes
+            .iter()
+            .find(|n| n.role == NodeRole::ControlPlane)
+        {
+            match self.client.inspect_container(&node.name).await {
+                Ok(inspect)
+                    if container_runtime_from_inspect(&inspect) == ContainerRuntime::CriO =>
+                {
+                    return Err(anyhow::anyhow!(
+                        "Cluster '{}' runs CRI-O; kina load only supports containerd nodes",
+                        options.cluster
+                    ));
+                }
+                Ok(_) => {}
+                Err(e) => debug!("Could not inspect '{}' for its runtime: {}", node.name, e),
+            }
         }
 
-        self.apple_container
+        self.client
             .load_image(&options)
             .await
             .context("Failed to load image into cluster")?;
@@ -193,6 +1039,49 @@ impl ClusterManager {
         Ok(())
     }
 
+    /// Load `image` into every running cluster concurrently. The image is
+    /// exported once and every cluster streams the same tarball, which is
+    /// removed after the last load. A failure in one cluster is reported in its
+    /// result and does not stop the others.
+    pub async fn load_image_all_clusters(&self, image: &str) -> Result<Vec<ClusterLoadResult>> {
+        let running: Vec<ClusterInfo> = self
+            .list_clusters()
+            .await?
+            .into_iter()
+            .filter(|c| c.status == ClusterStatus::Running)
+            .collect();
+        if running.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let image_tar = self
+            .client
+            .export_image(image)
+            .await
+            .with_context(|| format!("Failed to export image '{}'", image))?;
+        let loads = running.into_iter().map(|c| {
+            let archive = image_tar.to_path_buf();
+            async move {
+                let start = std::time::Instant::now();
+                let result = self
+                    .load_image(LoadImageOptions {
+                        image: image.to_string(),
+                        cluster: c.name.clone(),
+                        archive: Some(archive),
+                    })
+                    .await;
+                ClusterLoadResult {
+                    cluster: c.name,
+                    result,
+                    elapsed: start.elapsed(),
+                }
+            }
+        });
+        let results = futures::future::join_all(loads).await;
+        drop(image_tar);
+        Ok(results)
+    }
+
     /// Wait for a cluster to be ready
     async fn wait_for_cluster_ready(&self, name: &str, timeout_seconds: u64) -> Result<()> {
         info!(
@@ -255,14 +1144,231 @@ impl ClusterManager {
         debug!("Getting detailed status for cluster '{}'", name);
 
         let clusters = self.list_clusters().await?;
-        let cluster = clusters
+        let mut cluster = clusters
             .into_iter()
             .find(|c| c.name == name)
             .ok_or_else(|| anyhow::anyhow!("Cluster '{}' does not exist", name))?;
 
+        // Prefer the live Kubernetes node state over container state when the API
+        // server is reachable from the host; otherwise keep the container view.
+        if cluster.status == ClusterStatus::Running {
+            match self.live_node_info(name).await {
+                Ok(live) => merge_live_node_info(&mut cluster.nodes, &live),
+                Err(e) => debug!("Live node info unavailable for '{}': {}", name, e),
+            }
+        }
+
         Ok(cluster)
     }
 
+    /// Run the `kina benchmark` tests against a running cluster, `iterations`
+    /// samples per test (`API_BURST_REQUESTS` per iteration for the API burst).
+    ///
+    /// Workloads run in `BENCHMARK_NAMESPACE`, which is deleted afterwards.
+    pub async fn benchmark(&self, name: &str, iterations: u32) -> Result<Vec<BenchmarkResult>> {
+        let info = self
+            .list_clusters()
+            .await?
+            .into_iter()
+            .find(|c| c.name == name)
+            .ok_or_else(|| ClusterError::NotFound {
+                name: name.to_string(),
+            })?;
+        if info.status != ClusterStatus::Running {
+            return Err(ClusterError::NotRunning {
+                name: name.to_string(),
+            }
+            .into());
+        }
+
+        let cp_name = format!("{}-control-plane", name);
+        self.client
+            .run_node_script(&cp_name, &benchmark::setup_script())
+            .await
+            .context("Failed to create the benchmark namespace")?;
+
+        let results = self.run_benchmarks(&cp_name, iterations).await;
+
+        if let Err(e) = self
+            .client
+            .run_node_script(&cp_name, &benchmark::cleanup_script())
+            .await
+        {
+            warn!(
+                "Failed to delete namespace '{}': {}",
+                benchmark::BENCHMARK_NAMESPACE,
+                e
+            );
+        }
+        results
+    }
+
+    async fn run_benchmarks(&self, cp_name: &str, iterations: u32) -> Result<Vec<BenchmarkResult>> {
+        // Samples and failures from running `script`; a failed exec counts as
+        // one failed sample.
+        let sample = |script: String| async move {
+            match self.client.run_node_script(cp_name, &script).await {
+                Ok(out) => (benchmark::parse_samples(&out), out),
+                Err(e) => {
+                    debug!("Benchmark script failed: {}", e);
+                    ((Vec::new(), 1), String::new())
+                }
+            }
+        };
+        let mut results = Vec::new();
+
+        info!("Benchmarking pod startup ({} iterations)", iterations);
+        let (mut samples, mut failures) = (Vec::new(), 0);
+        for i in 0..iterations {
+            let ((s, f), _) = sample(benchmark::pod_startup_script(&format!(
+                "kina-bench-pod-{}",
+                i
+            )))
+            .await;
+            samples.extend(s);
+            failures += f;
+        }
+        results.push(BenchmarkResult::from_samples(
+            "pod-startup",
+            samples,
+            failures,
+        ));
+
+        info!("Benchmarking DNS resolution ({} lookups)", iterations);
+        let ((samples, failures), _) = sample(benchmark::dns_script(iterations)).await;
+        results.push(BenchmarkResult::from_samples(
+            "dns-lookup",
+            samples,
+            failures,
+        ));
+
+        let has_default_class = self
+            .client
+            .run_node_script(cp_name, &benchmark::default_storage_class_script())
+            .await?
+            .lines()
+            .any(|l| l.trim() == "true");
+        if has_default_class {
+            info!(
+                "Benchmarking volume provisioning ({} iterations)",
+                iterations
+            );
+            let (mut samples, mut failures) = (Vec::new(), 0);
+            for i in 0..iterations {
+                let ((s, f), _) = sample(benchmark::volume_provision_script(&format!(
+                    "kina-bench-pvc-{}",
+                    i
+                )))
+                .await;
+                samples.extend(s);
+                failures += f;
+            }
+            results.push(BenchmarkResult::from_samples(
+                "volume-provision",
+                samples,
+                failures,
+            ));
+        } else {
+            results.push(BenchmarkResult::skipped(
+                "volume-provision",
+                "no default StorageClass",
+            ));
+        }
+
+        info!(
+            "Benchmarking API server ({} bursts of {} requests)",
+            iterations,
+            benchmark::API_BURST_REQUESTS
+        );
+        let (mut samples, mut failures, mut wall_ms) = (Vec::new(), 0, 0.0);
+        for _ in 0..iterations {
+            let ((s, f), out) = sample(benchmark::api_burst_script()).await;
+            samples.extend(s);
+            failures += f;
+            wall_ms += benchmark::parse_wall_ms(&out).unwrap_or(0.0);
+        }
+        let served = samples.len();
+        let mut api = BenchmarkResult::from_samples("api-pod-list", samples, failures);
+        if wall_ms > 0.0 {
+            api = api.with_note(format!("{:.0} req/s", served as f64 * 1000.0 / wall_ms));
+        }
+        results.push(api);
+
+        Ok(results)
+    }
+
+    /// The last `lines` kubelet journal entries from a node, newest first
+    pub async fn node_logs(&self, node_name: &str, lines: u32) -> Result<String> {
+        self.client.node_logs(node_name, lines).await
+    }
+
+    /// Block until every node is Ready and every kube-system pod is running,
+    /// printing progress every 10 seconds. Errors after `timeout_seconds`.
+    pub async fn wait_ready(&self, name: &str, timeout_seconds: u64) -> Result<()> {
+        const PROGRESS_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+        const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+
+        let start = std::time::Instant::now();
+        let timeout = std::time::Duration::from_secs(timeout_seconds);
+        let mut last_progress: Option<std::time::Instant> = None;
+
+        loop {
+            let cluster = self.get_cluster_status(name).await?;
+            let snapshot = if cluster.status == ClusterStatus::Running {
+                match self.client.readiness_snapshot(name).await {
+                    Ok(snapshot) => snapshot,
+                    Err(e) => {
+                        debug!("Readiness query for '{}' failed: {}", name, e);
+                        ReadinessSnapshot::default()
+                    }
+                }
+            } else {
+                ReadinessSnapshot::default()
+            };
+
+            if snapshot.is_ready() {
+                println!("✅ Cluster '{}' is ready ({})", name, snapshot.summary());
+                return Ok(());
+            }
+
+            if start.elapsed() >= timeout {
+                return Err(anyhow::anyhow!(
+                    "Timed out after {}s waiting for cluster '{}' to be ready ({}, containers {})",
+                    timeout_seconds,
+                    name,
+                    snapshot.summary(),
+                    cluster.status
+                ));
+            }
+
+            if last_progress.map_or(true, |t| t.elapsed() >= PROGRESS_INTERVAL) {
+                println!(
+                    "[{:>4}s] containers {}: {}",
+                    start.elapsed().as_secs(),
+                    cluster.status,
+                    snapshot.summary()
+                );
+                last_progress = Some(std::time::Instant::now());
+            }
+
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Node state from the cluster's Kubernetes API, read with the kubeconfig kina
+    /// already wrote (`~/.kube/<name>`) — never generating one just for status.
+    async fn live_node_info(&self, name: &str) -> Result<Vec<NodeInfo>> {
+        let home_dir = std::env::var("HOME").context("HOME environment variable not set")?;
+        let kubeconfig = std::path::Path::new(&home_dir).join(".kube").join(name);
+        if !kubeconfig.exists() {
+            return Err(anyhow::anyhow!("no kubeconfig at {}", kubeconfig.display()));
+        }
+
+        KubernetesClient::new(&self.config)?
+            .get_live_node_info(&kubeconfig.to_string_lossy())
+            .await
+    }
+
     /// Bootstrap kubelet CSR auto-approval for a cluster.
     ///
     /// Runs the approval inside the control-plane container. On Apple Container the host
@@ -275,8 +1381,7 @@ impl ClusterManager {
             cluster_name
         );
 
-        self.apple_container
-            .approve_cluster_kubelet_csrs(cluster_name);
+        self.client.approve_cluster_kubelet_csrs(cluster_name);
 
         info!(
             "Kubelet CSR bootstrap completed for cluster '{}'",
@@ -292,30 +1397,553 @@ impl ClusterManager {
     /// (a `warn!` is emitted with the full `build_unreachable_diagnostic` message).
     /// Use this from the `kina kubeconfig <cluster>` subcommand.
     pub async fn repair_kubeconfig(&self, cluster_name: &str) -> Result<()> {
-        self.apple_container.repair_kubeconfig(cluster_name).await
+        self.client.repair_kubeconfig(cluster_name).await
     }
 
-    /// Manually approve any pending kubelet-serving CSRs for a cluster
-    /// This can be used to fix TLS issues in existing clusters
-    pub async fn approve_kubelet_csrs(&self, cluster_name: &str) -> Result<()> {
+    /// Install or replace the CNI plugin on an existing cluster
+    pub async fn install_cni(
+        &self,
+        cluster_name: &str,
+        cni: CniPlugin,
+        kernel_path: Option<&std::path::Path>,
+    ) -> Result<()> {
+        if !self.cluster_exists(cluster_name).await? {
+            return Err(anyhow::anyhow!("Cluster '{}' does not exist", cluster_name));
+        }
+
+        self.client
+            .install_cni(cluster_name, cni, kernel_path)
+            .await
+    }
+
+    /// Install `provisioner` and mark its StorageClass as the cluster default
+    pub async fn setup_storage_class(
+        &self,
+        cluster_name: &str,
+        provisioner: &StorageProvisioner,
+    ) -> Result<()> {
+        if !self.cluster_exists(cluster_name).await? {
+            return Err(anyhow::anyhow!("Cluster '{}' does not exist", cluster_name));
+        }
+
         info!(
-            "Approving pending kubelet CSRs for cluster '{}'",
+            "Setting up default StorageClass '{}' in cluster '{}'",
+            provisioner.storage_class(),
             cluster_name
         );
+        let cp_name = control_plane_node_name(cluster_name, 1);
+        self.client
+            .run_node_script(&cp_name, &storage::setup_script(provisioner))
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to set up StorageClass '{}'",
+                    provisioner.storage_class()
+                )
+            })?;
+        Ok(())
+    }
+
+    /// Names of the pending CSRs in `cluster_name`: kubelet-serving ones, or
+    /// every signer's with `all_types`.
+    ///
+    /// Listed inside the control-plane container — the host cannot reach the
+    /// in-VM API server on Apple Container (host kubectl fails with "no route
+    /// to host").
+    pub async fn pending_csrs(&self, cluster_name: &str, all_types: bool) -> Result<Vec<String>> {
+        let listing = self
+            .client
+            .run_node_script(
+                &control_plane_node_name(cluster_name, 1),
+                &format!(
+                    "kubectl get csr -o jsonpath='{}' \
+                     --kubeconfig=/etc/kubernetes/admin.conf",
+                    CSR_JSONPATH
+                ),
+            )
+            .await
+            .context("Failed to list CSRs")?;
+        let signer = (!all_types).then_some(KUBELET_SERVING_SIGNER);
+        Ok(parse_pending_csrs(&listing, signer))
+    }
+
+    /// Approve a cluster's pending kubelet-serving CSRs, or with `all_types`
+    /// every pending CSR whatever its signer. Returns the approved CSR names.
+    /// This can be used to fix TLS issues in existing clusters.
+    pub async fn approve_pending_csrs(
+        &self,
+        cluster_name: &str,
+        all_types: bool,
+    ) -> Result<Vec<String>> {
+        info!("Approving pending CSRs for cluster '{}'", cluster_name);
 
         if !self.cluster_exists(cluster_name).await? {
             return Err(anyhow::anyhow!("Cluster '{}' does not exist", cluster_name));
         }
 
-        // Approve inside the control-plane container — the host cannot reach the in-VM
-        // API server on Apple Container (host kubectl fails with "no route to host").
-        self.apple_container
-            .approve_cluster_kubelet_csrs(cluster_name);
+        let cp_name = control_plane_node_name(cluster_name, 1);
+        let pending = self.pending_csrs(cluster_name, all_types).await?;
+        if pending.is_empty() {
+            debug!("No pending CSRs in cluster '{}'", cluster_name);
+            return Ok(pending);
+        }
+
+        self.client
+            .run_node_script(
+                &cp_name,
+                &format!(
+                    "kubectl certificate approve --kubeconfig=/etc/kubernetes/admin.conf {}",
+                    pending.join(" ")
+                ),
+            )
+            .await
+            .context("Failed to approve CSRs")?;
 
         info!(
-            "Kubelet CSR approval completed for cluster '{}'",
+            "Approved {} CSR(s) for cluster '{}'",
+            pending.len(),
             cluster_name
         );
-        Ok(())
+        Ok(pending)
+    }
+}
+
+/// Write `contents` to `path` readable only by the current user, since
+/// kubeconfigs carry cluster-admin credentials
+fn write_private_file(path: &std::path::Path, contents: &str) -> Result<()> {
+    use std::io::Write;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    file.write_all(contents.as_bytes())
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Name of `node` in `info`: the full node name or the part after
+/// `<cluster>-` (e.g. `worker-2`).
+fn find_node<'a>(info: &'a ClusterInfo, node: &str) -> Result<&'a str> {
+    let prefixed = format!("{}-{}", info.name, node);
+    info.nodes
+        .iter()
+        .find(|n| n.name == node || n.name == prefixed)
+        .map(|n| n.name.as_str())
+        .ok_or_else(|| {
+            let names: Vec<&str> = info.nodes.iter().map(|n| n.name.as_str()).collect();
+            anyhow::anyhow!(
+                "Cluster '{}' has no node '{}' (nodes: {})",
+                info.name,
+                node,
+                names.join(", ")
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::mock::MockAppleContainerClient;
+    use crate::core::types::{CONTAINER_RUNTIME_LABEL, NETWORK_CLUSTER_LABEL};
+
+    const CSR_LISTING: &str = "\
+csr-serving kubernetes.io/kubelet-serving
+csr-client kubernetes.io/kube-apiserver-client-kubelet
+csr-done kubernetes.io/kubelet-serving Approved
+";
+
+    fn manager(mock: &MockAppleContainerClient) -> ClusterManager {
+        ClusterManager::new_with_client(&Config::default(), Box::new(mock.clone()))
+    }
+
+    fn approvals(mock: &MockAppleContainerClient) -> Vec<String> {
+        mock.state()
+            .scripts
+            .iter()
+            .filter(|(_, script)| script.contains("certificate approve"))
+            .map(|(node, script)| format!("{}: {}", node, script.rsplit(' ').next().unwrap()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn approve_pending_csrs_limits_to_kubelet_serving_by_default() {
+        let mock = MockAppleContainerClient::new()
+            .with_running_cluster("dev", 1)
+            .with_script_output("get csr", CSR_LISTING);
+        let manager = manager(&mock);
+
+        let approved = manager.approve_pending_csrs("dev", false).await.unwrap();
+        assert_eq!(approved, ["csr-serving"]);
+        assert_eq!(approvals(&mock), ["dev-control-plane: csr-serving"]);
+
+        let approved = manager.approve_pending_csrs("dev", true).await.unwrap();
+        assert_eq!(approved, ["csr-serving", "csr-client"]);
+
+        assert!(manager.approve_pending_csrs("missing", true).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn stored_cluster_config_reads_labels_and_kubeadm_config() {
+        let kubeadm = "apiVersion: kubeadm.k8s.io/v1beta3\nkind: ClusterConfiguration\nnetworking:\n  podSubnet: 10.99.0.0/16\n";
+        let mock = MockAppleContainerClient::new()
+            .with_running_cluster("dev", 1)
+            .with_kubeadm_config("dev", kubeadm);
+        let manager = manager(&mock);
+
+        let stored = manager.stored_cluster_config("dev").await.unwrap();
+        assert_eq!(stored.node, "dev-control-plane");
+        assert_eq!(stored.labels["io.kina.cluster"], "dev");
+        assert_eq!(stored.labels["io.kina.role"], "control-plane");
+        assert_eq!(stored.config.nodes.len(), 2);
+        assert_eq!(
+            stored.config.networking.pod_subnet.as_deref(),
+            Some("10.99.0.0/16")
+        );
+        let yaml = stored.to_yaml().unwrap();
+        assert!(yaml.starts_with("# Labels on dev-control-plane:\n#   io.kina.cluster=dev\n"));
+        assert!(yaml.contains("---\n# /kind/kubeadm.conf\napiVersion: kubeadm.k8s.io/v1beta3"));
+
+        mock.clone().fail_on("read_kubeadm_config", "exec failed");
+        let stored = manager.stored_cluster_config("dev").await.unwrap();
+        assert!(stored.kubeadm_config.is_none());
+        assert!(manager.stored_cluster_config("missing").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn network_commands_only_touch_kina_networks() {
+        let network = |name: &str, cluster: Option<&str>| NetworkInfo {
+            name: name.to_string(),
+            state: "running".to_string(),
+            subnet: None,
+            labels: cluster
+                .map(|c| (NETWORK_CLUSTER_LABEL.to_string(), c.to_string()))
+                .into_iter()
+                .collect(),
+        };
+        let mock = MockAppleContainerClient::new()
+            .with_network(network("default", None))
+            .with_network(network("kina-dev", Some("dev")));
+        let manager = manager(&mock);
+
+        let names: Vec<_> = manager
+            .list_networks()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|n| n.name)
+            .collect();
+        assert_eq!(names, ["kina-dev"]);
+
+        let err = manager.delete_network("default").await.unwrap_err();
+        assert!(err.to_string().contains("not managed by kina"));
+        let err = manager
+            .create_network("kina-dev", None, &Default::default())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+
+        manager.delete_network("kina-dev").await.unwrap();
+        assert_eq!(mock.state().networks.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn kubeconfig_path_writes_a_private_copy_without_merging() {
+        let mock = MockAppleContainerClient::new()
+            .with_node_ip("nokube-control-plane", "10.0.0.10")
+            .with_running_cluster("nokube", 0);
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = Config::default();
+        config.kubernetes.kubeconfig_dir = dir.path().join("kubeconfig");
+        let manager = ClusterManager::new_with_client(&config, Box::new(mock.clone()));
+        let primary = crate::utils::kubeconfig::resolve_primary_kubeconfig_path();
+        let before = std::fs::read(&primary).ok();
+
+        let path = manager.kubeconfig_path("nokube").await.unwrap();
+        assert_eq!(path, dir.path().join("kubeconfig").join("nokube.yaml"));
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert!(written.contains("server: https://10.0.0.10:6443"));
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(std::fs::read(&primary).ok(), before);
+
+        manager
+            .delete_cluster("nokube", &DeleteClusterOptions::default())
+            .await
+            .unwrap();
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn load_image_all_clusters_targets_running_clusters_only() {
+        let mock = MockAppleContainerClient::new()
+            .with_running_cluster("dev", 0)
+            .with_running_cluster("staging", 0)
+            .with_running_cluster("idle", 0);
+        let manager = manager(&mock);
+        manager.pause_cluster("idle").await.unwrap();
+
+        let mut results = manager.load_image_all_clusters("app:latest").await.unwrap();
+        results.sort_by(|a, b| a.cluster.cmp(&b.cluster));
+        let clusters: Vec<_> = results.iter().map(|r| r.cluster.as_str()).collect();
+        assert_eq!(clusters, ["dev", "staging"]);
+        assert!(results.iter().all(|r| r.result.is_ok()));
+        assert_eq!(mock.state().loaded_images.len(), 2);
+        assert_eq!(mock.state().exported_images, ["app:latest"]);
+
+        mock.clone().fail_on("load_image", "image not found");
+        let results = manager.load_image_all_clusters("app:latest").await.unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| r.result.is_err()));
+    }
+
+    #[tokio::test]
+    async fn load_image_refuses_cri_o_clusters() {
+        let mock = MockAppleContainerClient::new()
+            .with_running_cluster("dev", 1)
+            .with_running_cluster("crio", 1)
+            .with_container_labels("crio-control-plane", &[(CONTAINER_RUNTIME_LABEL, "cri-o")]);
+        let manager = manager(&mock);
+        let load = |cluster: &str| LoadImageOptions {
+            image: "app:latest".to_string(),
+            cluster: cluster.to_string(),
+            archive: None,
+        };
+
+        manager.load_image(load("dev")).await.unwrap();
+        let err = manager.load_image(load("crio")).await.unwrap_err();
+        assert!(err.to_string().contains("runs CRI-O"), "{}", err);
+        assert_eq!(
+            mock.state().loaded_images,
+            [("dev".to_string(), "app:latest".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn pending_csrs_lists_on_the_control_plane_without_approving() {
+        let mock = MockAppleContainerClient::new()
+            .with_running_cluster("dev", 1)
+            .with_script_output("get csr", CSR_LISTING);
+        let pending = manager(&mock).pending_csrs("dev", false).await.unwrap();
+        assert_eq!(pending, ["csr-serving"]);
+        assert!(approvals(&mock).is_empty());
+    }
+
+    #[tokio::test]
+    async fn approve_pending_csrs_skips_approve_when_nothing_is_pending() {
+        let mock = MockAppleContainerClient::new().with_running_cluster("dev", 0);
+        let approved = manager(&mock)
+            .approve_pending_csrs("dev", true)
+            .await
+            .unwrap();
+        assert!(approved.is_empty());
+        assert!(approvals(&mock).is_empty());
+    }
+
+    #[tokio::test]
+    async fn duplicate_cluster_copies_the_source_settings() {
+        use crate::core::apple_container::{
+            generate_kubeadm_init_config_with, KubeadmConfigOptions,
+        };
+
+        let empty = StoredClusterConfig {
+            node: String::new(),
+            labels: BTreeMap::new(),
+            config: ClusterConfig::default_with_name("dev"),
+            kubeadm_config: None,
+        };
+        let mut source = CreateClusterOptions {
+            pod_security_standard: Some("restricted".to_string()),
+            api_server_sans: vec!["dev.example.com".to_string()],
+            container_runtime: ContainerRuntime::CriO,
+            api_server_port: Some(7443),
+            networking: Some(NetworkingConfig {
+                pod_subnet: Some("10.99.0.0/16".to_string()),
+                service_subnet: Some("10.98.0.0/16".to_string()),
+                ..Default::default()
+            }),
+            ..empty.create_options("dev")
+        };
+        source
+            .api_server_extra_args
+            .insert("v".to_string(), "4".to_string());
+        source
+            .kubelet_extra_args
+            .insert("max-pods".to_string(), "50".to_string());
+        let kubeadm =
+            KubeadmConfigOptions::from_create_options(&source, std::path::Path::new("/tmp"))
+                .unwrap();
+        let kubeadm_yaml =
+            generate_kubeadm_init_config_with("dev-control-plane", "10.0.0.10", "dev", &kubeadm);
+
+        let mock = MockAppleContainerClient::new()
+            .with_running_cluster("dev", 2)
+            .with_kubeadm_config("dev", &kubeadm_yaml)
+            .with_container_labels(
+                "dev-control-plane",
+                &[(CNI_LABEL, "calico"), (API_SERVER_PORT_LABEL, "7443")],
+            )
+            .with_node_resources("dev-control-plane", 6, 8 << 30)
+            .with_node_resources("dev-worker", 2, 1536 << 20);
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = Config::default();
+        config.cluster.data_dir = dir.path().to_path_buf();
+        let manager = ClusterManager::new_with_client(&config, Box::new(mock.clone()));
+
+        manager
+            .duplicate_cluster("dev", "copy", |options| {
+                options.no_wait = true;
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        let created = mock.state().created.pop().unwrap();
+        assert_eq!(created.name, "copy");
+        assert_eq!(created.image, "kindest/node:mock");
+        assert_eq!(created.workers, Some(2));
+        assert_eq!(created.cni_plugin, CniPlugin::Calico);
+        assert_eq!(created.api_server_port, Some(7443));
+        assert_eq!(created.container_runtime, ContainerRuntime::CriO);
+        assert_eq!(
+            (
+                created.control_plane_cpus,
+                created.control_plane_memory.as_str()
+            ),
+            (6, "8g")
+        );
+        assert_eq!(
+            (created.worker_cpus, created.worker_memory.as_str()),
+            (2, "1536m")
+        );
+        let networking = created.networking.unwrap();
+        assert_eq!(networking.pod_subnet.as_deref(), Some("10.99.0.0/16"));
+        assert_eq!(networking.service_subnet.as_deref(), Some("10.98.0.0/16"));
+        assert_eq!(created.api_server_sans, ["dev.example.com"]);
+        assert_eq!(
+            created.api_server_extra_args,
+            BTreeMap::from([("v".to_string(), "4".to_string())])
+        );
+        assert_eq!(
+            created.kubelet_extra_args,
+            BTreeMap::from([("max-pods".to_string(), "50".to_string())])
+        );
+        assert!(created.no_wait);
+        assert!(dir.path().join("copy/source-kubeadm.conf").exists());
+
+        let err = manager
+            .duplicate_cluster("dev", "copy", |_| Ok(()))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+    }
+
+    #[tokio::test]
+    async fn delete_cluster_force_continues_past_kubeconfig_cleanup_failures() {
+        let mock = MockAppleContainerClient::new()
+            .with_running_cluster("a", 0)
+            .with_running_cluster("b", 0);
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = Config::default();
+        config.kubernetes.kubeconfig_dir = dir.path().to_path_buf();
+        let manager = ClusterManager::new_with_client(&config, Box::new(mock.clone()));
+        // A directory where the kubeconfig file should be cannot be removed
+        std::fs::create_dir(dir.path().join("a.yaml")).unwrap();
+        std::fs::create_dir(dir.path().join("b.yaml")).unwrap();
+
+        let err = manager
+            .delete_cluster("a", &DeleteClusterOptions::default())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("kubeconfig"), "{}", err);
+
+        let force = DeleteClusterOptions {
+            force: true,
+            ..Default::default()
+        };
+        manager.delete_cluster("b", &force).await.unwrap();
+        assert!(manager.list_clusters().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn delete_all_clusters_reports_each_deleted_cluster() {
+        let mock = MockAppleContainerClient::new()
+            .with_running_cluster("a", 0)
+            .with_running_cluster("b", 1);
+        let mut deleted = Vec::new();
+        manager(&mock)
+            .delete_all_clusters(&DeleteClusterOptions::default(), |name| {
+                deleted.push(name.to_string())
+            })
+            .await
+            .unwrap();
+        deleted.sort();
+        assert_eq!(deleted, ["a", "b"]);
+
+        let failing = MockAppleContainerClient::new()
+            .with_running_cluster("a", 0)
+            .fail_on("delete_cluster", "boom");
+        let mut deleted = Vec::new();
+        manager(&failing)
+            .delete_all_clusters(&DeleteClusterOptions::default(), |name| {
+                deleted.push(name.to_string())
+            })
+            .await
+            .unwrap();
+        assert!(deleted.is_empty());
+    }
+
+    #[tokio::test]
+    async fn setup_storage_class_runs_on_the_control_plane() {
+        let mock = MockAppleContainerClient::new().with_running_cluster("dev", 1);
+        let manager = manager(&mock);
+
+        manager
+            .setup_storage_class("dev", &StorageProvisioner::LocalPath)
+            .await
+            .unwrap();
+        let scripts = mock.state().scripts.clone();
+        assert_eq!(scripts.len(), 1);
+        assert_eq!(scripts[0].0, "dev-control-plane");
+        assert!(scripts[0].1.contains("patch storageclass local-path"));
+
+        assert!(manager
+            .setup_storage_class("missing", &StorageProvisioner::LocalPath)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn exec_in_node_defaults_to_the_control_plane() {
+        let mock = MockAppleContainerClient::new().with_running_cluster("dev", 2);
+        let manager = manager(&mock);
+        let bash = ["bash".to_string()];
+
+        assert_eq!(
+            manager
+                .exec_in_node("dev", None, &bash, true)
+                .await
+                .unwrap(),
+            0
+        );
+        manager
+            .exec_in_node("dev", Some("worker-2"), &bash, false)
+            .await
+            .unwrap();
+        let nodes: Vec<String> = mock.state().execs.iter().map(|(n, _)| n.clone()).collect();
+        assert_eq!(nodes, ["dev-control-plane", "dev-worker-2"]);
+
+        assert!(manager
+            .exec_in_node("dev", Some("worker9"), &bash, false)
+            .await
+            .is_err());
+        assert!(manager
+            .exec_in_node("missing", None, &bash, false)
+            .await
+            .is_err());
     }
 }
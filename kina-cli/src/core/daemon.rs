@@ -0,0 +1,614 @@
+//! `kina daemon` — serve cluster commands over gRPC on a Unix socket.
+//!
+//! The daemon keeps one [`ClusterManager`] alive, so locating the Apple
+//! Container CLI and probing its version happen once rather than on every
+//! `kina` invocation. The CLI talks to it through [`DaemonClient`] when the
+//! socket answers and falls back to running the command itself otherwise.
+//!
+//! The service is defined in `proto/kina/v1/kina.proto`.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::net::{UnixListener, UnixStream};
+use tonic::transport::{Channel, Endpoint, Server, Uri};
+use tonic::{Request, Response, Status};
+use tracing::{debug, info, warn};
+
+use super::cluster::ClusterManager;
+use super::types::{
+    ClusterInfo, ClusterStatus, DeleteClusterOptions, NodeInfo, DEFAULT_DELETE_GRACE_PERIOD,
+};
+use crate::config::Config;
+use crate::errors::ClusterError;
+
+/// Set to any value to make the CLI ignore a running daemon
+pub const NO_DAEMON_ENV: &str = "KINA_NO_DAEMON";
+
+/// How long the CLI waits for the daemon before executing directly
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Messages for `proto/kina/v1/kina.proto` and the service stubs build.rs
+/// generates from them (`kina_client`, `kina_server`).
+pub mod proto {
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct PingRequest {}
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct PingResponse {
+        #[prost(string, tag = "1")]
+        pub version: String,
+        #[prost(uint32, tag = "2")]
+        pub pid: u32,
+        #[prost(string, tag = "3")]
+        pub container_version: String,
+        #[prost(string, tag = "4")]
+        pub config_path: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ListClustersRequest {}
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ListClustersResponse {
+        #[prost(message, repeated, tag = "1")]
+        pub clusters: Vec<Cluster>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ClusterRequest {
+        #[prost(string, tag = "1")]
+        pub name: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Cluster {
+        #[prost(string, tag = "1")]
+        pub name: String,
+        #[prost(string, tag = "2")]
+        pub image: String,
+        #[prost(string, tag = "3")]
+        pub status: String,
+        #[prost(string, tag = "4")]
+        pub created: String,
+        #[prost(message, repeated, tag = "5")]
+        pub nodes: Vec<Node>,
+        #[prost(string, optional, tag = "6")]
+        pub kubeconfig_path: Option<String>,
+        /// RFC 3339
+        #[prost(string, optional, tag = "7")]
+        pub creation_timestamp: Option<String>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Node {
+        #[prost(string, tag = "1")]
+        pub name: String,
+        #[prost(string, tag = "2")]
+        pub role: String,
+        #[prost(string, tag = "3")]
+        pub status: String,
+        #[prost(string, tag = "4")]
+        pub version: String,
+        #[prost(string, optional, tag = "5")]
+        pub container_id: Option<String>,
+        #[prost(string, optional, tag = "6")]
+        pub ip_address: Option<String>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct KubeconfigResponse {
+        #[prost(string, tag = "1")]
+        pub kubeconfig: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct DeleteClusterRequest {
+        #[prost(string, tag = "1")]
+        pub name: String,
+        #[prost(uint64, optional, tag = "2")]
+        pub grace_period_seconds: Option<u64>,
+        #[prost(bool, tag = "3")]
+        pub force: bool,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct DeleteClusterResponse {}
+
+    include!(concat!(env!("OUT_DIR"), "/kina.v1.Kina.rs"));
+}
+
+/// `~/.local/share/kina/kina.sock`
+pub fn socket_path() -> PathBuf {
+    super::image_registry::cache_dir().join("kina.sock")
+}
+
+impl From<ClusterInfo> for proto::Cluster {
+    fn from(cluster: ClusterInfo) -> Self {
+        Self {
+            name: cluster.name,
+            image: cluster.image,
+            status: cluster.status.to_string(),
+            created: cluster.created,
+            creation_timestamp: cluster.creation_timestamp.map(|ts| ts.to_rfc3339()),
+            nodes: cluster.nodes.into_iter().map(proto::Node::from).collect(),
+            kubeconfig_path: cluster.kubeconfig_path,
+        }
+    }
+}
+
+impl From<NodeInfo> for proto::Node {
+    fn from(node: NodeInfo) -> Self {
+        Self {
+            name: node.name,
+            role: node.role.to_string(),
+            status: node.status,
+            version: node.version,
+            container_id: node.container_id,
+            ip_address: node.ip_address,
+        }
+    }
+}
+
+impl TryFrom<proto::Cluster> for ClusterInfo {
+    type Error = anyhow::Error;
+
+    fn try_from(cluster: proto::Cluster) -> Result<Self> {
+        Ok(Self {
+            status: cluster.status.parse().unwrap_or(ClusterStatus::Unknown),
+            nodes: cluster
+                .nodes
+                .into_iter()
+                .map(NodeInfo::try_from)
+                .collect::<Result<_>>()?,
+            name: cluster.name,
+            image: cluster.image,
+            created: cluster.created,
+            creation_timestamp: cluster
+                .creation_timestamp
+                .as_deref()
+                .map(chrono::DateTime::parse_from_rfc3339)
+                .transpose()
+                .context("daemon returned an invalid creation timestamp")?
+                .map(|ts| ts.with_timezone(&chrono::Utc)),
+            kubeconfig_path: cluster.kubeconfig_path,
+        })
+    }
+}
+
+impl TryFrom<proto::Node> for NodeInfo {
+    type Error = anyhow::Error;
+
+    fn try_from(node: proto::Node) -> Result<Self> {
+        let role = node
+            .role
+            .parse()
+            .with_context(|| format!("daemon returned node '{}' with a bad role", node.name))?;
+        Ok(Self {
+            name: node.name,
+            role,
+            status: node.status,
+            version: node.version,
+            container_id: node.container_id,
+            ip_address: node.ip_address,
+        })
+    }
+}
+
+impl From<&proto::DeleteClusterRequest> for DeleteClusterOptions {
+    fn from(request: &proto::DeleteClusterRequest) -> Self {
+        Self {
+            grace_period: request
+                .grace_period_seconds
+                .unwrap_or(DEFAULT_DELETE_GRACE_PERIOD),
+            force: request.force,
+        }
+    }
+}
+
+/// Map a cluster manager error to a gRPC status, keeping "not found" and
+/// state errors distinguishable for the client.
+fn to_status(err: anyhow::Error) -> Status {
+    let message = format!("{:#}", err);
+    match err.downcast_ref::<ClusterError>() {
+        Some(ClusterError::NotFound { .. }) => Status::not_found(message),
+        Some(ClusterError::InvalidState { .. }) | Some(ClusterError::NotRunning { .. }) => {
+            Status::failed_precondition(message)
+        }
+        _ => Status::internal(message),
+    }
+}
+
+/// The config file `config` was loaded from, as reported in `Ping`; empty for
+/// built-in defaults.
+fn config_path(config: &Config) -> String {
+    config
+        .config_file_path
+        .as_ref()
+        .map(|path| path.display().to_string())
+        .unwrap_or_default()
+}
+
+/// Server side of the daemon: every RPC delegates to the cached manager.
+struct DaemonService {
+    manager: ClusterManager,
+}
+
+#[tonic::async_trait]
+impl proto::kina_server::Kina for DaemonService {
+    async fn ping(
+        &self,
+        _request: Request<proto::PingRequest>,
+    ) -> Result<Response<proto::PingResponse>, Status> {
+        Ok(Response::new(proto::PingResponse {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            pid: std::process::id(),
+            container_version: self.manager.container_version().to_string(),
+            config_path: config_path(self.manager.config()),
+        }))
+    }
+
+    async fn list_clusters(
+        &self,
+        _request: Request<proto::ListClustersRequest>,
+    ) -> Result<Response<proto::ListClustersResponse>, Status> {
+        let clusters = self.manager.list_clusters().await.map_err(to_status)?;
+        Ok(Response::new(proto::ListClustersResponse {
+            clusters: clusters.into_iter().map(proto::Cluster::from).collect(),
+        }))
+    }
+
+    async fn get_cluster_status(
+        &self,
+        request: Request<proto::ClusterRequest>,
+    ) -> Result<Response<proto::Cluster>, Status> {
+        let name = request.into_inner().name;
+        let cluster = self
+            .manager
+            .get_cluster_status(&name)
+            .await
+            .map_err(to_status)?;
+        Ok(Response::new(cluster.into()))
+    }
+
+    async fn get_kubeconfig(
+        &self,
+        request: Request<proto::ClusterRequest>,
+    ) -> Result<Response<proto::KubeconfigResponse>, Status> {
+        let name = request.into_inner().name;
+        let kubeconfig = self
+            .manager
+            .get_kubeconfig(&name)
+            .await
+            .map_err(to_status)?;
+        Ok(Response::new(proto::KubeconfigResponse { kubeconfig }))
+    }
+
+    async fn delete_cluster(
+        &self,
+        request: Request<proto::DeleteClusterRequest>,
+    ) -> Result<Response<proto::DeleteClusterResponse>, Status> {
+        let request = request.into_inner();
+        info!("Deleting cluster '{}' (daemon request)", request.name);
+        self.manager
+            .delete_cluster(&request.name, &DeleteClusterOptions::from(&request))
+            .await
+            .map_err(to_status)?;
+        Ok(Response::new(proto::DeleteClusterResponse {}))
+    }
+}
+
+/// Serve the daemon on `socket` until SIGINT or SIGTERM, then remove the
+/// socket. A stale socket left by a crashed daemon is replaced; a live one
+/// is an error.
+pub async fn serve(manager: ClusterManager, socket: &Path) -> Result<()> {
+    if let Some(parent) = socket.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    if socket.exists() {
+        if UnixStream::connect(socket).await.is_ok() {
+            anyhow::bail!("kina daemon is already running on {}", socket.display());
+        }
+        debug!("Removing stale daemon socket {}", socket.display());
+        std::fs::remove_file(socket)
+            .with_context(|| format!("Failed to remove stale socket {}", socket.display()))?;
+    }
+
+    let listener = UnixListener::bind(socket)
+        .with_context(|| format!("Failed to listen on {}", socket.display()))?;
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(socket, std::fs::Permissions::from_mode(0o600))
+            .with_context(|| format!("Failed to restrict permissions on {}", socket.display()))?;
+    }
+    info!("kina daemon listening on {}", socket.display());
+
+    let result = Server::builder()
+        .add_service(proto::kina_server::KinaServer::new(DaemonService {
+            manager,
+        }))
+        .serve_with_incoming_shutdown(
+            tokio_stream::wrappers::UnixListenerStream::new(listener),
+            shutdown_signal(),
+        )
+        .await;
+
+    if let Err(e) = std::fs::remove_file(socket) {
+        warn!("Failed to remove {}: {}", socket.display(), e);
+    }
+    result.context("kina daemon stopped with an error")
+}
+
+async fn shutdown_signal() {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    match signal(SignalKind::terminate()) {
+        Ok(mut sigterm) => {
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {}
+                _ = sigterm.recv() => {}
+            }
+        }
+        Err(e) => {
+            warn!("Failed to install SIGTERM handler: {}", e);
+            let _ = tokio::signal::ctrl_c().await;
+        }
+    }
+    info!("kina daemon shutting down");
+}
+
+/// Client for a running `kina daemon`.
+pub struct DaemonClient {
+    inner: proto::kina_client::KinaClient<Channel>,
+    info: proto::PingResponse,
+}
+
+impl DaemonClient {
+    /// Connect to the daemon on `socket` and ping it.
+    pub async fn connect(socket: &Path) -> Result<Self> {
+        let socket = socket.to_path_buf();
+        // The URI is required by tonic but unused: the connector always
+        // dials the Unix socket.
+        let channel = Endpoint::from_static("http://kina.sock")
+            .connect_timeout(CONNECT_TIMEOUT)
+            .connect_with_connector(tower::service_fn(move |_: Uri| {
+                let socket = socket.clone();
+                async move {
+                    Ok::<_, std::io::Error>(hyper_util::rt::TokioIo::new(
+                        UnixStream::connect(socket).await?,
+                    ))
+                }
+            }))
+            .await
+            .context("Failed to connect to kina daemon")?;
+
+        let mut inner = proto::kina_client::KinaClient::new(channel);
+        let info = inner
+            .ping(proto::PingRequest {})
+            .await
+            .map_err(from_status)?
+            .into_inner();
+        Ok(Self { inner, info })
+    }
+
+    /// The daemon on [`socket_path`], if one is running with this kina
+    /// version and the same config file as `config` (so a `--config` the
+    /// daemon was not started with is honoured) and `KINA_NO_DAEMON` is unset.
+    /// Callers execute directly on `None`.
+    pub async fn connect_if_running(config: &Config) -> Option<Self> {
+        if std::env::var_os(NO_DAEMON_ENV).is_some() {
+            return None;
+        }
+        let socket = socket_path();
+        if !socket.exists() {
+            return None;
+        }
+
+        let client = match tokio::time::timeout(CONNECT_TIMEOUT, Self::connect(&socket)).await {
+            Ok(Ok(client)) => client,
+            Ok(Err(e)) => {
+                debug!("kina daemon unavailable, executing directly: {:#}", e);
+                return None;
+            }
+            Err(_) => {
+                debug!("kina daemon did not answer, executing directly");
+                return None;
+            }
+        };
+        if client.info.version != env!("CARGO_PKG_VERSION") {
+            debug!(
+                "kina daemon is version {}, this CLI is {}; executing directly",
+                client.info.version,
+                env!("CARGO_PKG_VERSION")
+            );
+            return None;
+        }
+        if client.info.config_path != config_path(config) {
+            debug!(
+                "kina daemon uses config '{}', this CLI uses '{}'; executing directly",
+                client.info.config_path,
+                config_path(config)
+            );
+            return None;
+        }
+        debug!("Using kina daemon (pid {})", client.info.pid);
+        Some(client)
+    }
+
+    /// Apple Container CLI version the daemon detected at startup
+    pub fn container_version(&self) -> &str {
+        &self.info.container_version
+    }
+
+    pub async fn list_clusters(&mut self) -> Result<Vec<ClusterInfo>> {
+        self.inner
+            .list_clusters(proto::ListClustersRequest {})
+            .await
+            .map_err(from_status)?
+            .into_inner()
+            .clusters
+            .into_iter()
+            .map(ClusterInfo::try_from)
+            .collect()
+    }
+
+    pub async fn get_cluster_status(&mut self, name: &str) -> Result<ClusterInfo> {
+        self.inner
+            .get_cluster_status(cluster_request(name))
+            .await
+            .map_err(from_status)?
+            .into_inner()
+            .try_into()
+    }
+
+    /// The kubeconfig for `name`; a cluster the daemon does not know is a
+    /// [`ClusterError::NotFound`]
+    pub async fn get_kubeconfig(&mut self, name: &str) -> Result<String> {
+        match self.inner.get_kubeconfig(cluster_request(name)).await {
+            Ok(response) => Ok(response.into_inner().kubeconfig),
+            Err(status) if status.code() == tonic::Code::NotFound => Err(ClusterError::NotFound {
+                name: name.to_string(),
+            }
+            .into()),
+            Err(status) => Err(from_status(status)),
+        }
+    }
+
+    pub async fn delete_cluster(
+        &mut self,
+        name: &str,
+        options: &DeleteClusterOptions,
+    ) -> Result<()> {
+        self.inner
+            .delete_cluster(proto::DeleteClusterRequest {
+                name: name.to_string(),
+                grace_period_seconds: Some(options.grace_period),
+                force: options.force,
+            })
+            .await
+            .map_err(from_status)?;
+        Ok(())
+    }
+}
+
+fn cluster_request(name: &str) -> proto::ClusterRequest {
+    proto::ClusterRequest {
+        name: name.to_string(),
+    }
+}
+
+/// The daemon already formats the full error chain into the message.
+fn from_status(status: Status) -> anyhow::Error {
+    anyhow::anyhow!("{}", status.message())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::NodeRole;
+
+    #[test]
+    fn cluster_info_round_trips_through_proto() {
+        let cluster = ClusterInfo {
+            name: "dev".to_string(),
+            image: "kindest/node:v1.31.0".to_string(),
+            status: ClusterStatus::Stopped,
+            created: "2026-01-02 03:04 UTC".to_string(),
+            creation_timestamp: Some("2026-01-02T03:04:05Z".parse().unwrap()),
+            nodes: vec![NodeInfo {
+                name: "dev-control-plane".to_string(),
+                role: NodeRole::ControlPlane,
+                status: "stopped".to_string(),
+                version: "v1.31.0".to_string(),
+                container_id: None,
+                ip_address: Some("192.168.64.5".to_string()),
+            }],
+            kubeconfig_path: None,
+        };
+
+        let back = ClusterInfo::try_from(proto::Cluster::from(cluster.clone())).unwrap();
+        assert_eq!(back.name, cluster.name);
+        assert_eq!(back.status, ClusterStatus::Stopped);
+        assert_eq!(back.nodes[0].role, NodeRole::ControlPlane);
+        assert_eq!(back.nodes[0].ip_address, cluster.nodes[0].ip_address);
+        assert_eq!(back.kubeconfig_path, None);
+    }
+
+    #[test]
+    fn delete_request_without_grace_period_uses_the_default() {
+        let request = proto::DeleteClusterRequest {
+            name: "dev".to_string(),
+            grace_period_seconds: None,
+            force: false,
+        };
+        let options = DeleteClusterOptions::from(&request);
+        assert_eq!(options.grace_period, DEFAULT_DELETE_GRACE_PERIOD);
+
+        let request = proto::DeleteClusterRequest {
+            grace_period_seconds: Some(0),
+            ..request
+        };
+        assert_eq!(DeleteClusterOptions::from(&request).grace_period, 0);
+    }
+
+    #[test]
+    fn unknown_node_role_is_rejected() {
+        let node = proto::Node {
+            role: "etcd".to_string(),
+            ..Default::default()
+        };
+        assert!(NodeInfo::try_from(node).is_err());
+    }
+
+    #[test]
+    fn not_found_maps_to_not_found_status() {
+        let err = anyhow::Error::new(ClusterError::NotFound {
+            name: "dev".to_string(),
+        });
+        let status = to_status(err);
+        assert_eq!(status.code(), tonic::Code::NotFound);
+        assert_eq!(from_status(status).to_string(), "Cluster 'dev' not found");
+    }
+
+    #[test]
+    fn config_path_identifies_the_loaded_config_file() {
+        let mut config = Config::default();
+        assert_eq!(config_path(&config), "");
+        config.config_file_path = Some(PathBuf::from("/tmp/other-kina.toml"));
+        assert_eq!(config_path(&config), "/tmp/other-kina.toml");
+    }
+
+    #[tokio::test]
+    async fn get_kubeconfig_reports_unknown_clusters_as_not_found() {
+        let dir = tempfile::tempdir().unwrap();
+        let mock =
+            crate::core::mock::MockAppleContainerClient::new().with_running_cluster("dev", 0);
+        let manager = ClusterManager::new_with_client(&Config::default(), Box::new(mock));
+        let socket = dir.path().join("kina.sock");
+        let server = tokio::spawn({
+            let socket = socket.clone();
+            async move { serve(manager, &socket).await }
+        });
+        while !socket.exists() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        let mut client = DaemonClient::connect(&socket).await.unwrap();
+        assert!(client.get_kubeconfig("dev").await.is_ok());
+        let err = client.get_kubeconfig("missing").await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref(),
+            Some(ClusterError::NotFound { name }) if name == "missing"
+        ));
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn connect_fails_without_a_daemon() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(DaemonClient::connect(&dir.path().join("kina.sock"))
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,232 @@
+//! Per-cluster lifecycle event log behind `kina status --history`.
+//!
+//! Events are appended as JSON lines to `<data_dir>/events/<cluster>.jsonl`,
+//! a directory of their own so a cluster name can't collide with the other
+//! entries of the data directory (e.g. `templates`). The file is kept when
+//! the cluster is deleted, so a recreated cluster's history continues from
+//! the old one, and follows the cluster on rename.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// Directory of the per-cluster event logs inside the data directory
+pub const EVENTS_DIR: &str = "events";
+
+/// Lifecycle events kina records
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EventType {
+    CreateStart,
+    CreateSuccess,
+    CreateFailure,
+    Delete,
+    Pause,
+    Resume,
+    ImageLoad,
+    AddonInstall,
+}
+
+impl std::fmt::Display for EventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = serde_json::to_value(self)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default();
+        f.write_str(&name)
+    }
+}
+
+/// One line of the event log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClusterEvent {
+    pub timestamp: DateTime<Utc>,
+    pub event_type: EventType,
+    /// The kina command that caused the event, e.g. `kina create`
+    pub actor: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+}
+
+/// Event log of `cluster` under `data_dir`
+pub fn events_path(data_dir: &Path, cluster: &str) -> PathBuf {
+    data_dir.join(EVENTS_DIR).join(format!("{}.jsonl", cluster))
+}
+
+/// Append `event` to the cluster's log
+pub fn append_event(data_dir: &Path, cluster: &str, event: &ClusterEvent) -> Result<()> {
+    let path = events_path(data_dir, cluster);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    writeln!(file, "{}", serde_json::to_string(event)?)
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Record an event happening now. The log is informational, so a failure to
+/// write it is logged rather than failing the command.
+pub fn record_event(
+    data_dir: &Path,
+    cluster: &str,
+    event_type: EventType,
+    actor: &str,
+    details: Option<String>,
+) {
+    let event = ClusterEvent {
+        timestamp: Utc::now(),
+        event_type,
+        actor: actor.to_string(),
+        details,
+    };
+    if let Err(e) = append_event(data_dir, cluster, &event) {
+        warn!(
+            "Could not record {} event for '{}': {:#}",
+            event_type, cluster, e
+        );
+    }
+}
+
+/// Move the event log of `old` to `new` (`kina rename`). When `new` already
+/// has a log, e.g. from a deleted cluster of that name, `old`'s events are
+/// appended to it.
+pub fn move_events(data_dir: &Path, old: &str, new: &str) -> Result<()> {
+    let from = events_path(data_dir, old);
+    let contents = match std::fs::read_to_string(&from) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read {}", from.display())),
+    };
+    let to = events_path(data_dir, new);
+    if let Some(dir) = to.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&to)
+        .with_context(|| format!("Failed to open {}", to.display()))?;
+    file.write_all(contents.as_bytes())
+        .with_context(|| format!("Failed to write {}", to.display()))?;
+    std::fs::remove_file(&from).with_context(|| format!("Failed to remove {}", from.display()))
+}
+
+/// The cluster's events, newest first. Unreadable lines are skipped; a
+/// cluster without a log has no events.
+pub fn read_events(data_dir: &Path, cluster: &str) -> Result<Vec<ClusterEvent>> {
+    let path = events_path(data_dir, cluster);
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+    };
+    let mut events: Vec<ClusterEvent> = contents
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    events.sort_by_key(|e| std::cmp::Reverse(e.timestamp));
+    Ok(events)
+}
+
+/// `kina status --history` table
+pub fn render_events_table(events: &[ClusterEvent]) -> String {
+    let mut out = format!(
+        "{:<24} {:<16} {:<20} {}\n",
+        "TIMESTAMP", "EVENT", "ACTOR", "DETAILS"
+    );
+    for event in events {
+        out.push_str(&format!(
+            "{:<24} {:<16} {:<20} {}\n",
+            event.timestamp.format("%Y-%m-%d %H:%M:%S UTC"),
+            event.event_type,
+            event.actor,
+            event.details.as_deref().unwrap_or("")
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_are_appended_and_read_newest_first() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(read_events(dir.path(), "dev").unwrap().is_empty());
+
+        let at = |secs| DateTime::<Utc>::from_timestamp(secs, 0).unwrap();
+        for (secs, event_type, details) in [
+            (100, EventType::CreateStart, None),
+            (200, EventType::CreateSuccess, None),
+            (300, EventType::ImageLoad, Some("app:dev".to_string())),
+        ] {
+            let event = ClusterEvent {
+                timestamp: at(secs),
+                event_type,
+                actor: "kina create".to_string(),
+                details,
+            };
+            append_event(dir.path(), "dev", &event).unwrap();
+        }
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(events_path(dir.path(), "dev"))
+            .and_then(|mut f| writeln!(f, "not json"))
+            .unwrap();
+
+        let events = read_events(dir.path(), "dev").unwrap();
+        let types: Vec<EventType> = events.iter().map(|e| e.event_type).collect();
+        assert_eq!(
+            types,
+            [
+                EventType::ImageLoad,
+                EventType::CreateSuccess,
+                EventType::CreateStart
+            ]
+        );
+
+        let table = render_events_table(&events);
+        let first = table.lines().nth(1).unwrap();
+        assert!(first.starts_with("1970-01-01 00:05:00 UTC"), "{}", table);
+        assert!(first.contains("image-load") && first.ends_with("app:dev"));
+    }
+
+    #[test]
+    fn move_events_follows_a_rename() {
+        let dir = tempfile::tempdir().unwrap();
+        let event = |event_type| ClusterEvent {
+            timestamp: Utc::now(),
+            event_type,
+            actor: "kina create".to_string(),
+            details: None,
+        };
+        append_event(dir.path(), "old", &event(EventType::CreateSuccess)).unwrap();
+        append_event(dir.path(), "new", &event(EventType::Delete)).unwrap();
+
+        move_events(dir.path(), "old", "new").unwrap();
+        assert!(!events_path(dir.path(), "old").exists());
+        assert_eq!(read_events(dir.path(), "new").unwrap().len(), 2);
+
+        // Nothing to move is not an error
+        move_events(dir.path(), "missing", "new").unwrap();
+    }
+
+    #[test]
+    fn events_path_stays_out_of_other_data_dir_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(
+            events_path(dir.path(), "templates"),
+            dir.path().join("events").join("templates.jsonl")
+        );
+    }
+}
@@ -0,0 +1,482 @@
+//! Kubernetes version → `kindest/node` image resolution for `kina create --kubernetes-version`.
+//!
+//! - A full version (`v1.31.0` / `1.31.0`) resolves to the image a
+//!   `kubernetes-sigs/kind` GitHub release publishes for it, digest-pinned as the
+//!   release notes list it, or to the plain `kindest/node:v1.31.0` tag when no
+//!   release lists that version.
+//! - A minor version (`v1.31` / `1.31`) resolves to the newest patch image published
+//!   in a kind release, digest-pinned the same way.
+//!
+//! Release lookups are cached in `~/.local/share/kina/image-map.json` for
+//! `IMAGE_MAP_TTL_SECS`.
+//!
+//! `kina image list` reads the repository's tags from Docker Hub, cached in
+//! `~/.local/share/kina/image-cache.json` for `TAG_CACHE_TTL_SECS`.
+//!
+//! `kina image pull` records what it pulled in `~/.local/share/kina/pulled-images.json`.
+//!
+//! Everything except the two network lookups and the cache load/save helpers is pure.
+
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
+
+/// Repository the node images are published under.
+pub const NODE_IMAGE_REPOSITORY: &str = "kindest/node";
+
+/// GitHub releases endpoint for kind; release notes list the node images built for each release.
+pub const KIND_RELEASES_URL: &str =
+    "https://api.github.com/repos/kubernetes-sigs/kind/releases?per_page=50";
+
+/// How long a cached image map is trusted before the releases API is queried again.
+pub const IMAGE_MAP_TTL_SECS: i64 = 24 * 60 * 60;
+
+/// Docker Hub tags endpoint for the node image repository (paginated via `next`).
+pub const DOCKER_HUB_TAGS_URL: &str =
+    "https://hub.docker.com/v2/repositories/kindest/node/tags?page_size=100";
+
+/// How long the cached Docker Hub tag list is trusted.
+pub const TAG_CACHE_TTL_SECS: i64 = 60 * 60;
+
+/// A parsed `--kubernetes-version` value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VersionSpec {
+    /// `vMAJOR.MINOR.PATCH`, normalised with a leading `v`
+    Exact(String),
+    /// `vMAJOR.MINOR` — resolve to the newest published patch
+    Minor(u32, u32),
+}
+
+/// Parse `v1.31.0`, `1.31.0`, `v1.31` or `1.31`.
+pub fn parse_version_spec(raw: &str) -> Result<VersionSpec> {
+    let trimmed = raw.trim();
+    let bare = trimmed.strip_prefix('v').unwrap_or(trimmed);
+    let parts: Vec<&str> = bare.split('.').collect();
+    let numbers: Option<Vec<u32>> = parts.iter().map(|p| p.parse::<u32>().ok()).collect();
+
+    match numbers.as_deref() {
+        Some([major, minor, patch]) => Ok(VersionSpec::Exact(format!(
+            "v{}.{}.{}",
+            major, minor, patch
+        ))),
+        Some([major, minor]) => Ok(VersionSpec::Minor(*major, *minor)),
+        _ => Err(anyhow::anyhow!(
+            "Invalid Kubernetes version '{}': expected vMAJOR.MINOR.PATCH or vMAJOR.MINOR",
+            raw
+        )),
+    }
+}
+
+/// Node image tag for an exact version: `kindest/node:<version>`.
+pub fn image_for_version(version: &str) -> String {
+    format!("{}:{}", NODE_IMAGE_REPOSITORY, version)
+}
+
+/// Extract `version → image` pairs from kind release notes.
+///
+/// Matches every `kindest/node:vX.Y.Z` reference, keeping an `@sha256:...` digest
+/// when present. The first reference to a version wins.
+pub fn parse_release_images(release_notes: &str) -> BTreeMap<String, String> {
+    let mut images = BTreeMap::new();
+    let prefix = format!("{}:", NODE_IMAGE_REPOSITORY);
+
+    for (start, _) in release_notes.match_indices(&prefix) {
+        let reference: String = release_notes[start..]
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '/' | ':' | '.' | '@' | '-'))
+            .collect();
+        let reference = reference.trim_end_matches('.');
+        let version = reference[prefix.len()..]
+            .split('@')
+            .next()
+            .unwrap_or_default();
+
+        if matches!(parse_version_spec(version), Ok(VersionSpec::Exact(ref v)) if v == version) {
+            images
+                .entry(version.to_string())
+                .or_insert_with(|| reference.to_string());
+        }
+    }
+
+    images
+}
+
+/// Newest patch image for `major.minor` in an image map.
+pub fn latest_for_minor(
+    images: &BTreeMap<String, String>,
+    major: u32,
+    minor: u32,
+) -> Option<String> {
+    images
+        .iter()
+        .filter_map(|(version, image)| match parse_version_spec(version) {
+            Ok(VersionSpec::Exact(_)) => {
+                let nums: Vec<u32> = version[1..]
+                    .split('.')
+                    .filter_map(|p| p.parse().ok())
+                    .collect();
+                (nums[0] == major && nums[1] == minor).then(|| (nums[2], image.clone()))
+            }
+            _ => None,
+        })
+        .max_by_key(|(patch, _)| *patch)
+        .map(|(_, image)| image)
+}
+
+/// A JSON cache file of a network lookup, trusted for `TTL_SECS` after
+/// `fetched_at`.
+pub trait TtlCache: Serialize + DeserializeOwned {
+    /// How long the cache is trusted
+    const TTL_SECS: i64;
+
+    /// Unix timestamp (seconds) of the lookup that produced the cache
+    fn fetched_at(&self) -> i64;
+
+    /// True while the cache is younger than `TTL_SECS`.
+    fn is_fresh(&self, now: i64) -> bool {
+        now - self.fetched_at() < Self::TTL_SECS
+    }
+
+    /// Load a cache file; a missing or unreadable file yields `None`.
+    fn load(path: &Path) -> Option<Self> {
+        let contents = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    /// Write the cache file, creating its parent directory.
+    fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+/// The cache at `path` while it is fresh, otherwise the cache `fetch` builds
+/// for the current time (saved back to `path`), falling back to the stale
+/// cache when `fetch` fails. `what` names the cache in log messages.
+async fn load_or_fetch<C, F, Fut>(path: &Path, what: &str, fetch: F) -> Result<C>
+where
+    C: TtlCache,
+    F: FnOnce(i64) -> Fut,
+    Fut: Future<Output = Result<C>>,
+{
+    let now = chrono::Utc::now().timestamp();
+    let cached = match C::load(path) {
+        Some(cache) if cache.is_fresh(now) => {
+            debug!("Using cached {} from {}", what, path.display());
+            return Ok(cache);
+        }
+        cached => cached,
+    };
+
+    match fetch(now).await {
+        Ok(cache) => {
+            if let Err(e) = cache.save(path) {
+                warn!("Failed to cache {}: {}", what, e);
+            }
+            Ok(cache)
+        }
+        Err(e) => match cached {
+            Some(stale) => {
+                warn!("{}; using cached {} from {}", e, what, path.display());
+                Ok(stale)
+            }
+            None => Err(e),
+        },
+    }
+}
+
+/// On-disk cache of the image map built from kind releases.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImageMapCache {
+    /// Unix timestamp (seconds) of the releases query that produced `images`
+    pub fetched_at: i64,
+    /// `vX.Y.Z` → image reference
+    pub images: BTreeMap<String, String>,
+}
+
+impl TtlCache for ImageMapCache {
+    const TTL_SECS: i64 = IMAGE_MAP_TTL_SECS;
+
+    fn fetched_at(&self) -> i64 {
+        self.fetched_at
+    }
+}
+
+/// `~/.local/share/kina`, where image lookups are cached.
+pub fn cache_dir() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".local")
+        .join("share")
+        .join("kina")
+}
+
+/// `~/.local/share/kina/image-map.json`
+pub fn image_map_path() -> PathBuf {
+    cache_dir().join("image-map.json")
+}
+
+/// `~/.local/share/kina/image-cache.json`
+pub fn tag_cache_path() -> PathBuf {
+    cache_dir().join("image-cache.json")
+}
+
+/// `~/.local/share/kina/pulled-images.json`
+pub fn pulled_images_path() -> PathBuf {
+    cache_dir().join("pulled-images.json")
+}
+
+/// An image pre-pulled with `kina image pull`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PulledImage {
+    /// Image reference as given on the command line
+    pub image: String,
+    /// `--platform` value, `None` for the host platform
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub platform: Option<String>,
+    /// RFC 3339 timestamp of the last successful pull
+    pub pulled_at: String,
+}
+
+/// Normalise a `--platform` value: a bare architecture (`arm64`, `amd64`)
+/// becomes `linux/<arch>`; `os/arch` values are passed through.
+pub fn normalize_platform(raw: &str) -> Result<String> {
+    let raw = raw.trim();
+    let platform = if raw.contains('/') {
+        raw.to_string()
+    } else {
+        format!("linux/{}", raw)
+    };
+    match platform.split_once('/') {
+        Some((os, arch)) if !os.is_empty() && !arch.is_empty() => Ok(platform),
+        _ => Err(anyhow::anyhow!(
+            "Invalid platform '{}': expected <arch> or <os>/<arch>",
+            raw
+        )),
+    }
+}
+
+/// Insert or refresh the record for `image` on `platform`.
+pub fn record_pulled_image(
+    records: &mut Vec<PulledImage>,
+    image: &str,
+    platform: Option<&str>,
+    pulled_at: &str,
+) {
+    match records
+        .iter_mut()
+        .find(|r| r.image == image && r.platform.as_deref() == platform)
+    {
+        Some(record) => record.pulled_at = pulled_at.to_string(),
+        None => records.push(PulledImage {
+            image: image.to_string(),
+            platform: platform.map(str::to_string),
+            pulled_at: pulled_at.to_string(),
+        }),
+    }
+}
+
+/// Load the pulled-image records; a missing or unreadable file yields an empty list.
+pub fn load_pulled_images(path: &Path) -> Vec<PulledImage> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Write the pulled-image records, creating the parent directory.
+pub fn save_pulled_images(path: &Path, records: &[PulledImage]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    std::fs::write(path, serde_json::to_string_pretty(records)?)
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Sort key for a `vX.Y.Z[-pre]` tag: releases sort above their pre-releases.
+/// `None` for tags that aren't versions (`latest`, `sha256-...`).
+pub fn tag_sort_key(tag: &str) -> Option<(u32, u32, u32, bool, String)> {
+    let bare = tag.strip_prefix('v')?;
+    let (core, pre) = match bare.split_once('-') {
+        Some((core, pre)) => (core, Some(pre)),
+        None => (bare, None),
+    };
+    let nums: Vec<u32> = core
+        .split('.')
+        .map(|p| p.parse().ok())
+        .collect::<Option<_>>()?;
+    match nums.as_slice() {
+        [major, minor, patch] => Some((
+            *major,
+            *minor,
+            *patch,
+            pre.is_none(),
+            pre.unwrap_or_default().to_string(),
+        )),
+        _ => None,
+    }
+}
+
+/// Version tags to show, newest first. Without `include_prereleases` only
+/// `vX.Y.Z` release tags are kept; non-version tags are always dropped.
+pub fn filter_and_sort_tags(tags: &[String], include_prereleases: bool) -> Vec<String> {
+    let mut keyed: Vec<_> = tags
+        .iter()
+        .filter_map(|tag| tag_sort_key(tag).map(|key| (key, tag.clone())))
+        .filter(|(key, _)| include_prereleases || key.3)
+        .collect();
+    keyed.sort_by(|a, b| b.0.cmp(&a.0));
+    keyed.dedup_by(|a, b| a.1 == b.1);
+    keyed.into_iter().map(|(_, tag)| tag).collect()
+}
+
+/// On-disk cache of the Docker Hub tag list.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TagListCache {
+    /// Unix timestamp (seconds) of the Docker Hub query that produced `tags`
+    pub fetched_at: i64,
+    /// Every tag in the repository, unfiltered
+    pub tags: Vec<String>,
+}
+
+impl TtlCache for TagListCache {
+    const TTL_SECS: i64 = TAG_CACHE_TTL_SECS;
+
+    fn fetched_at(&self) -> i64 {
+        self.fetched_at
+    }
+}
+
+/// Walk every page of the Docker Hub tags API.
+async fn fetch_docker_hub_tags() -> Result<Vec<String>> {
+    let client = reqwest::Client::new();
+    let mut tags = Vec::new();
+    let mut next = Some(DOCKER_HUB_TAGS_URL.to_string());
+
+    while let Some(url) = next {
+        debug!("Querying {}", url);
+        let page: serde_json::Value = client
+            .get(&url)
+            .header("User-Agent", "kina")
+            .send()
+            .await
+            .context("Failed to query Docker Hub tags")?
+            .error_for_status()
+            .context("Docker Hub tags API returned an error")?
+            .json()
+            .await
+            .context("Failed to parse Docker Hub tags response")?;
+
+        if let Some(results) = page["results"].as_array() {
+            tags.extend(
+                results
+                    .iter()
+                    .filter_map(|r| r["name"].as_str().map(str::to_string)),
+            );
+        }
+        next = page["next"].as_str().map(str::to_string);
+    }
+
+    Ok(tags)
+}
+
+/// Every `kindest/node` tag, from the cache when it is under an hour old.
+pub async fn list_node_image_tags() -> Result<Vec<String>> {
+    let cache: TagListCache = load_or_fetch(&tag_cache_path(), "tag list", |now| async move {
+        Ok(TagListCache {
+            fetched_at: now,
+            tags: fetch_docker_hub_tags().await?,
+        })
+    })
+    .await?;
+    Ok(cache.tags)
+}
+
+/// Query the kind releases API and build an image map from every release's notes.
+async fn fetch_release_images() -> Result<BTreeMap<String, String>> {
+    debug!("Querying {}", KIND_RELEASES_URL);
+    let releases: Vec<serde_json::Value> = reqwest::Client::new()
+        .get(KIND_RELEASES_URL)
+        .header("User-Agent", "kina")
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await
+        .context("Failed to query kind releases")?
+        .error_for_status()
+        .context("kind releases API returned an error")?
+        .json()
+        .await
+        .context("Failed to parse kind releases response")?;
+
+    // Releases are newest first; earlier entries win so the newest digest is kept.
+    let mut images = BTreeMap::new();
+    for release in &releases {
+        let notes = release["body"].as_str().unwrap_or_default();
+        for (version, image) in parse_release_images(notes) {
+            images.entry(version).or_insert(image);
+        }
+    }
+    Ok(images)
+}
+
+/// Find an image in the kind release image map with `find`: in the cache
+/// while it is fresh, so a version missing from it is not looked up again
+/// until it expires, otherwise in a fresh releases query, falling back to a
+/// stale cache when the query fails.
+async fn find_release_image<F>(find: F) -> Result<Option<String>>
+where
+    F: Fn(&BTreeMap<String, String>) -> Option<String>,
+{
+    let cache: ImageMapCache = load_or_fetch(&image_map_path(), "image map", |now| async move {
+        Ok(ImageMapCache {
+            fetched_at: now,
+            images: fetch_release_images().await?,
+        })
+    })
+    .await?;
+    Ok(find(&cache.images))
+}
+
+/// Resolve a `--kubernetes-version` value to a node image.
+pub async fn resolve_node_image(raw_version: &str) -> Result<String> {
+    let (major, minor) = match parse_version_spec(raw_version)? {
+        VersionSpec::Exact(version) => {
+            let image = match find_release_image(|images| images.get(&version).cloned()).await {
+                Ok(Some(image)) => image,
+                Ok(None) => {
+                    debug!("No kind release lists {}; using the plain tag", version);
+                    image_for_version(&version)
+                }
+                Err(e) => {
+                    warn!("{:#}; using the plain tag for {}", e, version);
+                    image_for_version(&version)
+                }
+            };
+            info!("Resolved Kubernetes {} to {}", version, image);
+            return Ok(image);
+        }
+        VersionSpec::Minor(major, minor) => (major, minor),
+    };
+
+    let image = find_release_image(|images| latest_for_minor(images, major, minor))
+        .await?
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "No kind release publishes a node image for Kubernetes v{}.{}; \
+                 pass a full version or --image instead",
+                major,
+                minor
+            )
+        })?;
+    info!("Resolved Kubernetes v{}.{} to {}", major, minor, image);
+    Ok(image)
+}
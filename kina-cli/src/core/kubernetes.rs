@@ -1,10 +1,549 @@
 #![allow(dead_code)]
 use anyhow::{Context, Result};
+use std::collections::BTreeMap;
 use std::process::Stdio;
+use std::time::Duration;
+use tabled::Tabled;
 use tokio::process::Command;
 use tracing::{debug, info, warn};
 
 use crate::config::Config;
+use crate::core::types::{NodeInfo, NodeRole};
+use crate::errors::{ClusterError, KubernetesError};
+
+/// A Kubernetes event, reduced to the fields `kina get events` shows
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClusterEvent {
+    /// Event type: `Normal` or `Warning`
+    pub event_type: String,
+    pub reason: String,
+    pub message: String,
+    pub namespace: String,
+    /// Involved object as `kind/name`
+    pub object: String,
+    /// RFC 3339 time the event was last seen (empty when unknown)
+    pub last_timestamp: String,
+}
+
+/// Parse `kubectl get events -o json` output, oldest first.
+///
+/// `lastTimestamp` is preferred; events recorded through the events.k8s.io API
+/// only carry `eventTime`, and some only `firstTimestamp`.
+pub fn parse_events(json: &str) -> Result<Vec<ClusterEvent>> {
+    let list: serde_json::Value =
+        serde_json::from_str(json).context("Failed to parse kubectl events JSON")?;
+    let field = |value: &serde_json::Value, pointer: &str| {
+        value
+            .pointer(pointer)
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+
+    let mut events: Vec<ClusterEvent> = list["items"]
+        .as_array()
+        .map(|items| items.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .map(|item| {
+            let last_timestamp = ["/lastTimestamp", "/eventTime", "/firstTimestamp"]
+                .iter()
+                .map(|pointer| field(item, pointer))
+                .find(|ts| !ts.is_empty())
+                .unwrap_or_default();
+            ClusterEvent {
+                event_type: field(item, "/type"),
+                reason: field(item, "/reason"),
+                message: field(item, "/message").trim().to_string(),
+                namespace: field(item, "/metadata/namespace"),
+                object: format!(
+                    "{}/{}",
+                    field(item, "/involvedObject/kind").to_lowercase(),
+                    field(item, "/involvedObject/name")
+                ),
+                last_timestamp,
+            }
+        })
+        .collect();
+
+    // RFC 3339 UTC timestamps sort lexicographically; events without one go first.
+    events.sort_by(|a, b| a.last_timestamp.cmp(&b.last_timestamp));
+    Ok(events)
+}
+
+/// One-line rendering of an event: ⚠️ for Warning, ℹ️ for everything else.
+pub fn format_event(event: &ClusterEvent) -> String {
+    let icon = if event.event_type == "Warning" {
+        "⚠️"
+    } else {
+        "ℹ️"
+    };
+    let timestamp = if event.last_timestamp.is_empty() {
+        "<unknown>"
+    } else {
+        event.last_timestamp.as_str()
+    };
+    format!(
+        "{} {} {}/{} {}: {}",
+        icon, timestamp, event.namespace, event.object, event.reason, event.message
+    )
+}
+
+/// One row of `kina get pods --format table`
+#[derive(Debug, Clone, PartialEq, Eq, Tabled)]
+pub struct PodSummary {
+    #[tabled(rename = "NAMESPACE")]
+    pub namespace: String,
+    #[tabled(rename = "NAME")]
+    pub name: String,
+    /// Ready containers as `ready/total`
+    #[tabled(rename = "READY")]
+    pub ready_containers: String,
+    /// Phase, or the more specific container state (e.g. `CrashLoopBackOff`)
+    #[tabled(rename = "STATUS", format("{}", self.status_cell()))]
+    pub status: String,
+    #[tabled(rename = "RESTARTS")]
+    pub restarts: u32,
+    /// Running with every container ready, or Succeeded
+    #[tabled(skip)]
+    pub ready: bool,
+}
+
+impl PodSummary {
+    /// STATUS column text; pods that aren't ready are marked with ❌
+    fn status_cell(&self) -> String {
+        if self.ready {
+            self.status.clone()
+        } else {
+            format!("❌ {}", self.status)
+        }
+    }
+}
+
+/// Parse `kubectl get pods -o json` output into table rows, in kubectl's order.
+///
+/// STATUS mirrors kubectl: `Terminating` for pods being deleted, otherwise the
+/// first waiting/terminated container reason, otherwise the pod phase.
+pub fn parse_pods(json: &str) -> Result<Vec<PodSummary>> {
+    let list: serde_json::Value =
+        serde_json::from_str(json).context("Failed to parse kubectl pods JSON")?;
+    let empty = Vec::new();
+
+    let pods = list["items"]
+        .as_array()
+        .unwrap_or(&empty)
+        .iter()
+        .map(|item| {
+            let statuses = item["status"]["containerStatuses"]
+                .as_array()
+                .unwrap_or(&empty);
+            let total = item["spec"]["containers"]
+                .as_array()
+                .map(|c| c.len())
+                .unwrap_or(statuses.len());
+            let ready_count = statuses
+                .iter()
+                .filter(|c| c["ready"].as_bool().unwrap_or(false))
+                .count();
+            let restarts = statuses
+                .iter()
+                .filter_map(|c| c["restartCount"].as_u64())
+                .sum::<u64>() as u32;
+
+            let phase = item["status"]["phase"]
+                .as_str()
+                .unwrap_or("Unknown")
+                .to_string();
+            let container_reason = statuses.iter().find_map(|c| {
+                ["waiting", "terminated"]
+                    .iter()
+                    .find_map(|state| c["state"][state]["reason"].as_str())
+            });
+            let status = if !item["metadata"]["deletionTimestamp"].is_null() {
+                "Terminating".to_string()
+            } else if phase == "Succeeded" {
+                phase.clone()
+            } else {
+                container_reason
+                    .map(str::to_string)
+                    .unwrap_or(phase.clone())
+            };
+
+            let ready = phase == "Succeeded"
+                || (phase == "Running" && status == "Running" && ready_count == total);
+
+            PodSummary {
+                namespace: item["metadata"]["namespace"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+                name: item["metadata"]["name"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+                ready_containers: format!("{}/{}", ready_count, total),
+                status,
+                restarts,
+                ready,
+            }
+        })
+        .collect();
+
+    Ok(pods)
+}
+
+/// Render pods as a column-aligned table
+pub fn render_pod_table(pods: &[PodSummary]) -> String {
+    tabled::Table::new(pods)
+        .with(tabled::settings::Style::blank())
+        .to_string()
+}
+
+/// What `kina delete-resource` should delete
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeleteResourceOptions {
+    /// Resource type, e.g. `pods` or `deployment.apps`
+    pub resource_type: String,
+    pub name: Option<String>,
+    pub namespace: Option<String>,
+    /// Delete every resource of the type in the namespace
+    pub all: bool,
+    pub label_selector: Option<String>,
+    pub grace_period: Option<i64>,
+    pub force: bool,
+    /// `background`, `foreground` or `orphan`
+    pub cascade: Option<String>,
+}
+
+/// Build the `kubectl delete` arguments (after `--kubeconfig <path>`).
+pub fn delete_resource_args(options: &DeleteResourceOptions) -> Vec<String> {
+    let mut args = vec!["delete".to_string(), options.resource_type.clone()];
+    if let Some(name) = &options.name {
+        args.push(name.clone());
+    }
+    if let Some(ns) = &options.namespace {
+        args.extend(["-n".to_string(), ns.clone()]);
+    }
+    if options.all {
+        args.push("--all".to_string());
+    }
+    if let Some(selector) = &options.label_selector {
+        args.extend(["-l".to_string(), selector.clone()]);
+    }
+    if let Some(seconds) = options.grace_period {
+        args.push(format!("--grace-period={}", seconds));
+    }
+    if options.force {
+        args.push("--force".to_string());
+    }
+    if let Some(cascade) = &options.cascade {
+        args.push(format!("--cascade={}", cascade));
+    }
+    args
+}
+
+/// Build the `kubectl wait` arguments (after `--kubeconfig <path>`).
+///
+/// `condition` is what follows `--for=`: `condition=<cond>`,
+/// `jsonpath=<path>=<value>` or `delete`. `namespace` None means all namespaces
+/// when `all_namespaces` is set, kubectl's default namespace otherwise.
+pub fn wait_args(
+    resource: &str,
+    condition: &str,
+    namespace: Option<&str>,
+    all_namespaces: bool,
+    timeout: &str,
+) -> Result<Vec<String>> {
+    let valid = condition == "delete"
+        || condition
+            .strip_prefix("condition=")
+            .is_some_and(|c| !c.is_empty())
+        || condition
+            .strip_prefix("jsonpath=")
+            .is_some_and(|j| j.contains('='));
+    if !valid {
+        return Err(anyhow::anyhow!(
+            "Invalid wait condition '{}': expected condition=<cond>, jsonpath=<path>=<value> or delete",
+            condition
+        ));
+    }
+
+    let mut args = vec![
+        "wait".to_string(),
+        resource.to_string(),
+        format!("--for={}", condition),
+        format!("--timeout={}", timeout),
+    ];
+    if all_namespaces {
+        args.push("--all-namespaces".to_string());
+    } else if let Some(ns) = namespace {
+        args.extend(["-n".to_string(), ns.to_string()]);
+    }
+    Ok(args)
+}
+
+/// Build the `kubectl rollout status` arguments (after `--kubeconfig <path>`)
+/// for `workload`, e.g. `deployment/web`, with the timeout in whole seconds.
+pub fn rollout_status_args(workload: &str, namespace: &str, timeout: Duration) -> Vec<String> {
+    vec![
+        "rollout".to_string(),
+        "status".to_string(),
+        workload.to_string(),
+        "-n".to_string(),
+        namespace.to_string(),
+        format!("--timeout={}s", timeout.as_secs()),
+    ]
+}
+
+/// Build the `kubectl port-forward` arguments (after `--kubeconfig <path>`)
+/// forwarding each `(local, remote)` pair to `resource`, e.g. `svc/web`.
+pub fn port_forward_args(
+    resource: &str,
+    ports: &[(u16, u16)],
+    namespace: Option<&str>,
+) -> Vec<String> {
+    let mut args = vec!["port-forward".to_string(), resource.to_string()];
+    args.extend(
+        ports
+            .iter()
+            .map(|(local, remote)| format!("{}:{}", local, remote)),
+    );
+    if let Some(ns) = namespace {
+        args.extend(["-n".to_string(), ns.to_string()]);
+    }
+    args
+}
+
+/// Build the `kubectl label node` arguments (after `--kubeconfig <path>`) that
+/// set `labels` on `node`, replacing any existing values.
+pub fn label_node_args(node: &str, labels: &BTreeMap<String, String>) -> Vec<String> {
+    let mut args = vec!["label".to_string(), "node".to_string(), node.to_string()];
+    args.extend(
+        labels
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value)),
+    );
+    args.push("--overwrite".to_string());
+    args
+}
+
+/// Set the `nodePort` of each named port of the Service `service` in a
+/// (possibly multi-document) manifest. Ports not listed keep their values.
+pub fn set_service_node_ports(
+    manifest: &str,
+    service: &str,
+    node_ports: &[(&str, u16)],
+) -> Result<String> {
+    use serde::Deserialize;
+
+    let mut docs = Vec::new();
+    let mut found = false;
+    for doc in serde_yaml::Deserializer::from_str(manifest) {
+        let mut doc = serde_yaml::Value::deserialize(doc).context("Failed to parse manifest")?;
+        if doc.is_null() {
+            continue;
+        }
+        if doc["kind"].as_str() == Some("Service")
+            && doc["metadata"]["name"].as_str() == Some(service)
+        {
+            found = true;
+            if let Some(ports) = doc["spec"]["ports"].as_sequence_mut() {
+                for port in ports {
+                    let name = port["name"].as_str().map(str::to_string);
+                    if let Some((_, node_port)) =
+                        node_ports.iter().find(|(n, _)| Some(*n) == name.as_deref())
+                    {
+                        port["nodePort"] = serde_yaml::Value::from(*node_port);
+                    }
+                }
+            }
+        }
+        docs.push(serde_yaml::to_string(&doc).context("Failed to serialize manifest")?);
+    }
+    if !found {
+        return Err(anyhow::anyhow!("No Service '{}' in manifest", service));
+    }
+    Ok(docs.join("---\n"))
+}
+
+/// Build the `kubectl drain` arguments (after `--kubeconfig <path>`).
+/// DaemonSet pods are skipped and emptyDir data is discarded, as a drain
+/// ahead of node maintenance would otherwise refuse to proceed.
+pub fn drain_args(node: &str, grace_period: Option<i64>, timeout: &str) -> Vec<String> {
+    let mut args = vec![
+        "drain".to_string(),
+        node.to_string(),
+        "--ignore-daemonsets".to_string(),
+        "--delete-emptydir-data".to_string(),
+        format!("--timeout={}", timeout),
+    ];
+    if let Some(seconds) = grace_period {
+        args.push(format!("--grace-period={}", seconds));
+    }
+    args
+}
+
+/// Pods evicted by a drain, as `<namespace>/<name>` from kubectl's
+/// `evicting pod ...` lines, in order and without duplicates.
+pub fn parse_evicted_pods(output: &str) -> Vec<String> {
+    let mut pods: Vec<String> = Vec::new();
+    for pod in output
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("evicting pod "))
+    {
+        if !pods.iter().any(|p| p == pod) {
+            pods.push(pod.to_string());
+        }
+    }
+    pods
+}
+
+/// Signer of kubelet serving certificates, requested because kubeadm sets
+/// `serverTLSBootstrap: true`
+pub const KUBELET_SERVING_SIGNER: &str = "kubernetes.io/kubelet-serving";
+
+/// `kubectl get csr -o jsonpath=...` template printing one
+/// `<name> <signer> [<condition type>...]` line per CSR
+pub const CSR_JSONPATH: &str = r#"{range .items[*]}{.metadata.name}{" "}{.spec.signerName}{" "}{.status.conditions[*].type}{"\n"}{end}"#;
+
+/// Names of the pending CSRs in [`CSR_JSONPATH`] output: those with no
+/// `Approved`, `Denied` or `Failed` condition. With `signer`, only CSRs for
+/// that signer are returned.
+pub fn parse_pending_csrs(output: &str, signer: Option<&str>) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let name = fields.next()?;
+            let csr_signer = fields.next().unwrap_or("");
+            if signer.is_some_and(|s| s != csr_signer) {
+                return None;
+            }
+            let decided = fields.any(|c| matches!(c, "Approved" | "Denied" | "Failed"));
+            (!decided).then(|| name.to_string())
+        })
+        .collect()
+}
+
+/// Parse `kubectl get nodes -o json` output into [`NodeInfo`]s carrying the live
+/// Kubernetes view: STATUS as kubectl prints it (`Ready`, `NotReady`, `Unknown`,
+/// plus `,SchedulingDisabled` when cordoned) and the kubelet version.
+pub fn parse_live_nodes(json: &str) -> Result<Vec<NodeInfo>> {
+    let list: serde_json::Value =
+        serde_json::from_str(json).context("Failed to parse kubectl nodes JSON")?;
+    let empty = Vec::new();
+
+    let nodes = list["items"]
+        .as_array()
+        .unwrap_or(&empty)
+        .iter()
+        .map(|item| {
+            let ready = item["status"]["conditions"]
+                .as_array()
+                .unwrap_or(&empty)
+                .iter()
+                .find(|c| c["type"] == "Ready")
+                .and_then(|c| c["status"].as_str());
+            let mut status = match ready {
+                Some("True") => "Ready".to_string(),
+                Some("False") => "NotReady".to_string(),
+                _ => "Unknown".to_string(),
+            };
+            if item["spec"]["unschedulable"].as_bool().unwrap_or(false) {
+                status.push_str(",SchedulingDisabled");
+            }
+
+            let is_control_plane = item["metadata"]["labels"]
+                .get("node-role.kubernetes.io/control-plane")
+                .is_some();
+            let ip_address = item["status"]["addresses"]
+                .as_array()
+                .unwrap_or(&empty)
+                .iter()
+                .find(|a| a["type"] == "InternalIP")
+                .and_then(|a| a["address"].as_str())
+                .map(str::to_string);
+
+            NodeInfo {
+                name: item["metadata"]["name"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+                role: if is_control_plane {
+                    NodeRole::ControlPlane
+                } else {
+                    NodeRole::Worker
+                },
+                status,
+                version: item["status"]["nodeInfo"]["kubeletVersion"]
+                    .as_str()
+                    .unwrap_or("unknown")
+                    .to_string(),
+                container_id: None,
+                ip_address,
+            }
+        })
+        .collect();
+
+    Ok(nodes)
+}
+
+/// Overlay live Kubernetes status and version onto container-derived nodes,
+/// matched by name (node containers use their name as the Kubernetes hostname).
+/// Nodes the API doesn't know about keep their container state.
+pub fn merge_live_node_info(nodes: &mut [NodeInfo], live: &[NodeInfo]) {
+    for node in nodes.iter_mut() {
+        if let Some(live_node) = live.iter().find(|l| l.name == node.name) {
+            node.status = live_node.status.clone();
+            node.version = live_node.version.clone();
+        }
+    }
+}
+
+/// Node and kube-system pod readiness counts, as polled by `kina wait-ready`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReadinessSnapshot {
+    pub nodes_ready: usize,
+    pub nodes_total: usize,
+    pub pods_running: usize,
+    pub pods_total: usize,
+}
+
+impl ReadinessSnapshot {
+    /// Count Ready nodes (cordoned nodes still count) and kube-system pods that
+    /// are `Running` or `Succeeded`.
+    pub fn from_parts(nodes: &[NodeInfo], pod_phases: &[(String, String)]) -> Self {
+        Self {
+            nodes_ready: nodes
+                .iter()
+                .filter(|n| n.status.split(',').next() == Some("Ready"))
+                .count(),
+            nodes_total: nodes.len(),
+            pods_running: pod_phases
+                .iter()
+                .filter(|(_, phase)| phase == "Running" || phase == "Succeeded")
+                .count(),
+            pods_total: pod_phases.len(),
+        }
+    }
+
+    /// Every node Ready and every kube-system pod running. Empty lists are not
+    /// ready: right after `kubeadm init` the API can briefly report nothing.
+    pub fn is_ready(&self) -> bool {
+        self.nodes_total > 0
+            && self.nodes_ready == self.nodes_total
+            && self.pods_total > 0
+            && self.pods_running == self.pods_total
+    }
+
+    /// `2/3 nodes Ready, 7/9 kube-system pods Running`
+    pub fn summary(&self) -> String {
+        format!(
+            "{}/{} nodes Ready, {}/{} kube-system pods Running",
+            self.nodes_ready, self.nodes_total, self.pods_running, self.pods_total
+        )
+    }
+}
 
 /// Client for Kubernetes operations
 #[allow(dead_code)]
@@ -164,6 +703,42 @@ impl KubernetesClient {
 
     /// Apply a Kubernetes manifest
     pub async fn apply_manifest(&self, kubeconfig_path: &str, manifest: &str) -> Result<()> {
+        self.apply_manifest_with(kubeconfig_path, manifest, None, None)
+            .await
+            .map(|_| ())
+    }
+
+    /// Apply the manifest at `url` (`kubectl apply -f <url>`)
+    pub async fn apply_manifest_url(&self, kubeconfig_path: &str, url: &str) -> Result<()> {
+        debug!("Applying Kubernetes manifest {}", url);
+
+        let output = Command::new(&self.kubectl_path)
+            .arg("--kubeconfig")
+            .arg(kubeconfig_path)
+            .args(["apply", "-f", url])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .context("Failed to execute kubectl apply")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow::anyhow!("kubectl apply failed: {}", stderr.trim()));
+        }
+        Ok(())
+    }
+
+    /// Apply a Kubernetes manifest, optionally into `namespace` and with a kubectl
+    /// `--dry-run` mode (`none`, `client` or `server`). Returns kubectl's output,
+    /// one `<kind>/<name> <action>` line per resource.
+    pub async fn apply_manifest_with(
+        &self,
+        kubeconfig_path: &str,
+        manifest: &str,
+        namespace: Option<&str>,
+        dry_run: Option<&str>,
+    ) -> Result<String> {
         debug!("Applying Kubernetes manifest");
 
         let mut cmd = Command::new(&self.kubectl_path);
@@ -172,6 +747,12 @@ impl KubernetesClient {
             .arg("apply")
             .arg("-f")
             .arg("-");
+        if let Some(ns) = namespace {
+            cmd.arg("-n").arg(ns);
+        }
+        if let Some(mode) = dry_run {
+            cmd.arg(format!("--dry-run={}", mode));
+        }
 
         cmd.stdin(Stdio::piped())
             .stdout(Stdio::piped())
@@ -181,8 +762,8 @@ impl KubernetesClient {
             .spawn()
             .context("Failed to spawn kubectl apply process")?;
 
-        // Write manifest to stdin
-        if let Some(stdin) = child.stdin.as_mut() {
+        // Write manifest to stdin, closing it so kubectl sees EOF
+        if let Some(mut stdin) = child.stdin.take() {
             use tokio::io::AsyncWriteExt;
             stdin
                 .write_all(manifest.as_bytes())
@@ -200,7 +781,7 @@ impl KubernetesClient {
             return Err(anyhow::anyhow!("kubectl apply failed: {}", stderr));
         }
 
-        Ok(())
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
     }
 
     /// Delete resources by label selector
@@ -234,4 +815,405 @@ impl KubernetesClient {
 
         Ok(())
     }
+
+    /// Recent events in one namespace, or across all namespaces when `namespace`
+    /// is None, oldest first
+    pub async fn get_events(
+        &self,
+        kubeconfig_path: &str,
+        namespace: Option<&str>,
+    ) -> Result<Vec<ClusterEvent>> {
+        debug!("Getting events with kubeconfig: {}", kubeconfig_path);
+
+        let mut cmd = Command::new(&self.kubectl_path);
+        cmd.arg("--kubeconfig")
+            .arg(kubeconfig_path)
+            .arg("get")
+            .arg("events")
+            .args(namespace_args(namespace))
+            .arg("-o")
+            .arg("json");
+
+        let output = cmd
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .context("Failed to execute kubectl get events")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow::anyhow!("kubectl get events failed: {}", stderr));
+        }
+
+        parse_events(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    /// Stream events to the terminal with `kubectl get events --watch` until
+    /// kubectl exits (e.g. on Ctrl-C)
+    pub async fn watch_events(&self, kubeconfig_path: &str, namespace: Option<&str>) -> Result<()> {
+        let mut cmd = Command::new(&self.kubectl_path);
+        cmd.arg("--kubeconfig")
+            .arg(kubeconfig_path)
+            .arg("get")
+            .arg("events")
+            .arg("--watch")
+            .args(namespace_args(namespace));
+
+        let status = cmd
+            .stdin(Stdio::inherit())
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status()
+            .await
+            .context("Failed to execute kubectl get events --watch")?;
+
+        if !status.success() {
+            return Err(anyhow::anyhow!(
+                "kubectl get events --watch exited with {}",
+                status
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Start `kubectl port-forward` and return the running child.
+    ///
+    /// stderr goes to the terminal. stdout is read in the background: the
+    /// "Forwarding from ..." lines are printed and per-connection chatter is
+    /// logged at debug level. The child is killed if the handle is dropped.
+    pub async fn port_forward(
+        &self,
+        kubeconfig_path: &str,
+        resource: &str,
+        ports: &[(u16, u16)],
+        namespace: Option<&str>,
+    ) -> Result<tokio::process::Child> {
+        let args = port_forward_args(resource, ports, namespace);
+        debug!("Running kubectl {}", args.join(" "));
+
+        let mut child = Command::new(&self.kubectl_path)
+            .arg("--kubeconfig")
+            .arg(kubeconfig_path)
+            .args(&args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .kill_on_drop(true)
+            .spawn()
+            .context("Failed to execute kubectl port-forward")?;
+
+        if let Some(stdout) = child.stdout.take() {
+            tokio::spawn(async move {
+                use tokio::io::AsyncBufReadExt;
+
+                let mut lines = tokio::io::BufReader::new(stdout).lines();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    if line.starts_with("Forwarding from") {
+                        println!("✅ {}", line);
+                    } else {
+                        debug!("kubectl port-forward: {}", line);
+                    }
+                }
+            });
+        }
+
+        Ok(child)
+    }
+
+    /// Run `kubectl get pods -o <format>` and return its output. `namespace` None
+    /// means all namespaces; `selector` is a label selector (`-l`).
+    pub async fn get_pods(
+        &self,
+        kubeconfig_path: &str,
+        namespace: Option<&str>,
+        selector: Option<&str>,
+        format: &str,
+    ) -> Result<String> {
+        debug!("Getting pods with kubeconfig: {}", kubeconfig_path);
+
+        let mut cmd = Command::new(&self.kubectl_path);
+        cmd.arg("--kubeconfig")
+            .arg(kubeconfig_path)
+            .arg("get")
+            .arg("pods")
+            .args(namespace_args(namespace));
+        if let Some(selector) = selector {
+            cmd.arg("-l").arg(selector);
+        }
+        cmd.arg("-o").arg(format);
+
+        let output = cmd
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .context("Failed to execute kubectl get pods")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow::anyhow!("kubectl get pods failed: {}", stderr));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// Run `kubectl delete` and return its output. A missing named resource is
+    /// reported as [`KubernetesError::ResourceNotFound`] unless `--all` was set.
+    pub async fn delete_resource(
+        &self,
+        kubeconfig_path: &str,
+        options: &DeleteResourceOptions,
+    ) -> Result<String> {
+        debug!(
+            "Deleting {} with kubeconfig: {}",
+            options.resource_type, kubeconfig_path
+        );
+
+        let mut cmd = Command::new(&self.kubectl_path);
+        cmd.arg("--kubeconfig")
+            .arg(kubeconfig_path)
+            .args(delete_resource_args(options));
+
+        let output = cmd
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .context("Failed to execute kubectl delete")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("(NotFound)") && !options.all {
+                let resource = match &options.name {
+                    Some(name) => format!("{}/{}", options.resource_type, name),
+                    None => options.resource_type.clone(),
+                };
+                return Err(KubernetesError::ResourceNotFound { resource }.into());
+            }
+            return Err(anyhow::anyhow!("kubectl delete failed: {}", stderr));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// Cordon and drain `node`, returning the pods evicted from it. Failures
+    /// are reported as [`ClusterError::NodeDrainFailed`].
+    pub async fn drain_node(
+        &self,
+        kubeconfig_path: &str,
+        node: &str,
+        grace_period: Option<i64>,
+        timeout: &str,
+    ) -> Result<Vec<String>> {
+        info!("Draining node '{}'", node);
+
+        let output = Command::new(&self.kubectl_path)
+            .arg("--kubeconfig")
+            .arg(kubeconfig_path)
+            .args(drain_args(node, grace_period, timeout))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .context("Failed to execute kubectl drain")?;
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !output.status.success() {
+            return Err(ClusterError::NodeDrainFailed {
+                node: node.to_string(),
+                reason: stderr.trim().to_string(),
+            }
+            .into());
+        }
+
+        // kubectl reports evictions on stderr and completions on stdout.
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(parse_evicted_pods(&format!("{}\n{}", stderr, stdout)))
+    }
+
+    /// Mark `node` schedulable again (`kubectl uncordon`)
+    pub async fn uncordon_node(&self, kubeconfig_path: &str, node: &str) -> Result<()> {
+        debug!("Uncordoning node '{}'", node);
+
+        let output = Command::new(&self.kubectl_path)
+            .arg("--kubeconfig")
+            .arg(kubeconfig_path)
+            .args(["uncordon", node])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .context("Failed to execute kubectl uncordon")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow::anyhow!(
+                "kubectl uncordon failed: {}",
+                stderr.trim()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Create `namespace` unless it already exists: the equivalent of
+    /// `kubectl create namespace <ns> --dry-run=client -o yaml | kubectl apply -f -`
+    pub async fn ensure_namespace(&self, kubeconfig_path: &str, namespace: &str) -> Result<()> {
+        debug!("Ensuring namespace '{}' exists", namespace);
+
+        let output = Command::new(&self.kubectl_path)
+            .arg("--kubeconfig")
+            .arg(kubeconfig_path)
+            .args([
+                "create",
+                "namespace",
+                namespace,
+                "--dry-run=client",
+                "-o",
+                "yaml",
+            ])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .context("Failed to execute kubectl create namespace")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow::anyhow!(
+                "kubectl create namespace {} failed: {}",
+                namespace,
+                stderr.trim()
+            ));
+        }
+        self.apply_manifest(kubeconfig_path, &String::from_utf8_lossy(&output.stdout))
+            .await
+            .with_context(|| format!("Failed to create namespace '{}'", namespace))
+    }
+
+    /// Wait for Deployment `namespace/name` to finish rolling out
+    /// (`kubectl rollout status`), failing after `timeout`.
+    pub async fn wait_for_deployment(
+        &self,
+        kubeconfig_path: &str,
+        namespace: &str,
+        name: &str,
+        timeout: Duration,
+    ) -> Result<()> {
+        self.rollout_status(
+            kubeconfig_path,
+            &format!("deployment/{}", name),
+            namespace,
+            timeout,
+        )
+        .await
+    }
+
+    /// Wait for DaemonSet `namespace/name` to be rolled out on every node,
+    /// failing after `timeout`.
+    pub async fn wait_for_daemonset(
+        &self,
+        kubeconfig_path: &str,
+        namespace: &str,
+        name: &str,
+        timeout: Duration,
+    ) -> Result<()> {
+        self.rollout_status(
+            kubeconfig_path,
+            &format!("daemonset/{}", name),
+            namespace,
+            timeout,
+        )
+        .await
+    }
+
+    async fn rollout_status(
+        &self,
+        kubeconfig_path: &str,
+        workload: &str,
+        namespace: &str,
+        timeout: Duration,
+    ) -> Result<()> {
+        debug!("Waiting for {} in '{}' to roll out", workload, namespace);
+
+        let output = Command::new(&self.kubectl_path)
+            .arg("--kubeconfig")
+            .arg(kubeconfig_path)
+            .args(rollout_status_args(workload, namespace, timeout))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .context("Failed to execute kubectl rollout status")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow::anyhow!(
+                "{} in namespace '{}' did not roll out within {}s: {}",
+                workload,
+                namespace,
+                timeout.as_secs(),
+                stderr.trim()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Run `kubectl wait` with its output streamed to the terminal and return
+    /// kubectl's exit code
+    pub async fn wait(&self, kubeconfig_path: &str, args: &[String]) -> Result<i32> {
+        debug!("Running kubectl {}", args.join(" "));
+
+        let status = Command::new(&self.kubectl_path)
+            .arg("--kubeconfig")
+            .arg(kubeconfig_path)
+            .args(args)
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status()
+            .await
+            .context("Failed to execute kubectl wait")?;
+
+        Ok(status.code().unwrap_or(1))
+    }
+
+    /// Live node list from the Kubernetes API (`kubectl get nodes -o json`)
+    pub async fn get_live_node_info(&self, kubeconfig_path: &str) -> Result<Vec<NodeInfo>> {
+        debug!(
+            "Getting live node info with kubeconfig: {}",
+            kubeconfig_path
+        );
+
+        let mut cmd = Command::new(&self.kubectl_path);
+        cmd.arg("--kubeconfig")
+            .arg(kubeconfig_path)
+            .arg("get")
+            .arg("nodes")
+            .arg("-o")
+            .arg("json")
+            .arg("--request-timeout=5s");
+
+        let output = cmd
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .context("Failed to execute kubectl get nodes")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow::anyhow!("kubectl get nodes failed: {}", stderr));
+        }
+
+        parse_live_nodes(&String::from_utf8_lossy(&output.stdout))
+    }
+}
+
+/// `-n <ns>` for one namespace, `--all-namespaces` otherwise.
+fn namespace_args(namespace: Option<&str>) -> Vec<String> {
+    match namespace {
+        Some(ns) => vec!["-n".to_string(), ns.to_string()],
+        None => vec!["--all-namespaces".to_string()],
+    }
 }
@@ -0,0 +1,715 @@
+//! In-memory [`ClusterClientTrait`] implementation for unit tests.
+//!
+//! [`MockAppleContainerClient`] keeps clusters, networks and node IPs in
+//! memory, records the images, scripts and other calls it receives, and can be
+//! told to fail specific methods. Clones share state, so a test can hand one
+//! clone to `ClusterManager::new_with_client` and inspect the other.
+//!
+//! Available under `cfg(test)` and with the `test-utils` feature.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::config::CniPlugin;
+use crate::core::apple_container::control_plane_node_name;
+use crate::core::kubernetes::ReadinessSnapshot;
+use crate::core::provider::{ClusterClientTrait, NetworkSpecBuilder};
+use crate::core::types::{
+    ClusterInfo, ClusterStatus, ContainerVersion, CreateClusterOptions, DeleteClusterOptions,
+    LoadImageOptions, NetworkInfo, NodeInfo, NodeRole,
+};
+
+/// First host octet handed out to nodes without a seeded IP
+const FIRST_MOCK_IP_OCTET: u32 = 2;
+
+/// Everything the mock knows and has been asked to do
+#[derive(Debug, Default)]
+pub struct MockState {
+    pub clusters: BTreeMap<String, ClusterInfo>,
+    pub networks: Vec<NetworkInfo>,
+    /// Node name -> IP; nodes without an entry get `192.168.64.<n>`
+    pub node_ips: HashMap<String, String>,
+    /// Cluster name -> `kubeadm.yaml` contents
+    pub kubeadm_configs: HashMap<String, String>,
+    /// Node name -> labels `inspect_container` reports besides the cluster and role
+    pub container_labels: HashMap<String, BTreeMap<String, String>>,
+    /// Node name -> `(cpus, memoryInBytes)` reported by `inspect_container`
+    pub node_resources: HashMap<String, (u32, u64)>,
+    /// Options of every successful `create_cluster`
+    pub created: Vec<CreateClusterOptions>,
+    /// `(cluster, image)` for every successful `load_image`
+    pub loaded_images: Vec<(String, String)>,
+    /// Image of every successful `export_image`
+    pub exported_images: Vec<String>,
+    pub pulled_images: Vec<String>,
+    /// `(node, script)` for every `run_node_script`
+    pub scripts: Vec<(String, String)>,
+    /// `(node, command)` for every `exec_attached`
+    pub execs: Vec<(String, Vec<String>)>,
+    /// `(pattern, stdout)`: a script containing `pattern` prints `stdout`;
+    /// the first match wins and other scripts print nothing
+    pub script_outputs: Vec<(String, String)>,
+    /// Clusters whose kubelet CSRs were approved
+    pub approved_csrs: Vec<String>,
+    /// Method name -> error message returned instead of running it
+    pub failures: HashMap<String, String>,
+    next_ip: u32,
+}
+
+impl MockState {
+    fn fail_if_set(&self, method: &str) -> Result<()> {
+        match self.failures.get(method) {
+            Some(message) => Err(anyhow::anyhow!("{}", message)),
+            None => Ok(()),
+        }
+    }
+
+    fn cluster(&self, name: &str) -> Result<&ClusterInfo> {
+        self.clusters
+            .get(name)
+            .ok_or_else(|| anyhow::anyhow!("Cluster '{}' not found", name))
+    }
+
+    fn cluster_mut(&mut self, name: &str) -> Result<&mut ClusterInfo> {
+        self.clusters
+            .get_mut(name)
+            .ok_or_else(|| anyhow::anyhow!("Cluster '{}' not found", name))
+    }
+
+    fn node(&self, name: &str) -> Result<&NodeInfo> {
+        self.clusters
+            .values()
+            .flat_map(|c| c.nodes.iter())
+            .find(|n| n.name == name)
+            .ok_or_else(|| anyhow::anyhow!("Container '{}' not found", name))
+    }
+
+    fn ip_for(&mut self, node: &str) -> String {
+        if let Some(ip) = self.node_ips.get(node) {
+            return ip.clone();
+        }
+        let ip = format!("192.168.64.{}", FIRST_MOCK_IP_OCTET + self.next_ip);
+        self.next_ip += 1;
+        self.node_ips.insert(node.to_string(), ip.clone());
+        ip
+    }
+
+    fn new_node(&mut self, name: String, role: NodeRole, version: &str) -> NodeInfo {
+        let ip = self.ip_for(&name);
+        NodeInfo {
+            container_id: Some(name.clone()),
+            name,
+            role,
+            status: "running".to_string(),
+            version: version.to_string(),
+            ip_address: Some(ip),
+        }
+    }
+}
+
+/// In-memory stand-in for `AppleContainerClient`
+#[derive(Debug, Clone)]
+pub struct MockAppleContainerClient {
+    state: Arc<Mutex<MockState>>,
+    container_version: ContainerVersion,
+}
+
+impl Default for MockAppleContainerClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockAppleContainerClient {
+    /// Empty mock reporting Apple Container `0.0.0-mock`
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(MockState::default())),
+            container_version: ContainerVersion {
+                version: "0.0.0-mock".to_string(),
+                build: "mock".to_string(),
+            },
+        }
+    }
+
+    /// Seed an existing cluster. Nodes without an IP get one from the mock.
+    pub fn with_cluster(self, mut cluster: ClusterInfo) -> Self {
+        {
+            let mut state = self.state();
+            for node in &mut cluster.nodes {
+                if let Some(ip) = &node.ip_address {
+                    state.node_ips.insert(node.name.clone(), ip.clone());
+                } else {
+                    node.ip_address = Some(state.ip_for(&node.name));
+                }
+            }
+            state.clusters.insert(cluster.name.clone(), cluster);
+        }
+        self
+    }
+
+    /// Seed a running cluster with one control plane and `workers` workers
+    pub fn with_running_cluster(self, name: &str, workers: u32) -> Self {
+        let cluster = {
+            let mut state = self.state();
+            mock_cluster(&mut state, name, "kindest/node:mock", 1, workers)
+        };
+        self.with_cluster(cluster)
+    }
+
+    /// Use `ip` for `node`, whether it exists yet or is created later
+    pub fn with_node_ip(self, node: &str, ip: &str) -> Self {
+        self.state()
+            .node_ips
+            .insert(node.to_string(), ip.to_string());
+        self
+    }
+
+    pub fn with_network(self, network: NetworkInfo) -> Self {
+        self.state().networks.push(network);
+        self
+    }
+
+    /// Answer node scripts containing `pattern` with `stdout`
+    pub fn with_script_output(self, pattern: &str, stdout: &str) -> Self {
+        self.state()
+            .script_outputs
+            .push((pattern.to_string(), stdout.to_string()));
+        self
+    }
+
+    /// Report `labels` in `inspect_container` for `node`
+    pub fn with_container_labels(self, node: &str, labels: &[(&str, &str)]) -> Self {
+        self.state()
+            .container_labels
+            .entry(node.to_string())
+            .or_default()
+            .extend(labels.iter().map(|(k, v)| (k.to_string(), v.to_string())));
+        self
+    }
+
+    /// Report `cpus` and `memory_bytes` in `inspect_container` for `node`
+    pub fn with_node_resources(self, node: &str, cpus: u32, memory_bytes: u64) -> Self {
+        self.state()
+            .node_resources
+            .insert(node.to_string(), (cpus, memory_bytes));
+        self
+    }
+
+    pub fn with_kubeadm_config(self, cluster: &str, config: &str) -> Self {
+        self.state()
+            .kubeadm_configs
+            .insert(cluster.to_string(), config.to_string());
+        self
+    }
+
+    /// Make `method` (a `ClusterClientTrait` method name) return `message` as
+    /// its error. `approve_cluster_kubelet_csrs` cannot fail and ignores this.
+    pub fn fail_on(self, method: &str, message: &str) -> Self {
+        self.state()
+            .failures
+            .insert(method.to_string(), message.to_string());
+        self
+    }
+
+    /// Lock the shared state, e.g. to assert on recorded calls
+    pub fn state(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// A running cluster laid out like `AppleContainerClient` creates it
+fn mock_cluster(
+    state: &mut MockState,
+    name: &str,
+    image: &str,
+    control_planes: u32,
+    workers: u32,
+) -> ClusterInfo {
+    let version = image.rsplit_once(':').map_or("unknown", |(_, tag)| tag);
+    let mut nodes: Vec<NodeInfo> = (1..=control_planes.max(1))
+        .map(|i| {
+            state.new_node(
+                control_plane_node_name(name, i),
+                NodeRole::ControlPlane,
+                version,
+            )
+        })
+        .collect();
+    for i in 0..workers {
+        let node_name = if i == 0 {
+            format!("{}-worker", name)
+        } else {
+            format!("{}-worker-{}", name, i + 1)
+        };
+        nodes.push(state.new_node(node_name, NodeRole::Worker, version));
+    }
+    let now = chrono::Utc::now();
+    ClusterInfo {
+        name: name.to_string(),
+        image: image.to_string(),
+        status: ClusterStatus::Running,
+        created: now.format("%Y-%m-%d %H:%M UTC").to_string(),
+        creation_timestamp: Some(now),
+        nodes,
+        kubeconfig_path: None,
+    }
+}
+
+#[async_trait]
+impl ClusterClientTrait for MockAppleContainerClient {
+    fn version(&self) -> &str {
+        &self.container_version.version
+    }
+
+    fn container_version(&self) -> Result<&ContainerVersion> {
+        Ok(&self.container_version)
+    }
+
+    async fn create_cluster(&self, options: &CreateClusterOptions) -> Result<()> {
+        let mut state = self.state();
+        state.fail_if_set("create_cluster")?;
+        if state.clusters.contains_key(&options.name) {
+            return Err(anyhow::anyhow!("Cluster '{}' already exists", options.name));
+        }
+        let cluster = mock_cluster(
+            &mut state,
+            &options.name,
+            &options.image,
+            options.control_plane_nodes.unwrap_or(1),
+            options.workers.unwrap_or(0),
+        );
+        state.clusters.insert(options.name.clone(), cluster);
+        state.created.push(options.clone());
+        Ok(())
+    }
+
+    async fn delete_cluster(&self, name: &str, _options: &DeleteClusterOptions) -> Result<()> {
+        let mut state = self.state();
+        state.fail_if_set("delete_cluster")?;
+        state
+            .clusters
+            .remove(name)
+            .map(|_| ())
+            .ok_or_else(|| anyhow::anyhow!("Cluster '{}' not found", name))
+    }
+
+    async fn rename_cluster(&self, old_name: &str, new_name: &str) -> Result<()> {
+        let mut state = self.state();
+        state.fail_if_set("rename_cluster")?;
+        let mut cluster = state
+            .clusters
+            .remove(old_name)
+            .ok_or_else(|| anyhow::anyhow!("Cluster '{}' not found", old_name))?;
+        cluster.name = new_name.to_string();
+        for node in &mut cluster.nodes {
+            let renamed = node.name.replacen(old_name, new_name, 1);
+            if let Some(ip) = state.node_ips.remove(&node.name) {
+                state.node_ips.insert(renamed.clone(), ip);
+            }
+            node.name = renamed;
+        }
+        state.clusters.insert(new_name.to_string(), cluster);
+        Ok(())
+    }
+
+    async fn pause_cluster(&self, name: &str) -> Result<()> {
+        let mut state = self.state();
+        state.fail_if_set("pause_cluster")?;
+        let cluster = state.cluster_mut(name)?;
+        cluster.status = ClusterStatus::Stopped;
+        for node in &mut cluster.nodes {
+            node.status = "stopped".to_string();
+        }
+        Ok(())
+    }
+
+    async fn resume_cluster(&self, name: &str) -> Result<String> {
+        let mut state = self.state();
+        state.fail_if_set("resume_cluster")?;
+        let cluster = state.cluster_mut(name)?;
+        cluster.status = ClusterStatus::Running;
+        for node in &mut cluster.nodes {
+            node.status = "running".to_string();
+        }
+        let cp_name = control_plane_node_name(name, 1);
+        Ok(state.ip_for(&cp_name))
+    }
+
+    async fn list_clusters(&self) -> Result<Vec<ClusterInfo>> {
+        let state = self.state();
+        state.fail_if_set("list_clusters")?;
+        Ok(state.clusters.values().cloned().collect())
+    }
+
+    async fn get_kubeconfig(&self, name: &str) -> Result<String> {
+        let mut state = self.state();
+        state.fail_if_set("get_kubeconfig")?;
+        state.cluster(name)?;
+        let ip = state.ip_for(&control_plane_node_name(name, 1));
+        Ok(format!(
+            "apiVersion: v1\nkind: Config\nclusters:\n- cluster:\n    server: https://{ip}:6443\n  name: {name}\ncontexts:\n- context:\n    cluster: {name}\n    user: {name}\n  name: {name}\ncurrent-context: {name}\nusers:\n- name: {name}\n  user: {{}}\n",
+        ))
+    }
+
+    async fn repair_kubeconfig(&self, cluster_name: &str) -> Result<()> {
+        let state = self.state();
+        state.fail_if_set("repair_kubeconfig")?;
+        state.cluster(cluster_name).map(|_| ())
+    }
+
+    async fn load_image(&self, options: &LoadImageOptions) -> Result<()> {
+        let mut state = self.state();
+        state.fail_if_set("load_image")?;
+        state.cluster(&options.cluster)?;
+        if let Some(archive) = &options.archive {
+            if !archive.exists() {
+                return Err(anyhow::anyhow!(
+                    "Image archive {} does not exist",
+                    archive.display()
+                ));
+            }
+        }
+        state
+            .loaded_images
+            .push((options.cluster.clone(), options.image.clone()));
+        Ok(())
+    }
+
+    async fn export_image(&self, image: &str) -> Result<tempfile::TempPath> {
+        let mut state = self.state();
+        state.fail_if_set("export_image")?;
+        state.exported_images.push(image.to_string());
+        Ok(tempfile::NamedTempFile::new()?.into_temp_path())
+    }
+
+    async fn pull_image(&self, image: &str, _platform: Option<&str>) -> Result<()> {
+        let mut state = self.state();
+        state.fail_if_set("pull_image")?;
+        state.pulled_images.push(image.to_string());
+        Ok(())
+    }
+
+    async fn verify_image(&self, _image: &str, _platform: Option<&str>) -> Result<()> {
+        self.state().fail_if_set("verify_image")
+    }
+
+    async fn list_networks(&self) -> Result<Vec<NetworkInfo>> {
+        let state = self.state();
+        state.fail_if_set("list_networks")?;
+        Ok(state.networks.clone())
+    }
+
+    async fn create_network(
+        &self,
+        name: &str,
+        subnet: Option<&str>,
+        labels: &BTreeMap<String, String>,
+    ) -> Result<NetworkInfo> {
+        let mut state = self.state();
+        state.fail_if_set("create_network")?;
+        if state.networks.iter().any(|n| n.name == name) {
+            return Err(anyhow::anyhow!("Network '{}' already exists", name));
+        }
+        let spec = NetworkSpecBuilder::new()
+            .name(name)
+            .subnet(subnet)
+            .labels(labels)
+            .build()?;
+        let network = NetworkInfo {
+            name: spec.name,
+            state: "running".to_string(),
+            subnet: spec.subnet,
+            labels: spec.labels,
+        };
+        state.networks.push(network.clone());
+        Ok(network)
+    }
+
+    async fn delete_network(&self, name: &str) -> Result<()> {
+        let mut state = self.state();
+        state.fail_if_set("delete_network")?;
+        let before = state.networks.len();
+        state.networks.retain(|n| n.name != name);
+        if state.networks.len() == before {
+            return Err(anyhow::anyhow!("Network '{}' not found", name));
+        }
+        Ok(())
+    }
+
+    async fn inspect_container(&self, name: &str) -> Result<serde_json::Value> {
+        let state = self.state();
+        state.fail_if_set("inspect_container")?;
+        let node = state.node(name)?;
+        let cluster = state
+            .clusters
+            .iter()
+            .find(|(_, c)| c.nodes.iter().any(|n| n.name == name))
+            .map(|(cluster, _)| cluster.as_str());
+        let mut labels = serde_json::json!({
+            "io.kina.cluster": cluster,
+            "io.kina.role": node.role.to_string(),
+        });
+        for (key, value) in state.container_labels.get(name).into_iter().flatten() {
+            labels[key] = value.clone().into();
+        }
+        let resources = state
+            .node_resources
+            .get(name)
+            .map(|(cpus, memory)| serde_json::json!({ "cpus": cpus, "memoryInBytes": memory }));
+        Ok(serde_json::json!({
+            "configuration": {
+                "id": node.name,
+                "labels": labels,
+                "resources": resources,
+            },
+            "status": node.status,
+            "networks": [{ "address": node.ip_address }],
+        }))
+    }
+
+    async fn read_kubeadm_config(&self, cluster_name: &str) -> Result<String> {
+        let state = self.state();
+        state.fail_if_set("read_kubeadm_config")?;
+        state
+            .kubeadm_configs
+            .get(cluster_name)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("No kubeadm config for cluster '{}'", cluster_name))
+    }
+
+    async fn readiness_snapshot(&self, cluster_name: &str) -> Result<ReadinessSnapshot> {
+        let state = self.state();
+        state.fail_if_set("readiness_snapshot")?;
+        let cluster = state.cluster(cluster_name)?;
+        let nodes_ready = if cluster.status == ClusterStatus::Running {
+            cluster.nodes.len()
+        } else {
+            0
+        };
+        Ok(ReadinessSnapshot {
+            nodes_ready,
+            nodes_total: cluster.nodes.len(),
+            pods_running: 0,
+            pods_total: 0,
+        })
+    }
+
+    async fn run_node_script(&self, node_name: &str, script: &str) -> Result<String> {
+        let mut state = self.state();
+        state.fail_if_set("run_node_script")?;
+        state.node(node_name)?;
+        state
+            .scripts
+            .push((node_name.to_string(), script.to_string()));
+        Ok(state
+            .script_outputs
+            .iter()
+            .find(|(pattern, _)| script.contains(pattern.as_str()))
+            .map(|(_, stdout)| stdout.clone())
+            .unwrap_or_default())
+    }
+
+    async fn node_logs(&self, node_name: &str, _lines: u32) -> Result<String> {
+        let state = self.state();
+        state.fail_if_set("node_logs")?;
+        state.node(node_name)?;
+        Ok(String::new())
+    }
+
+    async fn exec_attached(&self, node_name: &str, command: &[String], _tty: bool) -> Result<i32> {
+        let mut state = self.state();
+        state.fail_if_set("exec_attached")?;
+        state.node(node_name)?;
+        state.execs.push((node_name.to_string(), command.to_vec()));
+        Ok(0)
+    }
+
+    async fn install_cni(
+        &self,
+        cluster_name: &str,
+        _cni: CniPlugin,
+        _kernel_path: Option<&Path>,
+    ) -> Result<()> {
+        let state = self.state();
+        state.fail_if_set("install_cni")?;
+        state.cluster(cluster_name).map(|_| ())
+    }
+
+    fn approve_cluster_kubelet_csrs(&self, cluster_name: &str) {
+        self.state().approved_csrs.push(cluster_name.to_string());
+    }
+
+    async fn apply_pod_security_labels(&self, cluster_name: &str, _level: &str) -> Result<()> {
+        let state = self.state();
+        state.fail_if_set("apply_pod_security_labels")?;
+        state.cluster(cluster_name).map(|_| ())
+    }
+
+    async fn create_image_pull_secrets(
+        &self,
+        cluster_name: &str,
+        _secret_name: &str,
+    ) -> Result<()> {
+        let state = self.state();
+        state.fail_if_set("create_image_pull_secrets")?;
+        state.cluster(cluster_name).map(|_| ())
+    }
+
+    async fn wait_for_kube_system_pods(
+        &self,
+        cluster_name: &str,
+        _timeout_seconds: u64,
+    ) -> Result<()> {
+        let state = self.state();
+        state.fail_if_set("wait_for_kube_system_pods")?;
+        state.cluster(cluster_name).map(|_| ())
+    }
+
+    async fn collect_failure_diagnostics(&self, cluster_name: &str, dest_dir: &Path) -> Result<()> {
+        self.state().fail_if_set("collect_failure_diagnostics")?;
+        std::fs::create_dir_all(dest_dir)?;
+        std::fs::write(
+            dest_dir.join("mock.txt"),
+            format!("diagnostics for {}\n", cluster_name),
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::core::cluster::ClusterManager;
+
+    /// Manager over `mock` whose kubeconfig cleanup stays inside `dir`
+    fn manager(mock: &MockAppleContainerClient, dir: &tempfile::TempDir) -> ClusterManager {
+        let mut config = Config::default();
+        config.kubernetes.kubeconfig_dir = dir.path().to_path_buf();
+        ClusterManager::new_with_client(&config, Box::new(mock.clone()))
+    }
+
+    #[tokio::test]
+    async fn manager_lists_and_deletes_seeded_clusters() {
+        let mock = MockAppleContainerClient::new()
+            .with_node_ip("dev-control-plane", "10.0.0.10")
+            .with_running_cluster("dev", 2);
+        let dir = tempfile::tempdir().unwrap();
+        let manager = manager(&mock, &dir);
+
+        let clusters = manager.list_clusters().await.unwrap();
+        assert_eq!(clusters.len(), 1);
+        let names: Vec<_> = clusters[0].nodes.iter().map(|n| n.name.as_str()).collect();
+        assert_eq!(names, ["dev-control-plane", "dev-worker", "dev-worker-2"]);
+        assert_eq!(
+            clusters[0].nodes[0].ip_address.as_deref(),
+            Some("10.0.0.10")
+        );
+
+        let kubeconfig = manager.get_kubeconfig("dev").await.unwrap();
+        assert!(kubeconfig.contains("server: https://10.0.0.10:6443"));
+
+        manager
+            .delete_cluster("dev", &DeleteClusterOptions::default())
+            .await
+            .unwrap();
+        assert!(!manager.cluster_exists("dev").await.unwrap());
+        assert!(manager.get_kubeconfig("dev").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn manager_records_image_loads_and_surfaces_failures() {
+        let mock = MockAppleContainerClient::new().with_running_cluster("dev", 0);
+        let dir = tempfile::tempdir().unwrap();
+        let manager = manager(&mock, &dir);
+        let options = |cluster: &str| LoadImageOptions {
+            cluster: cluster.to_string(),
+            image: "nginx:latest".to_string(),
+            archive: None,
+        };
+
+        manager.load_image(options("dev")).await.unwrap();
+        assert!(manager.load_image(options("missing")).await.is_err());
+        assert_eq!(
+            mock.state().loaded_images,
+            [("dev".to_string(), "nginx:latest".to_string())]
+        );
+
+        let failing = mock.clone().fail_on("load_image", "disk full");
+        let err = manager.load_image(options("dev")).await.unwrap_err();
+        assert!(format!("{:#}", err).contains("disk full"));
+        assert_eq!(failing.state().loaded_images.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn manager_pauses_renames_and_resumes() {
+        let mock = MockAppleContainerClient::new().with_running_cluster("dev", 1);
+        let dir = tempfile::tempdir().unwrap();
+        let manager = manager(&mock, &dir);
+
+        manager.pause_cluster("dev").await.unwrap();
+        assert_eq!(
+            manager.list_clusters().await.unwrap()[0].status,
+            ClusterStatus::Stopped
+        );
+
+        manager.rename_cluster("dev", "lab").await.unwrap();
+        let ip = manager.resume_cluster("lab").await.unwrap();
+        let clusters = manager.list_clusters().await.unwrap();
+        assert_eq!(clusters[0].name, "lab");
+        assert_eq!(clusters[0].nodes[1].name, "lab-worker");
+        assert_eq!(
+            clusters[0].nodes[0].ip_address.as_deref(),
+            Some(ip.as_str())
+        );
+        assert_eq!(clusters[0].status, ClusterStatus::Running);
+    }
+
+    #[tokio::test]
+    async fn seeded_networks_can_be_listed_and_deleted() {
+        let mock = MockAppleContainerClient::new().with_network(NetworkInfo {
+            name: "kina-dev".to_string(),
+            state: "running".to_string(),
+            subnet: None,
+            labels: BTreeMap::new(),
+        });
+
+        let err = mock
+            .create_network("kina-dev", None, &BTreeMap::new())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+        mock.create_network("kina-lab", Some("10.50.0.0/24"), &BTreeMap::new())
+            .await
+            .unwrap();
+        assert_eq!(mock.list_networks().await.unwrap().len(), 2);
+
+        mock.delete_network("kina-dev").await.unwrap();
+        let names: Vec<_> = mock
+            .list_networks()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|n| n.name)
+            .collect();
+        assert_eq!(names, ["kina-lab"]);
+        assert!(mock.delete_network("kina-dev").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn manager_rejects_an_invalid_rename_target() {
+        let mock = MockAppleContainerClient::new().with_running_cluster("dev", 1);
+        let dir = tempfile::tempdir().unwrap();
+        let manager = manager(&mock, &dir);
+
+        for new_name in ["Lab", "lab_1", "-lab"] {
+            let err = manager.rename_cluster("dev", new_name).await.unwrap_err();
+            assert!(format!("{:#}", err).contains("invalid cluster name"));
+        }
+        assert_eq!(manager.list_clusters().await.unwrap()[0].name, "dev");
+    }
+}
@@ -1,9 +1,21 @@
 pub mod apple_container;
+pub mod benchmark;
 pub mod cluster;
+pub mod daemon;
+pub mod events;
+pub mod image_registry;
 pub mod kernel_fetch;
 pub mod kubernetes;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod mock;
 pub mod node_image_builder;
+pub mod plugin;
+pub mod prerequisites;
+pub mod provider;
+pub mod storage;
+pub mod template;
 pub mod types;
+pub mod update_check;
 pub mod verify;
 
 // Re-export commonly used types
@@ -0,0 +1,110 @@
+//! Plugin discovery for `kina plugin`: any executable named `kina-<name>` on
+//! `PATH` is the plugin `<name>`.
+
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+/// File name prefix that marks an executable as a kina plugin
+pub const PLUGIN_PREFIX: &str = "kina-";
+
+/// Env var carrying the active kina config file path to plugins
+pub const PLUGIN_CONFIG_ENV: &str = "KINA_CONFIG";
+
+/// Env var carrying the cluster list (as `kina list` JSON) to plugins
+pub const PLUGIN_CLUSTERS_ENV: &str = "KINA_CLUSTERS";
+
+/// Path of the `kina-<name>` plugin in the `PATH`-style list `path_var`.
+/// The first match wins, as with shell lookup. A name containing a path
+/// separator never matches, so `kina plugin ../x` cannot escape `PATH`.
+pub fn find_plugin(name: &str, path_var: &OsStr) -> Option<PathBuf> {
+    if name.is_empty() || name.chars().any(std::path::is_separator) {
+        return None;
+    }
+    let file_name = format!("{}{}", PLUGIN_PREFIX, name);
+    std::env::split_paths(path_var)
+        .map(|dir| dir.join(&file_name))
+        .find(|path| is_executable(path))
+}
+
+/// Every plugin in `path_var`, keyed by plugin name. A plugin shadowed by an
+/// earlier `PATH` entry is left out.
+pub fn discover_plugins(path_var: &OsStr) -> BTreeMap<String, PathBuf> {
+    let mut plugins = BTreeMap::new();
+    for dir in std::env::split_paths(path_var) {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Some(name) = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .and_then(|n| n.strip_prefix(PLUGIN_PREFIX))
+            else {
+                continue;
+            };
+            if !name.is_empty() && is_executable(&path) {
+                plugins.entry(name.to_string()).or_insert(path);
+            }
+        }
+    }
+    plugins
+}
+
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    std::fs::metadata(path)
+        .map(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    fn write_file(dir: &Path, name: &str, mode: u32) {
+        let path = dir.join(name);
+        std::fs::write(&path, "#!/bin/sh\n").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(mode)).unwrap();
+    }
+
+    #[test]
+    fn discovers_executables_and_respects_path_order() {
+        let first = tempfile::tempdir().unwrap();
+        let second = tempfile::tempdir().unwrap();
+        write_file(first.path(), "kina-hello", 0o755);
+        write_file(second.path(), "kina-hello", 0o755);
+        write_file(second.path(), "kina-world", 0o755);
+        write_file(second.path(), "kina-notes.txt", 0o644);
+        write_file(second.path(), "kubectl", 0o755);
+
+        let path_var = std::env::join_paths([first.path(), second.path()]).unwrap();
+        let plugins = discover_plugins(&path_var);
+        assert_eq!(plugins.keys().collect::<Vec<_>>(), ["hello", "world"]);
+        assert_eq!(plugins["hello"], first.path().join("kina-hello"));
+        assert_eq!(
+            find_plugin("hello", &path_var),
+            Some(first.path().join("kina-hello"))
+        );
+        assert_eq!(find_plugin("notes.txt", &path_var), None);
+        assert_eq!(find_plugin("missing", &path_var), None);
+    }
+
+    #[test]
+    fn find_plugin_rejects_names_with_path_separators() {
+        let dir = tempfile::tempdir().unwrap();
+        let bin = dir.path().join("bin");
+        std::fs::create_dir(&bin).unwrap();
+        write_file(dir.path(), "kina-escape", 0o755);
+        std::fs::create_dir(bin.join("kina-")).unwrap();
+        write_file(&bin.join("kina-"), "nested", 0o755);
+
+        let path_var = std::env::join_paths([&bin]).unwrap();
+        assert_eq!(find_plugin("../../kina-escape", &path_var), None);
+        assert_eq!(find_plugin("/nested", &path_var), None);
+        assert_eq!(find_plugin("", &path_var), None);
+    }
+}
@@ -0,0 +1,364 @@
+//! Environment checks for `kina config check-prerequisites`.
+//!
+//! Each check yields a [`PrerequisiteCheck`] with a remediation hint when it
+//! fails. The evaluators are pure so the thresholds can be tested; gathering
+//! the host facts (CLI versions, free disk, RAM) happens in [`run_checks`].
+
+use std::path::Path;
+
+use crate::config::Config;
+use crate::core::apple_container::{
+    container_memory_arg, macos_major_version, resolve_memory, AppleContainerClient,
+    DEFAULT_NODE_MEMORY, HA_MIN_MACOS_MAJOR, MIN_VERSION,
+};
+use crate::utils::process::command_exists;
+
+/// Free space wanted in the data directory: a few node images plus logs
+pub const MIN_FREE_DISK_BYTES: u64 = 10 * 1024 * 1024 * 1024;
+
+/// Oldest macOS Apple Container runs on
+pub const MIN_MACOS_MAJOR: u32 = 15;
+
+/// Image builders checked for as a convenience. kina never runs them:
+/// `kina load` exports images with `container image save`.
+pub const IMAGE_TOOLS: [&str; 3] = ["docker", "nerdctl", "podman"];
+
+/// Outcome of one prerequisite check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrerequisiteCheck {
+    pub name: String,
+    pub passed: bool,
+    /// What was found, e.g. a version or a size
+    pub detail: String,
+    /// How to fix a failed check, or what a warning means
+    pub hint: Option<String>,
+}
+
+impl PrerequisiteCheck {
+    fn pass(name: &str, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            passed: true,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    /// A passed check that still deserves a note, e.g. a missing optional tool
+    fn warn(name: &str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            passed: true,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    fn fail(name: &str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            passed: false,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    /// `✅ name: detail`, or `⚠️ name: detail` / `❌ name: detail` followed by
+    /// the hint for warnings and failures
+    pub fn render(&self) -> String {
+        let icon = match (self.passed, &self.hint) {
+            (false, _) => "❌",
+            (true, Some(_)) => "⚠️",
+            (true, None) => "✅",
+        };
+        let mut out = format!("{} {}: {}", icon, self.name, self.detail);
+        if let Some(hint) = &self.hint {
+            out.push_str(&format!("\n   → {}", hint));
+        }
+        out
+    }
+}
+
+/// Apple Container CLI presence and version, from `AppleContainerClient::new`
+pub fn check_container_cli(version: Result<semver::Version, String>) -> PrerequisiteCheck {
+    const NAME: &str = "Apple Container CLI";
+    match version {
+        Ok(version) => {
+            let (major, minor, patch) = MIN_VERSION;
+            if version >= semver::Version::new(major.into(), minor.into(), patch.into()) {
+                PrerequisiteCheck::pass(NAME, format!("version {}", version))
+            } else {
+                PrerequisiteCheck::fail(
+                    NAME,
+                    format!("version {} is too old", version),
+                    format!(
+                        "Upgrade to {}.{}.{} or later from https://github.com/apple/container/releases",
+                        major, minor, patch
+                    ),
+                )
+            }
+        }
+        Err(e) => PrerequisiteCheck::fail(
+            NAME,
+            e,
+            "Install Apple Container from https://github.com/apple/container/releases and run 'container system start'",
+        ),
+    }
+}
+
+pub fn check_kubectl(found: bool) -> PrerequisiteCheck {
+    if found {
+        PrerequisiteCheck::pass("kubectl", "found in PATH")
+    } else {
+        PrerequisiteCheck::fail(
+            "kubectl",
+            "not found in PATH",
+            "Install kubectl, e.g. 'brew install kubectl'",
+        )
+    }
+}
+
+/// `kubeadm version -o short` run in the default node image
+pub fn check_kubeadm(image: &str, result: Result<String, String>) -> PrerequisiteCheck {
+    const NAME: &str = "kubeadm in node image";
+    match result {
+        Ok(version) => PrerequisiteCheck::pass(NAME, format!("{} in {}", version.trim(), image)),
+        Err(e) => PrerequisiteCheck::fail(
+            NAME,
+            format!("could not run kubeadm in {}: {}", image, e),
+            format!(
+                "Check that 'container run --rm --entrypoint kubeadm {} version' works, or set cluster.default_image to a kindest/node image",
+                image
+            ),
+        ),
+    }
+}
+
+/// The first of [`IMAGE_TOOLS`] found, if any. Informational only: a missing
+/// builder is a warning, never a failure.
+pub fn check_image_tool(found: Option<&str>) -> PrerequisiteCheck {
+    const NAME: &str = "Image builder";
+    match found {
+        Some(tool) => PrerequisiteCheck::pass(NAME, format!("{} found in PATH", tool)),
+        None => PrerequisiteCheck::warn(
+            NAME,
+            format!("none of {} found in PATH (optional)", IMAGE_TOOLS.join(", ")),
+            "Only needed to build images; 'kina load' exports images from Apple Container with 'container image save'",
+        ),
+    }
+}
+
+pub fn check_disk_space(data_dir: &Path, available: Option<u64>) -> PrerequisiteCheck {
+    const NAME: &str = "Disk space";
+    match available {
+        Some(bytes) if bytes >= MIN_FREE_DISK_BYTES => PrerequisiteCheck::pass(
+            NAME,
+            format!("{} free in {}", format_gib(bytes), data_dir.display()),
+        ),
+        Some(bytes) => PrerequisiteCheck::fail(
+            NAME,
+            format!("only {} free in {}", format_gib(bytes), data_dir.display()),
+            format!(
+                "Free up at least {} (e.g. 'container image prune') or move cluster.data_dir",
+                format_gib(MIN_FREE_DISK_BYTES)
+            ),
+        ),
+        None => PrerequisiteCheck::fail(
+            NAME,
+            format!("could not read free space for {}", data_dir.display()),
+            "Check that cluster.data_dir points at a readable location",
+        ),
+    }
+}
+
+/// Host RAM against the memory the configured node limits need
+pub fn check_memory(total: Option<u64>, required: u64) -> PrerequisiteCheck {
+    const NAME: &str = "Memory";
+    match total {
+        Some(total) if total > required => PrerequisiteCheck::pass(
+            NAME,
+            format!(
+                "{} installed, {} needed for nodes",
+                format_gib(total),
+                format_gib(required)
+            ),
+        ),
+        Some(total) => PrerequisiteCheck::fail(
+            NAME,
+            format!(
+                "{} installed, but nodes are configured for {}",
+                format_gib(total),
+                format_gib(required)
+            ),
+            "Lower cluster.control_plane_memory / cluster.worker_memory or pass --memory to 'kina create'",
+        ),
+        None => PrerequisiteCheck::fail(
+            NAME,
+            "could not read installed memory",
+            "Run 'sysctl hw.memsize' to check the host",
+        ),
+    }
+}
+
+pub fn check_macos(major: Option<u32>) -> PrerequisiteCheck {
+    const NAME: &str = "macOS";
+    match major {
+        Some(major) if major >= HA_MIN_MACOS_MAJOR => {
+            PrerequisiteCheck::pass(NAME, format!("macOS {} (all features)", major))
+        }
+        Some(major) if major >= MIN_MACOS_MAJOR => PrerequisiteCheck::pass(
+            NAME,
+            format!(
+                "macOS {} (multiple control planes need macOS {})",
+                major, HA_MIN_MACOS_MAJOR
+            ),
+        ),
+        Some(major) => PrerequisiteCheck::fail(
+            NAME,
+            format!("macOS {} is not supported by Apple Container", major),
+            format!("Upgrade to macOS {} or later", MIN_MACOS_MAJOR),
+        ),
+        None => PrerequisiteCheck::fail(
+            NAME,
+            "could not detect a macOS version",
+            "kina runs on macOS with Apple Container",
+        ),
+    }
+}
+
+/// Bytes in a `<n><m|g>` memory value as produced by [`container_memory_arg`]
+pub fn memory_arg_bytes(arg: &str) -> Option<u64> {
+    let (number, unit) = arg.split_at(arg.len().checked_sub(1)?);
+    let number: u64 = number.parse().ok()?;
+    match unit {
+        "m" => Some(number * 1024 * 1024),
+        "g" => Some(number * 1024 * 1024 * 1024),
+        _ => None,
+    }
+}
+
+fn format_gib(bytes: u64) -> String {
+    format!("{:.1} GiB", bytes as f64 / (1024.0 * 1024.0 * 1024.0))
+}
+
+/// Free bytes on the filesystem holding `path` (or its nearest existing parent)
+fn available_disk_bytes(path: &Path) -> Option<u64> {
+    use std::os::unix::ffi::OsStrExt;
+
+    let existing = path.ancestors().find(|p| p.exists())?;
+    let c_path = std::ffi::CString::new(existing.as_os_str().as_bytes()).ok()?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    // SAFETY: c_path is NUL-terminated and stat is a valid out-pointer.
+    if unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) } != 0 {
+        return None;
+    }
+    Some(stat.f_bavail as u64 * stat.f_frsize as u64)
+}
+
+/// Installed RAM from `sysctl -n hw.memsize`
+fn total_memory_bytes() -> Option<u64> {
+    let output = std::process::Command::new("sysctl")
+        .args(["-n", "hw.memsize"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout).trim().parse().ok()
+}
+
+/// Run every check against this host and `config`
+pub fn run_checks(config: &Config) -> Vec<PrerequisiteCheck> {
+    let mut checks = Vec::new();
+
+    let client = AppleContainerClient::new(config);
+    checks.push(check_container_cli(
+        client
+            .as_ref()
+            .map_err(|e| format!("{:#}", e))
+            .and_then(|c| c.get_version().map_err(|e| format!("{:#}", e))),
+    ));
+
+    checks.push(check_kubectl(command_exists("kubectl")));
+
+    if let Ok(client) = &client {
+        let image = &config.cluster.default_image;
+        checks.push(check_kubeadm(
+            image,
+            client
+                .run_image_command(image, "kubeadm", &["version", "-o", "short"])
+                .map_err(|e| format!("{:#}", e)),
+        ));
+    }
+
+    checks.push(check_image_tool(
+        IMAGE_TOOLS
+            .iter()
+            .copied()
+            .find(|tool| command_exists(tool)),
+    ));
+
+    let data_dir = &config.cluster.data_dir;
+    checks.push(check_disk_space(data_dir, available_disk_bytes(data_dir)));
+
+    // The default cluster is a single node, which uses the control-plane limit.
+    let memory = container_memory_arg(&resolve_memory(
+        None,
+        config.cluster.control_plane_memory.as_deref(),
+        DEFAULT_NODE_MEMORY,
+    ));
+    let required = memory_arg_bytes(&memory).unwrap_or_default();
+    checks.push(check_memory(total_memory_bytes(), required));
+
+    checks.push(check_macos(macos_major_version()));
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GIB: u64 = 1024 * 1024 * 1024;
+
+    #[test]
+    fn thresholds_decide_pass_or_fail() {
+        assert!(check_container_cli(Ok(semver::Version::new(1, 0, 0))).passed);
+        let old = check_container_cli(Ok(semver::Version::new(0, 9, 0)));
+        assert!(!old.passed);
+        assert!(old.hint.unwrap().contains("1.0.0"));
+        assert!(!check_container_cli(Err("not found".into())).passed);
+
+        let dir = Path::new("/data");
+        assert!(check_disk_space(dir, Some(MIN_FREE_DISK_BYTES)).passed);
+        assert!(!check_disk_space(dir, Some(GIB)).passed);
+        assert!(!check_disk_space(dir, None).passed);
+
+        assert!(check_memory(Some(16 * GIB), 4 * GIB).passed);
+        assert!(!check_memory(Some(4 * GIB), 4 * GIB).passed);
+
+        assert!(check_macos(Some(26)).passed);
+        assert!(check_macos(Some(15)).detail.contains("macOS 26"));
+        assert!(!check_macos(Some(14)).passed);
+        assert!(check_image_tool(None).passed);
+        assert!(check_image_tool(None)
+            .render()
+            .starts_with("⚠️ Image builder"));
+    }
+
+    #[test]
+    fn render_marks_failures_with_hints() {
+        assert_eq!(check_kubectl(true).render(), "✅ kubectl: found in PATH");
+        assert_eq!(
+            check_kubectl(false).render(),
+            "❌ kubectl: not found in PATH\n   → Install kubectl, e.g. 'brew install kubectl'"
+        );
+    }
+
+    #[test]
+    fn memory_arg_bytes_reads_container_sizes() {
+        assert_eq!(memory_arg_bytes("4g"), Some(4 * GIB));
+        assert_eq!(memory_arg_bytes("512m"), Some(512 * 1024 * 1024));
+        assert_eq!(memory_arg_bytes("4x"), None);
+        assert_eq!(memory_arg_bytes(""), None);
+    }
+}
@@ -0,0 +1,391 @@
+//! Runtime-neutral descriptions of the containers and networks kina creates.
+//!
+//! A [`ContainerSpec`] says what a node container needs (labels, mounts,
+//! resources, ...) and a [`NetworkSpec`] what a network needs; the runtime
+//! client turns them into its own CLI arguments, e.g.
+//! [`crate::core::apple_container::container_run_args`].
+//!
+//! [`ClusterClientTrait`] is the set of runtime operations
+//! [`crate::core::cluster::ClusterManager`] drives, so the manager can run
+//! against [`crate::core::apple_container::AppleContainerClient`] or a test
+//! double.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::config::CniPlugin;
+use crate::core::kubernetes::ReadinessSnapshot;
+use crate::core::types::{
+    ClusterInfo, ContainerVersion, CreateClusterOptions, DeleteClusterOptions, LoadImageOptions,
+    NetworkInfo, NETWORK_CLUSTER_LABEL,
+};
+
+/// Container runtime operations used by `ClusterManager`.
+///
+/// Method contracts follow `AppleContainerClient`: node names are the
+/// container names (`<cluster>-control-plane`, `<cluster>-worker`, ...) and
+/// in-node commands run on the control plane unless a node is given.
+#[async_trait]
+pub trait ClusterClientTrait: Send + Sync {
+    /// Runtime CLI version string
+    fn version(&self) -> &str;
+
+    /// Runtime CLI version and build type
+    fn container_version(&self) -> Result<&ContainerVersion>;
+
+    async fn create_cluster(&self, options: &CreateClusterOptions) -> Result<()>;
+
+    /// Delete a cluster's nodes, giving each `options.grace_period` seconds to
+    /// stop; with `options.force`, delete them at once and carry on past
+    /// cleanup failures
+    async fn delete_cluster(&self, name: &str, options: &DeleteClusterOptions) -> Result<()>;
+
+    async fn rename_cluster(&self, old_name: &str, new_name: &str) -> Result<()>;
+
+    async fn pause_cluster(&self, name: &str) -> Result<()>;
+
+    /// Restart a paused cluster, returning the control plane IP
+    async fn resume_cluster(&self, name: &str) -> Result<String>;
+
+    async fn list_clusters(&self) -> Result<Vec<ClusterInfo>>;
+
+    async fn get_kubeconfig(&self, name: &str) -> Result<String>;
+
+    async fn repair_kubeconfig(&self, cluster_name: &str) -> Result<()>;
+
+    async fn load_image(&self, options: &LoadImageOptions) -> Result<()>;
+
+    /// Export `image` to a temp tarball, removed when the returned path is
+    /// dropped, that [`load_image`](Self::load_image) calls can share through
+    /// `LoadImageOptions::archive`
+    async fn export_image(&self, image: &str) -> Result<tempfile::TempPath>;
+
+    async fn pull_image(&self, image: &str, platform: Option<&str>) -> Result<()>;
+
+    async fn verify_image(&self, image: &str, platform: Option<&str>) -> Result<()>;
+
+    async fn list_networks(&self) -> Result<Vec<NetworkInfo>>;
+
+    async fn create_network(
+        &self,
+        name: &str,
+        subnet: Option<&str>,
+        labels: &BTreeMap<String, String>,
+    ) -> Result<NetworkInfo>;
+
+    async fn delete_network(&self, name: &str) -> Result<()>;
+
+    /// Runtime inspect data for one node container
+    async fn inspect_container(&self, name: &str) -> Result<serde_json::Value>;
+
+    async fn read_kubeadm_config(&self, cluster_name: &str) -> Result<String>;
+
+    async fn readiness_snapshot(&self, cluster_name: &str) -> Result<ReadinessSnapshot>;
+
+    /// Run a shell script on a node, returning its stdout
+    async fn run_node_script(&self, node_name: &str, script: &str) -> Result<String>;
+
+    async fn node_logs(&self, node_name: &str, lines: u32) -> Result<String>;
+
+    /// Run `command` in a node with this process's stdio attached (and a
+    /// terminal when `tty`), returning its exit code
+    async fn exec_attached(&self, node_name: &str, command: &[String], tty: bool) -> Result<i32>;
+
+    async fn install_cni(
+        &self,
+        cluster_name: &str,
+        cni: CniPlugin,
+        kernel_path: Option<&Path>,
+    ) -> Result<()>;
+
+    /// Best-effort approval of pending kubelet CSRs
+    fn approve_cluster_kubelet_csrs(&self, cluster_name: &str);
+
+    async fn apply_pod_security_labels(&self, cluster_name: &str, level: &str) -> Result<()>;
+
+    async fn create_image_pull_secrets(&self, cluster_name: &str, secret_name: &str) -> Result<()>;
+
+    async fn wait_for_kube_system_pods(
+        &self,
+        cluster_name: &str,
+        timeout_seconds: u64,
+    ) -> Result<()>;
+
+    async fn collect_failure_diagnostics(&self, cluster_name: &str, dest_dir: &Path) -> Result<()>;
+}
+
+/// Everything needed to start one detached node container
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerSpec {
+    /// Container name, also used as the node's hostname
+    pub name: String,
+    /// Image to run
+    pub image: String,
+    /// Command run as the container's entrypoint (e.g. `/sbin/init`)
+    pub command: Vec<String>,
+    /// Labels in the order given, as (key, value)
+    pub labels: Vec<(String, String)>,
+    /// Environment variables in the order given, as (name, value)
+    pub env: Vec<(String, String)>,
+    /// Paths mounted as tmpfs
+    pub tmpfs: Vec<String>,
+    /// CPU count; `None` uses the runtime default
+    pub cpus: Option<u32>,
+    /// Memory in the runtime's format (e.g. `4g`); `None` uses the runtime default
+    pub memory: Option<String>,
+    /// Custom kernel to boot the container VM with
+    pub kernel: Option<PathBuf>,
+    /// Host directories mounted into the container, as (host path, container path)
+    pub volumes: Vec<(PathBuf, String)>,
+}
+
+/// Builder for [`ContainerSpec`]
+#[derive(Debug, Clone)]
+pub struct ContainerSpecBuilder {
+    spec: ContainerSpec,
+}
+
+impl ContainerSpecBuilder {
+    /// Start a spec for a container called `name` running `image`
+    pub fn new(name: &str, image: &str) -> Self {
+        Self {
+            spec: ContainerSpec {
+                name: name.to_string(),
+                image: image.to_string(),
+                command: Vec::new(),
+                labels: Vec::new(),
+                env: Vec::new(),
+                tmpfs: Vec::new(),
+                cpus: None,
+                memory: None,
+                kernel: None,
+                volumes: Vec::new(),
+            },
+        }
+    }
+
+    /// Set the entrypoint command and its arguments
+    pub fn command<I, S>(mut self, command: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.spec.command = command.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Add a label; labels keep the order they are added in
+    pub fn label(mut self, key: &str, value: &str) -> Self {
+        self.spec.labels.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Add an environment variable
+    pub fn env(mut self, name: &str, value: &str) -> Self {
+        self.spec.env.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Mount a tmpfs at `path`
+    pub fn tmpfs(mut self, path: &str) -> Self {
+        self.spec.tmpfs.push(path.to_string());
+        self
+    }
+
+    /// Set the CPU count and memory (e.g. `4g`)
+    pub fn resources(mut self, cpus: u32, memory: &str) -> Self {
+        self.spec.cpus = Some(cpus);
+        self.spec.memory = Some(memory.to_string());
+        self
+    }
+
+    /// Boot with a custom kernel; `None` keeps the runtime default
+    pub fn kernel(mut self, kernel: Option<&Path>) -> Self {
+        self.spec.kernel = kernel.map(Path::to_path_buf);
+        self
+    }
+
+    /// Add host directory mounts, as (host path, container path)
+    pub fn volumes(mut self, volumes: &[(PathBuf, String)]) -> Self {
+        self.spec.volumes.extend_from_slice(volumes);
+        self
+    }
+
+    /// Finish the spec, rejecting empty names/images and malformed labels
+    pub fn build(self) -> Result<ContainerSpec> {
+        let spec = self.spec;
+        if spec.name.trim().is_empty() {
+            return Err(anyhow::anyhow!("container name must not be empty"));
+        }
+        if spec.image.trim().is_empty() {
+            return Err(anyhow::anyhow!("container '{}' has no image", spec.name));
+        }
+        if let Some((key, _)) = spec
+            .labels
+            .iter()
+            .find(|(key, _)| key.is_empty() || key.contains('='))
+        {
+            return Err(anyhow::anyhow!(
+                "container '{}' has an invalid label key '{}'",
+                spec.name,
+                key
+            ));
+        }
+        if spec.cpus == Some(0) {
+            return Err(anyhow::anyhow!(
+                "container '{}' needs at least one CPU",
+                spec.name
+            ));
+        }
+        Ok(spec)
+    }
+}
+
+/// A network to create
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkSpec {
+    /// Network name
+    pub name: String,
+    /// IPv4 subnet in CIDR form; `None` lets the runtime pick one
+    pub subnet: Option<String>,
+    /// Always carries [`NETWORK_CLUSTER_LABEL`]
+    pub labels: BTreeMap<String, String>,
+}
+
+/// Builder for [`NetworkSpec`]
+#[derive(Debug, Clone, Default)]
+pub struct NetworkSpecBuilder {
+    name: String,
+    subnet: Option<String>,
+    labels: BTreeMap<String, String>,
+}
+
+impl NetworkSpecBuilder {
+    /// Start an empty spec; a name must be set before [`Self::build`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the network name
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the IPv4 subnet in CIDR form; `None` lets the runtime pick one
+    pub fn subnet(mut self, subnet: Option<&str>) -> Self {
+        self.subnet = subnet.map(str::to_string);
+        self
+    }
+
+    /// Add labels, replacing any already set under the same key
+    pub fn labels(mut self, labels: &BTreeMap<String, String>) -> Self {
+        self.labels.extend(labels.clone());
+        self
+    }
+
+    /// Finish the spec. The network is labelled `io.kina.cluster=<name>`
+    /// unless the labels already set that label.
+    pub fn build(self) -> Result<NetworkSpec> {
+        if self.name.trim().is_empty() {
+            return Err(anyhow::anyhow!("network name must not be empty"));
+        }
+        if let Some(subnet) = &self.subnet {
+            if !crate::config::cluster_config::is_cidr(subnet) {
+                return Err(anyhow::anyhow!(
+                    "network '{}' has an invalid subnet '{}'",
+                    self.name,
+                    subnet
+                ));
+            }
+        }
+        let mut labels = self.labels;
+        labels
+            .entry(NETWORK_CLUSTER_LABEL.to_string())
+            .or_insert_with(|| self.name.clone());
+        Ok(NetworkSpec {
+            name: self.name,
+            subnet: self.subnet,
+            labels,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn network_builder_labels_networks_for_kina() {
+        let spec = NetworkSpecBuilder::new()
+            .name("lab")
+            .subnet(Some("10.50.0.0/24"))
+            .build()
+            .unwrap();
+        assert_eq!(spec.labels[NETWORK_CLUSTER_LABEL], "lab");
+
+        let labels = BTreeMap::from([(NETWORK_CLUSTER_LABEL.to_string(), "dev".to_string())]);
+        let spec = NetworkSpecBuilder::new()
+            .name("shared")
+            .labels(&labels)
+            .build()
+            .unwrap();
+        assert_eq!(spec.labels[NETWORK_CLUSTER_LABEL], "dev");
+        assert_eq!(spec.subnet, None);
+
+        assert!(NetworkSpecBuilder::new().build().is_err());
+        assert!(NetworkSpecBuilder::new()
+            .name("lab")
+            .subnet(Some("10.50.0.0"))
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn cluster_clients_can_be_shared_across_tasks() {
+        fn assert_send_sync<T: Send + Sync + ?Sized>() {}
+        fn assert_send<T: Send>(_: &T) {}
+
+        assert_send_sync::<dyn ClusterClientTrait>();
+        assert_send_sync::<crate::core::apple_container::AppleContainerClient>();
+        assert_send_sync::<crate::core::cluster::ClusterManager>();
+
+        let manager = crate::core::cluster::ClusterManager::new_with_client(
+            &crate::config::Config::default(),
+            Box::new(crate::core::mock::MockAppleContainerClient::new()),
+        );
+        assert_send(&manager.list_clusters());
+        let options = DeleteClusterOptions::default();
+        assert_send(&manager.delete_cluster("kina", &options));
+    }
+
+    #[test]
+    fn builder_keeps_order_and_validates() {
+        let spec = ContainerSpecBuilder::new("kina-control-plane", "kindest/node:v1.36.1")
+            .label("io.kina.cluster", "kina")
+            .label("io.kina.primary", "true")
+            .env("container", "docker")
+            .tmpfs("/tmp")
+            .resources(4, "4g")
+            .command(["/sbin/init"])
+            .build()
+            .unwrap();
+        assert_eq!(spec.labels[1], ("io.kina.primary".into(), "true".into()));
+        assert_eq!(spec.cpus, Some(4));
+        assert_eq!(spec.command, ["/sbin/init"]);
+        assert_eq!(spec.kernel, None);
+
+        assert!(ContainerSpecBuilder::new("", "img").build().is_err());
+        assert!(ContainerSpecBuilder::new("node", " ").build().is_err());
+        assert!(ContainerSpecBuilder::new("node", "img")
+            .label("a=b", "c")
+            .build()
+            .is_err());
+        assert!(ContainerSpecBuilder::new("node", "img")
+            .resources(0, "4g")
+            .build()
+            .is_err());
+    }
+}
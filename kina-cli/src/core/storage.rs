@@ -0,0 +1,154 @@
+//! Default StorageClass setup for `kina create --default-storage-class`.
+//!
+//! Node images ship no provisioner, so PVCs stay Pending until one is
+//! installed. The scripts here run `kubectl` on the control plane node (the
+//! host cannot always reach the API server) and mark the provisioner's
+//! StorageClass as the cluster default.
+
+/// local-path-provisioner release installed for `local-path`
+pub const LOCAL_PATH_PROVISIONER_VERSION: &str = "v0.0.30";
+
+/// StorageClass created by the local-path-provisioner manifest
+pub const LOCAL_PATH_STORAGE_CLASS: &str = "local-path";
+
+/// csi-driver-nfs release installed for `nfs`
+pub const CSI_DRIVER_NFS_VERSION: &str = "v4.9.0";
+
+/// StorageClass kina creates for `nfs`
+pub const NFS_STORAGE_CLASS: &str = "nfs-csi";
+
+const KUBECONFIG_EXPORT: &str = "export KUBECONFIG=/etc/kubernetes/admin.conf";
+
+const DEFAULT_CLASS_ANNOTATION: &str = "storageclass.kubernetes.io/is-default-class";
+
+/// Provisioner backing the cluster's default StorageClass
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageProvisioner {
+    /// rancher local-path-provisioner: hostPath volumes on the node
+    LocalPath,
+    /// csi-driver-nfs provisioning subdirectories of an NFS export
+    Nfs { server: String, path: String },
+}
+
+impl StorageProvisioner {
+    /// Name of the StorageClass this provisioner's setup creates
+    pub fn storage_class(&self) -> &'static str {
+        match self {
+            StorageProvisioner::LocalPath => LOCAL_PATH_STORAGE_CLASS,
+            StorageProvisioner::Nfs { .. } => NFS_STORAGE_CLASS,
+        }
+    }
+}
+
+pub fn local_path_manifest_url() -> String {
+    format!(
+        "https://raw.githubusercontent.com/rancher/local-path-provisioner/{}/deploy/local-path-storage.yaml",
+        LOCAL_PATH_PROVISIONER_VERSION
+    )
+}
+
+/// csi-driver-nfs install manifests, in apply order
+pub fn csi_driver_nfs_manifest_urls() -> Vec<String> {
+    [
+        "rbac-csi-nfs.yaml",
+        "csi-nfs-driverinfo.yaml",
+        "csi-nfs-controller.yaml",
+        "csi-nfs-node.yaml",
+    ]
+    .iter()
+    .map(|file| {
+        format!(
+            "https://raw.githubusercontent.com/kubernetes-csi/csi-driver-nfs/{0}/deploy/{0}/{1}",
+            CSI_DRIVER_NFS_VERSION, file
+        )
+    })
+    .collect()
+}
+
+/// Default StorageClass provisioning volumes as subdirectories of
+/// `server:path`
+pub fn nfs_storage_class_manifest(server: &str, path: &str) -> String {
+    format!(
+        "apiVersion: storage.k8s.io/v1
+kind: StorageClass
+metadata:
+  name: {name}
+  annotations:
+    {annotation}: \"true\"
+provisioner: nfs.csi.k8s.io
+parameters:
+  server: {server}
+  share: {path}
+reclaimPolicy: Delete
+volumeBindingMode: Immediate
+mountOptions:
+  - nfsvers=4.1
+",
+        name = NFS_STORAGE_CLASS,
+        annotation = DEFAULT_CLASS_ANNOTATION,
+        server = server,
+        path = path
+    )
+}
+
+/// Shell script installing `provisioner` and making its StorageClass the
+/// default, for `run_node_script` on the control plane
+pub fn setup_script(provisioner: &StorageProvisioner) -> String {
+    match provisioner {
+        StorageProvisioner::LocalPath => format!(
+            "set -e; {export}; \
+             kubectl apply -f {url}; \
+             kubectl patch storageclass {class} -p \
+             '{{\"metadata\":{{\"annotations\":{{\"{annotation}\":\"true\"}}}}}}'",
+            export = KUBECONFIG_EXPORT,
+            url = local_path_manifest_url(),
+            class = LOCAL_PATH_STORAGE_CLASS,
+            annotation = DEFAULT_CLASS_ANNOTATION,
+        ),
+        StorageProvisioner::Nfs { server, path } => {
+            let applies: Vec<String> = csi_driver_nfs_manifest_urls()
+                .iter()
+                .map(|url| format!("kubectl apply -f {}", url))
+                .collect();
+            format!(
+                "set -e; {export}; {applies}; \
+                 kubectl apply -f - <<'KINA_EOF'\n{manifest}KINA_EOF\n",
+                export = KUBECONFIG_EXPORT,
+                applies = applies.join("; "),
+                manifest = nfs_storage_class_manifest(server, path),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_path_setup_marks_class_default() {
+        let script = setup_script(&StorageProvisioner::LocalPath);
+        assert!(script.contains(&local_path_manifest_url()));
+        assert!(script.contains(
+            "kubectl patch storageclass local-path -p \
+             '{\"metadata\":{\"annotations\":{\"storageclass.kubernetes.io/is-default-class\":\"true\"}}}'"
+        ));
+    }
+
+    #[test]
+    fn nfs_setup_installs_driver_then_default_class() {
+        let provisioner = StorageProvisioner::Nfs {
+            server: "192.168.64.1".to_string(),
+            path: "/exports/k8s".to_string(),
+        };
+        let script = setup_script(&provisioner);
+        let urls = csi_driver_nfs_manifest_urls();
+        let driver = script.find(urls.last().unwrap().as_str()).unwrap();
+        let class = script.find("kind: StorageClass").unwrap();
+        assert!(driver < class);
+        assert!(script.contains("  server: 192.168.64.1\n  share: /exports/k8s\n"));
+        assert!(script.contains("is-default-class: \"true\""));
+        assert!(script.ends_with("KINA_EOF\n"));
+        assert_eq!(provisioner.storage_class(), "nfs-csi");
+    }
+}
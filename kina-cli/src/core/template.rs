@@ -0,0 +1,111 @@
+//! Named cluster templates for `kina template` and `kina create --from-template`.
+//!
+//! A template is a saved list of `kina create` flags, stored as TOML at
+//! `<data_dir>/templates/<name>.toml`. Keeping the flags rather than a parsed
+//! config means a template accepts exactly what `kina create` does.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Subdirectory of the data directory holding templates
+pub const TEMPLATE_DIR: &str = "templates";
+
+/// A saved set of `kina create` flags
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClusterTemplate {
+    /// Flags as given on the command line, e.g. `["--workers", "2"]`
+    pub args: Vec<String>,
+}
+
+/// Directory holding the templates under `data_dir`
+pub fn templates_dir(data_dir: &Path) -> PathBuf {
+    data_dir.join(TEMPLATE_DIR)
+}
+
+/// File of template `name`. Names are letters, digits, `-` and `_` so they
+/// cannot escape the templates directory.
+pub fn template_path(data_dir: &Path, name: &str) -> Result<PathBuf> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(anyhow::anyhow!(
+            "invalid template name \"{}\"; use letters, digits, '-' and '_'",
+            name
+        ));
+    }
+    Ok(templates_dir(data_dir).join(format!("{}.toml", name)))
+}
+
+pub fn save_template(data_dir: &Path, name: &str, template: &ClusterTemplate) -> Result<PathBuf> {
+    let path = template_path(data_dir, name)?;
+    std::fs::create_dir_all(templates_dir(data_dir))
+        .with_context(|| format!("Failed to create {}", templates_dir(data_dir).display()))?;
+    let contents = toml::to_string(template).context("Failed to serialize template")?;
+    std::fs::write(&path, contents)
+        .with_context(|| format!("Failed to write template {}", path.display()))?;
+    Ok(path)
+}
+
+pub fn load_template(data_dir: &Path, name: &str) -> Result<ClusterTemplate> {
+    let path = template_path(data_dir, name)?;
+    let contents = std::fs::read_to_string(&path)
+        .with_context(|| format!("Template '{}' not found (see 'kina template list')", name))?;
+    toml::from_str(&contents).with_context(|| format!("Invalid template {}", path.display()))
+}
+
+/// Names of the saved templates, sorted
+pub fn list_templates(data_dir: &Path) -> Result<Vec<String>> {
+    let dir = templates_dir(data_dir);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut names: Vec<String> = std::fs::read_dir(&dir)
+        .with_context(|| format!("Failed to read {}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .filter_map(|path| path.file_stem().map(|s| s.to_string_lossy().into_owned()))
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+pub fn delete_template(data_dir: &Path, name: &str) -> Result<()> {
+    let path = template_path(data_dir, name)?;
+    if !path.exists() {
+        return Err(anyhow::anyhow!("Template '{}' not found", name));
+    }
+    std::fs::remove_file(&path)
+        .with_context(|| format!("Failed to delete template {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn templates_round_trip_through_the_data_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let template = ClusterTemplate {
+            args: vec![
+                "--workers".into(),
+                "2".into(),
+                "--cni".into(),
+                "cilium".into(),
+            ],
+        };
+        let path = save_template(dir.path(), "ci", &template).unwrap();
+        assert_eq!(path, dir.path().join("templates").join("ci.toml"));
+        save_template(dir.path(), "a-small", &ClusterTemplate::default()).unwrap();
+
+        assert_eq!(load_template(dir.path(), "ci").unwrap(), template);
+        assert_eq!(list_templates(dir.path()).unwrap(), ["a-small", "ci"]);
+
+        delete_template(dir.path(), "ci").unwrap();
+        assert!(load_template(dir.path(), "ci").is_err());
+        assert!(delete_template(dir.path(), "ci").is_err());
+        assert!(template_path(dir.path(), "../escape").is_err());
+    }
+}
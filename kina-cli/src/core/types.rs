@@ -1,7 +1,10 @@
 #![allow(dead_code)]
+use crate::config::cluster_config::{EtcdConfig, NetworkingConfig, OidcConfig};
 use crate::config::CniPlugin;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
+use tabled::Tabled;
 
 // Re-export types from config module to avoid duplication
 // NodeRole is defined in this module, no need to re-export
@@ -23,10 +26,82 @@ pub struct CreateClusterOptions {
     pub control_plane_nodes: Option<u32>,
     /// Wait timeout for cluster readiness
     pub wait_timeout: Option<u64>,
+    /// Additionally wait until every kube-system pod is Running or Succeeded
+    pub wait_for_ready: bool,
+    /// Return as soon as the nodes are created, without any readiness wait
+    pub no_wait: bool,
     /// Retain cluster on failure
     pub retain_on_failure: bool,
     /// Skip automatic kubelet CSR approval
     pub skip_csr_approval: bool,
+    /// Skip writing and merging the host kubeconfig after cluster init
+    pub skip_kubeconfig: bool,
+    /// OIDC provider to configure on the API server
+    pub oidc: Option<OidcConfig>,
+    /// Enable API server audit logging
+    pub audit_log: bool,
+    /// Audit policy to use instead of the bundled default (requires `audit_log`)
+    pub audit_policy: Option<AuditPolicy>,
+    /// Name of the registry Secret created from `pull_secret_config`
+    /// (`--image-pull-secret`)
+    pub image_pull_secret: Option<String>,
+    /// Docker `config.json` with registry credentials, copied to every node
+    /// for the kubelet (`--docker-config-json`)
+    pub pull_secret_config: Option<PathBuf>,
+    /// Registries (`host[:port]`) containerd on every node pulls from over
+    /// plain HTTP without TLS verification (`--insecure-registry`)
+    pub insecure_registries: Vec<String>,
+    /// Bootstrap token (`<id>.<secret>`) kubeadm init creates instead of a
+    /// random one; nodes join with it (`--node-bootstrap-token`)
+    pub bootstrap_token: Option<String>,
+    /// `KubeSchedulerConfiguration` file kube-scheduler runs with
+    /// (`--scheduler-config`)
+    pub scheduler_config_file: Option<PathBuf>,
+    /// containerd `config.toml` snippet merged into every node's
+    /// `/etc/containerd/config.toml` before kubeadm runs (`--containerd-config`)
+    pub containerd_config: Option<String>,
+    /// `KubeletConfiguration` file merged over kina's kubelet defaults
+    /// (`--kubelet-config`)
+    pub kubelet_config_file: Option<PathBuf>,
+    /// Extra API server certificate SANs (hostnames or IPs) on top of the
+    /// node IP, node name and localhost (`--apiserver-san`)
+    pub api_server_sans: Vec<String>,
+    /// Container runtime the kubelet talks to (`--cri-o`)
+    pub container_runtime: ContainerRuntime,
+    /// Have kubeadm ignore its Swap preflight check (`--disable-swap-check`)
+    pub disable_swap_check: bool,
+    /// Have kubeadm ignore every preflight error (`--skip-kubeadm-preflight`)
+    pub skip_preflight: bool,
+    /// Port the API server listens on (`--api-server-port`); `None` is
+    /// [`DEFAULT_API_SERVER_PORT`]
+    pub api_server_port: Option<u16>,
+    /// Kubernetes node name for a single-node cluster's node (`--node-name`);
+    /// the container keeps its `<cluster>-control-plane` name
+    pub node_name: Option<String>,
+    /// Labels applied to every node once the cluster is up (`--node-extra-label`)
+    pub node_labels: BTreeMap<String, String>,
+    /// Pull `image` before creating the nodes even if it is already present
+    pub node_image_pull_always: bool,
+    /// Pod Security Standards level enforced on the `default` namespace
+    /// (`--psp`); also enables the PodSecurity admission plugin
+    pub pod_security_standard: Option<String>,
+    /// Skip installing a CNI plugin; pods stay NotReady until one is installed
+    pub skip_cni: bool,
+    /// Overrides for the kubeadm `networking` section (`--networking-config`)
+    pub networking: Option<NetworkingConfig>,
+    /// Extra kube-apiserver flags (`--api-server-extra-arg`), keyed without dashes
+    pub api_server_extra_args: BTreeMap<String, String>,
+    /// Extra kubelet flags for every node (`--kubelet-extra-arg`), keyed without dashes
+    pub kubelet_extra_args: BTreeMap<String, String>,
+    /// Extra kube-controller-manager flags (`--controller-manager-extra-arg`)
+    pub controller_manager_extra_args: BTreeMap<String, String>,
+    /// kube-vip VIP of an HA control plane (`--control-plane-vip`); `None`
+    /// picks a free address from `.250-.254` of the node network
+    pub control_plane_vip: Option<String>,
+    /// Extra etcd flags (`--etcd-extra-arg`), applied over `etcd.extra_args`
+    pub etcd_extra_args: BTreeMap<String, String>,
+    /// Local etcd settings from the cluster config file
+    pub etcd: Option<EtcdConfig>,
     /// CNI plugin to use
     pub cni_plugin: CniPlugin,
     /// Optional path to a custom Linux kernel for node containers.
@@ -51,6 +126,67 @@ pub struct CreateClusterOptions {
     pub worker_memory: String,
 }
 
+/// Apple Container CLI version, as reported by `container version --output json`
+/// (or `container --version`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContainerVersion {
+    /// Semantic version, e.g. `1.0.0`
+    pub version: String,
+    /// Build type (e.g. `release`), or `unknown`
+    pub build: String,
+}
+
+/// API server audit level, as defined by the Kubernetes audit policy spec
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditLevel {
+    /// Record nothing
+    None,
+    /// Record request metadata (user, verb, resource, ...) but no bodies
+    Metadata,
+    /// Record metadata and request bodies
+    Request,
+    /// Record metadata, request and response bodies
+    RequestResponse,
+}
+
+impl std::fmt::Display for AuditLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditLevel::None => write!(f, "none"),
+            AuditLevel::Metadata => write!(f, "metadata"),
+            AuditLevel::Request => write!(f, "request"),
+            AuditLevel::RequestResponse => write!(f, "requestresponse"),
+        }
+    }
+}
+
+impl std::str::FromStr for AuditLevel {
+    type Err = anyhow::Error;
+
+    /// Case-insensitive, so the spec's `RequestResponse` spelling also parses.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "none" => Ok(AuditLevel::None),
+            "metadata" => Ok(AuditLevel::Metadata),
+            "request" => Ok(AuditLevel::Request),
+            "requestresponse" => Ok(AuditLevel::RequestResponse),
+            other => Err(anyhow::anyhow!(
+                "unknown audit level '{}'; expected one of: none, metadata, request, requestresponse",
+                other
+            )),
+        }
+    }
+}
+
+/// Audit policy for `--audit-log`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AuditPolicy {
+    /// kina's bundled policy for one level
+    Level(AuditLevel),
+    /// A policy YAML file on the host
+    File(PathBuf),
+}
+
 /// Options for loading images into a cluster
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LoadImageOptions {
@@ -62,31 +198,76 @@ pub struct LoadImageOptions {
     pub archive: Option<PathBuf>,
 }
 
+/// Seconds a node gets to stop cleanly on `kina delete` before it is killed
+pub const DEFAULT_DELETE_GRACE_PERIOD: u64 = 30;
+
+/// Options for deleting a cluster
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeleteClusterOptions {
+    /// Seconds each node gets to stop cleanly before it is killed
+    /// (`--grace-period`)
+    pub grace_period: u64,
+    /// Skip the stop sequence, `container delete --force` every node and carry
+    /// on past any cleanup error (`--force`)
+    pub force: bool,
+}
+
+impl Default for DeleteClusterOptions {
+    fn default() -> Self {
+        Self {
+            grace_period: DEFAULT_DELETE_GRACE_PERIOD,
+            force: false,
+        }
+    }
+}
+
+/// Column width for the IMAGE column of `kina list --output table`.
+pub const IMAGE_COLUMN_WIDTH: usize = 40;
+
 /// Information about a cluster
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// The `Tabled` derive drives `kina list --output table`: NAME, STATUS, NODES,
+/// IMAGE (truncated to `IMAGE_COLUMN_WIDTH`) and CREATED.
+#[derive(Debug, Clone, Serialize, Deserialize, Tabled)]
 pub struct ClusterInfo {
     /// Name of the cluster
+    #[tabled(rename = "NAME", order = 0)]
     pub name: String,
     /// Image used for the cluster nodes
+    #[tabled(
+        rename = "IMAGE",
+        order = 3,
+        display("crate::utils::text::truncate", IMAGE_COLUMN_WIDTH)
+    )]
     pub image: String,
     /// Current status of the cluster
+    #[tabled(rename = "STATUS", order = 1)]
     pub status: ClusterStatus,
-    /// Creation timestamp
+    /// Creation time for display ("YYYY-MM-DD HH:MM UTC" or "unknown")
+    #[tabled(rename = "CREATED", order = 4)]
     pub created: String,
+    /// Creation time of the oldest node, when the runtime reports it
+    #[tabled(skip)]
+    #[serde(default)]
+    pub creation_timestamp: Option<chrono::DateTime<chrono::Utc>>,
     /// List of nodes in the cluster
+    #[tabled(rename = "NODES", order = 2, format("{}", self.nodes.len()))]
     pub nodes: Vec<NodeInfo>,
     /// Path to kubeconfig file
+    #[tabled(skip)]
     pub kubeconfig_path: Option<String>,
 }
 
-/// Status of a cluster
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// Status of a cluster. Usable as a CLI argument (e.g. `kina list --filter`);
+/// `paused` is accepted for `Stopped` there as in [`std::str::FromStr`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, clap::ValueEnum)]
 pub enum ClusterStatus {
     /// Cluster is running normally
     Running,
     /// Cluster is being created
     Creating,
     /// Cluster is stopped
+    #[value(alias = "paused")]
     Stopped,
     /// Cluster is in an error state
     Error,
@@ -110,6 +291,26 @@ impl std::fmt::Display for ClusterStatus {
     }
 }
 
+impl std::str::FromStr for ClusterStatus {
+    type Err = anyhow::Error;
+
+    /// Case-insensitive parse. `paused` is accepted as an alias for `Stopped`:
+    /// Apple Container has no separate paused state for node VMs.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "running" => Ok(ClusterStatus::Running),
+            "creating" => Ok(ClusterStatus::Creating),
+            "stopped" | "paused" => Ok(ClusterStatus::Stopped),
+            "error" => Ok(ClusterStatus::Error),
+            "unknown" => Ok(ClusterStatus::Unknown),
+            other => Err(anyhow::anyhow!(
+                "unknown cluster status '{}'; expected one of: running, stopped, paused, error, creating, unknown",
+                other
+            )),
+        }
+    }
+}
+
 /// Information about a node in a cluster
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NodeInfo {
@@ -127,6 +328,36 @@ pub struct NodeInfo {
     pub ip_address: Option<String>,
 }
 
+/// Container runtime behind the kubelet's CRI socket
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ContainerRuntime {
+    /// containerd, which every kindest/node image runs
+    #[default]
+    Containerd,
+    /// CRI-O; the node image must ship `crio` (`--cri-o`)
+    CriO,
+}
+
+impl ContainerRuntime {
+    /// `nodeRegistration.criSocket` for kubeadm
+    pub fn cri_socket(&self) -> &'static str {
+        match self {
+            ContainerRuntime::Containerd => "unix:///run/containerd/containerd.sock",
+            ContainerRuntime::CriO => "unix:///var/run/crio/crio.sock",
+        }
+    }
+}
+
+impl std::fmt::Display for ContainerRuntime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContainerRuntime::Containerd => write!(f, "containerd"),
+            ContainerRuntime::CriO => write!(f, "cri-o"),
+        }
+    }
+}
+
 /// Node role enumeration
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "kebab-case")]
@@ -144,6 +375,63 @@ impl std::fmt::Display for NodeRole {
     }
 }
 
+impl std::str::FromStr for NodeRole {
+    type Err = anyhow::Error;
+
+    /// Accepts the Kubernetes spelling (`control-plane`, `worker`) and the
+    /// variant names (`ControlPlane`, `Worker`), case-insensitively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "control-plane" | "controlplane" => Ok(NodeRole::ControlPlane),
+            "worker" => Ok(NodeRole::Worker),
+            other => Err(anyhow::anyhow!(
+                "unknown node role '{}'; expected one of: control-plane, worker",
+                other
+            )),
+        }
+    }
+}
+
+/// An Apple Container network, from `container network list --format json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NetworkInfo {
+    /// Network name (`id`)
+    pub name: String,
+    /// State reported by Apple Container, e.g. `running`
+    pub state: String,
+    /// IPv4 subnet in CIDR form, once assigned
+    pub subnet: Option<String>,
+    pub labels: BTreeMap<String, String>,
+}
+
+impl NetworkInfo {
+    /// The `io.kina.cluster` label, present on networks kina manages
+    pub fn kina_cluster(&self) -> Option<&str> {
+        self.labels.get(NETWORK_CLUSTER_LABEL).map(String::as_str)
+    }
+}
+
+/// Label marking kina-managed networks (and containers) with their cluster
+pub const NETWORK_CLUSTER_LABEL: &str = "io.kina.cluster";
+
+/// Label recording the API server port on control-plane containers. Clusters
+/// created before the label existed listen on [`DEFAULT_API_SERVER_PORT`].
+pub const API_SERVER_PORT_LABEL: &str = "io.kina.api-server-port";
+
+/// Label recording the CNI plugin installed on control-plane containers, or
+/// [`NO_CNI_LABEL_VALUE`] for `--no-default-cni`
+pub const CNI_LABEL: &str = "io.kina.cni";
+
+/// [`CNI_LABEL`] value for clusters created without a CNI
+pub const NO_CNI_LABEL_VALUE: &str = "none";
+
+/// Label recording the [`ContainerRuntime`] on control-plane containers.
+/// Clusters created before the label existed run containerd.
+pub const CONTAINER_RUNTIME_LABEL: &str = "io.kina.container-runtime";
+
+/// Port kubeadm binds the API server to unless `--api-server-port` is given
+pub const DEFAULT_API_SERVER_PORT: u16 = 6443;
+
 /// Information extracted from kubeadm init output needed for worker joins
 #[derive(Debug, Clone)]
 pub struct KubeadmJoinInfo {
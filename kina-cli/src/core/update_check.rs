@@ -0,0 +1,264 @@
+//! `kina update-check` — compare the running kina against the latest GitHub release.
+//!
+//! The latest release is cached in `~/.local/share/kina/update-check.json` for
+//! `UPDATE_CHECK_TTL_SECS`, so the automatic startup check costs at most one
+//! request a day. A failed lookup is cached too and not retried for
+//! `UPDATE_CHECK_RETRY_SECS`, so an offline machine doesn't wait on GitHub for
+//! every command. Set `KINA_NO_UPDATE_CHECK=1` to turn the startup check off.
+//!
+//! Everything except the release lookup and the cache load/save helpers is pure.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::{debug, warn};
+
+use super::image_registry::cache_dir;
+
+/// GitHub endpoint for the newest published kina release.
+pub const LATEST_RELEASE_URL: &str = "https://api.github.com/repos/vinnie357/kina/releases/latest";
+
+/// How long a cached release lookup is trusted before GitHub is queried again.
+pub const UPDATE_CHECK_TTL_SECS: i64 = 24 * 60 * 60;
+
+/// How long after a failed lookup GitHub is left alone.
+pub const UPDATE_CHECK_RETRY_SECS: i64 = 60 * 60;
+
+/// Environment variable that disables the automatic startup check when set to `1`.
+pub const NO_UPDATE_CHECK_ENV: &str = "KINA_NO_UPDATE_CHECK";
+
+/// The newest release as last seen on GitHub.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateCheckCache {
+    /// Unix timestamp (seconds) of the lookup
+    pub checked_at: i64,
+    /// Release tag, e.g. `v0.3.0`
+    pub latest_version: String,
+    /// Release page to download it from
+    pub url: String,
+    /// Unix timestamp (seconds) of the last failed lookup since `checked_at`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failed_at: Option<i64>,
+}
+
+impl UpdateCheckCache {
+    /// True while the cache is younger than `UPDATE_CHECK_TTL_SECS`.
+    pub fn is_fresh(&self, now: i64) -> bool {
+        now - self.checked_at < UPDATE_CHECK_TTL_SECS
+    }
+
+    /// True while a failed lookup is younger than `UPDATE_CHECK_RETRY_SECS`.
+    pub fn is_backing_off(&self, now: i64) -> bool {
+        self.failed_at
+            .is_some_and(|failed_at| now - failed_at < UPDATE_CHECK_RETRY_SECS)
+    }
+
+    /// True when a lookup has ever succeeded, i.e. `latest_version` is real
+    /// rather than the placeholder of a cache that only records a failure.
+    pub fn has_release(&self) -> bool {
+        !self.latest_version.is_empty()
+    }
+
+    /// `previous` with a failed lookup at `now` recorded.
+    pub fn with_failure(previous: Option<Self>, now: i64) -> Self {
+        let mut cache = previous.unwrap_or(UpdateCheckCache {
+            checked_at: 0,
+            latest_version: String::new(),
+            url: String::new(),
+            failed_at: None,
+        });
+        cache.failed_at = Some(now);
+        cache
+    }
+
+    /// Load a cache file; a missing or unreadable file yields `None`.
+    pub fn load(path: &Path) -> Option<Self> {
+        let contents = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    /// Write the cache file, creating its parent directory.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+/// `~/.local/share/kina/update-check.json`
+pub fn update_check_path() -> PathBuf {
+    cache_dir().join("update-check.json")
+}
+
+/// True when `latest` is a newer semver than `current`. A leading `v` is
+/// ignored; anything that isn't semver never counts as an update.
+pub fn is_newer(current: &str, latest: &str) -> bool {
+    let parse = |v: &str| semver::Version::parse(v.trim().trim_start_matches('v')).ok();
+    match (parse(current), parse(latest)) {
+        (Some(current), Some(latest)) => latest > current,
+        _ => false,
+    }
+}
+
+/// True unless `KINA_NO_UPDATE_CHECK` is set to `1`.
+pub fn startup_check_enabled(env_value: Option<&str>) -> bool {
+    env_value.map(str::trim) != Some("1")
+}
+
+/// Query GitHub for the latest release.
+async fn fetch_latest_release(timeout: Duration, now: i64) -> Result<UpdateCheckCache> {
+    debug!("Querying {}", LATEST_RELEASE_URL);
+    let release: serde_json::Value = reqwest::Client::builder()
+        .timeout(timeout)
+        .build()?
+        .get(LATEST_RELEASE_URL)
+        .header("User-Agent", "kina")
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await
+        .context("Failed to query kina releases")?
+        .error_for_status()
+        .context("kina releases API returned an error")?
+        .json()
+        .await
+        .context("Failed to parse kina release response")?;
+
+    let latest_version = release["tag_name"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("Latest kina release has no tag_name"))?
+        .to_string();
+    let url = release["html_url"]
+        .as_str()
+        .map(str::to_string)
+        .unwrap_or_else(|| {
+            format!(
+                "https://github.com/vinnie357/kina/releases/tag/{}",
+                latest_version
+            )
+        });
+    Ok(UpdateCheckCache {
+        checked_at: now,
+        latest_version,
+        url,
+        failed_at: None,
+    })
+}
+
+/// The latest release, from the cache while it is fresh (unless `force`) and
+/// from GitHub otherwise. A failed lookup is recorded in the cache and falls
+/// back to a stale release; until `UPDATE_CHECK_RETRY_SECS` have passed, GitHub
+/// is not queried again unless `force` is set.
+pub async fn latest_release(force: bool, timeout: Duration) -> Result<UpdateCheckCache> {
+    let path = update_check_path();
+    let now = chrono::Utc::now().timestamp();
+    let cached = UpdateCheckCache::load(&path);
+
+    if !force {
+        if let Some(cache) = cached
+            .as_ref()
+            .filter(|c| c.has_release() && c.is_fresh(now))
+        {
+            debug!("Using cached update check from {}", path.display());
+            return Ok(cache.clone());
+        }
+        if let Some(cache) = cached.as_ref().filter(|c| c.is_backing_off(now)) {
+            debug!("Last update check failed recently; not querying GitHub");
+            return if cache.has_release() {
+                Ok(cache.clone())
+            } else {
+                Err(anyhow::anyhow!(
+                    "Last update check failed; retrying after {} minutes (use --force to retry now)",
+                    UPDATE_CHECK_RETRY_SECS / 60
+                ))
+            };
+        }
+    }
+
+    match fetch_latest_release(timeout, now).await {
+        Ok(release) => {
+            if let Err(e) = release.save(&path) {
+                warn!("Failed to cache update check: {}", e);
+            }
+            Ok(release)
+        }
+        Err(e) => {
+            let failed = UpdateCheckCache::with_failure(cached, now);
+            if let Err(e) = failed.save(&path) {
+                warn!("Failed to cache update check failure: {}", e);
+            }
+            if failed.has_release() {
+                debug!("{}; using cached update check from {}", e, path.display());
+                Ok(failed)
+            } else {
+                Err(e)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_newer_compares_semver_with_or_without_v() {
+        assert!(is_newer("0.1.0", "v0.2.0"));
+        assert!(is_newer("0.1.9", "0.1.10"));
+        assert!(!is_newer("0.2.0", "v0.2.0"));
+        assert!(!is_newer("0.3.0", "v0.2.0"));
+        assert!(!is_newer("0.1.0", "nightly"));
+    }
+
+    #[test]
+    fn prerelease_is_older_than_its_release() {
+        assert!(is_newer("1.0.0-rc.1", "v1.0.0"));
+        assert!(!is_newer("1.0.0", "v1.0.0-rc.1"));
+    }
+
+    #[test]
+    fn cache_is_fresh_for_a_day() {
+        let cache = UpdateCheckCache {
+            checked_at: 1_000,
+            latest_version: "v0.2.0".to_string(),
+            url: "https://github.com/vinnie357/kina/releases/tag/v0.2.0".to_string(),
+            failed_at: None,
+        };
+        assert!(cache.is_fresh(1_000 + UPDATE_CHECK_TTL_SECS - 1));
+        assert!(!cache.is_fresh(1_000 + UPDATE_CHECK_TTL_SECS));
+    }
+
+    #[test]
+    fn failed_lookup_is_cached_and_backed_off() {
+        let failed = UpdateCheckCache::with_failure(None, 5_000);
+        assert!(!failed.has_release());
+        assert!(failed.is_backing_off(5_000 + UPDATE_CHECK_RETRY_SECS - 1));
+        assert!(!failed.is_backing_off(5_000 + UPDATE_CHECK_RETRY_SECS));
+
+        let stale = UpdateCheckCache {
+            checked_at: 1_000,
+            latest_version: "v0.2.0".to_string(),
+            url: "https://github.com/vinnie357/kina/releases/tag/v0.2.0".to_string(),
+            failed_at: None,
+        };
+        let failed = UpdateCheckCache::with_failure(Some(stale), 5_000);
+        assert!(failed.has_release());
+        assert_eq!(failed.latest_version, "v0.2.0");
+        assert_eq!(failed.failed_at, Some(5_000));
+
+        let round_trip: UpdateCheckCache =
+            serde_json::from_str(r#"{"checked_at":1,"latest_version":"v0.1.0","url":"u"}"#)
+                .unwrap();
+        assert_eq!(round_trip.failed_at, None);
+    }
+
+    #[test]
+    fn startup_check_is_disabled_only_by_one() {
+        assert!(startup_check_enabled(None));
+        assert!(startup_check_enabled(Some("0")));
+        assert!(!startup_check_enabled(Some("1")));
+    }
+}
@@ -345,18 +345,19 @@ pub fn demo_route_type(c: ActiveController) -> DemoRouteType {
 // kina-39 — host kubeconfig reachability (pure seams)
 // ===========================================================================
 
-/// Rewrite the `server:` line(s) in a kubeconfig YAML to point at `vm_ip:6443`.
+/// Rewrite the `server:` line(s) in a kubeconfig YAML to point at `vm_ip:port`.
 ///
 /// Walks lines and replaces any line whose trimmed content starts with
 /// `server: https://` with the same leading whitespace followed by
-/// `server: https://<vm_ip>:6443`.  Port is fixed at 6443.
+/// `server: https://<vm_ip>:<port>`, `port` being the API server port
+/// (6443 unless the cluster was created with `--api-server-port`).
 /// All other lines are left verbatim.  Idempotent: if the server already
-/// points at `https://<vm_ip>:6443`, the output equals the input exactly.
+/// points at `https://<vm_ip>:<port>`, the output equals the input exactly.
 ///
 /// This supersedes the ad-hoc `replace("https://127.0.0.1:6443", ...)` block
 /// that was inlined in `apple_container::setup_kubeconfig` and handles any
 /// existing server host (localhost, 127.0.0.1, old VM IP, internal cluster IP).
-pub fn rewrite_kubeconfig_server(kubeconfig_yaml: &str, vm_ip: &str) -> String {
+pub fn rewrite_kubeconfig_server(kubeconfig_yaml: &str, vm_ip: &str, port: u16) -> String {
     let ends_with_newline = kubeconfig_yaml.ends_with('\n');
     let mut result = kubeconfig_yaml
         .lines()
@@ -365,7 +366,7 @@ pub fn rewrite_kubeconfig_server(kubeconfig_yaml: &str, vm_ip: &str) -> String {
                 // Preserve the leading whitespace so YAML indentation is unchanged.
                 let leading_len = line.len() - line.trim_start().len();
                 let leading = &line[..leading_len];
-                format!("{}server: https://{}:6443", leading, vm_ip)
+                format!("{}server: https://{}:{}", leading, vm_ip, port)
             } else {
                 line.to_string()
             }
@@ -69,6 +69,9 @@ pub enum ClusterError {
     #[error("Cluster '{name}' not found")]
     NotFound { name: String },
 
+    #[error("Cannot rename: cluster '{name}' already exists")]
+    RenameConflict { name: String },
+
     #[error("Cluster '{name}' is not running")]
     NotRunning { name: String },
 
@@ -86,6 +89,9 @@ pub enum ClusterError {
 
     #[error("Kubeconfig error: {reason}")]
     KubeconfigError { reason: String },
+
+    #[error("Failed to drain node '{node}': {reason}")]
+    NodeDrainFailed { node: String, reason: String },
 }
 
 /// Apple Container specific errors
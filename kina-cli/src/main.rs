@@ -3,14 +3,9 @@ use clap::{CommandFactory, FromArgMatches};
 use tracing::{info, Level};
 use tracing_subscriber::FmtSubscriber;
 
-mod cli;
-mod config;
-mod core;
-mod errors;
-mod version;
-
-use cli::Cli;
-use config::Config;
+use kina_cli::cli::{self, Cli};
+use kina_cli::config::Config;
+use kina_cli::version;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -26,7 +21,8 @@ async fn main() -> Result<()> {
         .unwrap_or_else(|| version_str.clone());
     let matches = Cli::command().version(clap_version).get_matches();
     // Parse command line arguments first so we can set log level
-    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    let mut cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    cli.record_explicit_args(&matches);
 
     // Initialize tracing subscriber — write to stderr so stdout stays clean for JSON/machine output
     let log_level = if cli.quiet {
@@ -54,5 +50,11 @@ async fn main() -> Result<()> {
     info!("Starting kina CLI application");
 
     // Execute the command
-    cli.execute(&config).await
+    cli.execute(&config).await?;
+
+    // `update-check` reports on its own; `--quiet` means no extra output
+    if !cli.quiet && !matches!(cli.command, Some(cli::Commands::UpdateCheck(_))) {
+        cli::notify_if_update_available().await;
+    }
+    Ok(())
 }
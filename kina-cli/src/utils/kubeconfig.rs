@@ -0,0 +1,48 @@
+//! Kubeconfig path resolution.
+
+use std::path::{Path, PathBuf};
+
+/// The kubeconfig file kina merges cluster entries into, following kubectl:
+/// the first non-empty entry of a `KUBECONFIG` path list, else `~/.kube/config`.
+pub fn primary_kubeconfig_path(kubeconfig_env: Option<&str>, home: &Path) -> PathBuf {
+    kubeconfig_env
+        .and_then(|list| std::env::split_paths(list).find(|path| !path.as_os_str().is_empty()))
+        .unwrap_or_else(|| home.join(".kube").join("config"))
+}
+
+/// [`primary_kubeconfig_path`] for the current `KUBECONFIG` and home directory.
+pub fn resolve_primary_kubeconfig_path() -> PathBuf {
+    let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+    primary_kubeconfig_path(std::env::var("KUBECONFIG").ok().as_deref(), &home)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_to_home_kube_config() {
+        let home = Path::new("/home/dev");
+        assert_eq!(
+            primary_kubeconfig_path(None, home),
+            PathBuf::from("/home/dev/.kube/config")
+        );
+        assert_eq!(
+            primary_kubeconfig_path(Some(""), home),
+            PathBuf::from("/home/dev/.kube/config")
+        );
+    }
+
+    #[test]
+    fn uses_first_entry_of_kubeconfig_list() {
+        let home = Path::new("/home/dev");
+        assert_eq!(
+            primary_kubeconfig_path(Some("/tmp/a.yaml:/tmp/b.yaml"), home),
+            PathBuf::from("/tmp/a.yaml")
+        );
+        assert_eq!(
+            primary_kubeconfig_path(Some(":/tmp/b.yaml"), home),
+            PathBuf::from("/tmp/b.yaml")
+        );
+    }
+}
@@ -0,0 +1,7 @@
+//! Small helpers shared across CLI and core modules.
+
+pub mod kubeconfig;
+pub mod process;
+pub mod retry;
+pub mod text;
+pub mod validate;
@@ -0,0 +1,28 @@
+//! Helpers for finding and running external commands.
+
+use std::path::Path;
+
+/// Whether an executable called `name` is on `PATH`.
+pub fn command_exists(name: &str) -> bool {
+    std::env::var_os("PATH")
+        .is_some_and(|path| std::env::split_paths(&path).any(|dir| is_executable(&dir.join(name))))
+}
+
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    std::fs::metadata(path)
+        .map(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_commands_on_path() {
+        assert!(command_exists("sh"));
+        assert!(!command_exists("kina-no-such-command"));
+    }
+}
@@ -0,0 +1,102 @@
+//! Retry with exponential backoff for operations that fail transiently, such as
+//! querying an Apple Container VM that is still settling.
+
+use anyhow::Result;
+use std::future::Future;
+use std::time::Duration;
+use tracing::debug;
+
+/// Delay before retry number `retry` (1-based): `initial_delay * factor^(retry - 1)`.
+pub fn backoff_delay(initial_delay: Duration, factor: f64, retry: u32) -> Duration {
+    let exponent = retry.saturating_sub(1) as i32;
+    initial_delay.mul_f64(factor.max(1.0).powi(exponent))
+}
+
+/// Run `f` until it succeeds or `max_attempts` attempts have failed, sleeping
+/// `initial_delay`, then `initial_delay * factor`, ... between attempts.
+///
+/// Returns the last error when every attempt fails. `max_attempts` of 0 is
+/// treated as 1; a `factor` below 1.0 is treated as 1.0 (constant delay).
+pub async fn retry_with_backoff<F, Fut, T>(
+    max_attempts: u32,
+    initial_delay: Duration,
+    factor: f64,
+    mut f: F,
+) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let max_attempts = max_attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        match f().await {
+            Ok(value) => return Ok(value),
+            Err(e) if attempt >= max_attempts => return Err(e),
+            Err(e) => {
+                let delay = backoff_delay(initial_delay, factor, attempt);
+                debug!(
+                    "Attempt {}/{} failed, retrying in {:?}: {}",
+                    attempt, max_attempts, delay, e
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    /// Fails the first `failures` calls, then returns the call number.
+    fn flaky(calls: &Cell<u32>, failures: u32) -> impl Future<Output = Result<u32>> {
+        calls.set(calls.get() + 1);
+        let call = calls.get();
+        async move {
+            if call <= failures {
+                Err(anyhow::anyhow!("transient failure {}", call))
+            } else {
+                Ok(call)
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn succeeds_after_transient_failures() {
+        let calls = Cell::new(0);
+        let result =
+            retry_with_backoff(5, Duration::from_millis(1), 2.0, || flaky(&calls, 3)).await;
+        assert_eq!(result.unwrap(), 4);
+        assert_eq!(calls.get(), 4);
+    }
+
+    #[tokio::test]
+    async fn returns_last_error_when_attempts_run_out() {
+        let calls = Cell::new(0);
+        let result =
+            retry_with_backoff(3, Duration::from_millis(1), 2.0, || flaky(&calls, 10)).await;
+        assert_eq!(result.unwrap_err().to_string(), "transient failure 3");
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn zero_attempts_still_tries_once() {
+        let calls = Cell::new(0);
+        let result =
+            retry_with_backoff(0, Duration::from_millis(1), 2.0, || flaky(&calls, 0)).await;
+        assert_eq!(result.unwrap(), 1);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn backoff_delay_grows_by_factor() {
+        let initial = Duration::from_millis(100);
+        assert_eq!(backoff_delay(initial, 2.0, 1), Duration::from_millis(100));
+        assert_eq!(backoff_delay(initial, 2.0, 2), Duration::from_millis(200));
+        assert_eq!(backoff_delay(initial, 2.0, 4), Duration::from_millis(800));
+        assert_eq!(backoff_delay(initial, 0.5, 3), Duration::from_millis(100));
+    }
+}
@@ -0,0 +1,67 @@
+//! Text formatting helpers for terminal output.
+
+/// Truncate `s` to at most `max_chars` characters, replacing the tail with `…`
+/// when it does not fit. Counts `char`s, so multi-byte input is never split.
+pub fn truncate(s: &str, max_chars: usize) -> String {
+    if s.chars().count() <= max_chars {
+        return s.to_string();
+    }
+    if max_chars == 0 {
+        return String::new();
+    }
+    let mut out: String = s.chars().take(max_chars - 1).collect();
+    out.push('…');
+    out
+}
+
+/// Render `duration` in its largest whole unit, e.g. `45 seconds`,
+/// `1 minute`, `3 hours`, `12 days`.
+pub fn format_duration(duration: std::time::Duration) -> String {
+    let secs = duration.as_secs();
+    let (value, unit) = match secs {
+        0..=59 => (secs, "second"),
+        60..=3_599 => (secs / 60, "minute"),
+        3_600..=86_399 => (secs / 3_600, "hour"),
+        _ => (secs / 86_400, "day"),
+    };
+    if value == 1 {
+        format!("1 {}", unit)
+    } else {
+        format!("{} {}s", value, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_duration_uses_largest_whole_unit() {
+        let secs = std::time::Duration::from_secs;
+        assert_eq!(format_duration(secs(0)), "0 seconds");
+        assert_eq!(format_duration(secs(59)), "59 seconds");
+        assert_eq!(format_duration(secs(60)), "1 minute");
+        assert_eq!(format_duration(secs(12 * 60 + 30)), "12 minutes");
+        assert_eq!(format_duration(secs(3 * 3_600)), "3 hours");
+        assert_eq!(format_duration(secs(86_400)), "1 day");
+        assert_eq!(format_duration(secs(40 * 86_400)), "40 days");
+    }
+
+    #[test]
+    fn truncate_keeps_short_strings() {
+        assert_eq!(truncate("kina", 10), "kina");
+        assert_eq!(truncate("kina", 4), "kina");
+    }
+
+    #[test]
+    fn truncate_adds_ellipsis_within_limit() {
+        let out = truncate("kindest/node:v1.36.1", 10);
+        assert_eq!(out, "kindest/n…");
+        assert_eq!(out.chars().count(), 10);
+    }
+
+    #[test]
+    fn truncate_zero_width_is_empty() {
+        assert_eq!(truncate("kina", 0), "");
+    }
+}
@@ -0,0 +1,607 @@
+//! Validation for user-supplied flag values.
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::PathBuf;
+
+use crate::core::types::{AuditLevel, AuditPolicy};
+
+/// Size format accepted by `--memory` / `--storage`: `<number>[KMG]i?`, e.g.
+/// `512M`, `4G`, `4Gi`. The unit is case-insensitive so `4g` also passes.
+const SIZE_LIMIT_PATTERN: &str = r"^(?i)[1-9][0-9]*[KMG]i?$";
+
+// Compiled once on first use rather than on every validation.
+static SIZE_LIMIT_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(SIZE_LIMIT_PATTERN).expect("SIZE_LIMIT_PATTERN is a valid regex"));
+static EXTRA_ARG_KEY_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[a-z0-9-]+$").expect("extra arg key regex is valid"));
+static LABEL_NAME_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^([A-Za-z0-9]([-A-Za-z0-9_.]{0,61}[A-Za-z0-9])?)$")
+        .expect("label name pattern is a valid regex")
+});
+static LABEL_PREFIX_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*$")
+        .expect("label prefix pattern is a valid regex")
+});
+static KUBERNETES_VERSION_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^v(0|[1-9][0-9]*)\.(0|[1-9][0-9]*)\.(0|[1-9][0-9]*)$")
+        .expect("kubernetes version pattern is a valid regex")
+});
+static BOOTSTRAP_TOKEN_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[a-z0-9]{6}\.[a-z0-9]{16}$").expect("token regex is valid"));
+static NODE_NAME_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^[a-z0-9]([a-z0-9-]{0,61}[a-z0-9])?$").expect("node name regex is valid")
+});
+
+fn size_limit(field: &str, value: &str) -> Result<()> {
+    if SIZE_LIMIT_RE.is_match(value) {
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!(
+            "{}: invalid size \"{}\"; expected <number>[K|M|G] with an optional i (e.g. \"512M\", \"4Gi\")",
+            field,
+            value
+        ))
+    }
+}
+
+/// Validate a `--memory` limit.
+pub fn memory_limit(value: &str) -> Result<()> {
+    size_limit("memory", value)
+}
+
+/// Validate a `--storage` limit.
+pub fn storage_limit(value: &str) -> Result<()> {
+    size_limit("storage", value)
+}
+
+/// Parse a `<key>=<value>` component flag such as `--api-server-extra-arg`.
+///
+/// The key is the flag name without leading dashes (see [`extra_arg_key`]);
+/// the value may be empty but must not contain a newline, which would break
+/// the generated kubeadm YAML.
+pub fn extra_arg(raw: &str) -> Result<(String, String)> {
+    let (key, value) = raw
+        .split_once('=')
+        .ok_or_else(|| anyhow::anyhow!("invalid extra arg \"{}\"; expected <key>=<value>", raw))?;
+    let key = key.trim().trim_start_matches('-');
+    if key.is_empty() {
+        return Err(anyhow::anyhow!(
+            "invalid extra arg \"{}\"; the key must not be empty",
+            raw
+        ));
+    }
+    extra_arg_key(key)?;
+    if value.contains(['\n', '\r']) {
+        return Err(anyhow::anyhow!(
+            "invalid extra arg \"{}\"; the value must not contain newlines",
+            key
+        ));
+    }
+    Ok((key.to_string(), value.to_string()))
+}
+
+/// Validate a component flag name written unquoted into the kubeadm YAML's
+/// `extraArgs`: lowercase letters, digits and '-' only.
+pub fn extra_arg_key(key: &str) -> Result<()> {
+    if EXTRA_ARG_KEY_RE.is_match(key) {
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!(
+            "invalid extra arg name \"{}\"; expected lowercase letters, digits or '-'",
+            key
+        ))
+    }
+}
+
+/// Oldest Kubernetes minor release kina can create clusters with.
+pub const MIN_KUBERNETES_MINOR: u32 = 27;
+
+/// Newest Kubernetes minor release kina has been validated against.
+pub const MAX_KUBERNETES_MINOR: u32 = 36;
+
+/// Validate a full Kubernetes version such as `v1.31.0`: the form must be
+/// `v<major>.<minor>.<patch>`, and the release must be supported.
+pub fn kubernetes_version(v: &str) -> Result<String> {
+    let captures = KUBERNETES_VERSION_RE.captures(v).ok_or_else(|| {
+        anyhow::anyhow!(
+            "invalid Kubernetes version \"{}\"; expected v<major>.<minor>.<patch> (e.g. \"v1.31.0\")",
+            v
+        )
+    })?;
+    let number = |i: usize| captures[i].parse::<u32>().ok();
+    match (number(1), number(2)) {
+        (Some(major), Some(minor)) => kubernetes_release(major, minor).map(|_| v.to_string()),
+        _ => Err(anyhow::anyhow!("invalid Kubernetes version \"{}\"", v)),
+    }
+}
+
+/// Check that Kubernetes `<major>.<minor>` is a release kina supports.
+pub fn kubernetes_release(major: u32, minor: u32) -> Result<()> {
+    if major != 1 {
+        return Err(anyhow::anyhow!(
+            "unsupported Kubernetes version v{}.{}; only Kubernetes 1.x is supported",
+            major,
+            minor
+        ));
+    }
+    if !(MIN_KUBERNETES_MINOR..=MAX_KUBERNETES_MINOR).contains(&minor) {
+        return Err(anyhow::anyhow!(
+            "unsupported Kubernetes version v1.{}; supported releases are v1.{} to v1.{}",
+            minor,
+            MIN_KUBERNETES_MINOR,
+            MAX_KUBERNETES_MINOR
+        ));
+    }
+    Ok(())
+}
+
+/// Validate a `--subnet` CIDR such as `10.50.0.0/24`.
+pub fn subnet(value: &str) -> Result<String> {
+    if crate::config::cluster_config::is_cidr(value) {
+        Ok(value.to_string())
+    } else {
+        Err(anyhow::anyhow!(
+            "invalid subnet \"{}\"; expected CIDR notation such as 10.50.0.0/24",
+            value
+        ))
+    }
+}
+
+/// Parse a `<key>=<value>` resource label such as `--label team=infra`.
+/// Commas are rejected because the container CLI would split on them.
+pub fn label(raw: &str) -> Result<(String, String)> {
+    let (key, value) = raw
+        .split_once('=')
+        .ok_or_else(|| anyhow::anyhow!("invalid label \"{}\"; expected <key>=<value>", raw))?;
+    if key.trim().is_empty() || raw.contains(',') {
+        return Err(anyhow::anyhow!(
+            "invalid label \"{}\"; the key must not be empty and labels must not contain commas",
+            raw
+        ));
+    }
+    Ok((key.trim().to_string(), value.to_string()))
+}
+
+/// Parse a `<key>=<value>` Kubernetes node label such as
+/// `--node-extra-label example.com/tier=frontend`. The key is an optional DNS
+/// subdomain prefix plus a name; names and values are at most 63 characters of
+/// alphanumerics, `-`, `_` and `.`, starting and ending alphanumeric.
+pub fn node_label(raw: &str) -> Result<(String, String)> {
+    let invalid = |reason: &str| anyhow::anyhow!("invalid node label \"{}\"; {}", raw, reason);
+    let (key, value) = raw
+        .split_once('=')
+        .ok_or_else(|| invalid("expected <key>=<value>"))?;
+    let (prefix, name) = match key.split_once('/') {
+        Some((prefix, name)) => (Some(prefix), name),
+        None => (None, key),
+    };
+    if let Some(prefix) = prefix {
+        if prefix.len() > 253 || !LABEL_PREFIX_RE.is_match(prefix) {
+            return Err(invalid("the key prefix must be a DNS subdomain"));
+        }
+    }
+    if !LABEL_NAME_RE.is_match(name) {
+        return Err(invalid(
+            "the key name must be 1-63 alphanumerics, '-', '_' or '.'",
+        ));
+    }
+    if !value.is_empty() && !LABEL_NAME_RE.is_match(value) {
+        return Err(invalid(
+            "the value must be at most 63 alphanumerics, '-', '_' or '.'",
+        ));
+    }
+    Ok((key.to_string(), value.to_string()))
+}
+
+/// Parse a `kina port-forward` port: `LOCAL:REMOTE`, or `PORT` for the same
+/// port on both sides. Ports must be 1-65535.
+pub fn port_pair(raw: &str) -> Result<(u16, u16)> {
+    let parse = |port: &str| match port.parse::<u16>() {
+        Ok(p) if p > 0 => Ok(p),
+        _ => Err(anyhow::anyhow!(
+            "invalid port \"{}\" in \"{}\"; expected LOCAL:REMOTE or PORT with ports 1-65535",
+            port,
+            raw
+        )),
+    };
+    match raw.split_once(':') {
+        Some((local, remote)) => Ok((parse(local)?, parse(remote)?)),
+        None => parse(raw).map(|p| (p, p)),
+    }
+}
+
+/// Parse a Service node port, which must fall in Kubernetes' default
+/// `--service-node-port-range` of 30000-32767.
+pub fn node_port(raw: &str) -> Result<u16> {
+    match raw.parse::<u16>() {
+        Ok(port) if (30000..=32767).contains(&port) => Ok(port),
+        _ => Err(anyhow::anyhow!(
+            "invalid node port \"{}\"; expected a port in 30000-32767",
+            raw
+        )),
+    }
+}
+
+/// Parse an `--api-server-port`, which must be an unprivileged port
+/// (1024-65535).
+pub fn api_server_port(raw: &str) -> Result<u16> {
+    match raw.parse::<u16>() {
+        Ok(port) if port >= 1024 => Ok(port),
+        _ => Err(anyhow::anyhow!(
+            "invalid API server port \"{}\"; expected a port in 1024-65535",
+            raw
+        )),
+    }
+}
+
+/// Validate an `--insecure-registry` host such as `registry.local:5000`: a
+/// host name or IPv4 address with an optional port, without scheme or path.
+pub fn registry_host(raw: &str) -> Result<String> {
+    let invalid = || {
+        anyhow::anyhow!(
+            "invalid registry \"{}\"; expected HOST or HOST:PORT such as localhost:5000",
+            raw
+        )
+    };
+    let (host, port) = match raw.rsplit_once(':') {
+        Some((host, port)) => (host, Some(port)),
+        None => (raw, None),
+    };
+    if host.is_empty()
+        || !host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
+    {
+        return Err(invalid());
+    }
+    if let Some(port) = port {
+        match port.parse::<u16>() {
+            Ok(p) if p > 0 => {}
+            _ => return Err(invalid()),
+        }
+    }
+    Ok(raw.to_string())
+}
+
+/// Validate an API server certificate SAN: an IP address or a DNS name,
+/// optionally with a leading `*.` wildcard label.
+pub fn cert_san(raw: &str) -> Result<String> {
+    if raw.parse::<std::net::IpAddr>().is_ok() {
+        return Ok(raw.to_string());
+    }
+    let name = raw.strip_prefix("*.").unwrap_or(raw);
+    let valid_label = |label: &str| {
+        !label.is_empty()
+            && label.len() <= 63
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    };
+    if raw.len() > 253 || !name.split('.').all(valid_label) {
+        return Err(anyhow::anyhow!(
+            "invalid SAN \"{}\"; expected a hostname (e.g. kina.local) or an IP address",
+            raw
+        ));
+    }
+    Ok(raw.to_string())
+}
+
+/// Validate a Kubernetes node name (`--node-name`): an RFC 1123 DNS label of
+/// at most 63 lowercase alphanumerics or '-', starting and ending with an
+/// alphanumeric.
+pub fn node_name(raw: &str) -> Result<String> {
+    if NODE_NAME_RE.is_match(raw) {
+        Ok(raw.to_string())
+    } else {
+        Err(anyhow::anyhow!(
+            "invalid node name \"{}\"; expected at most 63 lowercase letters, digits or '-', starting and ending with a letter or digit",
+            raw
+        ))
+    }
+}
+
+/// Longest cluster name whose `<name>-control-plane` node name still fits a
+/// 63-character DNS label.
+pub const MAX_CLUSTER_NAME_LEN: usize = 63 - "-control-plane".len();
+
+/// Validate a cluster name such as `kina rename`'s new name: lowercase
+/// alphanumerics or '-', starting and ending with an alphanumeric, and at most
+/// [`MAX_CLUSTER_NAME_LEN`] characters so every node name is a DNS label.
+pub fn cluster_name(raw: &str) -> Result<String> {
+    let alphanumeric = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit();
+    let valid = !raw.is_empty()
+        && raw.len() <= MAX_CLUSTER_NAME_LEN
+        && raw.chars().all(|c| alphanumeric(c) || c == '-')
+        && raw.starts_with(alphanumeric)
+        && raw.ends_with(alphanumeric);
+    if valid {
+        Ok(raw.to_string())
+    } else {
+        Err(anyhow::anyhow!(
+            "invalid cluster name \"{}\"; expected at most {} lowercase letters, digits or '-', starting and ending with a letter or digit",
+            raw,
+            MAX_CLUSTER_NAME_LEN
+        ))
+    }
+}
+
+/// Validate a kubeadm bootstrap token: `[a-z0-9]{6}.[a-z0-9]{16}`.
+pub fn bootstrap_token(raw: &str) -> Result<String> {
+    if BOOTSTRAP_TOKEN_RE.is_match(raw) {
+        Ok(raw.to_string())
+    } else {
+        Err(anyhow::anyhow!(
+            "invalid bootstrap token; expected <id>.<secret> with a 6 and a 16 character lowercase alphanumeric part, e.g. abcdef.0123456789abcdef"
+        ))
+    }
+}
+
+/// Validate a Kubernetes object name such as `--image-pull-secret`'s: an RFC
+/// 1123 DNS subdomain of at most 253 lowercase alphanumerics, '-' or '.',
+/// starting and ending with an alphanumeric.
+pub fn object_name(raw: &str) -> Result<String> {
+    let alphanumeric = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit();
+    let valid = !raw.is_empty()
+        && raw.len() <= 253
+        && raw.chars().all(|c| alphanumeric(c) || c == '-' || c == '.')
+        && raw.starts_with(alphanumeric)
+        && raw.ends_with(alphanumeric);
+    if valid {
+        Ok(raw.to_string())
+    } else {
+        Err(anyhow::anyhow!(
+            "invalid name \"{}\"; expected at most 253 lowercase letters, digits, '-' or '.', starting and ending with a letter or digit",
+            raw
+        ))
+    }
+}
+
+/// Parse `--audit-policy`: an audit level name (`none`, `metadata`,
+/// `request`, `requestresponse`) selects a bundled policy; anything else is a
+/// policy file path. Use `./metadata` for a file named like a level.
+pub fn audit_policy(raw: &str) -> Result<AuditPolicy> {
+    if raw.trim().is_empty() {
+        return Err(anyhow::anyhow!(
+            "expected an audit level (none, metadata, request, requestresponse) or a policy file"
+        ));
+    }
+    Ok(match raw.parse::<AuditLevel>() {
+        Ok(level) => AuditPolicy::Level(level),
+        Err(_) => AuditPolicy::File(PathBuf::from(raw)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn port_pair_accepts_pairs_and_single_ports() {
+        assert_eq!(port_pair("8080:80").unwrap(), (8080, 80));
+        assert_eq!(port_pair("5432").unwrap(), (5432, 5432));
+        for raw in ["", ":80", "8080:", "0:80", "8080:70000", "a:b", "1:2:3"] {
+            assert!(port_pair(raw).is_err(), "{:?} should be rejected", raw);
+        }
+    }
+
+    #[test]
+    fn node_name_requires_a_dns_label() {
+        assert_eq!(node_name("test-node-1").unwrap(), "test-node-1");
+        assert!(node_name("a").is_ok());
+        for raw in ["", "-node", "node-", "Node", "node.local", &"a".repeat(64)] {
+            assert!(node_name(raw).is_err(), "{:?} should be rejected", raw);
+        }
+    }
+
+    #[test]
+    fn node_port_requires_the_node_port_range() {
+        assert_eq!(node_port("30080").unwrap(), 30080);
+        assert_eq!(node_port("32767").unwrap(), 32767);
+        for raw in ["", "80", "29999", "32768", "http"] {
+            assert!(node_port(raw).is_err(), "{:?} should be rejected", raw);
+        }
+    }
+
+    #[test]
+    fn api_server_port_requires_an_unprivileged_port() {
+        assert_eq!(api_server_port("8443").unwrap(), 8443);
+        assert_eq!(api_server_port("1024").unwrap(), 1024);
+        for raw in ["", "443", "1023", "65536", "https"] {
+            assert!(
+                api_server_port(raw).is_err(),
+                "{:?} should be rejected",
+                raw
+            );
+        }
+    }
+
+    #[test]
+    fn registry_host_accepts_hosts_with_optional_ports() {
+        assert_eq!(registry_host("localhost:5000").unwrap(), "localhost:5000");
+        assert_eq!(registry_host("192.168.64.1").unwrap(), "192.168.64.1");
+        assert!(registry_host("registry.local:5000").is_ok());
+        assert!(registry_host("http://registry.local").is_err());
+        assert!(registry_host("registry.local/path").is_err());
+        assert!(registry_host("registry.local:0").is_err());
+        assert!(registry_host(":5000").is_err());
+    }
+
+    #[test]
+    fn cert_san_accepts_hostnames_and_ips() {
+        for raw in [
+            "kina.local",
+            "api",
+            "*.kina.test",
+            "192.168.64.10",
+            "fd00::10",
+        ] {
+            assert_eq!(cert_san(raw).unwrap(), raw);
+        }
+        for raw in [
+            "",
+            "kina..local",
+            "-kina.local",
+            "kina_local",
+            "https://kina.local",
+        ] {
+            assert!(cert_san(raw).is_err(), "{:?} should be rejected", raw);
+        }
+    }
+
+    #[test]
+    fn cluster_name_leaves_room_for_node_suffixes() {
+        assert_eq!(cluster_name("prod-2").unwrap(), "prod-2");
+        assert!(cluster_name(&"a".repeat(MAX_CLUSTER_NAME_LEN)).is_ok());
+        let too_long = "a".repeat(MAX_CLUSTER_NAME_LEN + 1);
+        for raw in ["", "-prod", "prod-", "Prod", "prod.local", &too_long] {
+            assert!(cluster_name(raw).is_err(), "{:?} should be rejected", raw);
+        }
+    }
+
+    #[test]
+    fn bootstrap_token_matches_kubeadm_format() {
+        assert!(bootstrap_token("abcdef.0123456789abcdef").is_ok());
+        assert!(bootstrap_token("ABCDEF.0123456789abcdef").is_err());
+        assert!(bootstrap_token("abcdef0123456789abcdef").is_err());
+        assert!(bootstrap_token("abcde.0123456789abcdef").is_err());
+        assert!(bootstrap_token("abcdef.0123456789abcdef0").is_err());
+    }
+
+    #[test]
+    fn object_name_requires_a_dns_subdomain() {
+        assert_eq!(object_name("regcred").unwrap(), "regcred");
+        assert!(object_name("registry.example-creds").is_ok());
+        let too_long = "a".repeat(254);
+        for raw in [
+            "",
+            "RegCred",
+            "-regcred",
+            "regcred.",
+            "reg cred",
+            "x;rm -rf /",
+            &too_long,
+        ] {
+            assert!(object_name(raw).is_err(), "{:?} should be rejected", raw);
+        }
+    }
+
+    #[test]
+    fn audit_policy_prefers_levels_over_paths() {
+        assert_eq!(
+            audit_policy("RequestResponse").unwrap(),
+            AuditPolicy::Level(AuditLevel::RequestResponse)
+        );
+        assert_eq!(
+            audit_policy("none").unwrap(),
+            AuditPolicy::Level(AuditLevel::None)
+        );
+        assert_eq!(
+            audit_policy("./metadata").unwrap(),
+            AuditPolicy::File(PathBuf::from("./metadata"))
+        );
+        assert!(audit_policy("").is_err());
+    }
+
+    #[test]
+    fn memory_limit_accepts_decimal_and_binary_units() {
+        for value in ["512M", "4G", "4Gi", "1024Ki", "4g", "512m"] {
+            assert!(memory_limit(value).is_ok(), "{} should be accepted", value);
+        }
+    }
+
+    #[test]
+    fn memory_limit_rejects_malformed_values() {
+        for value in ["", "4096", "0G", "4GB", "4T", "-1G", "1.5G", "G"] {
+            assert!(memory_limit(value).is_err(), "{} should be rejected", value);
+        }
+    }
+
+    #[test]
+    fn extra_arg_splits_on_first_equals() {
+        assert_eq!(
+            extra_arg("feature-gates=Foo=true").unwrap(),
+            ("feature-gates".to_string(), "Foo=true".to_string())
+        );
+        assert_eq!(
+            extra_arg("--v=4").unwrap(),
+            ("v".to_string(), "4".to_string())
+        );
+        assert_eq!(extra_arg("flag=").unwrap().1, "");
+    }
+
+    #[test]
+    fn extra_arg_rejects_empty_keys_and_newlines() {
+        for raw in [
+            "novalue", "=x", "--=x", "key=a\nb", "a:b=x", "k#=x", "Key=x",
+        ] {
+            assert!(extra_arg(raw).is_err(), "{:?} should be rejected", raw);
+        }
+    }
+
+    #[test]
+    fn subnet_and_label_parsing() {
+        assert!(subnet("10.50.0.0/24").is_ok());
+        assert!(subnet("10.50.0.0").is_err());
+        assert_eq!(
+            label("team=infra").unwrap(),
+            ("team".to_string(), "infra".to_string())
+        );
+        for raw in ["team", "=infra", "a=b,c=d"] {
+            assert!(label(raw).is_err(), "{:?} should be rejected", raw);
+        }
+    }
+
+    #[test]
+    fn node_label_follows_kubernetes_label_syntax() {
+        assert_eq!(
+            node_label("example.com/tier=frontend").unwrap(),
+            ("example.com/tier".to_string(), "frontend".to_string())
+        );
+        assert_eq!(node_label("ingress-ready=").unwrap().1, "");
+        for raw in [
+            "tier",
+            "=x",
+            "-tier=x",
+            "Example.com/tier=x",
+            "tier=a b",
+            "a/b/c=x",
+        ] {
+            assert!(node_label(raw).is_err(), "{:?} should be rejected", raw);
+        }
+    }
+
+    #[test]
+    fn kubernetes_version_requires_full_supported_versions() {
+        for v in ["v1.27.0", "v1.31.0", "v1.36.1", "v1.31.12"] {
+            assert!(kubernetes_version(v).is_ok(), "{} should be accepted", v);
+        }
+        for v in [
+            "1.31.0",
+            "v1.31",
+            "v1.31.0-rc.1",
+            "v1.031.0",
+            "v1.31.0 ",
+            "",
+            "latest",
+        ] {
+            let err = kubernetes_version(v).unwrap_err().to_string();
+            assert!(err.contains("expected v<major>.<minor>.<patch>"), "{}", err);
+        }
+        let err = kubernetes_version("v2.0.0").unwrap_err().to_string();
+        assert!(err.contains("only Kubernetes 1.x"), "{}", err);
+        for v in ["v1.26.15", "v1.37.0", "v1.99999999999.0"] {
+            assert!(kubernetes_version(v).is_err(), "{} should be rejected", v);
+        }
+        assert!(kubernetes_release(1, 30).is_ok());
+    }
+
+    #[test]
+    fn errors_name_the_field() {
+        assert!(storage_limit("lots")
+            .unwrap_err()
+            .to_string()
+            .starts_with("storage:"));
+    }
+}
@@ -125,7 +125,7 @@ fn build_cli_script_download_url_from_version() {
 /// T9 — build_cilium_install_cmd("1.18.10","192.168.65.2") contains "cilium install --version 1.18.10"
 #[test]
 fn install_cmd_pins_version() {
-    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("cilium install --version 1.18.10"),
         "build_cilium_install_cmd must contain \"cilium install --version 1.18.10\"; got:\n{}",
@@ -136,7 +136,7 @@ fn install_cmd_pins_version() {
 /// T10 — contains "--set kubeProxyReplacement=false"
 #[test]
 fn install_cmd_kube_proxy_replacement_false() {
-    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("--set kubeProxyReplacement=false"),
         "build_cilium_install_cmd must contain \"--set kubeProxyReplacement=false\"; got:\n{}",
@@ -147,7 +147,7 @@ fn install_cmd_kube_proxy_replacement_false() {
 /// T11 — contains "--set ipam.mode=kubernetes"
 #[test]
 fn install_cmd_ipam_mode_kubernetes() {
-    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("--set ipam.mode=kubernetes"),
         "build_cilium_install_cmd must contain \"--set ipam.mode=kubernetes\"; got:\n{}",
@@ -158,7 +158,7 @@ fn install_cmd_ipam_mode_kubernetes() {
 /// T12 — contains "--set ipv4NativeRoutingCIDR=10.244.0.0/16"
 #[test]
 fn install_cmd_ipv4_native_routing_cidr() {
-    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("--set ipv4NativeRoutingCIDR=10.244.0.0/16"),
         "build_cilium_install_cmd must contain \"--set ipv4NativeRoutingCIDR=10.244.0.0/16\"; got:\n{}",
@@ -169,7 +169,7 @@ fn install_cmd_ipv4_native_routing_cidr() {
 /// T13 — contains "--set routingMode=tunnel"
 #[test]
 fn install_cmd_routing_mode_tunnel() {
-    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("--set routingMode=tunnel"),
         "build_cilium_install_cmd must contain \"--set routingMode=tunnel\"; got:\n{}",
@@ -180,7 +180,7 @@ fn install_cmd_routing_mode_tunnel() {
 /// T14 — contains "--set tunnelProtocol=vxlan"
 #[test]
 fn install_cmd_tunnel_protocol_vxlan() {
-    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("--set tunnelProtocol=vxlan"),
         "build_cilium_install_cmd must contain \"--set tunnelProtocol=vxlan\"; got:\n{}",
@@ -191,7 +191,7 @@ fn install_cmd_tunnel_protocol_vxlan() {
 /// T15 — contains "--set ipv6.enabled=false"
 #[test]
 fn install_cmd_ipv6_disabled() {
-    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("--set ipv6.enabled=false"),
         "build_cilium_install_cmd must contain \"--set ipv6.enabled=false\"; got:\n{}",
@@ -202,7 +202,7 @@ fn install_cmd_ipv6_disabled() {
 /// T16 — contains "--set enableLocalNodeRoute=false"
 #[test]
 fn install_cmd_enable_local_node_route_false() {
-    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("--set enableLocalNodeRoute=false"),
         "build_cilium_install_cmd must contain \"--set enableLocalNodeRoute=false\"; got:\n{}",
@@ -213,7 +213,7 @@ fn install_cmd_enable_local_node_route_false() {
 /// T17 — contains "--set nodePort.enabled=true"
 #[test]
 fn install_cmd_nodeport_enabled() {
-    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("--set nodePort.enabled=true"),
         "build_cilium_install_cmd must contain \"--set nodePort.enabled=true\"; got:\n{}",
@@ -224,7 +224,7 @@ fn install_cmd_nodeport_enabled() {
 /// T18 — contains "--set hostPort.enabled=true"
 #[test]
 fn install_cmd_hostport_enabled() {
-    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("--set hostPort.enabled=true"),
         "build_cilium_install_cmd must contain \"--set hostPort.enabled=true\"; got:\n{}",
@@ -235,7 +235,7 @@ fn install_cmd_hostport_enabled() {
 /// T19 — contains "--set k8sServiceHost=192.168.65.2" (templated from cp_ip input)
 #[test]
 fn install_cmd_k8s_service_host_uses_cp_ip() {
-    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("--set k8sServiceHost=192.168.65.2"),
         "build_cilium_install_cmd must contain \"--set k8sServiceHost=192.168.65.2\"; got:\n{}",
@@ -246,7 +246,7 @@ fn install_cmd_k8s_service_host_uses_cp_ip() {
 /// T20 — contains "--set k8sServicePort=6443"
 #[test]
 fn install_cmd_k8s_service_port_6443() {
-    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("--set k8sServicePort=6443"),
         "build_cilium_install_cmd must contain \"--set k8sServicePort=6443\"; got:\n{}",
@@ -254,10 +254,21 @@ fn install_cmd_k8s_service_port_6443() {
     );
 }
 
+/// T20b — a custom API server port reaches k8sServicePort
+#[test]
+fn install_cmd_k8s_service_port_follows_api_server_port() {
+    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2", 8443);
+    assert!(
+        cmd.contains("--set k8sServicePort=8443"),
+        "build_cilium_install_cmd must contain \"--set k8sServicePort=8443\"; got:\n{}",
+        cmd
+    );
+}
+
 /// T21 — contains "--set operator.replicas=1"
 #[test]
 fn install_cmd_operator_replicas_1() {
-    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("--set operator.replicas=1"),
         "build_cilium_install_cmd must contain \"--set operator.replicas=1\"; got:\n{}",
@@ -269,7 +280,7 @@ fn install_cmd_operator_replicas_1() {
 ///        and does NOT contain "192.168.65.2" (proves cp_ip is interpolated, not hardcoded)
 #[test]
 fn install_cmd_distinct_cp_ip_templated() {
-    let cmd = build_cilium_install_cmd("1.18.10", "10.0.0.5");
+    let cmd = build_cilium_install_cmd("1.18.10", "10.0.0.5", 6443);
     assert!(
         cmd.contains("--set k8sServiceHost=10.0.0.5"),
         "build_cilium_install_cmd with ip \"10.0.0.5\" must contain \
@@ -6,6 +6,12 @@ pub struct TestContext {
     pub config_path: String,
 }
 
+impl Default for TestContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TestContext {
     pub fn new() -> Self {
         // Use the fixture file from the test directory
@@ -55,7 +61,7 @@ fn test_help_command() {
 fn test_subcommand_help() {
     let mut cmd = Command::cargo_bin("kina").unwrap();
 
-    cmd.args(&["create", "--help"]);
+    cmd.args(["create", "--help"]);
     cmd.assert()
         .success()
         .stdout(predicate::str::contains("Create a new Kubernetes cluster"));
@@ -66,7 +72,7 @@ fn test_subcommand_help() {
 #[test]
 fn test_create_command_help() {
     let mut cmd = Command::cargo_bin("kina").unwrap();
-    cmd.args(&["create", "--help"]);
+    cmd.args(["create", "--help"]);
     cmd.assert()
         .success()
         .stdout(predicate::str::contains("Create a new Kubernetes cluster"));
@@ -78,7 +84,7 @@ fn test_create_command_basic() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["create", "test-cluster"]);
+    cmd.args(["create", "test-cluster"]);
     // This will likely fail since Apple Container isn't available, but tests command parsing
 }
 
@@ -98,7 +104,7 @@ fn test_create_command_with_image() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["create", "--image", "custom/image:latest"]);
+    cmd.args(["create", "--image", "custom/image:latest"]);
     // Tests create with custom image
 }
 
@@ -108,7 +114,7 @@ fn test_create_command_with_config() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["create", "--config", "custom-config.yaml"]);
+    cmd.args(["create", "--config", "custom-config.yaml"]);
     // Tests create with custom config file
 }
 
@@ -118,37 +124,504 @@ fn test_create_command_with_wait() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["create", "--wait", "300"]);
+    cmd.args(["create", "--wait", "300"]);
     // Tests create with wait timeout
 }
 
+#[test]
+fn test_create_command_wait_for_ready_in_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["create", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--wait-for-ready"));
+}
+
+#[test]
+fn test_create_command_kubernetes_version_conflicts_with_image() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args([
+        "create",
+        "--kubernetes-version",
+        "v1.31.0",
+        "--image",
+        "kindest/node:v1.30.0",
+    ]);
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_create_command_if_not_exists_in_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["create", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--if-not-exists"));
+}
+
 #[test]
 fn test_create_command_with_retain() {
     let context = TestContext::new();
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["create", "--retain"]);
+    cmd.args(["create", "--retain"]);
     // Tests create with retain on failure flag
 }
 
+#[test]
+fn test_create_command_with_retain_on_failure_alias() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["create", "--retain-on-failure", "--help"]);
+    cmd.assert().success();
+}
+
 #[test]
 fn test_create_command_skip_csr_approval() {
     let context = TestContext::new();
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["create", "--skip-csr-approval"]);
+    cmd.args(["create", "--skip-csr-approval"]);
     // Tests create with skip CSR approval flag
 }
 
+#[test]
+fn test_create_no_kubeconfig_in_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["create", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--no-kubeconfig"));
+}
+
+#[test]
+fn test_create_audit_policy_levels_in_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["create", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("requestresponse"));
+}
+
+#[test]
+fn test_create_image_load_conflicts_with_no_wait() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["create", "--image-load", "app:dev", "--no-wait"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_create_nfs_storage_class_requires_server_and_path() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args([
+        "create",
+        "--default-storage-class",
+        "nfs",
+        "--nfs-server",
+        "192.168.64.1",
+    ]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--nfs-path"));
+}
+
+#[test]
+fn test_create_lb_address_range_requires_loadbalancer_flag() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["create", "--lb-address-range", "192.168.64.240/28"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--service-type-loadbalancer"));
+}
+
+#[test]
+fn test_create_audit_policy_requires_audit_log() {
+    let context = TestContext::new();
+    context.create_test_config().unwrap();
+
+    let mut cmd = context.kina_command();
+    cmd.args(["create", "--audit-policy", "policy.yaml"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--audit-log"));
+}
+
+#[test]
+fn test_create_networking_config_in_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["create", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--networking-config <FILE>"));
+}
+
+#[test]
+fn test_create_extra_arg_flags_in_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["create", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "--api-server-extra-arg <KEY=VALUE>",
+        ))
+        .stdout(predicate::str::contains("--kubelet-extra-arg <KEY=VALUE>"))
+        .stdout(predicate::str::contains(
+            "--controller-manager-extra-arg <KEY=VALUE>",
+        ));
+}
+
+#[test]
+fn test_pause_and_stop_in_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.arg("--help");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Pause cluster VMs (same as 'stop')",
+        ))
+        .stdout(predicate::str::contains(
+            "Stop cluster VMs (same as 'pause')",
+        ));
+}
+
+#[test]
+fn test_start_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["start", "--help"]);
+    cmd.assert().success().stdout(predicate::str::contains(
+        "Start a paused or stopped cluster",
+    ));
+}
+
+#[test]
+fn test_tui_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["tui", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--refresh <SECONDS>"));
+}
+
+#[test]
+fn test_benchmark_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["benchmark", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--iterations <ITERATIONS>"))
+        .stdout(predicate::str::contains("--output <OUTPUT>"));
+}
+
+#[test]
+fn test_benchmark_rejects_zero_iterations() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["benchmark", "--iterations", "0"]);
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_create_from_kind_config_conflicts_with_workers() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args([
+        "create",
+        "--from-kind-config",
+        "kind.yaml",
+        "--workers",
+        "2",
+    ]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_daemon_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["daemon", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("KINA_NO_DAEMON"));
+}
+
+/// A `kina-hello` plugin that prints its arguments and environment and exits 3.
+fn hello_plugin_dir() -> tempfile::TempDir {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let plugin = dir.path().join("kina-hello");
+    std::fs::write(
+        &plugin,
+        "#!/bin/sh\n\
+         if [ \"$1\" = --version ]; then echo 'hello 1.2.3'; exit 0; fi\n\
+         echo \"args=$* config=$KINA_CONFIG clusters=$KINA_CLUSTERS\"\n\
+         exit 3\n",
+    )
+    .unwrap();
+    std::fs::set_permissions(&plugin, std::fs::Permissions::from_mode(0o755)).unwrap();
+    dir
+}
+
+#[test]
+fn test_plugin_runs_with_args_env_and_exit_code() {
+    let context = TestContext::new();
+    let plugins = hello_plugin_dir();
+
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.env("PATH", plugins.path())
+        .env("KINA_NO_DAEMON", "1")
+        .args(["--config", &context.config_path])
+        .args(["plugin", "hello", "--flag", "value"]);
+    cmd.assert()
+        .code(3)
+        .stdout(predicate::str::contains("args=--flag value"))
+        .stdout(predicate::str::contains(format!(
+            "config={}",
+            context.config_path
+        )))
+        .stdout(predicate::str::contains("clusters=["));
+}
+
+#[test]
+fn test_plugin_list_shows_versions() {
+    let plugins = hello_plugin_dir();
+
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.env("PATH", plugins.path())
+        .env("KINA_NO_UPDATE_CHECK", "1")
+        .args(["plugin", "list"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("hello"))
+        .stdout(predicate::str::contains("hello 1.2.3"));
+}
+
+#[test]
+fn test_plugin_missing_is_an_error() {
+    let plugins = tempfile::TempDir::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.env("PATH", plugins.path()).args(["plugin", "nope"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("kina-nope"));
+}
+
+#[test]
+fn test_create_psp_rejects_unknown_level() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["create", "--psp", "strict"]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "[possible values: restricted, baseline, privileged]",
+    ));
+}
+
+#[test]
+fn test_network_create_rejects_bad_subnet_and_driver() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["network", "create", "lab", "--subnet", "10.50.0.0"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid subnet"));
+
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["network", "create", "lab", "--driver", "bridge"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("[possible values: nat]"));
+}
+
+#[test]
+fn test_inspect_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["inspect", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--node <NODE>"));
+}
+
+#[test]
+fn test_create_control_plane_nodes_must_be_positive() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["create", "--control-plane-nodes", "0"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--control-plane-nodes"));
+}
+
+#[test]
+fn test_create_cri_o_conflicts_with_image_load() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["create", "--cri-o", "--image-load", "app:latest"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_create_control_plane_vip_requires_ha() {
+    let context = TestContext::new();
+    let mut cmd = context.kina_command();
+    cmd.args(["create", "--control-plane-vip", "192.168.64.250"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--control-plane-vip needs an HA"));
+}
+
+#[test]
+fn test_create_rejects_invalid_node_label() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["create", "--node-extra-label", "tier=front end"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid node label"));
+}
+
+#[test]
+fn test_create_image_pull_secret_requires_docker_config() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["create", "--image-pull-secret", "regcred"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--docker-config-json"));
+}
+
+#[test]
+fn test_create_rejects_invalid_image_pull_secret_name() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args([
+        "create",
+        "--image-pull-secret",
+        "reg;cred",
+        "--docker-config-json",
+        "config.json",
+    ]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid name \"reg;cred\""));
+}
+
+#[test]
+fn test_create_rejects_unsupported_kubernetes_version() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["create", "--kubernetes-version", "v1.20.0"]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "unsupported Kubernetes version v1.20",
+    ));
+}
+
+#[test]
+fn test_wait_ready_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["wait-ready", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--timeout <TIMEOUT>"))
+        .stdout(predicate::str::contains("[default: 300]"));
+}
+
+#[test]
+fn test_create_api_server_extra_arg_requires_key_value() {
+    let context = TestContext::new();
+    context.create_test_config().unwrap();
+
+    let mut cmd = context.kina_command();
+    cmd.args(["create", "--api-server-extra-arg", "novalue"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("expected <key>=<value>"));
+}
+
+#[test]
+fn test_create_no_wait_conflicts_with_wait() {
+    let context = TestContext::new();
+    context.create_test_config().unwrap();
+
+    let mut cmd = context.kina_command();
+    cmd.args(["create", "--no-wait", "--wait", "60"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_create_skip_cni_conflicts_with_wait_for_ready() {
+    let context = TestContext::new();
+    context.create_test_config().unwrap();
+
+    let mut cmd = context.kina_command();
+    cmd.args(["create", "--skip-cni", "--wait-for-ready"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_get_events_options_in_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["get", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("events"))
+        .stdout(predicate::str::contains("--watch"))
+        .stdout(predicate::str::contains("--namespace"));
+}
+
+#[test]
+fn test_get_pods_options_in_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["get", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("pods"))
+        .stdout(predicate::str::contains("--all-namespaces"))
+        .stdout(predicate::str::contains("--label-selector"));
+}
+
+#[test]
+fn test_get_pods_namespace_conflicts_with_all_namespaces() {
+    let context = TestContext::new();
+    context.create_test_config().unwrap();
+
+    let mut cmd = context.kina_command();
+    cmd.args(["get", "pods", "-n", "kube-system", "-A"]);
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_get_kubeconfig_output_in_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["get", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--output <FILE>"))
+        .stdout(predicate::str::contains("--format <FORMAT>"));
+}
+
+#[test]
+fn test_get_kubeconfig_set_context_requires_merge() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["get", "kubeconfig", "--set-context"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--merge"));
+
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["get", "kubeconfig", "--merge", "--output", "kc.yaml"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
 #[test]
 fn test_create_command_with_cni_ptp() {
     let context = TestContext::new();
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["create", "--cni", "ptp"]);
+    cmd.args(["create", "--cni", "ptp"]);
     // Tests create with PTP CNI
 }
 
@@ -158,7 +631,7 @@ fn test_create_command_with_workers() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["create", "--workers", "2"]);
+    cmd.args(["create", "--workers", "2"]);
     // Tests create with worker nodes (will fail without Apple Container, but validates CLI parsing)
 }
 
@@ -168,14 +641,14 @@ fn test_create_command_with_workers_zero() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["create", "--workers", "0"]);
+    cmd.args(["create", "--workers", "0"]);
     // Tests create with 0 workers (single-node mode, the default)
 }
 
 #[test]
 fn test_create_command_workers_in_help() {
     let mut cmd = Command::cargo_bin("kina").unwrap();
-    cmd.args(&["create", "--help"]);
+    cmd.args(["create", "--help"]);
     cmd.assert()
         .success()
         .stdout(predicate::str::contains("--workers"));
@@ -187,7 +660,7 @@ fn test_create_command_with_cni_cilium() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["create", "--cni", "cilium"]);
+    cmd.args(["create", "--cni", "cilium"]);
     // Tests create with Cilium CNI
 }
 
@@ -198,7 +671,7 @@ fn test_create_command_validation() {
 
     // Test create command with invalid cluster name
     let mut cmd = context.kina_command();
-    cmd.args(&["create", "-invalid-name-"]);
+    cmd.args(["create", "-invalid-name-"]);
     cmd.assert().failure(); // Should fail due to invalid cluster name
 }
 
@@ -210,8 +683,8 @@ fn test_list_command_empty() {
     // List command when no clusters exist
     let mut cmd = context.kina_command();
     cmd.arg("list");
-    // Note: This will likely fail because Apple Container isn't available in test environment
-    // In a real test, we would mock the Apple Container client
+    // Only parses the command; cluster_manager_tests.rs runs the list flow
+    // against the mock Apple Container client
 }
 
 #[test]
@@ -220,29 +693,97 @@ fn test_config_command() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["config", "show"]);
+    cmd.args(["config", "show"]);
     cmd.assert().success(); // Should show current config
 }
 
+#[test]
+fn test_config_show_lists_every_section() {
+    let context = TestContext::new();
+    context.create_test_config().unwrap();
+
+    let mut cmd = context.kina_command();
+    cmd.args(["config", "show"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("cluster:"))
+        .stdout(predicate::str::contains("apple_container:"))
+        .stdout(predicate::str::contains("kubernetes:"))
+        .stdout(predicate::str::contains("logging:"))
+        .stdout(predicate::str::contains("test-cluster"))
+        .stdout(predicate::str::contains(&context.config_path));
+}
+
 #[test]
 fn test_config_path_command() {
     let context = TestContext::new();
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["config", "path"]);
+    cmd.args(["config", "path"]);
     cmd.assert()
         .success()
         .stdout(predicate::str::contains(&context.config_path));
 }
 
+#[test]
+fn test_config_export_json_command() {
+    let context = TestContext::new();
+    context.create_test_config().unwrap();
+
+    let mut cmd = context.kina_command();
+    cmd.args(["config", "export", "--format", "json"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with("{"))
+        .stdout(predicate::str::contains("\"cluster\""));
+}
+
+#[test]
+fn test_config_reset_with_yes_backs_up_existing_config() {
+    let context = TestContext::new();
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("config.toml");
+    let original = std::fs::read_to_string(&context.config_path).unwrap();
+    std::fs::write(&config_path, &original).unwrap();
+
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.arg("--config")
+        .arg(&config_path)
+        .args(["config", "reset", "--yes"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("config.toml.bak"));
+
+    let backup = std::fs::read_to_string(temp_dir.path().join("config.toml.bak")).unwrap();
+    assert_eq!(backup, original);
+}
+
+#[test]
+fn test_config_reset_without_yes_requires_terminal() {
+    let context = TestContext::new();
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("config.toml");
+    std::fs::copy(&context.config_path, &config_path).unwrap();
+
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.arg("--config")
+        .arg(&config_path)
+        .args(["config", "reset"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--yes"));
+
+    assert!(!temp_dir.path().join("config.toml.bak").exists());
+}
+
 #[test]
 fn test_verbose_flag() {
     let context = TestContext::new();
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["--verbose", "list"]);
+    cmd.args(["--verbose", "list"]);
     // Should work with verbose output
 }
 
@@ -252,7 +793,7 @@ fn test_quiet_flag() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["--quiet", "list"]);
+    cmd.args(["--quiet", "list"]);
     // Should work with quiet output
 }
 
@@ -262,7 +803,7 @@ fn test_conflicting_flags() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["--verbose", "--quiet", "list"]);
+    cmd.args(["--verbose", "--quiet", "list"]);
     cmd.assert().failure(); // Should fail due to conflicting flags
 }
 
@@ -271,7 +812,7 @@ fn test_conflicting_flags() {
 #[test]
 fn test_delete_command_help() {
     let mut cmd = Command::cargo_bin("kina").unwrap();
-    cmd.args(&["delete", "--help"]);
+    cmd.args(["delete", "--help"]);
     cmd.assert()
         .success()
         .stdout(predicate::str::contains("Delete a Kubernetes cluster"));
@@ -283,7 +824,7 @@ fn test_delete_command_with_name() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["delete", "test-cluster"]);
+    cmd.args(["delete", "test-cluster"]);
     // This will likely fail since cluster doesn't exist, but tests command parsing
 }
 
@@ -293,26 +834,93 @@ fn test_delete_command_all_flag() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["delete", "--all"]);
+    cmd.args(["delete", "--all"]);
     // Tests the --all flag functionality
 }
 
+#[test]
+fn test_delete_force_conflicts_with_grace_period() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["delete", "dev", "--force", "--grace-period", "5"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
 #[test]
 fn test_delete_command_conflicting_args() {
     let context = TestContext::new();
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["delete", "--all", "some-cluster"]);
+    cmd.args(["delete", "--all", "some-cluster"]);
     cmd.assert().failure(); // Should fail due to conflicting arguments
 }
 
+// ===== RENAME COMMAND TESTS =====
+
+#[test]
+fn test_rename_command_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["rename", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("<OLD_NAME>"))
+        .stdout(predicate::str::contains("<NEW_NAME>"));
+}
+
+#[test]
+fn test_rename_command_requires_both_names() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["rename", "only-one"]);
+    cmd.assert().failure();
+}
+
+// ===== DUPLICATE COMMAND TESTS =====
+
+#[test]
+fn test_duplicate_command_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["duplicate", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("<SOURCE>"))
+        .stdout(predicate::str::contains("<DEST>"));
+}
+
+// ===== IMAGE COMMAND TESTS =====
+
+#[test]
+fn test_image_list_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["image", "list", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--all"));
+}
+
+#[test]
+fn test_image_pull_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["image", "pull", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--platform"));
+}
+
+#[test]
+fn test_image_pull_requires_image() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["image", "pull"]);
+    cmd.assert().failure();
+}
+
 // ===== STATUS COMMAND TESTS =====
 
 #[test]
 fn test_status_command_help() {
     let mut cmd = Command::cargo_bin("kina").unwrap();
-    cmd.args(&["status", "--help"]);
+    cmd.args(["status", "--help"]);
     cmd.assert().success().stdout(predicate::str::contains(
         "Show detailed status of a cluster",
     ));
@@ -334,7 +942,7 @@ fn test_status_command_with_name() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["status", "test-cluster"]);
+    cmd.args(["status", "test-cluster"]);
     // Tests status command with specific cluster name
 }
 
@@ -358,7 +966,7 @@ fn test_status_command_verbose() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["status", "--verbose"]);
+    cmd.args(["status", "--verbose"]);
     // Tests verbose output for status
 }
 
@@ -369,17 +977,17 @@ fn test_status_command_output_formats() {
 
     // Test YAML output
     let mut cmd = context.kina_command();
-    cmd.args(&["status", "--output", "yaml"]);
+    cmd.args(["status", "--output", "yaml"]);
     // Should accept yaml format
 
     // Test JSON output
     let mut cmd = context.kina_command();
-    cmd.args(&["status", "--output", "json"]);
+    cmd.args(["status", "--output", "json"]);
     // Should accept json format
 
     // Test table output (default)
     let mut cmd = context.kina_command();
-    cmd.args(&["status", "--output", "table"]);
+    cmd.args(["status", "--output", "table"]);
     // Should accept table format
 }
 
@@ -388,7 +996,7 @@ fn test_status_command_output_formats() {
 #[test]
 fn test_get_command_help() {
     let mut cmd = Command::cargo_bin("kina").unwrap();
-    cmd.args(&["get", "--help"]);
+    cmd.args(["get", "--help"]);
     cmd.assert().success().stdout(predicate::str::contains(
         "Get information about clusters or resources",
     ));
@@ -400,7 +1008,7 @@ fn test_get_clusters() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["get", "clusters"]);
+    cmd.args(["get", "clusters"]);
     // Tests getting cluster list
 }
 
@@ -410,7 +1018,7 @@ fn test_get_kubeconfig() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["get", "kubeconfig"]);
+    cmd.args(["get", "kubeconfig"]);
     // Tests getting kubeconfig for default cluster
 }
 
@@ -420,7 +1028,7 @@ fn test_get_kubeconfig_with_name() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["get", "kubeconfig", "test-cluster"]);
+    cmd.args(["get", "kubeconfig", "test-cluster"]);
     // Tests getting kubeconfig for specific cluster
 }
 
@@ -430,7 +1038,7 @@ fn test_get_nodes() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["get", "nodes"]);
+    cmd.args(["get", "nodes"]);
     // Tests getting nodes for default cluster
 }
 
@@ -440,7 +1048,7 @@ fn test_get_nodes_with_name() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["get", "nodes", "test-cluster"]);
+    cmd.args(["get", "nodes", "test-cluster"]);
     // Tests getting nodes for specific cluster
 }
 
@@ -449,7 +1057,7 @@ fn test_get_nodes_with_name() {
 #[test]
 fn test_load_command_help() {
     let mut cmd = Command::cargo_bin("kina").unwrap();
-    cmd.args(&["load", "--help"]);
+    cmd.args(["load", "--help"]);
     cmd.assert().success().stdout(predicate::str::contains(
         "Load container images into clusters",
     ));
@@ -461,7 +1069,7 @@ fn test_load_command_basic() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["load", "nginx:latest"]);
+    cmd.args(["load", "nginx:latest"]);
     // Tests loading image to default cluster
 }
 
@@ -471,7 +1079,7 @@ fn test_load_command_with_cluster() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["load", "nginx:latest", "--cluster", "test-cluster"]);
+    cmd.args(["load", "nginx:latest", "--cluster", "test-cluster"]);
     // Tests loading image to specific cluster
 }
 
@@ -485,12 +1093,21 @@ fn test_load_command_missing_image() {
     cmd.assert().failure(); // Should fail due to missing image argument
 }
 
+#[test]
+fn test_update_check_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["update-check", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--force"));
+}
+
 // ===== INSTALL COMMAND TESTS =====
 
 #[test]
 fn test_install_command_help() {
     let mut cmd = Command::cargo_bin("kina").unwrap();
-    cmd.args(&["install", "--help"]);
+    cmd.args(["install", "--help"]);
     cmd.assert()
         .success()
         .stdout(predicate::str::contains("Install addons"));
@@ -502,7 +1119,7 @@ fn test_install_nginx_ingress() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["install", "nginx-ingress"]);
+    cmd.args(["install", "nginx-ingress"]);
     // Tests installing nginx-ingress addon
 }
 
@@ -512,7 +1129,7 @@ fn test_install_ingress_nginx() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["install", "ingress-nginx"]);
+    cmd.args(["install", "ingress-nginx"]);
     // Tests installing ingress-nginx addon
 }
 
@@ -522,17 +1139,27 @@ fn test_install_cni() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["install", "cni"]);
+    cmd.args(["install", "cni"]);
     // Tests installing CNI addon
 }
 
+#[test]
+fn test_install_cni_plugin_in_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["install", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--plugin"))
+        .stdout(predicate::str::contains("calico"));
+}
+
 #[test]
 fn test_install_coredns() {
     let context = TestContext::new();
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["install", "coredns"]);
+    cmd.args(["install", "coredns"]);
     // Tests installing CoreDNS addon
 }
 
@@ -542,7 +1169,7 @@ fn test_install_metrics_server() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["install", "metrics-server"]);
+    cmd.args(["install", "metrics-server"]);
     // Tests installing metrics-server addon
 }
 
@@ -552,7 +1179,7 @@ fn test_install_with_cluster() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["install", "nginx-ingress", "--cluster", "test-cluster"]);
+    cmd.args(["install", "nginx-ingress", "--cluster", "test-cluster"]);
     // Tests installing addon to specific cluster
 }
 
@@ -562,7 +1189,7 @@ fn test_install_with_version() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["install", "nginx-ingress", "--version", "1.0.0"]);
+    cmd.args(["install", "nginx-ingress", "--version", "1.0.0"]);
     // Tests installing addon with specific version
 }
 
@@ -572,10 +1199,19 @@ fn test_install_with_config() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["install", "nginx-ingress", "--config", "custom-config.yaml"]);
+    cmd.args(["install", "nginx-ingress", "--config", "custom-config.yaml"]);
     // Tests installing addon with custom config
 }
 
+#[test]
+fn test_install_if_not_exists_in_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["install", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--if-not-exists"));
+}
+
 #[test]
 fn test_install_missing_addon() {
     let context = TestContext::new();
@@ -586,12 +1222,127 @@ fn test_install_missing_addon() {
     cmd.assert().failure(); // Should fail due to missing addon argument
 }
 
+// ===== APPLY COMMAND TESTS =====
+
+#[test]
+fn test_apply_command_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["apply", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--file"))
+        .stdout(predicate::str::contains("--namespace"))
+        .stdout(predicate::str::contains("--dry-run"));
+}
+
+#[test]
+fn test_apply_requires_file() {
+    let context = TestContext::new();
+    context.create_test_config().unwrap();
+
+    let mut cmd = context.kina_command();
+    cmd.args(["apply", "kina"]);
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_apply_rejects_unknown_dry_run_mode() {
+    let context = TestContext::new();
+    context.create_test_config().unwrap();
+
+    let mut cmd = context.kina_command();
+    cmd.args(["apply", "kina", "-f", "deploy.yaml", "--dry-run", "maybe"]);
+    cmd.assert().failure();
+}
+
+// ===== DELETE-RESOURCE COMMAND TESTS =====
+
+#[test]
+fn test_delete_resource_command_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["delete-resource", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--all"))
+        .stdout(predicate::str::contains("--grace-period"))
+        .stdout(predicate::str::contains("--cascade"));
+}
+
+#[test]
+fn test_delete_resource_requires_name_all_or_selector() {
+    let context = TestContext::new();
+    context.create_test_config().unwrap();
+
+    let mut cmd = context.kina_command();
+    cmd.args(["delete-resource", "kina", "pods"]);
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_delete_resource_name_conflicts_with_all() {
+    let context = TestContext::new();
+    context.create_test_config().unwrap();
+
+    let mut cmd = context.kina_command();
+    cmd.args(["delete-resource", "kina", "pods", "web", "--all"]);
+    cmd.assert().failure();
+}
+
+// ===== WAIT COMMAND TESTS =====
+
+#[test]
+fn test_wait_command_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["wait", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--for <CONDITION>"))
+        .stdout(predicate::str::contains("--timeout"))
+        .stdout(predicate::str::contains("--all-namespaces"));
+}
+
+#[test]
+fn test_wait_rejects_invalid_condition() {
+    let context = TestContext::new();
+    context.create_test_config().unwrap();
+
+    let mut cmd = context.kina_command();
+    cmd.args(["wait", "kina", "pod/web", "--for", "Ready"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid wait condition"));
+}
+
+// ===== PORT-FORWARD COMMAND TESTS =====
+
+#[test]
+fn test_port_forward_command_help() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["port-forward", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("LOCAL:REMOTE"))
+        .stdout(predicate::str::contains("--namespace"));
+}
+
+#[test]
+fn test_port_forward_rejects_invalid_port() {
+    let context = TestContext::new();
+    context.create_test_config().unwrap();
+
+    let mut cmd = context.kina_command();
+    cmd.args(["port-forward", "kina", "svc/web", "8080:0"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid port"));
+}
+
 // ===== EXPORT COMMAND TESTS =====
 
 #[test]
 fn test_export_command_help() {
     let mut cmd = Command::cargo_bin("kina").unwrap();
-    cmd.args(&["export", "--help"]);
+    cmd.args(["export", "--help"]);
     cmd.assert()
         .success()
         .stdout(predicate::str::contains("Export cluster configuration"));
@@ -613,7 +1364,7 @@ fn test_export_specific_cluster() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["export", "test-cluster"]);
+    cmd.args(["export", "test-cluster"]);
     // Tests exporting specific cluster
 }
 
@@ -623,7 +1374,7 @@ fn test_export_kubeconfig_format() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["export", "--format", "kubeconfig"]);
+    cmd.args(["export", "--format", "kubeconfig"]);
     // Tests kubeconfig export format
 }
 
@@ -633,7 +1384,7 @@ fn test_export_config_format() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["export", "--format", "config"]);
+    cmd.args(["export", "--format", "config"]);
     // Tests config export format
 }
 
@@ -643,7 +1394,7 @@ fn test_export_to_file() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["export", "--output", "/tmp/test-kubeconfig"]);
+    cmd.args(["export", "--output", "/tmp/test-kubeconfig"]);
     // Tests exporting to specific file
 }
 
@@ -652,7 +1403,7 @@ fn test_export_to_file() {
 #[test]
 fn test_approve_csr_command_help() {
     let mut cmd = Command::cargo_bin("kina").unwrap();
-    cmd.args(&["approve-csr", "--help"]);
+    cmd.args(["approve-csr", "--help"]);
     cmd.assert().success().stdout(predicate::str::contains(
         "Approve pending kubelet Certificate Signing Requests",
     ));
@@ -674,7 +1425,7 @@ fn test_approve_csr_specific_cluster() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["approve-csr", "test-cluster"]);
+    cmd.args(["approve-csr", "test-cluster"]);
     // Tests approving CSRs for specific cluster
 }
 
@@ -696,7 +1447,7 @@ fn test_get_kubeconfig_no_clusters() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["get", "kubeconfig"]);
+    cmd.args(["get", "kubeconfig"]);
     cmd.assert()
         .success()
         .stdout(predicate::str::contains("No clusters found"));
@@ -708,7 +1459,7 @@ fn test_get_nodes_no_clusters() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["get", "nodes"]);
+    cmd.args(["get", "nodes"]);
     cmd.assert()
         .success()
         .stdout(predicate::str::contains("No clusters found"));
@@ -732,7 +1483,7 @@ fn test_load_no_clusters() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["load", "nginx:latest"]);
+    cmd.args(["load", "nginx:latest"]);
     cmd.assert()
         .success()
         .stdout(predicate::str::contains("No clusters found"));
@@ -744,7 +1495,53 @@ fn test_install_no_clusters() {
     context.create_test_config().unwrap();
 
     let mut cmd = context.kina_command();
-    cmd.args(&["install", "nginx-ingress"]);
+    cmd.args(["install", "nginx-ingress"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No clusters found"));
+}
+
+// ===== LIST FILTER / SORT TESTS =====
+
+#[test]
+fn test_list_command_filter_running() {
+    let context = TestContext::new();
+    context.create_test_config().unwrap();
+
+    let mut cmd = context.kina_command();
+    cmd.args(["list", "--filter", "running"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No clusters found"));
+}
+
+#[test]
+fn test_list_command_filter_invalid_status() {
+    let context = TestContext::new();
+    context.create_test_config().unwrap();
+
+    let mut cmd = context.kina_command();
+    cmd.args(["list", "--filter", "sleeping"]);
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_list_command_sort_created() {
+    let context = TestContext::new();
+    context.create_test_config().unwrap();
+
+    let mut cmd = context.kina_command();
+    cmd.args(["list", "--sort", "created"]);
+    cmd.assert().success();
+}
+
+#[test]
+fn test_list_command_output_table() {
+    let context = TestContext::new();
+    context.create_test_config().unwrap();
+
+    let mut cmd = context.kina_command();
+    cmd.args(["list", "--output", "table"]);
     cmd.assert()
         .success()
         .stdout(predicate::str::contains("No clusters found"));
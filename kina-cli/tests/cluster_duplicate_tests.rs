@@ -0,0 +1,71 @@
+/// `kina duplicate` tests.
+///
+/// Pure tests of `ClusterConfig::from_cluster_info`, which exports a running
+/// cluster's node layout under the duplicate's name — no container CLI involved.
+use kina_cli::config::cluster_config::ClusterConfig;
+use kina_cli::core::types::{ClusterInfo, ClusterStatus, NodeInfo, NodeRole};
+
+fn node(name: &str, role: NodeRole) -> NodeInfo {
+    NodeInfo {
+        name: name.to_string(),
+        role,
+        status: "running".to_string(),
+        version: "v1.36.1".to_string(),
+        container_id: Some(name.to_string()),
+        ip_address: None,
+    }
+}
+
+fn source() -> ClusterInfo {
+    ClusterInfo {
+        name: "template".to_string(),
+        image: "kindest/node:v1.35.0".to_string(),
+        status: ClusterStatus::Running,
+        created: "2026-01-01 10:00 UTC".to_string(),
+        creation_timestamp: None,
+        nodes: vec![
+            node("template-control-plane", NodeRole::ControlPlane),
+            node("template-worker", NodeRole::Worker),
+            node("template-worker-2", NodeRole::Worker),
+        ],
+        kubeconfig_path: None,
+    }
+}
+
+#[test]
+fn from_cluster_info_uses_the_new_name() {
+    let config = ClusterConfig::from_cluster_info(&source(), "experiment");
+
+    assert_eq!(config.name, "experiment");
+    let names: Vec<&str> = config.nodes.iter().map(|n| n.name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec![
+            "experiment-control-plane",
+            "experiment-worker",
+            "experiment-worker-2"
+        ]
+    );
+}
+
+#[test]
+fn from_cluster_info_keeps_roles_and_image() {
+    let config = ClusterConfig::from_cluster_info(&source(), "experiment");
+
+    assert_eq!(config.control_plane_nodes().len(), 1);
+    assert_eq!(config.worker_nodes().len(), 2);
+    assert!(config
+        .nodes
+        .iter()
+        .all(|n| n.image.as_deref() == Some("kindest/node:v1.35.0")));
+}
+
+#[test]
+fn from_cluster_info_round_trips_through_yaml() {
+    let config = ClusterConfig::from_cluster_info(&source(), "experiment");
+    let yaml = serde_yaml::to_string(&config).unwrap();
+    let parsed: ClusterConfig = serde_yaml::from_str(&yaml).unwrap();
+
+    assert_eq!(parsed.name, "experiment");
+    assert_eq!(parsed.nodes.len(), 3);
+}
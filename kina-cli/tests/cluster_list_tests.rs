@@ -0,0 +1,192 @@
+/// `kina list --filter / --sort / --output table` tests.
+///
+/// All pure tests: `ListArgs::select` and `render_cluster_table` are exercised on hand-built `ClusterInfo` values and
+/// `ClusterStatus` parsing is exercised directly — no container CLI involved.
+use clap::{Parser, ValueEnum};
+use kina_cli::cli::{
+    render_cluster_table, Cli, Commands, ListArgs, ListOutputFormat, ListSortField,
+};
+use kina_cli::core::types::{ClusterInfo, ClusterStatus, NodeRole};
+
+fn cluster(name: &str, status: ClusterStatus, created: &str) -> ClusterInfo {
+    ClusterInfo {
+        name: name.to_string(),
+        image: "kindest/node:v1.36.1".to_string(),
+        status,
+        created: created.to_string(),
+        creation_timestamp: None,
+        nodes: Vec::new(),
+        kubeconfig_path: None,
+    }
+}
+
+fn names(clusters: &[ClusterInfo]) -> Vec<&str> {
+    clusters.iter().map(|c| c.name.as_str()).collect()
+}
+
+fn fixture() -> Vec<ClusterInfo> {
+    vec![
+        cluster("zeta", ClusterStatus::Running, "2026-01-03 10:00 UTC"),
+        cluster("alpha", ClusterStatus::Stopped, "2026-01-02 10:00 UTC"),
+        cluster("mid", ClusterStatus::Running, "2026-01-01 10:00 UTC"),
+    ]
+}
+
+#[test]
+fn cluster_status_parses_case_insensitively() {
+    for raw in ["running", "RUNNING", "Running"] {
+        assert_eq!(
+            raw.parse::<ClusterStatus>().unwrap(),
+            ClusterStatus::Running
+        );
+    }
+}
+
+#[test]
+fn cluster_status_paused_is_alias_for_stopped() {
+    assert_eq!(
+        "paused".parse::<ClusterStatus>().unwrap(),
+        ClusterStatus::Stopped
+    );
+}
+
+#[test]
+fn cluster_status_rejects_unknown_value() {
+    assert!("sleeping".parse::<ClusterStatus>().is_err());
+}
+
+#[test]
+fn cluster_status_display_round_trips_through_from_str() {
+    for status in ClusterStatus::value_variants() {
+        let shown = status.to_string();
+        assert_eq!(&shown.parse::<ClusterStatus>().unwrap(), status);
+    }
+    assert_eq!(ClusterStatus::Running.to_string(), "Running");
+    assert_eq!(ClusterStatus::Stopped.to_string(), "Stopped");
+}
+
+#[test]
+fn list_filter_accepts_any_case_and_paused() {
+    for (raw, expected) in [
+        ("running", ClusterStatus::Running),
+        ("RUNNING", ClusterStatus::Running),
+        ("Stopped", ClusterStatus::Stopped),
+        ("paused", ClusterStatus::Stopped),
+    ] {
+        let cli = Cli::try_parse_from(["kina", "list", "--filter", raw]).unwrap();
+        let Some(Commands::List(args)) = cli.command else {
+            panic!("expected list command");
+        };
+        assert_eq!(args.filter, Some(expected));
+    }
+    assert!(Cli::try_parse_from(["kina", "list", "--filter", "sleeping"]).is_err());
+}
+
+#[test]
+fn select_sorts_by_name_by_default() {
+    let args = ListArgs {
+        verbose: false,
+        output: ListOutputFormat::Plain,
+        filter: None,
+        sort: ListSortField::Name,
+    };
+    assert_eq!(names(&args.select(fixture())), vec!["alpha", "mid", "zeta"]);
+}
+
+#[test]
+fn select_filters_by_status() {
+    let args = ListArgs {
+        verbose: false,
+        output: ListOutputFormat::Plain,
+        filter: Some(ClusterStatus::Running),
+        sort: ListSortField::Name,
+    };
+    assert_eq!(names(&args.select(fixture())), vec!["mid", "zeta"]);
+}
+
+#[test]
+fn select_sorts_by_created_oldest_first() {
+    let args = ListArgs {
+        verbose: false,
+        output: ListOutputFormat::Plain,
+        filter: None,
+        sort: ListSortField::Created,
+    };
+    assert_eq!(names(&args.select(fixture())), vec!["mid", "alpha", "zeta"]);
+}
+
+#[test]
+fn select_sorts_by_creation_timestamp_before_created_text() {
+    let mut clusters = fixture();
+    // zeta is the oldest by timestamp even though its `created` text is newest
+    clusters[0].creation_timestamp = Some("2025-12-01T00:00:00Z".parse().unwrap());
+    clusters[1].creation_timestamp = Some("2026-01-02T10:00:00Z".parse().unwrap());
+    let args = ListArgs {
+        verbose: false,
+        output: ListOutputFormat::Plain,
+        filter: None,
+        sort: ListSortField::Created,
+    };
+    assert_eq!(names(&args.select(clusters)), vec!["zeta", "alpha", "mid"]);
+}
+
+#[test]
+fn select_sorts_by_status_then_name() {
+    let args = ListArgs {
+        verbose: false,
+        output: ListOutputFormat::Plain,
+        filter: None,
+        sort: ListSortField::Status,
+    };
+    assert_eq!(names(&args.select(fixture())), vec!["mid", "zeta", "alpha"]);
+}
+
+#[test]
+fn table_has_expected_headers_in_order() {
+    let table = render_cluster_table(&fixture());
+    let header = table.lines().next().unwrap_or_default();
+    let positions: Vec<usize> = ["NAME", "STATUS", "NODES", "IMAGE", "CREATED"]
+        .iter()
+        .map(|h| header.find(h).unwrap_or(usize::MAX))
+        .collect();
+    assert!(
+        positions.windows(2).all(|w| w[0] < w[1]),
+        "headers must appear in NAME, STATUS, NODES, IMAGE, CREATED order; got: {:?}",
+        header
+    );
+}
+
+#[test]
+fn table_keeps_long_cluster_names_intact() {
+    let long = "a-really-long-cluster-name-that-breaks-fixed-widths";
+    let table = render_cluster_table(&[cluster(long, ClusterStatus::Running, "unknown")]);
+    assert!(table.contains(long));
+}
+
+#[test]
+fn table_truncates_long_images() {
+    let mut c = cluster("kina", ClusterStatus::Running, "unknown");
+    c.image = format!("registry.example.com/{}/node:v1.36.1", "x".repeat(60));
+    let table = render_cluster_table(&[c.clone()]);
+    assert!(!table.contains(&c.image));
+    assert!(table.contains('…'));
+}
+
+#[test]
+fn node_role_uses_kubernetes_spelling() {
+    assert_eq!(NodeRole::ControlPlane.to_string(), "control-plane");
+    assert_eq!(NodeRole::Worker.to_string(), "worker");
+    assert_eq!(
+        serde_json::to_string(&NodeRole::ControlPlane).unwrap(),
+        "\"control-plane\""
+    );
+    assert_eq!(
+        serde_json::from_str::<NodeRole>("\"worker\"").unwrap(),
+        NodeRole::Worker
+    );
+    for raw in ["control-plane", "ControlPlane", "CONTROL-PLANE"] {
+        assert_eq!(raw.parse::<NodeRole>().unwrap(), NodeRole::ControlPlane);
+    }
+    assert_eq!("Worker".parse::<NodeRole>().unwrap(), NodeRole::Worker);
+    assert!("etcd".parse::<NodeRole>().is_err());
+}
@@ -0,0 +1,145 @@
+/// `ClusterManager` tests over `MockAppleContainerClient`.
+///
+/// The create, list, status, get, load and delete flows that `cli_tests.rs`
+/// can only parse without an Apple Container install run here against the
+/// in-memory client — no container CLI involved.
+use std::collections::BTreeMap;
+
+use kina_cli::config::cluster_config::ClusterConfig;
+use kina_cli::core::cluster::{ClusterManager, StoredClusterConfig};
+use kina_cli::core::mock::MockAppleContainerClient;
+use kina_cli::core::types::{
+    ClusterStatus, CreateClusterOptions, DeleteClusterOptions, LoadImageOptions,
+};
+use kina_cli::Config;
+
+/// Manager over `mock` whose kubeconfig cleanup stays inside `dir`
+fn manager(mock: &MockAppleContainerClient, dir: &tempfile::TempDir) -> ClusterManager {
+    let mut config = Config::default();
+    config.kubernetes.kubeconfig_dir = dir.path().to_path_buf();
+    config.cluster.data_dir = dir.path().join("data");
+    ClusterManager::new_with_client(&config, Box::new(mock.clone()))
+}
+
+/// Options for a cluster with `workers` workers that skip the readiness wait
+/// and CSR approval
+fn create_options(name: &str, workers: u32) -> CreateClusterOptions {
+    let empty = StoredClusterConfig {
+        node: format!("{}-control-plane", name),
+        labels: BTreeMap::new(),
+        config: ClusterConfig::default_with_name(name),
+        kubeadm_config: None,
+    };
+    CreateClusterOptions {
+        image: "kindest/node:test".to_string(),
+        workers: (workers > 0).then_some(workers),
+        no_wait: true,
+        skip_csr_approval: true,
+        ..empty.create_options(name)
+    }
+}
+
+#[tokio::test]
+async fn create_then_list_status_and_nodes() {
+    let mock = MockAppleContainerClient::new();
+    let dir = tempfile::tempdir().unwrap();
+    let manager = manager(&mock, &dir);
+
+    manager
+        .create_cluster(create_options("test-cluster", 2))
+        .await
+        .unwrap();
+
+    let clusters = manager.list_clusters().await.unwrap();
+    assert_eq!(clusters.len(), 1);
+    assert_eq!(clusters[0].name, "test-cluster");
+
+    let status = manager.get_cluster_status("test-cluster").await.unwrap();
+    assert_eq!(status.status, ClusterStatus::Running);
+    assert_eq!(
+        manager.get_nodes("test-cluster").await.unwrap(),
+        [
+            "test-cluster-control-plane",
+            "test-cluster-worker",
+            "test-cluster-worker-2"
+        ]
+    );
+    assert!(manager.get_cluster_status("missing").await.is_err());
+
+    let err = manager
+        .create_cluster(create_options("test-cluster", 0))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("already exists"), "{}", err);
+    assert_eq!(mock.state().created.len(), 1);
+}
+
+#[tokio::test]
+async fn failed_create_deletes_the_partial_cluster() {
+    let mock =
+        MockAppleContainerClient::new().fail_on("apply_pod_security_labels", "labels rejected");
+    let dir = tempfile::tempdir().unwrap();
+    let manager = manager(&mock, &dir);
+
+    let options = CreateClusterOptions {
+        pod_security_standard: Some("restricted".to_string()),
+        ..create_options("test-cluster", 0)
+    };
+    let err = manager.create_cluster(options).await.unwrap_err();
+    assert!(err.to_string().contains("labels rejected"), "{}", err);
+    assert!(!manager.cluster_exists("test-cluster").await.unwrap());
+}
+
+#[tokio::test]
+async fn kubeconfig_and_image_load_target_the_named_cluster() {
+    let mock = MockAppleContainerClient::new()
+        .with_node_ip("test-cluster-control-plane", "192.168.64.5")
+        .with_running_cluster("test-cluster", 0)
+        .with_running_cluster("kina", 0);
+    let dir = tempfile::tempdir().unwrap();
+    let manager = manager(&mock, &dir);
+
+    let kubeconfig = manager.get_kubeconfig("test-cluster").await.unwrap();
+    assert!(kubeconfig.contains("server: https://192.168.64.5:6443"));
+    assert!(manager.get_kubeconfig("missing").await.is_err());
+
+    manager
+        .load_image(LoadImageOptions {
+            cluster: "test-cluster".to_string(),
+            image: "nginx:latest".to_string(),
+            archive: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        mock.state().loaded_images,
+        [("test-cluster".to_string(), "nginx:latest".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn delete_named_and_all_clusters() {
+    let mock = MockAppleContainerClient::new()
+        .with_running_cluster("test-cluster", 1)
+        .with_running_cluster("kina", 0);
+    let dir = tempfile::tempdir().unwrap();
+    let manager = manager(&mock, &dir);
+    let options = DeleteClusterOptions::default();
+
+    // Deleting a cluster that does not exist only warns
+    manager.delete_cluster("missing", &options).await.unwrap();
+
+    manager
+        .delete_cluster("test-cluster", &options)
+        .await
+        .unwrap();
+    assert!(!manager.cluster_exists("test-cluster").await.unwrap());
+
+    let mut deleted = Vec::new();
+    manager
+        .delete_all_clusters(&options, |name| deleted.push(name.to_string()))
+        .await
+        .unwrap();
+    assert_eq!(deleted, ["kina"]);
+    assert!(manager.list_clusters().await.unwrap().is_empty());
+}
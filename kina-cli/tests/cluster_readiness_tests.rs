@@ -0,0 +1,96 @@
+/// Cluster creation lifecycle tests — `--wait-for-ready` and `--retain` diagnostics.
+///
+/// All pure tests: the kube-system pod poll and diagnostics collection run against live
+/// node containers, so only the parsing / predicates / path helpers are exercised here.
+use kina_cli::core::apple_container::{
+    parse_pod_phases, pods_settled, resolve_wait_timeout, DEFAULT_READY_TIMEOUT_SECS,
+};
+
+#[test]
+fn parse_pod_phases_extracts_name_and_phase() {
+    let out = "coredns-abc   Running\netcd-kina-control-plane   Running\nkube-proxy-x   Pending\n";
+    let phases = parse_pod_phases(out);
+    assert_eq!(
+        phases,
+        vec![
+            ("coredns-abc".to_string(), "Running".to_string()),
+            ("etcd-kina-control-plane".to_string(), "Running".to_string()),
+            ("kube-proxy-x".to_string(), "Pending".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn parse_pod_phases_skips_blank_and_malformed_lines() {
+    let phases = parse_pod_phases("\n   \nlonely\ncoredns Running\n");
+    assert_eq!(phases, vec![("coredns".to_string(), "Running".to_string())]);
+}
+
+#[test]
+fn pods_settled_accepts_running_and_succeeded() {
+    let phases = parse_pod_phases("a Running\nb Succeeded\n");
+    assert!(pods_settled(&phases));
+}
+
+#[test]
+fn pods_settled_rejects_pending_pod() {
+    let phases = parse_pod_phases("a Running\nb Pending\n");
+    assert!(!pods_settled(&phases));
+}
+
+#[test]
+fn pods_settled_rejects_empty_namespace() {
+    assert!(!pods_settled(&[]));
+}
+
+#[test]
+fn resolve_wait_timeout_explicit_wait_wins() {
+    assert_eq!(resolve_wait_timeout(Some(60), true), Some(60));
+    assert_eq!(resolve_wait_timeout(Some(60), false), Some(60));
+}
+
+#[test]
+fn resolve_wait_timeout_wait_for_ready_implies_default() {
+    assert_eq!(
+        resolve_wait_timeout(None, true),
+        Some(DEFAULT_READY_TIMEOUT_SECS)
+    );
+    assert_eq!(DEFAULT_READY_TIMEOUT_SECS, 300);
+}
+
+#[test]
+fn resolve_wait_timeout_no_flags_means_no_wait() {
+    assert_eq!(resolve_wait_timeout(None, false), None);
+}
+
+// ===========================================================================
+// --retain failure diagnostics
+// ===========================================================================
+
+#[test]
+fn failure_diagnostics_dir_is_scoped_per_cluster_and_timestamp() {
+    let dir = kina_cli::core::apple_container::failure_diagnostics_dir(
+        std::path::Path::new("/data/kina"),
+        "demo",
+        "20260101T000000Z",
+    );
+    assert_eq!(
+        dir,
+        std::path::PathBuf::from("/data/kina/diagnostics/demo/20260101T000000Z")
+    );
+    // A cluster named after a data directory entry stays inside diagnostics/
+    let dir = kina_cli::core::apple_container::failure_diagnostics_dir(
+        std::path::Path::new("/data/kina"),
+        "events",
+        "20260101T000000Z",
+    );
+    assert!(dir.starts_with("/data/kina/diagnostics"));
+}
+
+#[test]
+fn kubeadm_log_path_is_under_var_log() {
+    assert_eq!(
+        kina_cli::core::apple_container::KUBEADM_LOG_PATH,
+        "/var/log/kubeadm.log"
+    );
+}
@@ -0,0 +1,103 @@
+/// `kina rename` tests.
+///
+/// Pure tests of the naming helpers behind the rename: node container names keep
+/// their role suffix, and kubeconfig cluster/context/user entries follow the new
+/// cluster name — no container CLI or kubectl involved.
+use kina_cli::core::apple_container::{
+    help_lists_subcommand, rename_kubeconfig_entries, renamed_node_name,
+};
+
+const KUBECONFIG: &str = r#"apiVersion: v1
+kind: Config
+clusters:
+- name: dev
+  cluster:
+    server: https://192.168.64.2:6443
+contexts:
+- name: dev
+  context:
+    cluster: dev
+    user: dev-admin
+users:
+- name: dev-admin
+  user:
+    client-certificate-data: abc
+current-context: dev
+"#;
+
+#[test]
+fn renamed_node_name_keeps_role_suffix() {
+    assert_eq!(
+        renamed_node_name("dev-control-plane", "dev", "prod"),
+        "prod-control-plane"
+    );
+    assert_eq!(
+        renamed_node_name("dev-worker", "dev", "prod"),
+        "prod-worker"
+    );
+    assert_eq!(
+        renamed_node_name("dev-worker-2", "dev", "prod"),
+        "prod-worker-2"
+    );
+}
+
+#[test]
+fn renamed_node_name_leaves_foreign_names_alone() {
+    // "devbox-worker" shares a string prefix with "dev" but isn't one of its nodes.
+    assert_eq!(
+        renamed_node_name("devbox-worker", "dev", "prod"),
+        "devbox-worker"
+    );
+    assert_eq!(renamed_node_name("other", "dev", "prod"), "other");
+}
+
+#[test]
+fn rename_kubeconfig_entries_rewrites_cluster_context_and_user() {
+    let renamed = rename_kubeconfig_entries(KUBECONFIG, "dev", "prod").unwrap();
+    let doc: serde_yaml::Value = serde_yaml::from_str(&renamed).unwrap();
+
+    assert_eq!(doc["clusters"][0]["name"].as_str(), Some("prod"));
+    assert_eq!(doc["contexts"][0]["name"].as_str(), Some("prod"));
+    assert_eq!(
+        doc["contexts"][0]["context"]["cluster"].as_str(),
+        Some("prod")
+    );
+    assert_eq!(
+        doc["contexts"][0]["context"]["user"].as_str(),
+        Some("prod-admin")
+    );
+    assert_eq!(doc["users"][0]["name"].as_str(), Some("prod-admin"));
+    assert_eq!(doc["current-context"].as_str(), Some("prod"));
+}
+
+#[test]
+fn rename_kubeconfig_entries_preserves_server_and_credentials() {
+    let renamed = rename_kubeconfig_entries(KUBECONFIG, "dev", "prod").unwrap();
+    let doc: serde_yaml::Value = serde_yaml::from_str(&renamed).unwrap();
+
+    assert_eq!(
+        doc["clusters"][0]["cluster"]["server"].as_str(),
+        Some("https://192.168.64.2:6443")
+    );
+    assert_eq!(
+        doc["users"][0]["user"]["client-certificate-data"].as_str(),
+        Some("abc")
+    );
+}
+
+#[test]
+fn rename_kubeconfig_entries_rejects_invalid_yaml() {
+    assert!(rename_kubeconfig_entries("clusters: [", "dev", "prod").is_err());
+}
+
+#[test]
+fn help_lists_subcommand_matches_whole_command_names() {
+    let help = "USAGE: container <subcommand>\n\nCONTAINER SUBCOMMANDS:\n  \
+                run                     Run a container\n  \
+                rename                  Rename a container\n  \
+                stop                    Stop one or more running containers\n";
+    assert!(help_lists_subcommand(help, "rename"));
+    assert!(help_lists_subcommand(help, "stop"));
+    assert!(!help_lists_subcommand(help, "update"));
+    assert!(!help_lists_subcommand(help, "ren"));
+}
@@ -1,5 +1,4 @@
-use kina_cli::config::Config;
-use serde_yaml;
+use kina_cli::config::{Config, ConfigFormat, RetryConfig};
 use std::fs;
 use tempfile::TempDir;
 
@@ -470,3 +469,84 @@ fn test_kubeconfig_cleanup_command_patterns() {
         vec!["config", "delete-user", "test-cluster-admin"]
     );
 }
+
+#[test]
+fn test_config_export_round_trips_in_every_format() {
+    let mut config = Config::default();
+    config.cluster.default_name = "exported".to_string();
+
+    for format in [ConfigFormat::Toml, ConfigFormat::Yaml, ConfigFormat::Json] {
+        let exported = config.to_string_as(format).unwrap();
+        let reimported = Config::from_str_as(&exported, format).unwrap();
+        assert_eq!(
+            reimported.to_string_as(format).unwrap(),
+            exported,
+            "{:?} export → reimport → export must be identical",
+            format
+        );
+        assert_eq!(reimported.cluster.default_name, "exported");
+    }
+}
+
+#[test]
+fn test_config_export_json_loads_from_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("exported.json");
+
+    let mut config = Config::default();
+    config.cluster.data_dir = temp_dir.path().join("data");
+    config.kubernetes.kubeconfig_dir = temp_dir.path().join("kubeconfig");
+    fs::write(
+        &config_path,
+        config.to_string_as(ConfigFormat::Json).unwrap(),
+    )
+    .unwrap();
+
+    let loaded = Config::load_from_file(&config_path).unwrap();
+    assert_eq!(loaded.cluster.default_name, config.cluster.default_name);
+}
+
+#[test]
+fn test_config_backup_path_appends_bak() {
+    assert_eq!(
+        Config::backup_path(std::path::Path::new("/home/dev/.config/kina/config.toml")),
+        std::path::PathBuf::from("/home/dev/.config/kina/config.toml.bak")
+    );
+}
+
+#[test]
+fn test_config_retry_defaults_when_section_missing() {
+    let yaml = serde_yaml::to_string(&Config::default()).unwrap();
+    let without_retry: String = yaml
+        .lines()
+        .filter(|l| !l.trim_start().starts_with("retry:"))
+        .filter(|l| {
+            ![
+                "max_attempts",
+                "initial_delay_ms",
+                "backoff_factor",
+                "kubeadm_init_attempts",
+            ]
+            .iter()
+            .any(|k| l.trim_start().starts_with(k))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert!(!without_retry.contains("retry:"));
+
+    let config = Config::from_str_as(&without_retry, ConfigFormat::Yaml).unwrap();
+    assert_eq!(config.apple_container.retry, RetryConfig::default());
+}
+
+#[test]
+fn test_config_retry_partial_override() {
+    let mut yaml = serde_yaml::to_value(Config::default()).unwrap();
+    yaml["apple_container"]["retry"] = serde_yaml::from_str("max_attempts: 3").unwrap();
+    let config: Config = serde_yaml::from_value(yaml).unwrap();
+
+    assert_eq!(config.apple_container.retry.max_attempts, 3);
+    assert_eq!(
+        config.apple_container.retry.kubeadm_init_attempts,
+        RetryConfig::default().kubeadm_init_attempts
+    );
+}
@@ -4,7 +4,7 @@
 //! ## P2 contract — these tests are INTENTIONALLY RED until the implementer
 //! adds the two pure fns to `kina_cli::core::verify`:
 //!
-//!   `pub fn rewrite_kubeconfig_server(kubeconfig_yaml: &str, vm_ip: &str) -> String`
+//!   `pub fn rewrite_kubeconfig_server(kubeconfig_yaml: &str, vm_ip: &str, port: u16) -> String`
 //!   `pub fn build_unreachable_diagnostic(
 //!       cluster: &str, vm_ip: &str, port: u16,
 //!       bridge: Option<&str>, gateway: Option<&str>,
@@ -15,11 +15,11 @@
 //! `rewrite_kubeconfig_server`:
 //!   - Accepts a raw kubeconfig YAML string and a target VM IP.
 //!   - Rewrites the `server:` value inside every cluster stanza to
-//!     `https://<vm_ip>:6443` (port 6443 fixed).
+//!     `https://<vm_ip>:<port>` (6443 unless --api-server-port is set).
 //!   - Generalises the ad-hoc localhost→VM-IP replace that lives in
 //!     apple_container.rs ~line 1774:
-//!       kubeconfig.replace("https://127.0.0.1:6443", &format!("https://{}:6443", vm_ip))
-//!       kubeconfig.replace("https://localhost:6443", &format!("https://{}:6443", vm_ip))
+//!     kubeconfig.replace("https://127.0.0.1:6443", &format!("https://{}:6443", vm_ip))
+//!     kubeconfig.replace("https://localhost:6443", &format!("https://{}:6443", vm_ip))
 //!     The new pure fn handles ANY host that currently appears after `server: https://`.
 //!   - Idempotent: if the server is already `https://<vm_ip>:6443`, output == input.
 //!   - Must NOT touch any other line (cluster names, certificate-authority-data,
@@ -111,7 +111,7 @@ users:\n\
 #[test]
 fn rewrite_replaces_localhost_ip_with_vm_ip() {
     let vm_ip = "10.211.55.4";
-    let result = rewrite_kubeconfig_server(kubeconfig_localhost(), vm_ip);
+    let result = rewrite_kubeconfig_server(kubeconfig_localhost(), vm_ip, 6443);
 
     assert!(
         result.contains(&format!("server: https://{}:6443", vm_ip)),
@@ -136,7 +136,7 @@ fn rewrite_replaces_localhost_ip_with_vm_ip() {
 #[test]
 fn rewrite_replaces_old_vm_ip_with_new_vm_ip() {
     let new_ip = "10.211.55.7";
-    let result = rewrite_kubeconfig_server(kubeconfig_old_ip(), new_ip);
+    let result = rewrite_kubeconfig_server(kubeconfig_old_ip(), new_ip, 6443);
 
     assert!(
         result.contains(&format!("server: https://{}:6443", new_ip)),
@@ -174,7 +174,7 @@ kind: Config\n",
         vm_ip
     );
 
-    let result = rewrite_kubeconfig_server(&already_correct, vm_ip);
+    let result = rewrite_kubeconfig_server(&already_correct, vm_ip, 6443);
 
     assert_eq!(
         result, already_correct,
@@ -193,7 +193,7 @@ kind: Config\n",
 #[test]
 fn rewrite_preserves_non_server_lines_verbatim() {
     let vm_ip = "10.211.55.4";
-    let result = rewrite_kubeconfig_server(kubeconfig_localhost(), vm_ip);
+    let result = rewrite_kubeconfig_server(kubeconfig_localhost(), vm_ip, 6443);
 
     let preserved = [
         "apiVersion: v1",
@@ -227,8 +227,8 @@ fn rewrite_preserves_port_6443_only_host_changes() {
     let ip_a = "10.211.55.4";
     let ip_b = "172.16.0.10";
 
-    let result_a = rewrite_kubeconfig_server(kubeconfig_localhost(), ip_a);
-    let result_b = rewrite_kubeconfig_server(kubeconfig_old_ip(), ip_b);
+    let result_a = rewrite_kubeconfig_server(kubeconfig_localhost(), ip_a, 6443);
+    let result_b = rewrite_kubeconfig_server(kubeconfig_old_ip(), ip_b, 6443);
 
     assert!(
         result_a.contains(&format!("https://{}:6443", ip_a)),
@@ -248,6 +248,25 @@ fn rewrite_preserves_port_6443_only_host_changes() {
     );
 }
 
+/// kina-39/A6 — a custom API server port replaces 6443.
+#[test]
+fn rewrite_uses_custom_api_server_port() {
+    let vm_ip = "10.211.55.4";
+    let result = rewrite_kubeconfig_server(kubeconfig_localhost(), vm_ip, 8443);
+
+    assert!(
+        result.contains("server: https://10.211.55.4:8443"),
+        "rewrite_kubeconfig_server with port 8443 must produce https://{}:8443; got:\n{}",
+        vm_ip,
+        result
+    );
+    assert!(
+        !result.contains(":6443"),
+        "no :6443 should remain; got:\n{}",
+        result
+    );
+}
+
 // ===========================================================================
 // Group B — build_unreachable_diagnostic
 // ===========================================================================
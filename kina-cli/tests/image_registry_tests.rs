@@ -0,0 +1,192 @@
+/// `kina create --kubernetes-version`, `kina image list` and `kina image pull` tests.
+///
+/// Pure tests of `core::image_registry`: version parsing, release-notes scraping,
+/// newest-patch selection, tag filtering/sorting, cache TTLs, platform
+/// normalisation and pulled-image bookkeeping. No network or filesystem access.
+use kina_cli::core::image_registry::{
+    filter_and_sort_tags, image_for_version, latest_for_minor, normalize_platform,
+    parse_release_images, parse_version_spec, record_pulled_image, ImageMapCache, PulledImage,
+    TagListCache, TtlCache, VersionSpec, IMAGE_MAP_TTL_SECS, TAG_CACHE_TTL_SECS,
+};
+use std::collections::BTreeMap;
+
+const RELEASE_NOTES: &str = "\
+Images pre-built for this release:
+- v1.31.0: `kindest/node:v1.31.0@sha256:53df588e04085fd41ae12de0c3fe4c72f7013bba32a20e7325357a1ac94ba865`
+- v1.30.4: `kindest/node:v1.30.4@sha256:976ea815844d5fa93be213437e3ff5754cd599b040946b5cca43ca45c2047114`
+- v1.31.2: `kindest/node:v1.31.2`.
+Build your own with kind build node-image (kindest/node:latest is not published).
+";
+
+#[test]
+fn parse_version_spec_accepts_exact_versions_with_or_without_v() {
+    assert_eq!(
+        parse_version_spec("v1.31.0").unwrap(),
+        VersionSpec::Exact("v1.31.0".to_string())
+    );
+    assert_eq!(
+        parse_version_spec("1.31.0").unwrap(),
+        VersionSpec::Exact("v1.31.0".to_string())
+    );
+}
+
+#[test]
+fn parse_version_spec_accepts_minor_versions() {
+    assert_eq!(
+        parse_version_spec("v1.31").unwrap(),
+        VersionSpec::Minor(1, 31)
+    );
+    assert_eq!(
+        parse_version_spec("1.30").unwrap(),
+        VersionSpec::Minor(1, 30)
+    );
+}
+
+#[test]
+fn parse_version_spec_rejects_garbage() {
+    for raw in ["", "latest", "v1", "v1.31.0.1", "v1.x.0"] {
+        assert!(
+            parse_version_spec(raw).is_err(),
+            "{:?} should be rejected",
+            raw
+        );
+    }
+}
+
+#[test]
+fn image_for_version_uses_kindest_node() {
+    assert_eq!(image_for_version("v1.31.0"), "kindest/node:v1.31.0");
+}
+
+#[test]
+fn parse_release_images_keeps_digests_and_skips_non_versions() {
+    let images = parse_release_images(RELEASE_NOTES);
+
+    assert_eq!(images.len(), 3);
+    assert_eq!(
+        images["v1.31.0"],
+        "kindest/node:v1.31.0@sha256:53df588e04085fd41ae12de0c3fe4c72f7013bba32a20e7325357a1ac94ba865"
+    );
+    assert_eq!(images["v1.31.2"], "kindest/node:v1.31.2");
+    assert!(!images.contains_key("latest"));
+}
+
+#[test]
+fn latest_for_minor_picks_newest_patch() {
+    let images = parse_release_images(RELEASE_NOTES);
+
+    assert_eq!(
+        latest_for_minor(&images, 1, 31).as_deref(),
+        Some("kindest/node:v1.31.2")
+    );
+    assert!(latest_for_minor(&images, 1, 30)
+        .unwrap()
+        .starts_with("kindest/node:v1.30.4@sha256:"));
+    assert_eq!(latest_for_minor(&images, 1, 29), None);
+}
+
+#[test]
+fn latest_for_minor_compares_patches_numerically() {
+    let mut images = BTreeMap::new();
+    images.insert("v1.31.9".to_string(), "kindest/node:v1.31.9".to_string());
+    images.insert("v1.31.10".to_string(), "kindest/node:v1.31.10".to_string());
+
+    assert_eq!(
+        latest_for_minor(&images, 1, 31).as_deref(),
+        Some("kindest/node:v1.31.10")
+    );
+}
+
+#[test]
+fn image_map_cache_expires_after_ttl() {
+    let cache = ImageMapCache {
+        fetched_at: 1_000,
+        images: BTreeMap::new(),
+    };
+
+    assert!(cache.is_fresh(1_000 + IMAGE_MAP_TTL_SECS - 1));
+    assert!(!cache.is_fresh(1_000 + IMAGE_MAP_TTL_SECS));
+}
+
+fn tags(raw: &[&str]) -> Vec<String> {
+    raw.iter().map(|t| t.to_string()).collect()
+}
+
+#[test]
+fn filter_and_sort_tags_drops_non_versions_and_prereleases() {
+    let listed = filter_and_sort_tags(
+        &tags(&[
+            "latest",
+            "v1.30.4",
+            "sha256-53df588e04085fd41ae12de0c3fe4c72.sig",
+            "v1.31.0",
+            "v1.32.0-alpha.1",
+        ]),
+        false,
+    );
+
+    assert_eq!(listed, tags(&["v1.31.0", "v1.30.4"]));
+}
+
+#[test]
+fn filter_and_sort_tags_all_includes_prereleases_below_their_release() {
+    let listed = filter_and_sort_tags(
+        &tags(&["v1.31.0", "v1.31.0-rc.1", "v1.32.0-alpha.1", "latest"]),
+        true,
+    );
+
+    assert_eq!(
+        listed,
+        tags(&["v1.32.0-alpha.1", "v1.31.0", "v1.31.0-rc.1"])
+    );
+}
+
+#[test]
+fn filter_and_sort_tags_orders_by_semver_not_lexically() {
+    let listed = filter_and_sort_tags(&tags(&["v1.9.0", "v1.10.0", "v1.31.10", "v1.31.9"]), false);
+
+    assert_eq!(listed, tags(&["v1.31.10", "v1.31.9", "v1.10.0", "v1.9.0"]));
+}
+
+#[test]
+fn tag_list_cache_expires_after_an_hour() {
+    let cache = TagListCache {
+        fetched_at: 0,
+        tags: Vec::new(),
+    };
+
+    assert!(cache.is_fresh(TAG_CACHE_TTL_SECS - 1));
+    assert!(!cache.is_fresh(TAG_CACHE_TTL_SECS));
+}
+
+#[test]
+fn normalize_platform_expands_bare_architectures() {
+    assert_eq!(normalize_platform("arm64").unwrap(), "linux/arm64");
+    assert_eq!(normalize_platform("linux/amd64").unwrap(), "linux/amd64");
+    assert!(normalize_platform("").is_err());
+    assert!(normalize_platform("linux/").is_err());
+}
+
+#[test]
+fn record_pulled_image_refreshes_existing_entries() {
+    let mut records = Vec::new();
+    record_pulled_image(&mut records, "kindest/node:v1.31.0", None, "t1");
+    record_pulled_image(
+        &mut records,
+        "kindest/node:v1.31.0",
+        Some("linux/amd64"),
+        "t2",
+    );
+    record_pulled_image(&mut records, "kindest/node:v1.31.0", None, "t3");
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(
+        records[0],
+        PulledImage {
+            image: "kindest/node:v1.31.0".to_string(),
+            platform: None,
+            pulled_at: "t3".to_string(),
+        }
+    );
+    assert_eq!(records[1].platform.as_deref(), Some("linux/amd64"));
+}
@@ -21,3 +21,30 @@ fn pods_all_ready_all_up() {
                traefik-def   1/1   Running   0   2m\n";
     assert_eq!(pods_all_ready(out), Some((2, 2)));
 }
+
+#[test]
+fn set_service_node_ports_patches_named_ports_of_the_service() {
+    use kina_cli::core::kubernetes::set_service_node_ports;
+
+    let manifest = include_str!("../manifests/nginx-ingress/nodeport-service.yaml");
+    let patched = set_service_node_ports(
+        manifest,
+        "nginx-ingress",
+        &[("http", 31080), ("https", 31443)],
+    )
+    .unwrap();
+    let service: serde_yaml::Value = serde_yaml::from_str(&patched).unwrap();
+    let ports = service["spec"]["ports"].as_sequence().unwrap();
+    assert_eq!(ports[0]["nodePort"].as_u64(), Some(31080));
+    assert_eq!(ports[1]["nodePort"].as_u64(), Some(31443));
+    assert_eq!(ports[1]["port"].as_u64(), Some(443));
+
+    let partial = set_service_node_ports(manifest, "nginx-ingress", &[("http", 31080)]).unwrap();
+    let service: serde_yaml::Value = serde_yaml::from_str(&partial).unwrap();
+    assert_eq!(
+        service["spec"]["ports"][1]["nodePort"].as_u64(),
+        Some(30443)
+    );
+
+    assert!(set_service_node_ports(manifest, "ingress-nginx-controller", &[]).is_err());
+}
@@ -13,7 +13,7 @@
 /// Design binding (from kina-6 Phase-B comment):
 ///   - node_kernel_args(kernel_path: Option<&Path>) -> Vec<String>
 ///   - select_kernel_path(cli_flag: Option<PathBuf>, config_default: Option<PathBuf>) -> Option<PathBuf>
-///   - build_cilium_install_cmd_ebpf(version: &str, cp_ip: &str) -> String
+///   - build_cilium_install_cmd_ebpf(version: &str, cp_ip: &str, api_server_port: u16) -> String
 ///   - build_kubeadm_init_args(full_ebpf: bool) -> Vec<String>
 ///   - ClusterDefaults.node_kernel_path: Option<PathBuf>
 ///   - CreateClusterOptions.node_kernel_path: Option<PathBuf>
@@ -23,7 +23,7 @@ use kina_cli::core::apple_container::{
     build_cilium_install_cmd, build_cilium_install_cmd_ebpf, build_kubeadm_init_args,
     node_kernel_args, select_kernel_path,
 };
-use kina_cli::core::types::CreateClusterOptions;
+use kina_cli::core::types::{ContainerRuntime, CreateClusterOptions};
 use std::path::{Path, PathBuf};
 
 // ===========================================================================
@@ -170,8 +170,38 @@ fn create_cluster_options_has_node_kernel_path_field() {
         workers: None,
         control_plane_nodes: None,
         wait_timeout: None,
+        wait_for_ready: false,
+        no_wait: false,
         retain_on_failure: false,
         skip_csr_approval: false,
+        skip_kubeconfig: false,
+        oidc: None,
+        audit_log: false,
+        audit_policy: None,
+        pod_security_standard: None,
+        node_image_pull_always: false,
+        skip_cni: false,
+        networking: None,
+        api_server_extra_args: Default::default(),
+        kubelet_extra_args: Default::default(),
+        controller_manager_extra_args: Default::default(),
+        control_plane_vip: None,
+        etcd_extra_args: Default::default(),
+        node_labels: Default::default(),
+        image_pull_secret: None,
+        pull_secret_config: None,
+        insecure_registries: Vec::new(),
+        bootstrap_token: None,
+        scheduler_config_file: None,
+        containerd_config: None,
+        kubelet_config_file: None,
+        api_server_sans: Vec::new(),
+        container_runtime: ContainerRuntime::default(),
+        disable_swap_check: false,
+        skip_preflight: false,
+        api_server_port: None,
+        node_name: None,
+        etcd: None,
         cni_plugin: kina_cli::config::CniPlugin::Ptp,
         node_kernel_path: None,
         control_plane_cpus: 4u32,
@@ -279,7 +309,7 @@ fn source_create_args_has_kernel_path_flag() {
 /// "cilium install --version 1.18.10"
 #[test]
 fn ebpf_cmd_pins_version() {
-    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("cilium install --version 1.18.10"),
         "build_cilium_install_cmd_ebpf must contain \"cilium install --version 1.18.10\"; \
@@ -291,7 +321,7 @@ fn ebpf_cmd_pins_version() {
 /// T13 — Full-eBPF cmd contains "--set kubeProxyReplacement=true"
 #[test]
 fn ebpf_cmd_kube_proxy_replacement_true() {
-    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("--set kubeProxyReplacement=true"),
         "build_cilium_install_cmd_ebpf must contain \"--set kubeProxyReplacement=true\"; \
@@ -303,7 +333,7 @@ fn ebpf_cmd_kube_proxy_replacement_true() {
 /// T14 — Full-eBPF cmd contains "--set bpf.masquerade=true"
 #[test]
 fn ebpf_cmd_bpf_masquerade_true() {
-    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("--set bpf.masquerade=true"),
         "build_cilium_install_cmd_ebpf must contain \"--set bpf.masquerade=true\"; got:\n{}",
@@ -314,7 +344,7 @@ fn ebpf_cmd_bpf_masquerade_true() {
 /// T15 — Full-eBPF cmd contains "--set bpf.hostLegacyRouting=false"
 #[test]
 fn ebpf_cmd_bpf_host_legacy_routing_false() {
-    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("--set bpf.hostLegacyRouting=false"),
         "build_cilium_install_cmd_ebpf must contain \"--set bpf.hostLegacyRouting=false\"; \
@@ -326,7 +356,7 @@ fn ebpf_cmd_bpf_host_legacy_routing_false() {
 /// T16 — Full-eBPF cmd contains "--set hubble.enabled=true"
 #[test]
 fn ebpf_cmd_hubble_enabled() {
-    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("--set hubble.enabled=true"),
         "build_cilium_install_cmd_ebpf must contain \"--set hubble.enabled=true\"; got:\n{}",
@@ -337,7 +367,7 @@ fn ebpf_cmd_hubble_enabled() {
 /// T17 — Full-eBPF cmd retains "--set ipam.mode=kubernetes"
 #[test]
 fn ebpf_cmd_ipam_mode_kubernetes_retained() {
-    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("--set ipam.mode=kubernetes"),
         "build_cilium_install_cmd_ebpf must contain \"--set ipam.mode=kubernetes\"; got:\n{}",
@@ -348,7 +378,7 @@ fn ebpf_cmd_ipam_mode_kubernetes_retained() {
 /// T18 — Full-eBPF cmd contains "--set k8sServiceHost=192.168.65.2" (templated from cp_ip)
 #[test]
 fn ebpf_cmd_k8s_service_host_uses_cp_ip() {
-    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("--set k8sServiceHost=192.168.65.2"),
         "build_cilium_install_cmd_ebpf must contain \"--set k8sServiceHost=192.168.65.2\" \
@@ -360,7 +390,7 @@ fn ebpf_cmd_k8s_service_host_uses_cp_ip() {
 /// T19 — Full-eBPF cmd contains "--set k8sServicePort=6443"
 #[test]
 fn ebpf_cmd_k8s_service_port_6443() {
-    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("--set k8sServicePort=6443"),
         "build_cilium_install_cmd_ebpf must contain \"--set k8sServicePort=6443\"; got:\n{}",
@@ -372,7 +402,7 @@ fn ebpf_cmd_k8s_service_port_6443() {
 /// "k8sServiceHost=10.0.0.5" and NOT "192.168.65.2" (cp_ip is templated, not hardcoded).
 #[test]
 fn ebpf_cmd_distinct_cp_ip_templated() {
-    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "10.0.0.5");
+    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "10.0.0.5", 6443);
     assert!(
         cmd.contains("k8sServiceHost=10.0.0.5"),
         "build_cilium_install_cmd_ebpf with ip \"10.0.0.5\" must contain \
@@ -395,7 +425,7 @@ fn ebpf_cmd_distinct_cp_ip_templated() {
 /// (workaround retired by multiple-routing-tables kernel config)
 #[test]
 fn ebpf_cmd_no_enable_local_node_route() {
-    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2", 6443);
     assert!(
         !cmd.contains("enableLocalNodeRoute=false"),
         "build_cilium_install_cmd_ebpf must NOT contain \"enableLocalNodeRoute=false\" \
@@ -409,7 +439,7 @@ fn ebpf_cmd_no_enable_local_node_route() {
 /// (transparent DNS proxy ON; retired by xt_socket+TPROXY in custom kernel)
 #[test]
 fn ebpf_cmd_no_dnsproxy_transparent_override() {
-    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2", 6443);
     assert!(
         !cmd.contains("dnsproxy-enable-transparent-mode"),
         "build_cilium_install_cmd_ebpf must NOT contain \"dnsproxy-enable-transparent-mode\" \
@@ -423,7 +453,7 @@ fn ebpf_cmd_no_dnsproxy_transparent_override() {
 /// (l7Proxy true or omitted-default-true; NOT disabled in full-eBPF profile)
 #[test]
 fn ebpf_cmd_no_l7proxy_false() {
-    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2", 6443);
     assert!(
         !cmd.contains("l7Proxy=false"),
         "build_cilium_install_cmd_ebpf must NOT contain \"l7Proxy=false\" \
@@ -437,7 +467,7 @@ fn ebpf_cmd_no_l7proxy_false() {
 /// (no stock-kernel xt_socket mitigation in the eBPF profile)
 #[test]
 fn ebpf_cmd_no_xt_socket_fallback() {
-    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2", 6443);
     assert!(
         !cmd.contains("xtSocketFallback"),
         "build_cilium_install_cmd_ebpf must NOT contain \"xtSocketFallback\" \
@@ -456,7 +486,7 @@ fn ebpf_cmd_no_xt_socket_fallback() {
 /// (proves it is the eBPF profile, not the stock one)
 #[test]
 fn ebpf_cmd_no_kube_proxy_replacement_false() {
-    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd_ebpf("1.18.10", "192.168.65.2", 6443);
     assert!(
         !cmd.contains("kubeProxyReplacement=false"),
         "build_cilium_install_cmd_ebpf must NOT contain \"kubeProxyReplacement=false\" \
@@ -474,7 +504,7 @@ fn ebpf_cmd_no_kube_proxy_replacement_false() {
 /// "xtSocketFallback" — the wrong-direction a81a825 line is removed.
 #[test]
 fn stock_cmd_no_xt_socket_fallback() {
-    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2", 6443);
     assert!(
         !cmd.contains("xtSocketFallback"),
         "build_cilium_install_cmd (stock) must NOT contain \"xtSocketFallback\" \
@@ -511,7 +541,7 @@ fn source_no_xt_socket_fallback() {
 /// (only the xt-socket line removed; rest of workaround profile intact — guards over-removal).
 #[test]
 fn stock_cmd_retains_enable_local_node_route() {
-    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2");
+    let cmd = build_cilium_install_cmd("1.18.10", "192.168.65.2", 6443);
     assert!(
         cmd.contains("enableLocalNodeRoute=false"),
         "build_cilium_install_cmd (stock) must STILL contain \"--set enableLocalNodeRoute=false\" \
@@ -34,8 +34,8 @@ use kina_cli::core::kernel_fetch::{
     doctor_report, fetch_decision, first_run_notice, install_kernel, kernel_cache_dir,
     kernel_cache_file, pinned_asset_url, release_asset_url, requires_kernel,
     resolve_kernel_for_cilium, sha256_mismatch_remediation, should_show_first_run_notice,
-    verify_sha256, DoctorReport, FetchDecision, KernelChoice, KernelFetcher, KERNEL_SHA256,
-    KERNEL_SIZE_BYTES, KERNEL_TAG,
+    verify_sha256, FetchDecision, KernelChoice, KernelFetcher, KERNEL_SHA256, KERNEL_SIZE_BYTES,
+    KERNEL_TAG,
 };
 use std::path::{Path, PathBuf};
 
@@ -569,6 +569,7 @@ impl KernelFetcher for FakeErrFetcher {
 
 /// Compute the sha256 of a byte slice, returning a lowercase hex string.
 /// Used in tests to produce correct expected sha256 for known-good fake bytes.
+#[allow(dead_code)]
 fn sha256_of_bytes(bytes: &[u8]) -> String {
     // We need a sha256 implementation. Since the project does not yet have sha2 as a dep,
     // we use a pure-Rust portable approach: use std::io and a simple accumulation.
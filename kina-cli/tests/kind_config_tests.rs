@@ -0,0 +1,380 @@
+//! Tests for importing and exporting kind cluster configs
+//! (`kina create --from-kind-config`, `kina export --format kind`).
+//!
+//! Fixtures under tests/fixtures/kind/ are taken from the kind documentation.
+
+use kina_cli::config::cluster_config::{ClusterConfig, NetworkingConfig};
+use kina_cli::core::types::NodeRole;
+use std::path::PathBuf;
+
+fn fixture(name: &str) -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/kind")
+        .join(name);
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+}
+
+#[test]
+fn multi_node_config_maps_roles_and_names() {
+    let (config, warnings) =
+        ClusterConfig::from_kind_config_str(&fixture("multi-node.yaml")).unwrap();
+    assert!(warnings.is_empty(), "{:?}", warnings);
+    assert_eq!(config.name, "kina");
+    assert_eq!(config.control_plane_nodes().len(), 1);
+    assert_eq!(config.worker_nodes().len(), 2);
+    // kind has no resource limits; none may be made up for the create
+    assert!(config.runtime_config.is_none());
+    let names: Vec<&str> = config.nodes.iter().map(|n| n.name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["kina-control-plane", "kina-worker", "kina-worker-2"]
+    );
+}
+
+#[test]
+fn ingress_config_keeps_port_mappings_and_patches() {
+    let (config, warnings) = ClusterConfig::from_kind_config_str(&fixture("ingress.yaml")).unwrap();
+    assert!(warnings.is_empty(), "{:?}", warnings);
+    let node = &config.nodes[0];
+    assert_eq!(node.role, NodeRole::ControlPlane);
+    let ports: Vec<u16> = node
+        .extra_port_mappings
+        .iter()
+        .map(|p| p.container_port)
+        .collect();
+    assert_eq!(ports, [80, 443]);
+    assert!(node.kubeadm_config_patches[0].contains("ingress-ready=true"));
+}
+
+#[test]
+fn containerd_patches_warn_instead_of_failing() {
+    let (config, warnings) =
+        ClusterConfig::from_kind_config_str(&fixture("local-registry.yaml")).unwrap();
+    assert_eq!(config.nodes.len(), 1);
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("containerdConfigPatches has no kina equivalent"));
+}
+
+#[test]
+fn networking_config_maps_subnets_images_and_mounts() {
+    let (config, warnings) =
+        ClusterConfig::from_kind_config_str(&fixture("networking.yaml")).unwrap();
+    assert_eq!(config.name, "app-1-cluster");
+    assert_eq!(
+        config.networking.pod_subnet.as_deref(),
+        Some("10.244.0.0/16")
+    );
+    assert_eq!(
+        config.networking.service_subnet.as_deref(),
+        Some("10.96.0.0/12")
+    );
+    assert!(config.networking.disable_default_cni);
+    assert_eq!(config.feature_gates.get("CSIMigration"), Some(&true));
+    assert_eq!(
+        config.nodes[0].image.as_deref(),
+        Some("kindest/node:v1.31.0")
+    );
+    assert_eq!(
+        config.nodes[0].extra_mounts[0].container_path,
+        PathBuf::from("/files")
+    );
+    assert!(!config.nodes[0].extra_mounts[0].read_only);
+
+    assert_eq!(warnings.len(), 2, "{:?}", warnings);
+    assert!(warnings.iter().any(|w| w.contains("ipFamily")));
+    assert!(warnings.iter().any(|w| w.contains("runtimeConfig")));
+}
+
+#[test]
+fn rejects_non_kind_documents() {
+    let err = ClusterConfig::from_kind_config_str("kind: Pod\napiVersion: v1\n")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("kind: Cluster"), "{}", err);
+
+    let err = ClusterConfig::from_kind_config_str("kind: Cluster\napiVersion: kind.x-k8s.io/v1\n")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("unsupported kind apiVersion"), "{}", err);
+}
+
+#[test]
+fn unknown_fields_and_invalid_nodes() {
+    let (_, warnings) = ClusterConfig::from_kind_config_str(
+        "kind: Cluster\napiVersion: kind.x-k8s.io/v1alpha4\nnodes:\n- role: control-plane\n  foo: bar\n",
+    )
+    .unwrap();
+    assert_eq!(warnings, ["unknown field nodes[0].foo is ignored"]);
+
+    assert!(ClusterConfig::from_kind_config_str(
+        "kind: Cluster\napiVersion: kind.x-k8s.io/v1alpha4\nnodes:\n- role: worker\n",
+    )
+    .is_err());
+}
+
+#[test]
+fn kind_export_round_trips_without_kina_fields() {
+    let (mut config, _) = ClusterConfig::from_kind_config_str(&fixture("networking.yaml")).unwrap();
+    config.networking.api_server_address = Some("192.168.64.5".to_string());
+
+    let yaml = config.to_kind_yaml().unwrap();
+    assert!(yaml.starts_with("# Exported by kina for kind."), "{}", yaml);
+    assert!(!yaml.contains("runtimeConfig:"), "{}", yaml);
+    assert!(!yaml.contains("apiServerAddress:"), "{}", yaml);
+    assert!(yaml.contains("apiServerAddress/apiServerPort"), "{}", yaml);
+
+    let (exported, warnings) = ClusterConfig::from_kind_config_str(&yaml).unwrap();
+    assert!(warnings.is_empty(), "{:?}", warnings);
+    assert_eq!(exported.name, "app-1-cluster");
+    assert_eq!(exported.nodes.len(), 2);
+    assert_eq!(exported.networking.pod_subnet, config.networking.pod_subnet);
+    assert_eq!(exported.feature_gates, config.feature_gates);
+}
+
+/// Fields kind's v1alpha4 `Cluster` accepts; its strict decoding rejects
+/// anything else, so an export must stay within them.
+fn assert_kind_v1alpha4_fields(yaml: &str) {
+    fn keys(value: &serde_yaml::Value) -> Vec<&str> {
+        value
+            .as_mapping()
+            .map(|m| m.keys().filter_map(|k| k.as_str()).collect())
+            .unwrap_or_default()
+    }
+    fn check(path: &str, value: &serde_yaml::Value, allowed: &[&str]) {
+        for key in keys(value) {
+            assert!(allowed.contains(&key), "kind rejects {}.{}", path, key);
+        }
+    }
+
+    let cluster: serde_yaml::Value = serde_yaml::from_str(yaml).unwrap();
+    check(
+        "cluster",
+        &cluster,
+        &[
+            "kind",
+            "apiVersion",
+            "name",
+            "featureGates",
+            "runtimeConfig",
+            "networking",
+            "nodes",
+            "kubeadmConfigPatches",
+            "kubeadmConfigPatchesJSON6902",
+            "containerdConfigPatches",
+            "containerdConfigPatchesJSON6902",
+        ],
+    );
+    check(
+        "networking",
+        &cluster["networking"],
+        &[
+            "ipFamily",
+            "apiServerPort",
+            "apiServerAddress",
+            "podSubnet",
+            "serviceSubnet",
+            "disableDefaultCNI",
+            "kubeProxyMode",
+            "dnsSearch",
+        ],
+    );
+    for node in cluster["nodes"].as_sequence().unwrap() {
+        check(
+            "nodes[]",
+            node,
+            &[
+                "role",
+                "image",
+                "labels",
+                "extraMounts",
+                "extraPortMappings",
+                "kubeadmConfigPatches",
+                "kubeadmConfigPatchesJSON6902",
+            ],
+        );
+    }
+}
+
+#[test]
+fn kind_export_only_uses_kind_v1alpha4_fields() {
+    let (mut config, _) = ClusterConfig::from_kind_config_str(&fixture("networking.yaml")).unwrap();
+    config.networking.dns_domain = Some("cluster.example".to_string());
+    config.networking.api_server_port = Some(6443);
+
+    let yaml = config.to_kind_yaml().unwrap();
+    assert_kind_v1alpha4_fields(&yaml);
+    assert!(!yaml.contains("dnsDomain:"), "{}", yaml);
+    assert!(yaml.contains("#   - networking.dnsDomain"), "{}", yaml);
+
+    let (exported, warnings) = ClusterConfig::from_kind_config_str(&yaml).unwrap();
+    assert!(warnings.is_empty(), "{:?}", warnings);
+    assert_kind_v1alpha4_fields(&exported.to_kind_yaml().unwrap());
+    assert_eq!(exported.networking.dns_domain, None);
+    assert_eq!(exported.networking.pod_subnet, config.networking.pod_subnet);
+}
+
+#[test]
+fn terraform_export_wraps_create_and_delete() {
+    let (config, _) = ClusterConfig::from_kind_config_str(&fixture("networking.yaml")).unwrap();
+    let hcl = config.to_terraform();
+    assert!(
+        hcl.contains(r#"resource "null_resource" "kina_app_1_cluster" {"#),
+        "{}",
+        hcl
+    );
+    assert!(
+        hcl.contains(r#"name                = "app-1-cluster""#),
+        "{}",
+        hcl
+    );
+    assert!(hcl.contains(r#"workers             = "1""#), "{}", hcl);
+    assert!(hcl.contains("when    = destroy"), "{}", hcl);
+    assert!(hcl.contains(r#"command = "kina delete ${self.triggers.name}""#));
+    assert!(hcl.contains(r#"filename   = pathexpand("~/.kube/app-1-cluster")"#));
+    assert!(hcl.contains("depends_on = [null_resource.kina_app_1_cluster]"));
+    assert_eq!(
+        hcl.matches('{').count(),
+        hcl.matches('}').count(),
+        "unbalanced braces:\n{}",
+        hcl
+    );
+}
+
+#[test]
+fn github_actions_export_caches_the_node_image() {
+    let (config, _) = ClusterConfig::from_kind_config_str(&fixture("networking.yaml")).unwrap();
+    let yaml = config.to_github_actions("0.2.0");
+    let steps: Vec<serde_yaml::Value> = serde_yaml::from_str(&yaml).unwrap();
+
+    let cache = steps
+        .iter()
+        .find(|s| s["uses"] == "actions/cache@v4")
+        .expect("cache step");
+    assert_eq!(cache["with"]["key"], "kina-node-image-kindest-node-v1.31.0");
+    let install = steps.iter().find(|s| s["name"] == "Install kina").unwrap();
+    assert_eq!(install["run"], "mise use -g github:vinnie357/kina@0.2.0");
+    let create = steps.last().unwrap()["run"].as_str().unwrap();
+    assert_eq!(
+        create,
+        "kina create app-1-cluster --image kindest/node:v1.31.0 --control-plane-nodes 1 --workers 1"
+    );
+}
+
+#[test]
+fn kind_export_omits_default_networking() {
+    let yaml = ClusterConfig::default_with_name("dev")
+        .to_kind_yaml()
+        .unwrap();
+    assert!(!yaml.contains("networking:"), "{}", yaml);
+    assert!(yaml.contains("- role: control-plane"), "{}", yaml);
+}
+
+#[test]
+fn networking_from_kubeadm_config_reads_cluster_configuration() {
+    let kubeadm = "\
+apiVersion: kubeadm.k8s.io/v1beta4
+kind: InitConfiguration
+---
+apiVersion: kubeadm.k8s.io/v1beta4
+kind: ClusterConfiguration
+networking:
+  serviceSubnet: 10.96.0.0/16
+  podSubnet: 10.244.0.0/16
+  dnsDomain: cluster.local
+";
+    let networking = NetworkingConfig::from_kubeadm_config(kubeadm);
+    assert_eq!(networking.pod_subnet.as_deref(), Some("10.244.0.0/16"));
+    assert_eq!(networking.service_subnet.as_deref(), Some("10.96.0.0/16"));
+    assert_eq!(networking.dns_domain.as_deref(), Some("cluster.local"));
+    assert_eq!(
+        NetworkingConfig::from_kubeadm_config("kind: InitConfiguration\n"),
+        NetworkingConfig::default()
+    );
+}
+
+#[test]
+fn common_node_labels_are_the_ones_every_node_shares() {
+    let (config, _) = ClusterConfig::from_kind_config_str(
+        "kind: Cluster\n\
+         apiVersion: kind.x-k8s.io/v1alpha4\n\
+         nodes:\n\
+         - role: control-plane\n  \
+           labels:\n    tier: dev\n    ingress-ready: \"true\"\n\
+         - role: worker\n  \
+           labels:\n    tier: dev\n",
+    )
+    .unwrap();
+    let labels = config.common_node_labels();
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels.get("tier").map(String::as_str), Some("dev"));
+}
+
+/// Every fixture gets through `kina create --from-kind-config` up to the first
+/// node `container run`, which a stub container CLI fails on purpose: option
+/// resolution (resource limits, labels, networking) must accept kind configs.
+#[test]
+fn create_from_kind_config_fixtures_reaches_node_creation() {
+    use assert_cmd::Command;
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let cli_path = dir.path().join("container");
+    std::fs::write(
+        &cli_path,
+        "#!/bin/sh\n\
+         case \"$1\" in\n\
+         \x20 --version) echo \"container CLI version 1.0.0 (stub)\" ;;\n\
+         \x20 run) echo \"stub refuses container run\" >&2; exit 1 ;;\n\
+         \x20 *) echo \"[]\" ;;\n\
+         esac\n",
+    )
+    .unwrap();
+    std::fs::set_permissions(&cli_path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    // The shared test config, pointed at the stub and the temp directory
+    let config = std::fs::read_to_string(
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/test-config.toml"),
+    )
+    .unwrap()
+    .replace(
+        "tests/fixtures/fake-container.sh",
+        &cli_path.to_string_lossy(),
+    )
+    .replace(
+        "/tmp/kina-test-data",
+        &dir.path().join("data").to_string_lossy(),
+    )
+    .replace(
+        "/tmp/kina-test-kubeconfig",
+        &dir.path().join("kube").to_string_lossy(),
+    );
+    let config_path = dir.path().join("config.toml");
+    std::fs::write(&config_path, config).unwrap();
+
+    for name in [
+        "ingress.yaml",
+        "local-registry.yaml",
+        "multi-node.yaml",
+        "networking.yaml",
+    ] {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/kind")
+            .join(name);
+        let output = Command::cargo_bin("kina")
+            .unwrap()
+            .arg("--config")
+            .arg(&config_path)
+            .arg("create")
+            .arg("--from-kind-config")
+            .arg(&path)
+            .output()
+            .unwrap();
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(!output.status.success(), "{}: {}", name, stderr);
+        assert!(
+            stderr.contains("stub refuses container run"),
+            "{} failed before node creation: {}",
+            name,
+            stderr
+        );
+    }
+}
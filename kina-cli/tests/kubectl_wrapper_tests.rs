@@ -0,0 +1,562 @@
+/// Tests for the kubectl convenience commands (`kina get events`, `kina apply`, ...).
+///
+/// Pure tests of the output parsing and formatting in `core::kubernetes` — no
+/// cluster or kubectl binary involved.
+use kina_cli::cli::{manifest_files, startup_manifest_files};
+use kina_cli::core::kubernetes::{
+    delete_resource_args, drain_args, format_event, label_node_args, merge_live_node_info,
+    parse_events, parse_evicted_pods, parse_live_nodes, parse_pending_csrs, parse_pods,
+    port_forward_args, render_pod_table, rollout_status_args, wait_args, ClusterEvent,
+    DeleteResourceOptions, ReadinessSnapshot,
+};
+use kina_cli::core::types::{NodeInfo, NodeRole};
+use std::path::{Path, PathBuf};
+
+const EVENTS_JSON: &str = r#"{
+  "apiVersion": "v1",
+  "kind": "List",
+  "items": [
+    {
+      "metadata": {"namespace": "kube-system", "name": "coredns.1"},
+      "type": "Warning",
+      "reason": "FailedScheduling",
+      "message": "0/1 nodes are available\n",
+      "involvedObject": {"kind": "Pod", "name": "coredns-abc"},
+      "lastTimestamp": "2026-06-14T10:05:00Z"
+    },
+    {
+      "metadata": {"namespace": "default", "name": "web.1"},
+      "type": "Normal",
+      "reason": "Scheduled",
+      "message": "Successfully assigned default/web to kina-control-plane",
+      "involvedObject": {"kind": "Pod", "name": "web"},
+      "lastTimestamp": null,
+      "eventTime": "2026-06-14T10:01:00.000000Z"
+    }
+  ]
+}"#;
+
+#[test]
+fn parse_events_sorts_by_last_timestamp() {
+    let events = parse_events(EVENTS_JSON).unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].reason, "Scheduled");
+    assert_eq!(events[1].reason, "FailedScheduling");
+}
+
+#[test]
+fn parse_events_falls_back_to_event_time() {
+    let events = parse_events(EVENTS_JSON).unwrap();
+    assert_eq!(events[0].last_timestamp, "2026-06-14T10:01:00.000000Z");
+}
+
+#[test]
+fn parse_events_extracts_object_and_trims_message() {
+    let events = parse_events(EVENTS_JSON).unwrap();
+    let warning = &events[1];
+    assert_eq!(warning.object, "pod/coredns-abc");
+    assert_eq!(warning.namespace, "kube-system");
+    assert_eq!(warning.message, "0/1 nodes are available");
+}
+
+#[test]
+fn parse_events_handles_empty_list() {
+    let events = parse_events(r#"{"apiVersion":"v1","items":[],"kind":"List"}"#).unwrap();
+    assert!(events.is_empty());
+}
+
+#[test]
+fn parse_events_rejects_invalid_json() {
+    assert!(parse_events("not json").is_err());
+}
+
+fn event(event_type: &str) -> ClusterEvent {
+    ClusterEvent {
+        event_type: event_type.to_string(),
+        reason: "Pulled".to_string(),
+        message: "Container image pulled".to_string(),
+        namespace: "default".to_string(),
+        object: "pod/web".to_string(),
+        last_timestamp: "2026-06-14T10:00:00Z".to_string(),
+    }
+}
+
+#[test]
+fn format_event_uses_type_emoji() {
+    assert!(format_event(&event("Warning")).starts_with("⚠️ "));
+    assert!(format_event(&event("Normal")).starts_with("ℹ️ "));
+}
+
+#[test]
+fn format_event_includes_object_and_message() {
+    assert_eq!(
+        format_event(&event("Normal")),
+        "ℹ️ 2026-06-14T10:00:00Z default/pod/web Pulled: Container image pulled"
+    );
+}
+
+const PODS_JSON: &str = r#"{
+  "apiVersion": "v1",
+  "kind": "List",
+  "items": [
+    {
+      "metadata": {"namespace": "default", "name": "web"},
+      "spec": {"containers": [{"name": "nginx"}]},
+      "status": {
+        "phase": "Running",
+        "containerStatuses": [{"ready": true, "restartCount": 0, "state": {"running": {}}}]
+      }
+    },
+    {
+      "metadata": {"namespace": "default", "name": "crashy"},
+      "spec": {"containers": [{"name": "app"}, {"name": "sidecar"}]},
+      "status": {
+        "phase": "Running",
+        "containerStatuses": [
+          {"ready": false, "restartCount": 4, "state": {"waiting": {"reason": "CrashLoopBackOff"}}},
+          {"ready": true, "restartCount": 1, "state": {"running": {}}}
+        ]
+      }
+    },
+    {
+      "metadata": {"namespace": "default", "name": "job-abc"},
+      "spec": {"containers": [{"name": "job"}]},
+      "status": {
+        "phase": "Succeeded",
+        "containerStatuses": [{"ready": false, "restartCount": 0, "state": {"terminated": {"reason": "Completed"}}}]
+      }
+    },
+    {
+      "metadata": {"namespace": "default", "name": "pending", "deletionTimestamp": "2026-06-14T10:00:00Z"},
+      "spec": {"containers": [{"name": "app"}]},
+      "status": {"phase": "Pending"}
+    }
+  ]
+}"#;
+
+#[test]
+fn parse_pods_reports_ready_running_pod() {
+    let pods = parse_pods(PODS_JSON).unwrap();
+    assert_eq!(pods[0].name, "web");
+    assert_eq!(pods[0].ready_containers, "1/1");
+    assert_eq!(pods[0].status, "Running");
+    assert!(pods[0].ready);
+}
+
+#[test]
+fn parse_pods_uses_container_reason_and_sums_restarts() {
+    let pods = parse_pods(PODS_JSON).unwrap();
+    assert_eq!(pods[1].ready_containers, "1/2");
+    assert_eq!(pods[1].status, "CrashLoopBackOff");
+    assert_eq!(pods[1].restarts, 5);
+    assert!(!pods[1].ready);
+}
+
+#[test]
+fn parse_pods_treats_succeeded_as_ready() {
+    let pods = parse_pods(PODS_JSON).unwrap();
+    assert_eq!(pods[2].status, "Succeeded");
+    assert!(pods[2].ready);
+}
+
+#[test]
+fn parse_pods_marks_deleting_pods_terminating() {
+    let pods = parse_pods(PODS_JSON).unwrap();
+    assert_eq!(pods[3].status, "Terminating");
+    assert_eq!(pods[3].ready_containers, "0/1");
+    assert!(!pods[3].ready);
+}
+
+#[test]
+fn render_pod_table_marks_unready_pods() {
+    let table = render_pod_table(&parse_pods(PODS_JSON).unwrap());
+    assert!(table.contains("NAMESPACE"));
+    assert!(table.contains("RESTARTS"));
+    assert!(table.contains("❌ CrashLoopBackOff"));
+    assert!(!table.contains("❌ Running"));
+}
+
+#[test]
+fn manifest_files_lists_directory_manifests_alphabetically() {
+    let dir = tempfile::tempdir().unwrap();
+    for name in ["b.yml", "a.yaml", "c.json", "notes.txt", "README.md"] {
+        std::fs::write(dir.path().join(name), "").unwrap();
+    }
+    std::fs::create_dir(dir.path().join("nested.yaml")).unwrap();
+
+    let files: Vec<String> = manifest_files(dir.path())
+        .unwrap()
+        .iter()
+        .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+        .collect();
+    assert_eq!(files, vec!["a.yaml", "b.yml", "c.json"]);
+}
+
+#[test]
+fn startup_manifests_apply_numbered_files_first_and_skip_underscores() {
+    let dir = tempfile::tempdir().unwrap();
+    for name in [
+        "10-ingress.yaml",
+        "2-deployment.yaml",
+        "01-namespace.yaml",
+        "config.yaml",
+        "_draft.yaml",
+        "notes.txt",
+    ] {
+        std::fs::write(dir.path().join(name), "").unwrap();
+    }
+
+    let files: Vec<String> = startup_manifest_files(dir.path())
+        .unwrap()
+        .iter()
+        .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+        .collect();
+    assert_eq!(
+        files,
+        vec![
+            "01-namespace.yaml",
+            "2-deployment.yaml",
+            "10-ingress.yaml",
+            "config.yaml"
+        ]
+    );
+    assert!(startup_manifest_files(&dir.path().join("missing")).is_err());
+}
+
+#[test]
+fn manifest_files_passes_files_and_stdin_through() {
+    assert_eq!(
+        manifest_files(Path::new("-")).unwrap(),
+        vec![PathBuf::from("-")]
+    );
+    assert_eq!(
+        manifest_files(Path::new("deploy.yaml")).unwrap(),
+        vec![PathBuf::from("deploy.yaml")]
+    );
+}
+
+#[test]
+fn delete_resource_args_for_named_resource() {
+    let options = DeleteResourceOptions {
+        resource_type: "pods".to_string(),
+        name: Some("web".to_string()),
+        namespace: Some("apps".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        delete_resource_args(&options),
+        vec!["delete", "pods", "web", "-n", "apps"]
+    );
+}
+
+#[test]
+fn delete_resource_args_pass_through_flags() {
+    let options = DeleteResourceOptions {
+        resource_type: "deployments".to_string(),
+        all: true,
+        label_selector: Some("app=web".to_string()),
+        grace_period: Some(0),
+        force: true,
+        cascade: Some("orphan".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        delete_resource_args(&options),
+        vec![
+            "delete",
+            "deployments",
+            "--all",
+            "-l",
+            "app=web",
+            "--grace-period=0",
+            "--force",
+            "--cascade=orphan"
+        ]
+    );
+}
+
+#[test]
+fn port_forward_args_list_every_port_pair() {
+    assert_eq!(
+        port_forward_args("svc/web", &[(8080, 80), (8443, 443)], Some("apps")),
+        vec![
+            "port-forward",
+            "svc/web",
+            "8080:80",
+            "8443:443",
+            "-n",
+            "apps"
+        ]
+    );
+    assert_eq!(
+        port_forward_args("pod/db", &[(5432, 5432)], None),
+        vec!["port-forward", "pod/db", "5432:5432"]
+    );
+}
+
+#[test]
+fn rollout_status_args_use_whole_second_timeout() {
+    assert_eq!(
+        rollout_status_args(
+            "deployment/metrics-server",
+            "kube-system",
+            std::time::Duration::from_secs(120)
+        ),
+        vec![
+            "rollout",
+            "status",
+            "deployment/metrics-server",
+            "-n",
+            "kube-system",
+            "--timeout=120s"
+        ]
+    );
+}
+
+#[test]
+fn wait_args_for_condition_in_namespace() {
+    assert_eq!(
+        wait_args("pod/web", "condition=Ready", Some("apps"), false, "30s").unwrap(),
+        vec![
+            "wait",
+            "pod/web",
+            "--for=condition=Ready",
+            "--timeout=30s",
+            "-n",
+            "apps"
+        ]
+    );
+}
+
+#[test]
+fn wait_args_for_jsonpath_across_namespaces() {
+    assert_eq!(
+        wait_args("pods", "jsonpath={.status.phase}=Running", None, true, "2m").unwrap(),
+        vec![
+            "wait",
+            "pods",
+            "--for=jsonpath={.status.phase}=Running",
+            "--timeout=2m",
+            "--all-namespaces"
+        ]
+    );
+}
+
+#[test]
+fn wait_args_accepts_delete() {
+    assert!(wait_args("pod/web", "delete", None, false, "60s").is_ok());
+}
+
+#[test]
+fn wait_args_rejects_malformed_conditions() {
+    for condition in ["Ready", "condition=", "jsonpath={.status.phase}", "exists"] {
+        assert!(
+            wait_args("pod/web", condition, None, false, "60s").is_err(),
+            "{} should be rejected",
+            condition
+        );
+    }
+}
+
+#[test]
+fn parse_pending_csrs_skips_decided_and_filters_signer() {
+    let output = "\
+csr-abc kubernetes.io/kubelet-serving
+csr-def kubernetes.io/kube-apiserver-client-kubelet
+csr-ghi kubernetes.io/kubelet-serving Approved
+csr-jkl kubernetes.io/kubelet-serving Denied
+csr-mno example.com/custom Approved Failed
+";
+    assert_eq!(
+        parse_pending_csrs(output, Some("kubernetes.io/kubelet-serving")),
+        vec!["csr-abc"]
+    );
+    assert_eq!(parse_pending_csrs(output, None), vec!["csr-abc", "csr-def"]);
+    assert!(parse_pending_csrs("", None).is_empty());
+}
+
+#[test]
+fn label_node_args_overwrite_every_label() {
+    let labels = [
+        ("tier".to_string(), "frontend".to_string()),
+        ("example.com/zone".to_string(), "a".to_string()),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(
+        label_node_args("dev-worker", &labels),
+        vec![
+            "label",
+            "node",
+            "dev-worker",
+            "example.com/zone=a",
+            "tier=frontend",
+            "--overwrite"
+        ]
+    );
+}
+
+const NODES_JSON: &str = r#"{
+  "apiVersion": "v1",
+  "kind": "List",
+  "items": [
+    {
+      "metadata": {
+        "name": "dev-control-plane",
+        "labels": {"node-role.kubernetes.io/control-plane": ""}
+      },
+      "spec": {},
+      "status": {
+        "conditions": [
+          {"type": "MemoryPressure", "status": "False"},
+          {"type": "Ready", "status": "True"}
+        ],
+        "addresses": [
+          {"type": "InternalIP", "address": "192.168.64.2"},
+          {"type": "Hostname", "address": "dev-control-plane"}
+        ],
+        "nodeInfo": {"kubeletVersion": "v1.36.1"}
+      }
+    },
+    {
+      "metadata": {"name": "dev-worker", "labels": {}},
+      "spec": {"unschedulable": true},
+      "status": {
+        "conditions": [{"type": "Ready", "status": "False"}],
+        "nodeInfo": {"kubeletVersion": "v1.36.1"}
+      }
+    }
+  ]
+}"#;
+
+#[test]
+fn parse_live_nodes_reads_ready_condition_and_version() {
+    let nodes = parse_live_nodes(NODES_JSON).unwrap();
+    assert_eq!(nodes.len(), 2);
+    assert_eq!(nodes[0].name, "dev-control-plane");
+    assert_eq!(nodes[0].role, NodeRole::ControlPlane);
+    assert_eq!(nodes[0].status, "Ready");
+    assert_eq!(nodes[0].version, "v1.36.1");
+    assert_eq!(nodes[0].ip_address.as_deref(), Some("192.168.64.2"));
+}
+
+#[test]
+fn parse_live_nodes_reports_not_ready_and_cordoned() {
+    let nodes = parse_live_nodes(NODES_JSON).unwrap();
+    assert_eq!(nodes[1].role, NodeRole::Worker);
+    assert_eq!(nodes[1].status, "NotReady,SchedulingDisabled");
+    assert_eq!(nodes[1].ip_address, None);
+}
+
+fn container_node(name: &str) -> NodeInfo {
+    NodeInfo {
+        name: name.to_string(),
+        role: NodeRole::Worker,
+        status: "running".to_string(),
+        version: "unknown".to_string(),
+        container_id: Some(name.to_string()),
+        ip_address: Some("192.168.64.9".to_string()),
+    }
+}
+
+#[test]
+fn merge_live_node_info_overlays_status_and_version_by_name() {
+    let mut nodes = vec![container_node("dev-worker"), container_node("dev-worker-2")];
+    merge_live_node_info(&mut nodes, &parse_live_nodes(NODES_JSON).unwrap());
+
+    assert_eq!(nodes[0].status, "NotReady,SchedulingDisabled");
+    assert_eq!(nodes[0].version, "v1.36.1");
+    // Container-derived fields are kept
+    assert_eq!(nodes[0].container_id.as_deref(), Some("dev-worker"));
+    assert_eq!(nodes[0].ip_address.as_deref(), Some("192.168.64.9"));
+    // Nodes the API doesn't report keep their container state
+    assert_eq!(nodes[1].status, "running");
+    assert_eq!(nodes[1].version, "unknown");
+}
+
+fn node(name: &str, status: &str) -> NodeInfo {
+    NodeInfo {
+        name: name.to_string(),
+        role: NodeRole::Worker,
+        status: status.to_string(),
+        version: "v1.36.1".to_string(),
+        container_id: None,
+        ip_address: None,
+    }
+}
+
+fn phases(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(pod, phase)| (pod.to_string(), phase.to_string()))
+        .collect()
+}
+
+#[test]
+fn readiness_snapshot_counts_ready_nodes_and_running_pods() {
+    let snapshot = ReadinessSnapshot::from_parts(
+        &[
+            node("dev-control-plane", "Ready"),
+            node("dev-worker", "Ready,SchedulingDisabled"),
+            node("dev-worker-2", "NotReady"),
+        ],
+        &phases(&[
+            ("coredns-1", "Running"),
+            ("kube-proxy-x", "Pending"),
+            ("job-1", "Succeeded"),
+        ]),
+    );
+    assert_eq!(snapshot.nodes_ready, 2);
+    assert_eq!(snapshot.pods_running, 2);
+    assert!(!snapshot.is_ready());
+    assert_eq!(
+        snapshot.summary(),
+        "2/3 nodes Ready, 2/3 kube-system pods Running"
+    );
+}
+
+#[test]
+fn readiness_snapshot_requires_nodes_and_pods() {
+    assert!(!ReadinessSnapshot::default().is_ready());
+    assert!(!ReadinessSnapshot::from_parts(&[node("dev-control-plane", "Ready")], &[]).is_ready());
+    assert!(ReadinessSnapshot::from_parts(
+        &[node("dev-control-plane", "Ready")],
+        &phases(&[("etcd-dev-control-plane", "Running")]),
+    )
+    .is_ready());
+}
+
+#[test]
+fn drain_args_skip_daemonsets_and_pass_grace_period() {
+    assert_eq!(
+        drain_args("kina-worker", Some(30), "5m"),
+        [
+            "drain",
+            "kina-worker",
+            "--ignore-daemonsets",
+            "--delete-emptydir-data",
+            "--timeout=5m",
+            "--grace-period=30",
+        ]
+    );
+    assert!(!drain_args("kina-worker", None, "5m")
+        .iter()
+        .any(|a| a.starts_with("--grace-period")));
+}
+
+#[test]
+fn parse_evicted_pods_reads_evicting_lines_once() {
+    let output = "node/kina-worker cordoned
+Warning: ignoring DaemonSet-managed Pods: kube-system/kube-proxy-x2x
+evicting pod default/web-7d4b9-abcde
+evicting pod kube-system/coredns-5d78c-fghij
+evicting pod default/web-7d4b9-abcde
+pod/web-7d4b9-abcde evicted
+node/kina-worker drained
+";
+    assert_eq!(
+        parse_evicted_pods(output),
+        ["default/web-7d4b9-abcde", "kube-system/coredns-5d78c-fghij"]
+    );
+    assert!(parse_evicted_pods("node/kina-worker drained\n").is_empty());
+}
@@ -35,6 +35,7 @@ use kina_cli::core::node_image_builder::{
     resolve_build_args, BuildDecision, DEFAULT_CNI_PLUGINS_VERSION, DEFAULT_CONTAINERD_VERSION,
     DEFAULT_KUBERNETES_VERSION, DEFAULT_RUNC_VERSION,
 };
+use predicates::prelude::*;
 use std::collections::HashMap;
 use std::path::PathBuf;
 
@@ -99,6 +100,23 @@ fn build_subcommand_appears_in_top_level_help() {
     );
 }
 
+/// `--kubernetes-version` goes through the same validation as `kina create`
+#[test]
+fn build_node_image_rejects_unsupported_kubernetes_version() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args([
+        "build",
+        "node-image",
+        "--tag",
+        "kina/node:test",
+        "--kubernetes-version",
+        "v1.20.0",
+    ]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "unsupported Kubernetes version v1.20",
+    ));
+}
+
 /// AC1-3: omitting --arch yields arch=arm64 in parsed BuildNodeImageArgs
 #[test]
 fn build_node_image_arch_defaults_to_arm64() {
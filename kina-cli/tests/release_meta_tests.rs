@@ -305,7 +305,7 @@ fn release_workflow_actions_are_sha_pinned() {
             line.trim()
         );
 
-        let ref_part = uses_value.split('@').last().unwrap_or("");
+        let ref_part = uses_value.split('@').next_back().unwrap_or("");
         // SHA-pinned: exactly 40 lowercase hex characters
         assert!(
             ref_part.len() == 40 && ref_part.chars().all(|c| c.is_ascii_hexdigit()),
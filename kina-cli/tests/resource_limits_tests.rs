@@ -25,10 +25,10 @@
 use assert_cmd::Command;
 use kina_cli::config::Config;
 use kina_cli::core::apple_container::{
-    node_resource_args, resolve_cpus, resolve_memory, validate_resources, DEFAULT_NODE_CPUS,
-    DEFAULT_NODE_MEMORY,
+    container_memory_arg, node_resource_args, parse_cpu_limit, resolve_cpus, resolve_memory,
+    validate_resources, DEFAULT_NODE_CPUS, DEFAULT_NODE_MEMORY,
 };
-use kina_cli::core::types::CreateClusterOptions;
+use kina_cli::core::types::{ContainerRuntime, CreateClusterOptions};
 use predicates::prelude::*;
 
 // ===========================================================================
@@ -678,8 +678,38 @@ fn create_cluster_options_has_resource_fields() {
         workers: None,
         control_plane_nodes: None,
         wait_timeout: None,
+        wait_for_ready: false,
+        no_wait: false,
         retain_on_failure: false,
         skip_csr_approval: false,
+        skip_kubeconfig: false,
+        oidc: None,
+        audit_log: false,
+        audit_policy: None,
+        pod_security_standard: None,
+        node_image_pull_always: false,
+        skip_cni: false,
+        networking: None,
+        api_server_extra_args: Default::default(),
+        kubelet_extra_args: Default::default(),
+        controller_manager_extra_args: Default::default(),
+        control_plane_vip: None,
+        etcd_extra_args: Default::default(),
+        node_labels: Default::default(),
+        image_pull_secret: None,
+        pull_secret_config: None,
+        insecure_registries: Vec::new(),
+        bootstrap_token: None,
+        scheduler_config_file: None,
+        containerd_config: None,
+        kubelet_config_file: None,
+        api_server_sans: Vec::new(),
+        container_runtime: ContainerRuntime::default(),
+        disable_swap_check: false,
+        skip_preflight: false,
+        api_server_port: None,
+        node_name: None,
+        etcd: None,
         cni_plugin: kina_cli::config::CniPlugin::Ptp,
         node_kernel_path: None,
         control_plane_cpus: 4u32,
@@ -705,3 +735,55 @@ fn create_cluster_options_has_resource_fields() {
         "CreateClusterOptions must have worker_memory: String field"
     );
 }
+
+// ===========================================================================
+// Group K — --cpu / --memory / --storage limits
+// ===========================================================================
+
+/// `<number>[KMG]i?` sizes are converted to the `<n><m|g>` form `container run` takes,
+/// and the converted value passes `validate_resources`.
+#[test]
+fn container_memory_arg_normalises_units() {
+    assert_eq!(container_memory_arg("4Gi"), "4g");
+    assert_eq!(container_memory_arg("4G"), "4g");
+    assert_eq!(container_memory_arg("512Mi"), "512m");
+    assert_eq!(container_memory_arg("2048K"), "2m");
+    assert_eq!(container_memory_arg("1500Ki"), "2m");
+    assert!(validate_resources(4, &container_memory_arg("8Gi")).is_ok());
+}
+
+/// A cluster config `cpuLimit` must be a whole number of CPUs.
+#[test]
+fn parse_cpu_limit_requires_whole_cpus() {
+    assert_eq!(parse_cpu_limit("2").unwrap(), 2);
+    assert_eq!(parse_cpu_limit(" 8 ").unwrap(), 8);
+    assert!(parse_cpu_limit("1.5").is_err());
+    assert!(parse_cpu_limit("two").is_err());
+}
+
+/// `--cpu` is an alias for `--cpus`, and `--storage` is registered.
+#[test]
+fn create_args_accepts_cpu_alias_and_storage_flag() {
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["create", "--help"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--cpu"))
+        .stdout(predicate::str::contains("--storage"));
+}
+
+/// An invalid `--storage` value is rejected before any container is created.
+#[test]
+fn create_args_rejects_invalid_storage_value() {
+    let config_path = std::env::current_dir()
+        .expect("Failed to get current directory")
+        .join("tests/fixtures/test-config.toml")
+        .to_string_lossy()
+        .to_string();
+
+    let mut cmd = Command::cargo_bin("kina").unwrap();
+    cmd.args(["--config", &config_path, "create", "--storage", "lots"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("storage"));
+}